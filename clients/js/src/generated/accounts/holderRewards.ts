@@ -13,12 +13,18 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Account,
   type Address,
   type Codec,
@@ -30,32 +36,84 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import {
+  getAccountHeaderDecoder,
+  getAccountHeaderEncoder,
+  getRewardStreamCheckpointDecoder,
+  getRewardStreamCheckpointEncoder,
+  type AccountHeader,
+  type AccountHeaderArgs,
+  type RewardStreamCheckpoint,
+  type RewardStreamCheckpointArgs,
+} from '../types';
 
 export type HolderRewards = {
+  header: AccountHeader;
   lastAccumulatedRewardsPerToken: bigint;
   unharvestedRewards: bigint;
-  padding: bigint;
+  lastSeenBalance: bigint;
+  rewardStreamCheckpoints: Array<RewardStreamCheckpoint>;
+  autoClaimThreshold: bigint;
+  recipient: Address;
+  mint: Address;
+  owner: Address;
+  excluded: number;
+  lastSeenBalanceUnknown: number;
+  padding: Array<number>;
 };
 
 export type HolderRewardsArgs = {
+  header: AccountHeaderArgs;
   lastAccumulatedRewardsPerToken: number | bigint;
   unharvestedRewards: number | bigint;
-  padding: number | bigint;
+  lastSeenBalance: number | bigint;
+  rewardStreamCheckpoints: Array<RewardStreamCheckpointArgs>;
+  autoClaimThreshold: number | bigint;
+  recipient: Address;
+  mint: Address;
+  owner: Address;
+  excluded: number;
+  lastSeenBalanceUnknown: number;
+  padding: Array<number>;
 };
 
 export function getHolderRewardsEncoder(): Encoder<HolderRewardsArgs> {
   return getStructEncoder([
+    ['header', getAccountHeaderEncoder()],
     ['lastAccumulatedRewardsPerToken', getU128Encoder()],
     ['unharvestedRewards', getU64Encoder()],
-    ['padding', getU64Encoder()],
+    ['lastSeenBalance', getU64Encoder()],
+    [
+      'rewardStreamCheckpoints',
+      getArrayEncoder(getRewardStreamCheckpointEncoder(), { size: 4 }),
+    ],
+    ['autoClaimThreshold', getU64Encoder()],
+    ['recipient', getAddressEncoder()],
+    ['mint', getAddressEncoder()],
+    ['owner', getAddressEncoder()],
+    ['excluded', getU8Encoder()],
+    ['lastSeenBalanceUnknown', getU8Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 6 })],
   ]);
 }
 
 export function getHolderRewardsDecoder(): Decoder<HolderRewards> {
   return getStructDecoder([
+    ['header', getAccountHeaderDecoder()],
     ['lastAccumulatedRewardsPerToken', getU128Decoder()],
     ['unharvestedRewards', getU64Decoder()],
-    ['padding', getU64Decoder()],
+    ['lastSeenBalance', getU64Decoder()],
+    [
+      'rewardStreamCheckpoints',
+      getArrayDecoder(getRewardStreamCheckpointDecoder(), { size: 4 }),
+    ],
+    ['autoClaimThreshold', getU64Decoder()],
+    ['recipient', getAddressDecoder()],
+    ['mint', getAddressDecoder()],
+    ['owner', getAddressDecoder()],
+    ['excluded', getU8Decoder()],
+    ['lastSeenBalanceUnknown', getU8Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 6 })],
   ]);
 }
 
@@ -124,5 +182,5 @@ export async function fetchAllMaybeHolderRewards(
 }
 
 export function getHolderRewardsSize(): number {
-  return 32;
+  return 288;
 }
@@ -13,10 +13,16 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
   type Account,
   type Address,
   type Codec,
@@ -28,19 +34,111 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import {
+  getAccountHeaderDecoder,
+  getAccountHeaderEncoder,
+  getEmissionScheduleDecoder,
+  getEmissionScheduleEncoder,
+  getRewardStreamDecoder,
+  getRewardStreamEncoder,
+  type AccountHeader,
+  type AccountHeaderArgs,
+  type EmissionSchedule,
+  type EmissionScheduleArgs,
+  type RewardStream,
+  type RewardStreamArgs,
+} from '../types';
 
-export type HolderRewardsPool = { accumulatedRewardsPerToken: bigint };
+export type HolderRewardsPool = {
+  header: AccountHeader;
+  accumulatedRewardsPerToken: bigint;
+  authority: Address;
+  pendingAuthority: Address;
+  rewardStreams: Array<RewardStream>;
+  emissionSchedule: EmissionSchedule;
+  totalDistributed: bigint;
+  totalHarvested: bigint;
+  distributionCount: bigint;
+  lastDistributionSlot: bigint;
+  undistributedCarryover: bigint;
+  rewardsPerTokenRemainder: bigint;
+  excludedSupply: bigint;
+  excludedHolders: bigint;
+  dust: bigint;
+  compoundPrograms: Array<Address>;
+  distributionAuthority: Address;
+  minDistributionAmount: bigint;
+  dustRemainder: bigint;
+};
 
 export type HolderRewardsPoolArgs = {
+  header: AccountHeaderArgs;
   accumulatedRewardsPerToken: number | bigint;
+  authority: Address;
+  pendingAuthority: Address;
+  rewardStreams: Array<RewardStreamArgs>;
+  emissionSchedule: EmissionScheduleArgs;
+  totalDistributed: number | bigint;
+  totalHarvested: number | bigint;
+  distributionCount: number | bigint;
+  lastDistributionSlot: number | bigint;
+  undistributedCarryover: number | bigint;
+  rewardsPerTokenRemainder: number | bigint;
+  excludedSupply: number | bigint;
+  excludedHolders: number | bigint;
+  dust: number | bigint;
+  compoundPrograms: Array<Address>;
+  distributionAuthority: Address;
+  minDistributionAmount: number | bigint;
+  dustRemainder: number | bigint;
 };
 
 export function getHolderRewardsPoolEncoder(): Encoder<HolderRewardsPoolArgs> {
-  return getStructEncoder([['accumulatedRewardsPerToken', getU128Encoder()]]);
+  return getStructEncoder([
+    ['header', getAccountHeaderEncoder()],
+    ['accumulatedRewardsPerToken', getU128Encoder()],
+    ['authority', getAddressEncoder()],
+    ['pendingAuthority', getAddressEncoder()],
+    ['rewardStreams', getArrayEncoder(getRewardStreamEncoder(), { size: 4 })],
+    ['emissionSchedule', getEmissionScheduleEncoder()],
+    ['totalDistributed', getU64Encoder()],
+    ['totalHarvested', getU64Encoder()],
+    ['distributionCount', getU64Encoder()],
+    ['lastDistributionSlot', getU64Encoder()],
+    ['undistributedCarryover', getU64Encoder()],
+    ['rewardsPerTokenRemainder', getU64Encoder()],
+    ['excludedSupply', getU64Encoder()],
+    ['excludedHolders', getU64Encoder()],
+    ['dust', getU64Encoder()],
+    ['compoundPrograms', getArrayEncoder(getAddressEncoder(), { size: 4 })],
+    ['distributionAuthority', getAddressEncoder()],
+    ['minDistributionAmount', getU64Encoder()],
+    ['dustRemainder', getU64Encoder()],
+  ]);
 }
 
 export function getHolderRewardsPoolDecoder(): Decoder<HolderRewardsPool> {
-  return getStructDecoder([['accumulatedRewardsPerToken', getU128Decoder()]]);
+  return getStructDecoder([
+    ['header', getAccountHeaderDecoder()],
+    ['accumulatedRewardsPerToken', getU128Decoder()],
+    ['authority', getAddressDecoder()],
+    ['pendingAuthority', getAddressDecoder()],
+    ['rewardStreams', getArrayDecoder(getRewardStreamDecoder(), { size: 4 })],
+    ['emissionSchedule', getEmissionScheduleDecoder()],
+    ['totalDistributed', getU64Decoder()],
+    ['totalHarvested', getU64Decoder()],
+    ['distributionCount', getU64Decoder()],
+    ['lastDistributionSlot', getU64Decoder()],
+    ['undistributedCarryover', getU64Decoder()],
+    ['rewardsPerTokenRemainder', getU64Decoder()],
+    ['excludedSupply', getU64Decoder()],
+    ['excludedHolders', getU64Decoder()],
+    ['dust', getU64Decoder()],
+    ['compoundPrograms', getArrayDecoder(getAddressDecoder(), { size: 4 })],
+    ['distributionAuthority', getAddressDecoder()],
+    ['minDistributionAmount', getU64Decoder()],
+    ['dustRemainder', getU64Decoder()],
+  ]);
 }
 
 export function getHolderRewardsPoolCodec(): Codec<
@@ -117,5 +215,5 @@ export async function fetchAllMaybeHolderRewardsPool(
 }
 
 export function getHolderRewardsPoolSize(): number {
-  return 16;
+  return 568;
 }
@@ -8,3 +8,5 @@
 
 export * from './holderRewards';
 export * from './holderRewardsPool';
+export * from './tokenHolderRewards';
+export * from './tokenRewardsPool';
@@ -0,0 +1,137 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type TokenHolderRewards = {
+  lastAccumulatedRewardsPerToken: bigint;
+  unharvestedRewards: bigint;
+  lastSeenBalance: bigint;
+};
+
+export type TokenHolderRewardsArgs = {
+  lastAccumulatedRewardsPerToken: number | bigint;
+  unharvestedRewards: number | bigint;
+  lastSeenBalance: number | bigint;
+};
+
+export function getTokenHolderRewardsEncoder(): Encoder<TokenHolderRewardsArgs> {
+  return getStructEncoder([
+    ['lastAccumulatedRewardsPerToken', getU128Encoder()],
+    ['unharvestedRewards', getU64Encoder()],
+    ['lastSeenBalance', getU64Encoder()],
+  ]);
+}
+
+export function getTokenHolderRewardsDecoder(): Decoder<TokenHolderRewards> {
+  return getStructDecoder([
+    ['lastAccumulatedRewardsPerToken', getU128Decoder()],
+    ['unharvestedRewards', getU64Decoder()],
+    ['lastSeenBalance', getU64Decoder()],
+  ]);
+}
+
+export function getTokenHolderRewardsCodec(): Codec<
+  TokenHolderRewardsArgs,
+  TokenHolderRewards
+> {
+  return combineCodec(
+    getTokenHolderRewardsEncoder(),
+    getTokenHolderRewardsDecoder()
+  );
+}
+
+export function decodeTokenHolderRewards<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<TokenHolderRewards, TAddress>;
+export function decodeTokenHolderRewards<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<TokenHolderRewards, TAddress>;
+export function decodeTokenHolderRewards<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<TokenHolderRewards, TAddress>
+  | MaybeAccount<TokenHolderRewards, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getTokenHolderRewardsDecoder()
+  );
+}
+
+export async function fetchTokenHolderRewards<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<TokenHolderRewards, TAddress>> {
+  const maybeAccount = await fetchMaybeTokenHolderRewards(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeTokenHolderRewards<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<TokenHolderRewards, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeTokenHolderRewards(maybeAccount);
+}
+
+export async function fetchAllTokenHolderRewards(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<TokenHolderRewards>[]> {
+  const maybeAccounts = await fetchAllMaybeTokenHolderRewards(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeTokenHolderRewards(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<TokenHolderRewards>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeTokenHolderRewards(maybeAccount)
+  );
+}
+
+export function getTokenHolderRewardsSize(): number {
+  return 32;
+}
@@ -0,0 +1,151 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type TokenRewardsPool = {
+  accumulatedRewardsPerToken: bigint;
+  rewardMint: Address;
+  rewardTokenProgram: Address;
+  rewardsPerTokenRemainder: bigint;
+  padding: Array<number>;
+};
+
+export type TokenRewardsPoolArgs = {
+  accumulatedRewardsPerToken: number | bigint;
+  rewardMint: Address;
+  rewardTokenProgram: Address;
+  rewardsPerTokenRemainder: number | bigint;
+  padding: Array<number>;
+};
+
+export function getTokenRewardsPoolEncoder(): Encoder<TokenRewardsPoolArgs> {
+  return getStructEncoder([
+    ['accumulatedRewardsPerToken', getU128Encoder()],
+    ['rewardMint', getAddressEncoder()],
+    ['rewardTokenProgram', getAddressEncoder()],
+    ['rewardsPerTokenRemainder', getU64Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 8 })],
+  ]);
+}
+
+export function getTokenRewardsPoolDecoder(): Decoder<TokenRewardsPool> {
+  return getStructDecoder([
+    ['accumulatedRewardsPerToken', getU128Decoder()],
+    ['rewardMint', getAddressDecoder()],
+    ['rewardTokenProgram', getAddressDecoder()],
+    ['rewardsPerTokenRemainder', getU64Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 8 })],
+  ]);
+}
+
+export function getTokenRewardsPoolCodec(): Codec<
+  TokenRewardsPoolArgs,
+  TokenRewardsPool
+> {
+  return combineCodec(
+    getTokenRewardsPoolEncoder(),
+    getTokenRewardsPoolDecoder()
+  );
+}
+
+export function decodeTokenRewardsPool<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<TokenRewardsPool, TAddress>;
+export function decodeTokenRewardsPool<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<TokenRewardsPool, TAddress>;
+export function decodeTokenRewardsPool<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<TokenRewardsPool, TAddress>
+  | MaybeAccount<TokenRewardsPool, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getTokenRewardsPoolDecoder()
+  );
+}
+
+export async function fetchTokenRewardsPool<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<TokenRewardsPool, TAddress>> {
+  const maybeAccount = await fetchMaybeTokenRewardsPool(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeTokenRewardsPool<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<TokenRewardsPool, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeTokenRewardsPool(maybeAccount);
+}
+
+export async function fetchAllTokenRewardsPool(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<TokenRewardsPool>[]> {
+  const maybeAccounts = await fetchAllMaybeTokenRewardsPool(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeTokenRewardsPool(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<TokenRewardsPool>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeTokenRewardsPool(maybeAccount)
+  );
+}
+
+export function getTokenRewardsPoolSize(): number {
+  return 96;
+}
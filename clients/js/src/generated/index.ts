@@ -9,3 +9,4 @@
 export * from './accounts';
 export * from './instructions';
 export * from './programs';
+export * from './types';
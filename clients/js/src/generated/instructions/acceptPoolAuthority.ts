@@ -0,0 +1,195 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type AcceptPoolAuthorityInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountPendingPoolAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountPendingPoolAuthority extends string
+        ? ReadonlySignerAccount<TAccountPendingPoolAuthority> &
+            IAccountSignerMeta<TAccountPendingPoolAuthority>
+        : TAccountPendingPoolAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AcceptPoolAuthorityInstructionData = { discriminator: number };
+
+export type AcceptPoolAuthorityInstructionDataArgs = {};
+
+export function getAcceptPoolAuthorityInstructionDataEncoder(): Encoder<AcceptPoolAuthorityInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 6 })
+  );
+}
+
+export function getAcceptPoolAuthorityInstructionDataDecoder(): Decoder<AcceptPoolAuthorityInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getAcceptPoolAuthorityInstructionDataCodec(): Codec<
+  AcceptPoolAuthorityInstructionDataArgs,
+  AcceptPoolAuthorityInstructionData
+> {
+  return combineCodec(
+    getAcceptPoolAuthorityInstructionDataEncoder(),
+    getAcceptPoolAuthorityInstructionDataDecoder()
+  );
+}
+
+export type AcceptPoolAuthorityInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountPendingPoolAuthority extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pending pool authority. */
+  pendingPoolAuthority: TransactionSigner<TAccountPendingPoolAuthority>;
+};
+
+export function getAcceptPoolAuthorityInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountPendingPoolAuthority extends string,
+>(
+  input: AcceptPoolAuthorityInput<
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountPendingPoolAuthority
+  >
+): AcceptPoolAuthorityInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountPendingPoolAuthority
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    pendingPoolAuthority: {
+      value: input.pendingPoolAuthority ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.pendingPoolAuthority),
+    ],
+    programAddress,
+    data: getAcceptPoolAuthorityInstructionDataEncoder().encode({}),
+  } as AcceptPoolAuthorityInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountPendingPoolAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedAcceptPoolAuthorityInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Token mint. */
+    mint: TAccountMetas[1];
+    /** Pending pool authority. */
+    pendingPoolAuthority: TAccountMetas[2];
+  };
+  data: AcceptPoolAuthorityInstructionData;
+};
+
+export function parseAcceptPoolAuthorityInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAcceptPoolAuthorityInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      pendingPoolAuthority: getNextAccount(),
+    },
+    data: getAcceptPoolAuthorityInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
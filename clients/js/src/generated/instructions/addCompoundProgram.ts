@@ -0,0 +1,209 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type AddCompoundProgramInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountPoolAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountPoolAuthority extends string
+        ? ReadonlySignerAccount<TAccountPoolAuthority> &
+            IAccountSignerMeta<TAccountPoolAuthority>
+        : TAccountPoolAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AddCompoundProgramInstructionData = {
+  discriminator: number;
+  pubkey: Address;
+};
+
+export type AddCompoundProgramInstructionDataArgs = { pubkey: Address };
+
+export function getAddCompoundProgramInstructionDataEncoder(): Encoder<AddCompoundProgramInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['pubkey', getAddressEncoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 25 })
+  );
+}
+
+export function getAddCompoundProgramInstructionDataDecoder(): Decoder<AddCompoundProgramInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['pubkey', getAddressDecoder()],
+  ]);
+}
+
+export function getAddCompoundProgramInstructionDataCodec(): Codec<
+  AddCompoundProgramInstructionDataArgs,
+  AddCompoundProgramInstructionData
+> {
+  return combineCodec(
+    getAddCompoundProgramInstructionDataEncoder(),
+    getAddCompoundProgramInstructionDataDecoder()
+  );
+}
+
+export type AddCompoundProgramInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountPoolAuthority extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  poolAuthority: TransactionSigner<TAccountPoolAuthority>;
+  pubkey: AddCompoundProgramInstructionDataArgs['pubkey'];
+};
+
+export function getAddCompoundProgramInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountPoolAuthority extends string,
+>(
+  input: AddCompoundProgramInput<
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountPoolAuthority
+  >
+): AddCompoundProgramInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountPoolAuthority
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    poolAuthority: { value: input.poolAuthority ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.poolAuthority),
+    ],
+    programAddress,
+    data: getAddCompoundProgramInstructionDataEncoder().encode(
+      args as AddCompoundProgramInstructionDataArgs
+    ),
+  } as AddCompoundProgramInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountPoolAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedAddCompoundProgramInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Token mint. */
+    mint: TAccountMetas[1];
+    /** Pool authority. */
+    poolAuthority: TAccountMetas[2];
+  };
+  data: AddCompoundProgramInstructionData;
+};
+
+export function parseAddCompoundProgramInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAddCompoundProgramInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      poolAuthority: getNextAccount(),
+    },
+    data: getAddCompoundProgramInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,207 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type AddRewardStreamInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountPoolAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountPoolAuthority extends string
+        ? ReadonlySignerAccount<TAccountPoolAuthority> &
+            IAccountSignerMeta<TAccountPoolAuthority>
+        : TAccountPoolAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type AddRewardStreamInstructionData = {
+  discriminator: number;
+  args: bigint;
+};
+
+export type AddRewardStreamInstructionDataArgs = { args: number | bigint };
+
+export function getAddRewardStreamInstructionDataEncoder(): Encoder<AddRewardStreamInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['args', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 13 })
+  );
+}
+
+export function getAddRewardStreamInstructionDataDecoder(): Decoder<AddRewardStreamInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['args', getU64Decoder()],
+  ]);
+}
+
+export function getAddRewardStreamInstructionDataCodec(): Codec<
+  AddRewardStreamInstructionDataArgs,
+  AddRewardStreamInstructionData
+> {
+  return combineCodec(
+    getAddRewardStreamInstructionDataEncoder(),
+    getAddRewardStreamInstructionDataDecoder()
+  );
+}
+
+export type AddRewardStreamInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountPoolAuthority extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  poolAuthority: TransactionSigner<TAccountPoolAuthority>;
+  args: AddRewardStreamInstructionDataArgs['args'];
+};
+
+export function getAddRewardStreamInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountPoolAuthority extends string,
+>(
+  input: AddRewardStreamInput<
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountPoolAuthority
+  >
+): AddRewardStreamInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountPoolAuthority
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    poolAuthority: { value: input.poolAuthority ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.poolAuthority),
+    ],
+    programAddress,
+    data: getAddRewardStreamInstructionDataEncoder().encode(
+      args as AddRewardStreamInstructionDataArgs
+    ),
+  } as AddRewardStreamInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountPoolAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedAddRewardStreamInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Token mint. */
+    mint: TAccountMetas[1];
+    /** Pool authority. */
+    poolAuthority: TAccountMetas[2];
+  };
+  data: AddRewardStreamInstructionData;
+};
+
+export function parseAddRewardStreamInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedAddRewardStreamInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      poolAuthority: getNextAccount(),
+    },
+    data: getAddRewardStreamInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,262 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type CloseHolderRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountDestination extends string | IAccountMeta<string> = string,
+  TAccountRecipient extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountTokenAccount extends string
+        ? WritableAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountOwner extends string
+        ? ReadonlySignerAccount<TAccountOwner> &
+            IAccountSignerMeta<TAccountOwner>
+        : TAccountOwner,
+      TAccountDestination extends string
+        ? WritableAccount<TAccountDestination>
+        : TAccountDestination,
+      TAccountRecipient extends string
+        ? WritableAccount<TAccountRecipient>
+        : TAccountRecipient,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CloseHolderRewardsInstructionData = { discriminator: number };
+
+export type CloseHolderRewardsInstructionDataArgs = {};
+
+export function getCloseHolderRewardsInstructionDataEncoder(): Encoder<CloseHolderRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 7 })
+  );
+}
+
+export function getCloseHolderRewardsInstructionDataDecoder(): Decoder<CloseHolderRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getCloseHolderRewardsInstructionDataCodec(): Codec<
+  CloseHolderRewardsInstructionDataArgs,
+  CloseHolderRewardsInstructionData
+> {
+  return combineCodec(
+    getCloseHolderRewardsInstructionDataEncoder(),
+    getCloseHolderRewardsInstructionDataDecoder()
+  );
+}
+
+export type CloseHolderRewardsInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountOwner extends string = string,
+  TAccountDestination extends string = string,
+  TAccountRecipient extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Token account owner. */
+  owner: TransactionSigner<TAccountOwner>;
+  /** Destination account. */
+  destination: Address<TAccountDestination>;
+  /** Rewards recipient. */
+  recipient?: Address<TAccountRecipient>;
+};
+
+export function getCloseHolderRewardsInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountOwner extends string,
+  TAccountDestination extends string,
+  TAccountRecipient extends string,
+>(
+  input: CloseHolderRewardsInput<
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountOwner,
+    TAccountDestination,
+    TAccountRecipient
+  >
+): CloseHolderRewardsInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountOwner,
+  TAccountDestination,
+  TAccountRecipient
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: false },
+    destination: { value: input.destination ?? null, isWritable: true },
+    recipient: { value: input.recipient ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.recipient),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
+    programAddress,
+    data: getCloseHolderRewardsInstructionDataEncoder().encode({}),
+  } as CloseHolderRewardsInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountOwner,
+    TAccountDestination,
+    TAccountRecipient
+  >;
+
+  return instruction;
+}
+
+export type ParsedCloseHolderRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[1];
+    /** Token account. */
+    tokenAccount: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Token account owner. */
+    owner: TAccountMetas[4];
+    /** Destination account. */
+    destination: TAccountMetas[5];
+    /** Rewards recipient. */
+    recipient?: TAccountMetas[6] | undefined;
+  };
+  data: CloseHolderRewardsInstructionData;
+};
+
+export function parseCloseHolderRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCloseHolderRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  let optionalAccountsRemaining = instruction.accounts.length - 6;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      holderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      owner: getNextAccount(),
+      destination: getNextAccount(),
+      recipient: getNextOptionalAccount(),
+    },
+    data: getCloseHolderRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
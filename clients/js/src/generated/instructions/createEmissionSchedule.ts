@@ -0,0 +1,265 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getI64Decoder,
+  getI64Encoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type CreateEmissionScheduleInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountPoolAuthority extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountPoolAuthority extends string
+        ? ReadonlySignerAccount<TAccountPoolAuthority> &
+            IAccountSignerMeta<TAccountPoolAuthority>
+        : TAccountPoolAuthority,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type CreateEmissionScheduleInstructionData = {
+  discriminator: number;
+  amount: bigint;
+  startTimestamp: bigint;
+  endTimestamp: bigint;
+};
+
+export type CreateEmissionScheduleInstructionDataArgs = {
+  amount: number | bigint;
+  startTimestamp: number | bigint;
+  endTimestamp: number | bigint;
+};
+
+export function getCreateEmissionScheduleInstructionDataEncoder(): Encoder<CreateEmissionScheduleInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+      ['startTimestamp', getI64Encoder()],
+      ['endTimestamp', getI64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 16 })
+  );
+}
+
+export function getCreateEmissionScheduleInstructionDataDecoder(): Decoder<CreateEmissionScheduleInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+    ['startTimestamp', getI64Decoder()],
+    ['endTimestamp', getI64Decoder()],
+  ]);
+}
+
+export function getCreateEmissionScheduleInstructionDataCodec(): Codec<
+  CreateEmissionScheduleInstructionDataArgs,
+  CreateEmissionScheduleInstructionData
+> {
+  return combineCodec(
+    getCreateEmissionScheduleInstructionDataEncoder(),
+    getCreateEmissionScheduleInstructionDataDecoder()
+  );
+}
+
+export type CreateEmissionScheduleInput<
+  TAccountPayer extends string = string,
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountPoolAuthority extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Payer account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  poolAuthority: TransactionSigner<TAccountPoolAuthority>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  amount: CreateEmissionScheduleInstructionDataArgs['amount'];
+  startTimestamp: CreateEmissionScheduleInstructionDataArgs['startTimestamp'];
+  endTimestamp: CreateEmissionScheduleInstructionDataArgs['endTimestamp'];
+};
+
+export function getCreateEmissionScheduleInstruction<
+  TAccountPayer extends string,
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountPoolAuthority extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: CreateEmissionScheduleInput<
+    TAccountPayer,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountPoolAuthority,
+    TAccountSystemProgram
+  >
+): CreateEmissionScheduleInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountPayer,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountPoolAuthority,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    poolAuthority: { value: input.poolAuthority ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.poolAuthority),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getCreateEmissionScheduleInstructionDataEncoder().encode(
+      args as CreateEmissionScheduleInstructionDataArgs
+    ),
+  } as CreateEmissionScheduleInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountPoolAuthority,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedCreateEmissionScheduleInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Payer account. */
+    payer: TAccountMetas[0];
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** Pool authority. */
+    poolAuthority: TAccountMetas[3];
+    /** System program. */
+    systemProgram: TAccountMetas[4];
+  };
+  data: CreateEmissionScheduleInstructionData;
+};
+
+export function parseCreateEmissionScheduleInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedCreateEmissionScheduleInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 5) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      payer: getNextAccount(),
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      poolAuthority: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getCreateEmissionScheduleInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,199 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type DepositRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountDistributionAuthority extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountDistributionAuthority extends string
+        ? ReadonlySignerAccount<TAccountDistributionAuthority> &
+            IAccountSignerMeta<TAccountDistributionAuthority>
+        : TAccountDistributionAuthority,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type DepositRewardsInstructionData = { discriminator: number };
+
+export type DepositRewardsInstructionDataArgs = {};
+
+export function getDepositRewardsInstructionDataEncoder(): Encoder<DepositRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 29 })
+  );
+}
+
+export function getDepositRewardsInstructionDataDecoder(): Decoder<DepositRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getDepositRewardsInstructionDataCodec(): Codec<
+  DepositRewardsInstructionDataArgs,
+  DepositRewardsInstructionData
+> {
+  return combineCodec(
+    getDepositRewardsInstructionDataEncoder(),
+    getDepositRewardsInstructionDataDecoder()
+  );
+}
+
+export type DepositRewardsInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountDistributionAuthority extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Distribution authority. */
+  distributionAuthority?: TransactionSigner<TAccountDistributionAuthority>;
+};
+
+export function getDepositRewardsInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountDistributionAuthority extends string,
+>(
+  input: DepositRewardsInput<
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountDistributionAuthority
+  >
+): DepositRewardsInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountDistributionAuthority
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    distributionAuthority: {
+      value: input.distributionAuthority ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.distributionAuthority),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
+    programAddress,
+    data: getDepositRewardsInstructionDataEncoder().encode({}),
+  } as DepositRewardsInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountDistributionAuthority
+  >;
+
+  return instruction;
+}
+
+export type ParsedDepositRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Token mint. */
+    mint: TAccountMetas[1];
+    /** Distribution authority. */
+    distributionAuthority?: TAccountMetas[2] | undefined;
+  };
+  data: DepositRewardsInstructionData;
+};
+
+export function parseDepositRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedDepositRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  let optionalAccountsRemaining = instruction.accounts.length - 2;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      distributionAuthority: getNextOptionalAccount(),
+    },
+    data: getDepositRewardsInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,240 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type DistributeStreamRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type DistributeStreamRewardsInstructionData = {
+  discriminator: number;
+  rewardId: bigint;
+  amount: bigint;
+};
+
+export type DistributeStreamRewardsInstructionDataArgs = {
+  rewardId: number | bigint;
+  amount: number | bigint;
+};
+
+export function getDistributeStreamRewardsInstructionDataEncoder(): Encoder<DistributeStreamRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['rewardId', getU64Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 14 })
+  );
+}
+
+export function getDistributeStreamRewardsInstructionDataDecoder(): Decoder<DistributeStreamRewardsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['rewardId', getU64Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getDistributeStreamRewardsInstructionDataCodec(): Codec<
+  DistributeStreamRewardsInstructionDataArgs,
+  DistributeStreamRewardsInstructionData
+> {
+  return combineCodec(
+    getDistributeStreamRewardsInstructionDataEncoder(),
+    getDistributeStreamRewardsInstructionDataDecoder()
+  );
+}
+
+export type DistributeStreamRewardsInput<
+  TAccountPayer extends string = string,
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Payer account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  rewardId: DistributeStreamRewardsInstructionDataArgs['rewardId'];
+  amount: DistributeStreamRewardsInstructionDataArgs['amount'];
+};
+
+export function getDistributeStreamRewardsInstruction<
+  TAccountPayer extends string,
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountSystemProgram extends string,
+>(
+  input: DistributeStreamRewardsInput<
+    TAccountPayer,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountSystemProgram
+  >
+): DistributeStreamRewardsInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountPayer,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getDistributeStreamRewardsInstructionDataEncoder().encode(
+      args as DistributeStreamRewardsInstructionDataArgs
+    ),
+  } as DistributeStreamRewardsInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedDistributeStreamRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Payer account. */
+    payer: TAccountMetas[0];
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** System program. */
+    systemProgram: TAccountMetas[3];
+  };
+  data: DistributeStreamRewardsInstructionData;
+};
+
+export function parseDistributeStreamRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedDistributeStreamRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      payer: getNextAccount(),
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getDistributeStreamRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,300 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type DistributeTokenRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountSourceAuthority extends string | IAccountMeta<string> = string,
+  TAccountSource extends string | IAccountMeta<string> = string,
+  TAccountTokenRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountRewardMint extends string | IAccountMeta<string> = string,
+  TAccountRewardTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountSourceAuthority extends string
+        ? ReadonlySignerAccount<TAccountSourceAuthority> &
+            IAccountSignerMeta<TAccountSourceAuthority>
+        : TAccountSourceAuthority,
+      TAccountSource extends string
+        ? WritableAccount<TAccountSource>
+        : TAccountSource,
+      TAccountTokenRewardsPool extends string
+        ? WritableAccount<TAccountTokenRewardsPool>
+        : TAccountTokenRewardsPool,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountRewardMint extends string
+        ? ReadonlyAccount<TAccountRewardMint>
+        : TAccountRewardMint,
+      TAccountRewardTokenProgram extends string
+        ? ReadonlyAccount<TAccountRewardTokenProgram>
+        : TAccountRewardTokenProgram,
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type DistributeTokenRewardsInstructionData = {
+  discriminator: number;
+  args: bigint;
+};
+
+export type DistributeTokenRewardsInstructionDataArgs = {
+  args: number | bigint;
+};
+
+export function getDistributeTokenRewardsInstructionDataEncoder(): Encoder<DistributeTokenRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['args', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 10 })
+  );
+}
+
+export function getDistributeTokenRewardsInstructionDataDecoder(): Decoder<DistributeTokenRewardsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['args', getU64Decoder()],
+  ]);
+}
+
+export function getDistributeTokenRewardsInstructionDataCodec(): Codec<
+  DistributeTokenRewardsInstructionDataArgs,
+  DistributeTokenRewardsInstructionData
+> {
+  return combineCodec(
+    getDistributeTokenRewardsInstructionDataEncoder(),
+    getDistributeTokenRewardsInstructionDataDecoder()
+  );
+}
+
+export type DistributeTokenRewardsInput<
+  TAccountSourceAuthority extends string = string,
+  TAccountSource extends string = string,
+  TAccountTokenRewardsPool extends string = string,
+  TAccountVault extends string = string,
+  TAccountMint extends string = string,
+  TAccountRewardMint extends string = string,
+  TAccountRewardTokenProgram extends string = string,
+  TAccountHolderRewardsPool extends string = string,
+> = {
+  /** Source token account owner or delegate. */
+  sourceAuthority: TransactionSigner<TAccountSourceAuthority>;
+  /** Source reward token account. */
+  source: Address<TAccountSource>;
+  /** Token rewards pool account. */
+  tokenRewardsPool: Address<TAccountTokenRewardsPool>;
+  /** Token rewards pool vault. */
+  vault: Address<TAccountVault>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Reward token mint. */
+  rewardMint: Address<TAccountRewardMint>;
+  /** Reward token program. */
+  rewardTokenProgram: Address<TAccountRewardTokenProgram>;
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  args: DistributeTokenRewardsInstructionDataArgs['args'];
+};
+
+export function getDistributeTokenRewardsInstruction<
+  TAccountSourceAuthority extends string,
+  TAccountSource extends string,
+  TAccountTokenRewardsPool extends string,
+  TAccountVault extends string,
+  TAccountMint extends string,
+  TAccountRewardMint extends string,
+  TAccountRewardTokenProgram extends string,
+  TAccountHolderRewardsPool extends string,
+>(
+  input: DistributeTokenRewardsInput<
+    TAccountSourceAuthority,
+    TAccountSource,
+    TAccountTokenRewardsPool,
+    TAccountVault,
+    TAccountMint,
+    TAccountRewardMint,
+    TAccountRewardTokenProgram,
+    TAccountHolderRewardsPool
+  >
+): DistributeTokenRewardsInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountSourceAuthority,
+  TAccountSource,
+  TAccountTokenRewardsPool,
+  TAccountVault,
+  TAccountMint,
+  TAccountRewardMint,
+  TAccountRewardTokenProgram,
+  TAccountHolderRewardsPool
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    sourceAuthority: {
+      value: input.sourceAuthority ?? null,
+      isWritable: false,
+    },
+    source: { value: input.source ?? null, isWritable: true },
+    tokenRewardsPool: {
+      value: input.tokenRewardsPool ?? null,
+      isWritable: true,
+    },
+    vault: { value: input.vault ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    rewardMint: { value: input.rewardMint ?? null, isWritable: false },
+    rewardTokenProgram: {
+      value: input.rewardTokenProgram ?? null,
+      isWritable: false,
+    },
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.sourceAuthority),
+      getAccountMeta(accounts.source),
+      getAccountMeta(accounts.tokenRewardsPool),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.rewardMint),
+      getAccountMeta(accounts.rewardTokenProgram),
+      getAccountMeta(accounts.holderRewardsPool),
+    ],
+    programAddress,
+    data: getDistributeTokenRewardsInstructionDataEncoder().encode(
+      args as DistributeTokenRewardsInstructionDataArgs
+    ),
+  } as DistributeTokenRewardsInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountSourceAuthority,
+    TAccountSource,
+    TAccountTokenRewardsPool,
+    TAccountVault,
+    TAccountMint,
+    TAccountRewardMint,
+    TAccountRewardTokenProgram,
+    TAccountHolderRewardsPool
+  >;
+
+  return instruction;
+}
+
+export type ParsedDistributeTokenRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Source token account owner or delegate. */
+    sourceAuthority: TAccountMetas[0];
+    /** Source reward token account. */
+    source: TAccountMetas[1];
+    /** Token rewards pool account. */
+    tokenRewardsPool: TAccountMetas[2];
+    /** Token rewards pool vault. */
+    vault: TAccountMetas[3];
+    /** Token mint. */
+    mint: TAccountMetas[4];
+    /** Reward token mint. */
+    rewardMint: TAccountMetas[5];
+    /** Reward token program. */
+    rewardTokenProgram: TAccountMetas[6];
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[7];
+  };
+  data: DistributeTokenRewardsInstructionData;
+};
+
+export function parseDistributeTokenRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedDistributeTokenRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 8) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      sourceAuthority: getNextAccount(),
+      source: getNextAccount(),
+      tokenRewardsPool: getNextAccount(),
+      vault: getNextAccount(),
+      mint: getNextAccount(),
+      rewardMint: getNextAccount(),
+      rewardTokenProgram: getNextAccount(),
+      holderRewardsPool: getNextAccount(),
+    },
+    data: getDistributeTokenRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
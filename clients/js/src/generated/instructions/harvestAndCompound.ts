@@ -0,0 +1,262 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type HarvestAndCompoundInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountRewardsOwner extends string | IAccountMeta<string> = string,
+  TAccountCompoundProgram extends string | IAccountMeta<string> = string,
+  TAccountCompoundDestination extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountTokenAccount extends string
+        ? ReadonlyAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountRewardsOwner extends string
+        ? ReadonlySignerAccount<TAccountRewardsOwner> &
+            IAccountSignerMeta<TAccountRewardsOwner>
+        : TAccountRewardsOwner,
+      TAccountCompoundProgram extends string
+        ? ReadonlyAccount<TAccountCompoundProgram>
+        : TAccountCompoundProgram,
+      TAccountCompoundDestination extends string
+        ? WritableAccount<TAccountCompoundDestination>
+        : TAccountCompoundDestination,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type HarvestAndCompoundInstructionData = { discriminator: number };
+
+export type HarvestAndCompoundInstructionDataArgs = {};
+
+export function getHarvestAndCompoundInstructionDataEncoder(): Encoder<HarvestAndCompoundInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 27 })
+  );
+}
+
+export function getHarvestAndCompoundInstructionDataDecoder(): Decoder<HarvestAndCompoundInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getHarvestAndCompoundInstructionDataCodec(): Codec<
+  HarvestAndCompoundInstructionDataArgs,
+  HarvestAndCompoundInstructionData
+> {
+  return combineCodec(
+    getHarvestAndCompoundInstructionDataEncoder(),
+    getHarvestAndCompoundInstructionDataDecoder()
+  );
+}
+
+export type HarvestAndCompoundInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountRewardsOwner extends string = string,
+  TAccountCompoundProgram extends string = string,
+  TAccountCompoundDestination extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Rewards owner. */
+  rewardsOwner: TransactionSigner<TAccountRewardsOwner>;
+  /** Compound program. */
+  compoundProgram: Address<TAccountCompoundProgram>;
+  /** Compound destination, owned by the compound program. */
+  compoundDestination: Address<TAccountCompoundDestination>;
+};
+
+export function getHarvestAndCompoundInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountRewardsOwner extends string,
+  TAccountCompoundProgram extends string,
+  TAccountCompoundDestination extends string,
+>(
+  input: HarvestAndCompoundInput<
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountRewardsOwner,
+    TAccountCompoundProgram,
+    TAccountCompoundDestination
+  >
+): HarvestAndCompoundInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountRewardsOwner,
+  TAccountCompoundProgram,
+  TAccountCompoundDestination
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    rewardsOwner: { value: input.rewardsOwner ?? null, isWritable: false },
+    compoundProgram: {
+      value: input.compoundProgram ?? null,
+      isWritable: false,
+    },
+    compoundDestination: {
+      value: input.compoundDestination ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.rewardsOwner),
+      getAccountMeta(accounts.compoundProgram),
+      getAccountMeta(accounts.compoundDestination),
+    ],
+    programAddress,
+    data: getHarvestAndCompoundInstructionDataEncoder().encode({}),
+  } as HarvestAndCompoundInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountRewardsOwner,
+    TAccountCompoundProgram,
+    TAccountCompoundDestination
+  >;
+
+  return instruction;
+}
+
+export type ParsedHarvestAndCompoundInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[1];
+    /** Token account. */
+    tokenAccount: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Rewards owner. */
+    rewardsOwner: TAccountMetas[4];
+    /** Compound program. */
+    compoundProgram: TAccountMetas[5];
+    /** Compound destination, owned by the compound program. */
+    compoundDestination: TAccountMetas[6];
+  };
+  data: HarvestAndCompoundInstructionData;
+};
+
+export function parseHarvestAndCompoundInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedHarvestAndCompoundInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      holderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      rewardsOwner: getNextAccount(),
+      compoundProgram: getNextAccount(),
+      compoundDestination: getNextAccount(),
+    },
+    data: getHarvestAndCompoundInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,223 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type HarvestEscrowedRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountEscrow extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountRecipient extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountEscrow extends string
+        ? WritableAccount<TAccountEscrow>
+        : TAccountEscrow,
+      TAccountHolderRewards extends string
+        ? ReadonlyAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountTokenAccount extends string
+        ? WritableAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountRecipient extends string
+        ? WritableAccount<TAccountRecipient>
+        : TAccountRecipient,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type HarvestEscrowedRewardsInstructionData = { discriminator: number };
+
+export type HarvestEscrowedRewardsInstructionDataArgs = {};
+
+export function getHarvestEscrowedRewardsInstructionDataEncoder(): Encoder<HarvestEscrowedRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 24 })
+  );
+}
+
+export function getHarvestEscrowedRewardsInstructionDataDecoder(): Decoder<HarvestEscrowedRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getHarvestEscrowedRewardsInstructionDataCodec(): Codec<
+  HarvestEscrowedRewardsInstructionDataArgs,
+  HarvestEscrowedRewardsInstructionData
+> {
+  return combineCodec(
+    getHarvestEscrowedRewardsInstructionDataEncoder(),
+    getHarvestEscrowedRewardsInstructionDataDecoder()
+  );
+}
+
+export type HarvestEscrowedRewardsInput<
+  TAccountEscrow extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountRecipient extends string = string,
+> = {
+  /** Auto-claim escrow account. */
+  escrow: Address<TAccountEscrow>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Rewards recipient. */
+  recipient?: Address<TAccountRecipient>;
+};
+
+export function getHarvestEscrowedRewardsInstruction<
+  TAccountEscrow extends string,
+  TAccountHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountRecipient extends string,
+>(
+  input: HarvestEscrowedRewardsInput<
+    TAccountEscrow,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountRecipient
+  >
+): HarvestEscrowedRewardsInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountEscrow,
+  TAccountHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountRecipient
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    escrow: { value: input.escrow ?? null, isWritable: true },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: false },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    recipient: { value: input.recipient ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.escrow),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.recipient),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
+    programAddress,
+    data: getHarvestEscrowedRewardsInstructionDataEncoder().encode({}),
+  } as HarvestEscrowedRewardsInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountEscrow,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountRecipient
+  >;
+
+  return instruction;
+}
+
+export type ParsedHarvestEscrowedRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Auto-claim escrow account. */
+    escrow: TAccountMetas[0];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[1];
+    /** Token account. */
+    tokenAccount: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Rewards recipient. */
+    recipient?: TAccountMetas[4] | undefined;
+  };
+  data: HarvestEscrowedRewardsInstructionData;
+};
+
+export function parseHarvestEscrowedRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedHarvestEscrowedRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  let optionalAccountsRemaining = instruction.accounts.length - 4;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      escrow: getNextAccount(),
+      holderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      recipient: getNextOptionalAccount(),
+    },
+    data: getHarvestEscrowedRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -33,6 +33,7 @@ export type HarvestRewardsInstruction<
   TAccountHolderRewards extends string | IAccountMeta<string> = string,
   TAccountTokenAccount extends string | IAccountMeta<string> = string,
   TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountRecipient extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -50,6 +51,9 @@ export type HarvestRewardsInstruction<
       TAccountMint extends string
         ? ReadonlyAccount<TAccountMint>
         : TAccountMint,
+      TAccountRecipient extends string
+        ? WritableAccount<TAccountRecipient>
+        : TAccountRecipient,
       ...TRemainingAccounts,
     ]
   >;
@@ -84,6 +88,7 @@ export type HarvestRewardsInput<
   TAccountHolderRewards extends string = string,
   TAccountTokenAccount extends string = string,
   TAccountMint extends string = string,
+  TAccountRecipient extends string = string,
 > = {
   /** Holder rewards pool account. */
   holderRewardsPool: Address<TAccountHolderRewardsPool>;
@@ -93,6 +98,8 @@ export type HarvestRewardsInput<
   tokenAccount: Address<TAccountTokenAccount>;
   /** Token mint. */
   mint: Address<TAccountMint>;
+  /** Rewards recipient. */
+  recipient?: Address<TAccountRecipient>;
 };
 
 export function getHarvestRewardsInstruction<
@@ -100,19 +107,22 @@ export function getHarvestRewardsInstruction<
   TAccountHolderRewards extends string,
   TAccountTokenAccount extends string,
   TAccountMint extends string,
+  TAccountRecipient extends string,
 >(
   input: HarvestRewardsInput<
     TAccountHolderRewardsPool,
     TAccountHolderRewards,
     TAccountTokenAccount,
-    TAccountMint
+    TAccountMint,
+    TAccountRecipient
   >
 ): HarvestRewardsInstruction<
   typeof REWARDS_PROGRAM_ADDRESS,
   TAccountHolderRewardsPool,
   TAccountHolderRewards,
   TAccountTokenAccount,
-  TAccountMint
+  TAccountMint,
+  TAccountRecipient
 > {
   // Program address.
   const programAddress = REWARDS_PROGRAM_ADDRESS;
@@ -126,20 +136,22 @@ export function getHarvestRewardsInstruction<
     holderRewards: { value: input.holderRewards ?? null, isWritable: true },
     tokenAccount: { value: input.tokenAccount ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: false },
+    recipient: { value: input.recipient ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
-  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
   const instruction = {
     accounts: [
       getAccountMeta(accounts.holderRewardsPool),
       getAccountMeta(accounts.holderRewards),
       getAccountMeta(accounts.tokenAccount),
       getAccountMeta(accounts.mint),
-    ],
+      getAccountMeta(accounts.recipient),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
     programAddress,
     data: getHarvestRewardsInstructionDataEncoder().encode({}),
   } as HarvestRewardsInstruction<
@@ -147,7 +159,8 @@ export function getHarvestRewardsInstruction<
     TAccountHolderRewardsPool,
     TAccountHolderRewards,
     TAccountTokenAccount,
-    TAccountMint
+    TAccountMint,
+    TAccountRecipient
   >;
 
   return instruction;
@@ -167,6 +180,8 @@ export type ParsedHarvestRewardsInstruction<
     tokenAccount: TAccountMetas[2];
     /** Token mint. */
     mint: TAccountMetas[3];
+    /** Rewards recipient. */
+    recipient?: TAccountMetas[4] | undefined;
   };
   data: HarvestRewardsInstructionData;
 };
@@ -189,6 +204,12 @@ export function parseHarvestRewardsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  let optionalAccountsRemaining = instruction.accounts.length - 4;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -196,6 +217,7 @@ export function parseHarvestRewardsInstruction<
       holderRewards: getNextAccount(),
       tokenAccount: getNextAccount(),
       mint: getNextAccount(),
+      recipient: getNextOptionalAccount(),
     },
     data: getHarvestRewardsInstructionDataDecoder().decode(instruction.data),
   };
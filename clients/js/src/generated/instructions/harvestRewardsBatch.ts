@@ -0,0 +1,169 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type HarvestRewardsBatchInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type HarvestRewardsBatchInstructionData = { discriminator: number };
+
+export type HarvestRewardsBatchInstructionDataArgs = {};
+
+export function getHarvestRewardsBatchInstructionDataEncoder(): Encoder<HarvestRewardsBatchInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 21 })
+  );
+}
+
+export function getHarvestRewardsBatchInstructionDataDecoder(): Decoder<HarvestRewardsBatchInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getHarvestRewardsBatchInstructionDataCodec(): Codec<
+  HarvestRewardsBatchInstructionDataArgs,
+  HarvestRewardsBatchInstructionData
+> {
+  return combineCodec(
+    getHarvestRewardsBatchInstructionDataEncoder(),
+    getHarvestRewardsBatchInstructionDataDecoder()
+  );
+}
+
+export type HarvestRewardsBatchInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+};
+
+export function getHarvestRewardsBatchInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+>(
+  input: HarvestRewardsBatchInput<TAccountHolderRewardsPool, TAccountMint>
+): HarvestRewardsBatchInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountMint
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+    ],
+    programAddress,
+    data: getHarvestRewardsBatchInstructionDataEncoder().encode({}),
+  } as HarvestRewardsBatchInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountMint
+  >;
+
+  return instruction;
+}
+
+export type ParsedHarvestRewardsBatchInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Token mint. */
+    mint: TAccountMetas[1];
+  };
+  data: HarvestRewardsBatchInstructionData;
+};
+
+export function parseHarvestRewardsBatchInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedHarvestRewardsBatchInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+    },
+    data: getHarvestRewardsBatchInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,242 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type HarvestRewardsToDestinationInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountDestination extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountTokenAccount extends string
+        ? ReadonlyAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountAuthority extends string
+        ? ReadonlySignerAccount<TAccountAuthority> &
+            IAccountSignerMeta<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountDestination extends string
+        ? WritableAccount<TAccountDestination>
+        : TAccountDestination,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type HarvestRewardsToDestinationInstructionData = {
+  discriminator: number;
+};
+
+export type HarvestRewardsToDestinationInstructionDataArgs = {};
+
+export function getHarvestRewardsToDestinationInstructionDataEncoder(): Encoder<HarvestRewardsToDestinationInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 8 })
+  );
+}
+
+export function getHarvestRewardsToDestinationInstructionDataDecoder(): Decoder<HarvestRewardsToDestinationInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getHarvestRewardsToDestinationInstructionDataCodec(): Codec<
+  HarvestRewardsToDestinationInstructionDataArgs,
+  HarvestRewardsToDestinationInstructionData
+> {
+  return combineCodec(
+    getHarvestRewardsToDestinationInstructionDataEncoder(),
+    getHarvestRewardsToDestinationInstructionDataDecoder()
+  );
+}
+
+export type HarvestRewardsToDestinationInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountDestination extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Token account owner or delegate. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Destination account. */
+  destination: Address<TAccountDestination>;
+};
+
+export function getHarvestRewardsToDestinationInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountAuthority extends string,
+  TAccountDestination extends string,
+>(
+  input: HarvestRewardsToDestinationInput<
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountDestination
+  >
+): HarvestRewardsToDestinationInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountAuthority,
+  TAccountDestination
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    authority: { value: input.authority ?? null, isWritable: false },
+    destination: { value: input.destination ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.destination),
+    ],
+    programAddress,
+    data: getHarvestRewardsToDestinationInstructionDataEncoder().encode({}),
+  } as HarvestRewardsToDestinationInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountAuthority,
+    TAccountDestination
+  >;
+
+  return instruction;
+}
+
+export type ParsedHarvestRewardsToDestinationInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[1];
+    /** Token account. */
+    tokenAccount: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Token account owner or delegate. */
+    authority: TAccountMetas[4];
+    /** Destination account. */
+    destination: TAccountMetas[5];
+  };
+  data: HarvestRewardsToDestinationInstructionData;
+};
+
+export function parseHarvestRewardsToDestinationInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedHarvestRewardsToDestinationInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      holderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      authority: getNextAccount(),
+      destination: getNextAccount(),
+    },
+    data: getHarvestRewardsToDestinationInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
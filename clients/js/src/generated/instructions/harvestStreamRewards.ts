@@ -0,0 +1,243 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type HarvestStreamRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountRecipient extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountTokenAccount extends string
+        ? WritableAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountRecipient extends string
+        ? WritableAccount<TAccountRecipient>
+        : TAccountRecipient,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type HarvestStreamRewardsInstructionData = {
+  discriminator: number;
+  args: bigint;
+};
+
+export type HarvestStreamRewardsInstructionDataArgs = { args: number | bigint };
+
+export function getHarvestStreamRewardsInstructionDataEncoder(): Encoder<HarvestStreamRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['args', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 15 })
+  );
+}
+
+export function getHarvestStreamRewardsInstructionDataDecoder(): Decoder<HarvestStreamRewardsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['args', getU64Decoder()],
+  ]);
+}
+
+export function getHarvestStreamRewardsInstructionDataCodec(): Codec<
+  HarvestStreamRewardsInstructionDataArgs,
+  HarvestStreamRewardsInstructionData
+> {
+  return combineCodec(
+    getHarvestStreamRewardsInstructionDataEncoder(),
+    getHarvestStreamRewardsInstructionDataDecoder()
+  );
+}
+
+export type HarvestStreamRewardsInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountRecipient extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Rewards recipient. */
+  recipient?: Address<TAccountRecipient>;
+  args: HarvestStreamRewardsInstructionDataArgs['args'];
+};
+
+export function getHarvestStreamRewardsInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountRecipient extends string,
+>(
+  input: HarvestStreamRewardsInput<
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountRecipient
+  >
+): HarvestStreamRewardsInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountRecipient
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    recipient: { value: input.recipient ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.recipient),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
+    programAddress,
+    data: getHarvestStreamRewardsInstructionDataEncoder().encode(
+      args as HarvestStreamRewardsInstructionDataArgs
+    ),
+  } as HarvestStreamRewardsInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountRecipient
+  >;
+
+  return instruction;
+}
+
+export type ParsedHarvestStreamRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[1];
+    /** Token account. */
+    tokenAccount: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Rewards recipient. */
+    recipient?: TAccountMetas[4] | undefined;
+  };
+  data: HarvestStreamRewardsInstructionData;
+};
+
+export function parseHarvestStreamRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedHarvestStreamRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  let optionalAccountsRemaining = instruction.accounts.length - 4;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      holderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      recipient: getNextOptionalAccount(),
+    },
+    data: getHarvestStreamRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
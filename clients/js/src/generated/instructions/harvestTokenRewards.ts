@@ -0,0 +1,290 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type HarvestTokenRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountTokenRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountTokenHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountVault extends string | IAccountMeta<string> = string,
+  TAccountDestination extends string | IAccountMeta<string> = string,
+  TAccountRewardMint extends string | IAccountMeta<string> = string,
+  TAccountRewardTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTokenRewardsPool extends string
+        ? ReadonlyAccount<TAccountTokenRewardsPool>
+        : TAccountTokenRewardsPool,
+      TAccountTokenHolderRewards extends string
+        ? WritableAccount<TAccountTokenHolderRewards>
+        : TAccountTokenHolderRewards,
+      TAccountTokenAccount extends string
+        ? ReadonlyAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountVault extends string
+        ? WritableAccount<TAccountVault>
+        : TAccountVault,
+      TAccountDestination extends string
+        ? WritableAccount<TAccountDestination>
+        : TAccountDestination,
+      TAccountRewardMint extends string
+        ? ReadonlyAccount<TAccountRewardMint>
+        : TAccountRewardMint,
+      TAccountRewardTokenProgram extends string
+        ? ReadonlyAccount<TAccountRewardTokenProgram>
+        : TAccountRewardTokenProgram,
+      TAccountHolderRewards extends string
+        ? ReadonlyAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type HarvestTokenRewardsInstructionData = { discriminator: number };
+
+export type HarvestTokenRewardsInstructionDataArgs = {};
+
+export function getHarvestTokenRewardsInstructionDataEncoder(): Encoder<HarvestTokenRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 12 })
+  );
+}
+
+export function getHarvestTokenRewardsInstructionDataDecoder(): Decoder<HarvestTokenRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getHarvestTokenRewardsInstructionDataCodec(): Codec<
+  HarvestTokenRewardsInstructionDataArgs,
+  HarvestTokenRewardsInstructionData
+> {
+  return combineCodec(
+    getHarvestTokenRewardsInstructionDataEncoder(),
+    getHarvestTokenRewardsInstructionDataDecoder()
+  );
+}
+
+export type HarvestTokenRewardsInput<
+  TAccountTokenRewardsPool extends string = string,
+  TAccountTokenHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountVault extends string = string,
+  TAccountDestination extends string = string,
+  TAccountRewardMint extends string = string,
+  TAccountRewardTokenProgram extends string = string,
+  TAccountHolderRewards extends string = string,
+> = {
+  /** Token rewards pool account. */
+  tokenRewardsPool: Address<TAccountTokenRewardsPool>;
+  /** Token holder rewards account. */
+  tokenHolderRewards: Address<TAccountTokenHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Token rewards pool vault. */
+  vault: Address<TAccountVault>;
+  /** Destination reward token account. */
+  destination: Address<TAccountDestination>;
+  /** Reward token mint. */
+  rewardMint: Address<TAccountRewardMint>;
+  /** Reward token program. */
+  rewardTokenProgram: Address<TAccountRewardTokenProgram>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+};
+
+export function getHarvestTokenRewardsInstruction<
+  TAccountTokenRewardsPool extends string,
+  TAccountTokenHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountVault extends string,
+  TAccountDestination extends string,
+  TAccountRewardMint extends string,
+  TAccountRewardTokenProgram extends string,
+  TAccountHolderRewards extends string,
+>(
+  input: HarvestTokenRewardsInput<
+    TAccountTokenRewardsPool,
+    TAccountTokenHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountVault,
+    TAccountDestination,
+    TAccountRewardMint,
+    TAccountRewardTokenProgram,
+    TAccountHolderRewards
+  >
+): HarvestTokenRewardsInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountTokenRewardsPool,
+  TAccountTokenHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountVault,
+  TAccountDestination,
+  TAccountRewardMint,
+  TAccountRewardTokenProgram,
+  TAccountHolderRewards
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    tokenRewardsPool: {
+      value: input.tokenRewardsPool ?? null,
+      isWritable: false,
+    },
+    tokenHolderRewards: {
+      value: input.tokenHolderRewards ?? null,
+      isWritable: true,
+    },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    vault: { value: input.vault ?? null, isWritable: true },
+    destination: { value: input.destination ?? null, isWritable: true },
+    rewardMint: { value: input.rewardMint ?? null, isWritable: false },
+    rewardTokenProgram: {
+      value: input.rewardTokenProgram ?? null,
+      isWritable: false,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.tokenRewardsPool),
+      getAccountMeta(accounts.tokenHolderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.vault),
+      getAccountMeta(accounts.destination),
+      getAccountMeta(accounts.rewardMint),
+      getAccountMeta(accounts.rewardTokenProgram),
+      getAccountMeta(accounts.holderRewards),
+    ],
+    programAddress,
+    data: getHarvestTokenRewardsInstructionDataEncoder().encode({}),
+  } as HarvestTokenRewardsInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountTokenRewardsPool,
+    TAccountTokenHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountVault,
+    TAccountDestination,
+    TAccountRewardMint,
+    TAccountRewardTokenProgram,
+    TAccountHolderRewards
+  >;
+
+  return instruction;
+}
+
+export type ParsedHarvestTokenRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Token rewards pool account. */
+    tokenRewardsPool: TAccountMetas[0];
+    /** Token holder rewards account. */
+    tokenHolderRewards: TAccountMetas[1];
+    /** Token account. */
+    tokenAccount: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Token rewards pool vault. */
+    vault: TAccountMetas[4];
+    /** Destination reward token account. */
+    destination: TAccountMetas[5];
+    /** Reward token mint. */
+    rewardMint: TAccountMetas[6];
+    /** Reward token program. */
+    rewardTokenProgram: TAccountMetas[7];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[8];
+  };
+  data: HarvestTokenRewardsInstructionData;
+};
+
+export function parseHarvestTokenRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedHarvestTokenRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 9) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      tokenRewardsPool: getNextAccount(),
+      tokenHolderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      vault: getNextAccount(),
+      destination: getNextAccount(),
+      rewardMint: getNextAccount(),
+      rewardTokenProgram: getNextAccount(),
+      holderRewards: getNextAccount(),
+    },
+    data: getHarvestTokenRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -6,7 +6,33 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './acceptPoolAuthority';
+export * from './addCompoundProgram';
+export * from './addRewardStream';
+export * from './closeHolderRewards';
+export * from './createEmissionSchedule';
+export * from './depositRewards';
 export * from './distributeRewards';
+export * from './distributeStreamRewards';
+export * from './distributeTokenRewards';
+export * from './harvestAndCompound';
+export * from './harvestEscrowedRewards';
 export * from './harvestRewards';
+export * from './harvestRewardsBatch';
+export * from './harvestRewardsToDestination';
+export * from './harvestStreamRewards';
+export * from './harvestTokenRewards';
 export * from './initializeHolderRewards';
+export * from './initializeHolderRewardsBatch';
 export * from './initializeHolderRewardsPool';
+export * from './initializeTokenHolderRewards';
+export * from './initializeTokenRewardsPool';
+export * from './migrateAccount';
+export * from './removeCompoundProgram';
+export * from './setAutoClaim';
+export * from './setDistributionConfig';
+export * from './setPoolAuthority';
+export * from './setRewardsExclusion';
+export * from './setRewardsRecipient';
+export * from './sweepDust';
+export * from './syncHolderRewards';
@@ -18,11 +18,14 @@ import {
   type Decoder,
   type Encoder,
   type IAccountMeta,
+  type IAccountSignerMeta,
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/web3.js';
 import { REWARDS_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -36,13 +39,14 @@ export type InitializeHolderRewardsInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountPayer extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
   IInstructionWithAccounts<
     [
       TAccountHolderRewardsPool extends string
-        ? ReadonlyAccount<TAccountHolderRewardsPool>
+        ? WritableAccount<TAccountHolderRewardsPool>
         : TAccountHolderRewardsPool,
       TAccountHolderRewards extends string
         ? WritableAccount<TAccountHolderRewards>
@@ -56,6 +60,10 @@ export type InitializeHolderRewardsInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
       ...TRemainingAccounts,
     ]
   >;
@@ -91,6 +99,7 @@ export type InitializeHolderRewardsInput<
   TAccountTokenAccount extends string = string,
   TAccountMint extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountPayer extends string = string,
 > = {
   /** Holder rewards pool account. */
   holderRewardsPool: Address<TAccountHolderRewardsPool>;
@@ -102,6 +111,8 @@ export type InitializeHolderRewardsInput<
   mint: Address<TAccountMint>;
   /** System program. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Payer account. */
+  payer?: TransactionSigner<TAccountPayer>;
 };
 
 export function getInitializeHolderRewardsInstruction<
@@ -110,13 +121,15 @@ export function getInitializeHolderRewardsInstruction<
   TAccountTokenAccount extends string,
   TAccountMint extends string,
   TAccountSystemProgram extends string,
+  TAccountPayer extends string,
 >(
   input: InitializeHolderRewardsInput<
     TAccountHolderRewardsPool,
     TAccountHolderRewards,
     TAccountTokenAccount,
     TAccountMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountPayer
   >
 ): InitializeHolderRewardsInstruction<
   typeof REWARDS_PROGRAM_ADDRESS,
@@ -124,7 +137,8 @@ export function getInitializeHolderRewardsInstruction<
   TAccountHolderRewards,
   TAccountTokenAccount,
   TAccountMint,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountPayer
 > {
   // Program address.
   const programAddress = REWARDS_PROGRAM_ADDRESS;
@@ -133,12 +147,13 @@ export function getInitializeHolderRewardsInstruction<
   const originalAccounts = {
     holderRewardsPool: {
       value: input.holderRewardsPool ?? null,
-      isWritable: false,
+      isWritable: true,
     },
     holderRewards: { value: input.holderRewards ?? null, isWritable: true },
     tokenAccount: { value: input.tokenAccount ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -151,7 +166,7 @@ export function getInitializeHolderRewardsInstruction<
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
 
-  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
   const instruction = {
     accounts: [
       getAccountMeta(accounts.holderRewardsPool),
@@ -159,7 +174,8 @@ export function getInitializeHolderRewardsInstruction<
       getAccountMeta(accounts.tokenAccount),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.systemProgram),
-    ],
+      getAccountMeta(accounts.payer),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
     programAddress,
     data: getInitializeHolderRewardsInstructionDataEncoder().encode({}),
   } as InitializeHolderRewardsInstruction<
@@ -168,7 +184,8 @@ export function getInitializeHolderRewardsInstruction<
     TAccountHolderRewards,
     TAccountTokenAccount,
     TAccountMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountPayer
   >;
 
   return instruction;
@@ -190,6 +207,8 @@ export type ParsedInitializeHolderRewardsInstruction<
     mint: TAccountMetas[3];
     /** System program. */
     systemProgram: TAccountMetas[4];
+    /** Payer account. */
+    payer?: TAccountMetas[5] | undefined;
   };
   data: InitializeHolderRewardsInstructionData;
 };
@@ -212,6 +231,12 @@ export function parseInitializeHolderRewardsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  let optionalAccountsRemaining = instruction.accounts.length - 5;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -220,6 +245,7 @@ export function parseInitializeHolderRewardsInstruction<
       tokenAccount: getNextAccount(),
       mint: getNextAccount(),
       systemProgram: getNextAccount(),
+      payer: getNextOptionalAccount(),
     },
     data: getInitializeHolderRewardsInstructionDataDecoder().decode(
       instruction.data
@@ -0,0 +1,218 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type InitializeHolderRewardsBatchInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeHolderRewardsBatchInstructionData = {
+  discriminator: number;
+};
+
+export type InitializeHolderRewardsBatchInstructionDataArgs = {};
+
+export function getInitializeHolderRewardsBatchInstructionDataEncoder(): Encoder<InitializeHolderRewardsBatchInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 22 })
+  );
+}
+
+export function getInitializeHolderRewardsBatchInstructionDataDecoder(): Decoder<InitializeHolderRewardsBatchInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeHolderRewardsBatchInstructionDataCodec(): Codec<
+  InitializeHolderRewardsBatchInstructionDataArgs,
+  InitializeHolderRewardsBatchInstructionData
+> {
+  return combineCodec(
+    getInitializeHolderRewardsBatchInstructionDataEncoder(),
+    getInitializeHolderRewardsBatchInstructionDataDecoder()
+  );
+}
+
+export type InitializeHolderRewardsBatchInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountPayer extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Payer account. */
+  payer: TransactionSigner<TAccountPayer>;
+};
+
+export function getInitializeHolderRewardsBatchInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountSystemProgram extends string,
+  TAccountPayer extends string,
+>(
+  input: InitializeHolderRewardsBatchInput<
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountSystemProgram,
+    TAccountPayer
+  >
+): InitializeHolderRewardsBatchInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountSystemProgram,
+  TAccountPayer
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.payer),
+    ],
+    programAddress,
+    data: getInitializeHolderRewardsBatchInstructionDataEncoder().encode({}),
+  } as InitializeHolderRewardsBatchInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountSystemProgram,
+    TAccountPayer
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeHolderRewardsBatchInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Token mint. */
+    mint: TAccountMetas[1];
+    /** System program. */
+    systemProgram: TAccountMetas[2];
+    /** Payer account. */
+    payer: TAccountMetas[3];
+  };
+  data: InitializeHolderRewardsBatchInstructionData;
+};
+
+export function parseInitializeHolderRewardsBatchInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeHolderRewardsBatchInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      systemProgram: getNextAccount(),
+      payer: getNextAccount(),
+    },
+    data: getInitializeHolderRewardsBatchInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
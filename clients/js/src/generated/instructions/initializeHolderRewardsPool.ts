@@ -26,6 +26,7 @@ import {
   type ReadonlySignerAccount,
   type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/web3.js';
 import { REWARDS_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -39,6 +40,7 @@ export type InitializeHolderRewardsPoolInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountPayer extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -60,6 +62,10 @@ export type InitializeHolderRewardsPoolInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
       ...TRemainingAccounts,
     ]
   >;
@@ -97,6 +103,7 @@ export type InitializeHolderRewardsPoolInput<
   TAccountMint extends string = string,
   TAccountMintAuthority extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountPayer extends string = string,
 > = {
   /** Holder rewards pool account. */
   holderRewardsPool: Address<TAccountHolderRewardsPool>;
@@ -108,6 +115,8 @@ export type InitializeHolderRewardsPoolInput<
   mintAuthority: TransactionSigner<TAccountMintAuthority>;
   /** System program. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Payer account. */
+  payer?: TransactionSigner<TAccountPayer>;
 };
 
 export function getInitializeHolderRewardsPoolInstruction<
@@ -116,13 +125,15 @@ export function getInitializeHolderRewardsPoolInstruction<
   TAccountMint extends string,
   TAccountMintAuthority extends string,
   TAccountSystemProgram extends string,
+  TAccountPayer extends string,
 >(
   input: InitializeHolderRewardsPoolInput<
     TAccountHolderRewardsPool,
     TAccountExtraAccountMetas,
     TAccountMint,
     TAccountMintAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountPayer
   >
 ): InitializeHolderRewardsPoolInstruction<
   typeof REWARDS_PROGRAM_ADDRESS,
@@ -130,7 +141,8 @@ export function getInitializeHolderRewardsPoolInstruction<
   TAccountExtraAccountMetas,
   TAccountMint,
   TAccountMintAuthority,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountPayer
 > {
   // Program address.
   const programAddress = REWARDS_PROGRAM_ADDRESS;
@@ -148,6 +160,7 @@ export function getInitializeHolderRewardsPoolInstruction<
     mint: { value: input.mint ?? null, isWritable: false },
     mintAuthority: { value: input.mintAuthority ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -160,7 +173,7 @@ export function getInitializeHolderRewardsPoolInstruction<
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
 
-  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
   const instruction = {
     accounts: [
       getAccountMeta(accounts.holderRewardsPool),
@@ -168,7 +181,8 @@ export function getInitializeHolderRewardsPoolInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.mintAuthority),
       getAccountMeta(accounts.systemProgram),
-    ],
+      getAccountMeta(accounts.payer),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
     programAddress,
     data: getInitializeHolderRewardsPoolInstructionDataEncoder().encode({}),
   } as InitializeHolderRewardsPoolInstruction<
@@ -177,7 +191,8 @@ export function getInitializeHolderRewardsPoolInstruction<
     TAccountExtraAccountMetas,
     TAccountMint,
     TAccountMintAuthority,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountPayer
   >;
 
   return instruction;
@@ -199,6 +214,8 @@ export type ParsedInitializeHolderRewardsPoolInstruction<
     mintAuthority: TAccountMetas[3];
     /** System program. */
     systemProgram: TAccountMetas[4];
+    /** Payer account. */
+    payer?: TAccountMetas[5] | undefined;
   };
   data: InitializeHolderRewardsPoolInstructionData;
 };
@@ -221,6 +238,12 @@ export function parseInitializeHolderRewardsPoolInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  let optionalAccountsRemaining = instruction.accounts.length - 5;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -229,6 +252,7 @@ export function parseInitializeHolderRewardsPoolInstruction<
       mint: getNextAccount(),
       mintAuthority: getNextAccount(),
       systemProgram: getNextAccount(),
+      payer: getNextOptionalAccount(),
     },
     data: getInitializeHolderRewardsPoolInstructionDataDecoder().decode(
       instruction.data
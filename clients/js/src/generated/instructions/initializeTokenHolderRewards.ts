@@ -0,0 +1,275 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type InitializeTokenHolderRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountTokenRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountTokenHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountRewardMint extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTokenRewardsPool extends string
+        ? ReadonlyAccount<TAccountTokenRewardsPool>
+        : TAccountTokenRewardsPool,
+      TAccountTokenHolderRewards extends string
+        ? WritableAccount<TAccountTokenHolderRewards>
+        : TAccountTokenHolderRewards,
+      TAccountTokenAccount extends string
+        ? ReadonlyAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountRewardMint extends string
+        ? ReadonlyAccount<TAccountRewardMint>
+        : TAccountRewardMint,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeTokenHolderRewardsInstructionData = {
+  discriminator: number;
+};
+
+export type InitializeTokenHolderRewardsInstructionDataArgs = {};
+
+export function getInitializeTokenHolderRewardsInstructionDataEncoder(): Encoder<InitializeTokenHolderRewardsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 11 })
+  );
+}
+
+export function getInitializeTokenHolderRewardsInstructionDataDecoder(): Decoder<InitializeTokenHolderRewardsInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeTokenHolderRewardsInstructionDataCodec(): Codec<
+  InitializeTokenHolderRewardsInstructionDataArgs,
+  InitializeTokenHolderRewardsInstructionData
+> {
+  return combineCodec(
+    getInitializeTokenHolderRewardsInstructionDataEncoder(),
+    getInitializeTokenHolderRewardsInstructionDataDecoder()
+  );
+}
+
+export type InitializeTokenHolderRewardsInput<
+  TAccountTokenRewardsPool extends string = string,
+  TAccountTokenHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountRewardMint extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountPayer extends string = string,
+> = {
+  /** Token rewards pool account. */
+  tokenRewardsPool: Address<TAccountTokenRewardsPool>;
+  /** Token holder rewards account. */
+  tokenHolderRewards: Address<TAccountTokenHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Reward token mint. */
+  rewardMint: Address<TAccountRewardMint>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Payer account. */
+  payer?: TransactionSigner<TAccountPayer>;
+};
+
+export function getInitializeTokenHolderRewardsInstruction<
+  TAccountTokenRewardsPool extends string,
+  TAccountTokenHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountRewardMint extends string,
+  TAccountSystemProgram extends string,
+  TAccountPayer extends string,
+>(
+  input: InitializeTokenHolderRewardsInput<
+    TAccountTokenRewardsPool,
+    TAccountTokenHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountRewardMint,
+    TAccountSystemProgram,
+    TAccountPayer
+  >
+): InitializeTokenHolderRewardsInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountTokenRewardsPool,
+  TAccountTokenHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountRewardMint,
+  TAccountSystemProgram,
+  TAccountPayer
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    tokenRewardsPool: {
+      value: input.tokenRewardsPool ?? null,
+      isWritable: false,
+    },
+    tokenHolderRewards: {
+      value: input.tokenHolderRewards ?? null,
+      isWritable: true,
+    },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    rewardMint: { value: input.rewardMint ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.tokenRewardsPool),
+      getAccountMeta(accounts.tokenHolderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.rewardMint),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.payer),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
+    programAddress,
+    data: getInitializeTokenHolderRewardsInstructionDataEncoder().encode({}),
+  } as InitializeTokenHolderRewardsInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountTokenRewardsPool,
+    TAccountTokenHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountRewardMint,
+    TAccountSystemProgram,
+    TAccountPayer
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeTokenHolderRewardsInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Token rewards pool account. */
+    tokenRewardsPool: TAccountMetas[0];
+    /** Token holder rewards account. */
+    tokenHolderRewards: TAccountMetas[1];
+    /** Token account. */
+    tokenAccount: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Reward token mint. */
+    rewardMint: TAccountMetas[4];
+    /** System program. */
+    systemProgram: TAccountMetas[5];
+    /** Payer account. */
+    payer?: TAccountMetas[6] | undefined;
+  };
+  data: InitializeTokenHolderRewardsInstructionData;
+};
+
+export function parseInitializeTokenHolderRewardsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeTokenHolderRewardsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  let optionalAccountsRemaining = instruction.accounts.length - 6;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      tokenRewardsPool: getNextAccount(),
+      tokenHolderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      rewardMint: getNextAccount(),
+      systemProgram: getNextAccount(),
+      payer: getNextOptionalAccount(),
+    },
+    data: getInitializeTokenHolderRewardsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
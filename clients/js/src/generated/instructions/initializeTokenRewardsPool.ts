@@ -0,0 +1,296 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type InitializeTokenRewardsPoolInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountTokenRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountExtraAccountMetas extends string | IAccountMeta<string> = string,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountRewardMint extends string | IAccountMeta<string> = string,
+  TAccountPoolAuthority extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountTokenRewardsPool extends string
+        ? WritableAccount<TAccountTokenRewardsPool>
+        : TAccountTokenRewardsPool,
+      TAccountExtraAccountMetas extends string
+        ? WritableAccount<TAccountExtraAccountMetas>
+        : TAccountExtraAccountMetas,
+      TAccountHolderRewardsPool extends string
+        ? ReadonlyAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountRewardMint extends string
+        ? ReadonlyAccount<TAccountRewardMint>
+        : TAccountRewardMint,
+      TAccountPoolAuthority extends string
+        ? ReadonlySignerAccount<TAccountPoolAuthority> &
+            IAccountSignerMeta<TAccountPoolAuthority>
+        : TAccountPoolAuthority,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeTokenRewardsPoolInstructionData = {
+  discriminator: number;
+};
+
+export type InitializeTokenRewardsPoolInstructionDataArgs = {};
+
+export function getInitializeTokenRewardsPoolInstructionDataEncoder(): Encoder<InitializeTokenRewardsPoolInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 9 })
+  );
+}
+
+export function getInitializeTokenRewardsPoolInstructionDataDecoder(): Decoder<InitializeTokenRewardsPoolInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getInitializeTokenRewardsPoolInstructionDataCodec(): Codec<
+  InitializeTokenRewardsPoolInstructionDataArgs,
+  InitializeTokenRewardsPoolInstructionData
+> {
+  return combineCodec(
+    getInitializeTokenRewardsPoolInstructionDataEncoder(),
+    getInitializeTokenRewardsPoolInstructionDataDecoder()
+  );
+}
+
+export type InitializeTokenRewardsPoolInput<
+  TAccountTokenRewardsPool extends string = string,
+  TAccountExtraAccountMetas extends string = string,
+  TAccountHolderRewardsPool extends string = string,
+  TAccountMint extends string = string,
+  TAccountRewardMint extends string = string,
+  TAccountPoolAuthority extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountPayer extends string = string,
+> = {
+  /** Token rewards pool account. */
+  tokenRewardsPool: Address<TAccountTokenRewardsPool>;
+  /** Transfer hook extra account metas account. */
+  extraAccountMetas: Address<TAccountExtraAccountMetas>;
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Reward token mint. */
+  rewardMint: Address<TAccountRewardMint>;
+  /** Pool authority. */
+  poolAuthority: TransactionSigner<TAccountPoolAuthority>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Payer account. */
+  payer?: TransactionSigner<TAccountPayer>;
+};
+
+export function getInitializeTokenRewardsPoolInstruction<
+  TAccountTokenRewardsPool extends string,
+  TAccountExtraAccountMetas extends string,
+  TAccountHolderRewardsPool extends string,
+  TAccountMint extends string,
+  TAccountRewardMint extends string,
+  TAccountPoolAuthority extends string,
+  TAccountSystemProgram extends string,
+  TAccountPayer extends string,
+>(
+  input: InitializeTokenRewardsPoolInput<
+    TAccountTokenRewardsPool,
+    TAccountExtraAccountMetas,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountRewardMint,
+    TAccountPoolAuthority,
+    TAccountSystemProgram,
+    TAccountPayer
+  >
+): InitializeTokenRewardsPoolInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountTokenRewardsPool,
+  TAccountExtraAccountMetas,
+  TAccountHolderRewardsPool,
+  TAccountMint,
+  TAccountRewardMint,
+  TAccountPoolAuthority,
+  TAccountSystemProgram,
+  TAccountPayer
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    tokenRewardsPool: {
+      value: input.tokenRewardsPool ?? null,
+      isWritable: true,
+    },
+    extraAccountMetas: {
+      value: input.extraAccountMetas ?? null,
+      isWritable: true,
+    },
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: false,
+    },
+    mint: { value: input.mint ?? null, isWritable: false },
+    rewardMint: { value: input.rewardMint ?? null, isWritable: false },
+    poolAuthority: { value: input.poolAuthority ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.tokenRewardsPool),
+      getAccountMeta(accounts.extraAccountMetas),
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.rewardMint),
+      getAccountMeta(accounts.poolAuthority),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.payer),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
+    programAddress,
+    data: getInitializeTokenRewardsPoolInstructionDataEncoder().encode({}),
+  } as InitializeTokenRewardsPoolInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountTokenRewardsPool,
+    TAccountExtraAccountMetas,
+    TAccountHolderRewardsPool,
+    TAccountMint,
+    TAccountRewardMint,
+    TAccountPoolAuthority,
+    TAccountSystemProgram,
+    TAccountPayer
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeTokenRewardsPoolInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Token rewards pool account. */
+    tokenRewardsPool: TAccountMetas[0];
+    /** Transfer hook extra account metas account. */
+    extraAccountMetas: TAccountMetas[1];
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Reward token mint. */
+    rewardMint: TAccountMetas[4];
+    /** Pool authority. */
+    poolAuthority: TAccountMetas[5];
+    /** System program. */
+    systemProgram: TAccountMetas[6];
+    /** Payer account. */
+    payer?: TAccountMetas[7] | undefined;
+  };
+  data: InitializeTokenRewardsPoolInstructionData;
+};
+
+export function parseInitializeTokenRewardsPoolInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeTokenRewardsPoolInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  let optionalAccountsRemaining = instruction.accounts.length - 7;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      tokenRewardsPool: getNextAccount(),
+      extraAccountMetas: getNextAccount(),
+      holderRewardsPool: getNextAccount(),
+      mint: getNextAccount(),
+      rewardMint: getNextAccount(),
+      poolAuthority: getNextAccount(),
+      systemProgram: getNextAccount(),
+      payer: getNextOptionalAccount(),
+    },
+    data: getInitializeTokenRewardsPoolInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
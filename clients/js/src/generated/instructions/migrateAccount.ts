@@ -0,0 +1,236 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type MigrateAccountInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountAccount extends string | IAccountMeta<string> = string,
+  TAccountAddressSeed extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountExtraAccountMetas extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountAccount extends string
+        ? WritableAccount<TAccountAccount>
+        : TAccountAccount,
+      TAccountAddressSeed extends string
+        ? ReadonlyAccount<TAccountAddressSeed>
+        : TAccountAddressSeed,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountExtraAccountMetas extends string
+        ? WritableAccount<TAccountExtraAccountMetas>
+        : TAccountExtraAccountMetas,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type MigrateAccountInstructionData = { discriminator: number };
+
+export type MigrateAccountInstructionDataArgs = {};
+
+export function getMigrateAccountInstructionDataEncoder(): Encoder<MigrateAccountInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([['discriminator', getU8Encoder()]]),
+    (value) => ({ ...value, discriminator: 17 })
+  );
+}
+
+export function getMigrateAccountInstructionDataDecoder(): Decoder<MigrateAccountInstructionData> {
+  return getStructDecoder([['discriminator', getU8Decoder()]]);
+}
+
+export function getMigrateAccountInstructionDataCodec(): Codec<
+  MigrateAccountInstructionDataArgs,
+  MigrateAccountInstructionData
+> {
+  return combineCodec(
+    getMigrateAccountInstructionDataEncoder(),
+    getMigrateAccountInstructionDataDecoder()
+  );
+}
+
+export type MigrateAccountInput<
+  TAccountPayer extends string = string,
+  TAccountAccount extends string = string,
+  TAccountAddressSeed extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountExtraAccountMetas extends string = string,
+> = {
+  /** Payer account. */
+  payer: TransactionSigner<TAccountPayer>;
+  /** Holder rewards pool or holder rewards account. */
+  account: Address<TAccountAccount>;
+  /** Token mint or token account the account's address is derived from. */
+  addressSeed: Address<TAccountAddressSeed>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Transfer hook extra account metas account. Required when migrating a pool in the original layout. */
+  extraAccountMetas?: Address<TAccountExtraAccountMetas>;
+};
+
+export function getMigrateAccountInstruction<
+  TAccountPayer extends string,
+  TAccountAccount extends string,
+  TAccountAddressSeed extends string,
+  TAccountSystemProgram extends string,
+  TAccountExtraAccountMetas extends string,
+>(
+  input: MigrateAccountInput<
+    TAccountPayer,
+    TAccountAccount,
+    TAccountAddressSeed,
+    TAccountSystemProgram,
+    TAccountExtraAccountMetas
+  >
+): MigrateAccountInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountPayer,
+  TAccountAccount,
+  TAccountAddressSeed,
+  TAccountSystemProgram,
+  TAccountExtraAccountMetas
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    payer: { value: input.payer ?? null, isWritable: true },
+    account: { value: input.account ?? null, isWritable: true },
+    addressSeed: { value: input.addressSeed ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    extraAccountMetas: {
+      value: input.extraAccountMetas ?? null,
+      isWritable: true,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.account),
+      getAccountMeta(accounts.addressSeed),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.extraAccountMetas),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
+    programAddress,
+    data: getMigrateAccountInstructionDataEncoder().encode({}),
+  } as MigrateAccountInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountPayer,
+    TAccountAccount,
+    TAccountAddressSeed,
+    TAccountSystemProgram,
+    TAccountExtraAccountMetas
+  >;
+
+  return instruction;
+}
+
+export type ParsedMigrateAccountInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Payer account. */
+    payer: TAccountMetas[0];
+    /** Holder rewards pool or holder rewards account. */
+    account: TAccountMetas[1];
+    /** Token mint or token account the account's address is derived from. */
+    addressSeed: TAccountMetas[2];
+    /** System program. */
+    systemProgram: TAccountMetas[3];
+    /** Transfer hook extra account metas account. Required when migrating a pool in the original layout. */
+    extraAccountMetas?: TAccountMetas[4] | undefined;
+  };
+  data: MigrateAccountInstructionData;
+};
+
+export function parseMigrateAccountInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedMigrateAccountInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  let optionalAccountsRemaining = instruction.accounts.length - 4;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      payer: getNextAccount(),
+      account: getNextAccount(),
+      addressSeed: getNextAccount(),
+      systemProgram: getNextAccount(),
+      extraAccountMetas: getNextOptionalAccount(),
+    },
+    data: getMigrateAccountInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,284 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type SetAutoClaimInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TAccountEscrow extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountTokenAccount extends string
+        ? ReadonlyAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountOwner extends string
+        ? ReadonlySignerAccount<TAccountOwner> &
+            IAccountSignerMeta<TAccountOwner>
+        : TAccountOwner,
+      TAccountEscrow extends string
+        ? WritableAccount<TAccountEscrow>
+        : TAccountEscrow,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetAutoClaimInstructionData = {
+  discriminator: number;
+  args: bigint;
+};
+
+export type SetAutoClaimInstructionDataArgs = { args: number | bigint };
+
+export function getSetAutoClaimInstructionDataEncoder(): Encoder<SetAutoClaimInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['args', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 23 })
+  );
+}
+
+export function getSetAutoClaimInstructionDataDecoder(): Decoder<SetAutoClaimInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['args', getU64Decoder()],
+  ]);
+}
+
+export function getSetAutoClaimInstructionDataCodec(): Codec<
+  SetAutoClaimInstructionDataArgs,
+  SetAutoClaimInstructionData
+> {
+  return combineCodec(
+    getSetAutoClaimInstructionDataEncoder(),
+    getSetAutoClaimInstructionDataDecoder()
+  );
+}
+
+export type SetAutoClaimInput<
+  TAccountHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountOwner extends string = string,
+  TAccountEscrow extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountPayer extends string = string,
+> = {
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Token account owner. */
+  owner: TransactionSigner<TAccountOwner>;
+  /** Auto-claim escrow account. */
+  escrow: Address<TAccountEscrow>;
+  /** System program. */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** Payer account. */
+  payer?: TransactionSigner<TAccountPayer>;
+  args: SetAutoClaimInstructionDataArgs['args'];
+};
+
+export function getSetAutoClaimInstruction<
+  TAccountHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountOwner extends string,
+  TAccountEscrow extends string,
+  TAccountSystemProgram extends string,
+  TAccountPayer extends string,
+>(
+  input: SetAutoClaimInput<
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountOwner,
+    TAccountEscrow,
+    TAccountSystemProgram,
+    TAccountPayer
+  >
+): SetAutoClaimInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountOwner,
+  TAccountEscrow,
+  TAccountSystemProgram,
+  TAccountPayer
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: false },
+    escrow: { value: input.escrow ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.escrow),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.payer),
+    ].filter(<T,>(x: T | undefined): x is T => x !== undefined),
+    programAddress,
+    data: getSetAutoClaimInstructionDataEncoder().encode(
+      args as SetAutoClaimInstructionDataArgs
+    ),
+  } as SetAutoClaimInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountOwner,
+    TAccountEscrow,
+    TAccountSystemProgram,
+    TAccountPayer
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetAutoClaimInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[0];
+    /** Token account. */
+    tokenAccount: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** Token account owner. */
+    owner: TAccountMetas[3];
+    /** Auto-claim escrow account. */
+    escrow: TAccountMetas[4];
+    /** System program. */
+    systemProgram: TAccountMetas[5];
+    /** Payer account. */
+    payer?: TAccountMetas[6] | undefined;
+  };
+  data: SetAutoClaimInstructionData;
+};
+
+export function parseSetAutoClaimInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetAutoClaimInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  let optionalAccountsRemaining = instruction.accounts.length - 6;
+  const getNextOptionalAccount = () => {
+    if (optionalAccountsRemaining === 0) return undefined;
+    optionalAccountsRemaining -= 1;
+    return getNextAccount();
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      owner: getNextAccount(),
+      escrow: getNextAccount(),
+      systemProgram: getNextAccount(),
+      payer: getNextOptionalAccount(),
+    },
+    data: getSetAutoClaimInstructionDataDecoder().decode(instruction.data),
+  };
+}
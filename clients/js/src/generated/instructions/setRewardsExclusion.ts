@@ -0,0 +1,260 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type SetRewardsExclusionInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool extends string | IAccountMeta<string> = string,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountPoolAuthority extends string | IAccountMeta<string> = string,
+  TAccountExtraAccountMetas extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewardsPool extends string
+        ? WritableAccount<TAccountHolderRewardsPool>
+        : TAccountHolderRewardsPool,
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountTokenAccount extends string
+        ? ReadonlyAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountPoolAuthority extends string
+        ? ReadonlySignerAccount<TAccountPoolAuthority> &
+            IAccountSignerMeta<TAccountPoolAuthority>
+        : TAccountPoolAuthority,
+      TAccountExtraAccountMetas extends string
+        ? ReadonlyAccount<TAccountExtraAccountMetas>
+        : TAccountExtraAccountMetas,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetRewardsExclusionInstructionData = {
+  discriminator: number;
+  args: boolean;
+};
+
+export type SetRewardsExclusionInstructionDataArgs = { args: boolean };
+
+export function getSetRewardsExclusionInstructionDataEncoder(): Encoder<SetRewardsExclusionInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['args', getBooleanEncoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 19 })
+  );
+}
+
+export function getSetRewardsExclusionInstructionDataDecoder(): Decoder<SetRewardsExclusionInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['args', getBooleanDecoder()],
+  ]);
+}
+
+export function getSetRewardsExclusionInstructionDataCodec(): Codec<
+  SetRewardsExclusionInstructionDataArgs,
+  SetRewardsExclusionInstructionData
+> {
+  return combineCodec(
+    getSetRewardsExclusionInstructionDataEncoder(),
+    getSetRewardsExclusionInstructionDataDecoder()
+  );
+}
+
+export type SetRewardsExclusionInput<
+  TAccountHolderRewardsPool extends string = string,
+  TAccountHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountPoolAuthority extends string = string,
+  TAccountExtraAccountMetas extends string = string,
+> = {
+  /** Holder rewards pool account. */
+  holderRewardsPool: Address<TAccountHolderRewardsPool>;
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Pool authority. */
+  poolAuthority: TransactionSigner<TAccountPoolAuthority>;
+  /** Transfer hook extra account metas account. */
+  extraAccountMetas: Address<TAccountExtraAccountMetas>;
+  args: SetRewardsExclusionInstructionDataArgs['args'];
+};
+
+export function getSetRewardsExclusionInstruction<
+  TAccountHolderRewardsPool extends string,
+  TAccountHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountPoolAuthority extends string,
+  TAccountExtraAccountMetas extends string,
+>(
+  input: SetRewardsExclusionInput<
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountPoolAuthority,
+    TAccountExtraAccountMetas
+  >
+): SetRewardsExclusionInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewardsPool,
+  TAccountHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountPoolAuthority,
+  TAccountExtraAccountMetas
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewardsPool: {
+      value: input.holderRewardsPool ?? null,
+      isWritable: true,
+    },
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    poolAuthority: { value: input.poolAuthority ?? null, isWritable: false },
+    extraAccountMetas: {
+      value: input.extraAccountMetas ?? null,
+      isWritable: false,
+    },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewardsPool),
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.poolAuthority),
+      getAccountMeta(accounts.extraAccountMetas),
+    ],
+    programAddress,
+    data: getSetRewardsExclusionInstructionDataEncoder().encode(
+      args as SetRewardsExclusionInstructionDataArgs
+    ),
+  } as SetRewardsExclusionInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewardsPool,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountPoolAuthority,
+    TAccountExtraAccountMetas
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetRewardsExclusionInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards pool account. */
+    holderRewardsPool: TAccountMetas[0];
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[1];
+    /** Token account. */
+    tokenAccount: TAccountMetas[2];
+    /** Token mint. */
+    mint: TAccountMetas[3];
+    /** Pool authority. */
+    poolAuthority: TAccountMetas[4];
+    /** Transfer hook extra account metas account. */
+    extraAccountMetas: TAccountMetas[5];
+  };
+  data: SetRewardsExclusionInstructionData;
+};
+
+export function parseSetRewardsExclusionInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetRewardsExclusionInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewardsPool: getNextAccount(),
+      holderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      poolAuthority: getNextAccount(),
+      extraAccountMetas: getNextAccount(),
+    },
+    data: getSetRewardsExclusionInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -0,0 +1,222 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { REWARDS_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export type SetRewardsRecipientInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewards extends string | IAccountMeta<string> = string,
+  TAccountTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountOwner extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountHolderRewards extends string
+        ? WritableAccount<TAccountHolderRewards>
+        : TAccountHolderRewards,
+      TAccountTokenAccount extends string
+        ? ReadonlyAccount<TAccountTokenAccount>
+        : TAccountTokenAccount,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountOwner extends string
+        ? ReadonlySignerAccount<TAccountOwner> &
+            IAccountSignerMeta<TAccountOwner>
+        : TAccountOwner,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SetRewardsRecipientInstructionData = {
+  discriminator: number;
+  pubkey: Address;
+};
+
+export type SetRewardsRecipientInstructionDataArgs = { pubkey: Address };
+
+export function getSetRewardsRecipientInstructionDataEncoder(): Encoder<SetRewardsRecipientInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['pubkey', getAddressEncoder()],
+    ]),
+    (value) => ({ ...value, discriminator: 20 })
+  );
+}
+
+export function getSetRewardsRecipientInstructionDataDecoder(): Decoder<SetRewardsRecipientInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['pubkey', getAddressDecoder()],
+  ]);
+}
+
+export function getSetRewardsRecipientInstructionDataCodec(): Codec<
+  SetRewardsRecipientInstructionDataArgs,
+  SetRewardsRecipientInstructionData
+> {
+  return combineCodec(
+    getSetRewardsRecipientInstructionDataEncoder(),
+    getSetRewardsRecipientInstructionDataDecoder()
+  );
+}
+
+export type SetRewardsRecipientInput<
+  TAccountHolderRewards extends string = string,
+  TAccountTokenAccount extends string = string,
+  TAccountMint extends string = string,
+  TAccountOwner extends string = string,
+> = {
+  /** Holder rewards account. */
+  holderRewards: Address<TAccountHolderRewards>;
+  /** Token account. */
+  tokenAccount: Address<TAccountTokenAccount>;
+  /** Token mint. */
+  mint: Address<TAccountMint>;
+  /** Token account owner. */
+  owner: TransactionSigner<TAccountOwner>;
+  pubkey: SetRewardsRecipientInstructionDataArgs['pubkey'];
+};
+
+export function getSetRewardsRecipientInstruction<
+  TAccountHolderRewards extends string,
+  TAccountTokenAccount extends string,
+  TAccountMint extends string,
+  TAccountOwner extends string,
+>(
+  input: SetRewardsRecipientInput<
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountOwner
+  >
+): SetRewardsRecipientInstruction<
+  typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountHolderRewards,
+  TAccountTokenAccount,
+  TAccountMint,
+  TAccountOwner
+> {
+  // Program address.
+  const programAddress = REWARDS_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    holderRewards: { value: input.holderRewards ?? null, isWritable: true },
+    tokenAccount: { value: input.tokenAccount ?? null, isWritable: false },
+    mint: { value: input.mint ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'omitted');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.holderRewards),
+      getAccountMeta(accounts.tokenAccount),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.owner),
+    ],
+    programAddress,
+    data: getSetRewardsRecipientInstructionDataEncoder().encode(
+      args as SetRewardsRecipientInstructionDataArgs
+    ),
+  } as SetRewardsRecipientInstruction<
+    typeof REWARDS_PROGRAM_ADDRESS,
+    TAccountHolderRewards,
+    TAccountTokenAccount,
+    TAccountMint,
+    TAccountOwner
+  >;
+
+  return instruction;
+}
+
+export type ParsedSetRewardsRecipientInstruction<
+  TProgram extends string = typeof REWARDS_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    /** Holder rewards account. */
+    holderRewards: TAccountMetas[0];
+    /** Token account. */
+    tokenAccount: TAccountMetas[1];
+    /** Token mint. */
+    mint: TAccountMetas[2];
+    /** Token account owner. */
+    owner: TAccountMetas[3];
+  };
+  data: SetRewardsRecipientInstructionData;
+};
+
+export function parseSetRewardsRecipientInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSetRewardsRecipientInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      holderRewards: getNextAccount(),
+      tokenAccount: getNextAccount(),
+      mint: getNextAccount(),
+      owner: getNextAccount(),
+    },
+    data: getSetRewardsRecipientInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
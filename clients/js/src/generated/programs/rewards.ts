@@ -8,10 +8,36 @@
 
 import { containsBytes, getU8Encoder, type Address } from '@solana/web3.js';
 import {
+  type ParsedAcceptPoolAuthorityInstruction,
+  type ParsedAddCompoundProgramInstruction,
+  type ParsedAddRewardStreamInstruction,
+  type ParsedCloseHolderRewardsInstruction,
+  type ParsedCreateEmissionScheduleInstruction,
+  type ParsedDepositRewardsInstruction,
   type ParsedDistributeRewardsInstruction,
+  type ParsedDistributeStreamRewardsInstruction,
+  type ParsedDistributeTokenRewardsInstruction,
+  type ParsedHarvestAndCompoundInstruction,
+  type ParsedHarvestEscrowedRewardsInstruction,
+  type ParsedHarvestRewardsBatchInstruction,
   type ParsedHarvestRewardsInstruction,
+  type ParsedHarvestRewardsToDestinationInstruction,
+  type ParsedHarvestStreamRewardsInstruction,
+  type ParsedHarvestTokenRewardsInstruction,
+  type ParsedInitializeHolderRewardsBatchInstruction,
   type ParsedInitializeHolderRewardsInstruction,
   type ParsedInitializeHolderRewardsPoolInstruction,
+  type ParsedInitializeTokenHolderRewardsInstruction,
+  type ParsedInitializeTokenRewardsPoolInstruction,
+  type ParsedMigrateAccountInstruction,
+  type ParsedRemoveCompoundProgramInstruction,
+  type ParsedSetAutoClaimInstruction,
+  type ParsedSetDistributionConfigInstruction,
+  type ParsedSetPoolAuthorityInstruction,
+  type ParsedSetRewardsExclusionInstruction,
+  type ParsedSetRewardsRecipientInstruction,
+  type ParsedSweepDustInstruction,
+  type ParsedSyncHolderRewardsInstruction,
 } from '../instructions';
 
 export const REWARDS_PROGRAM_ADDRESS =
@@ -19,7 +45,9 @@ export const REWARDS_PROGRAM_ADDRESS =
 
 export enum RewardsAccount {
   HolderRewards,
+  TokenHolderRewards,
   HolderRewardsPool,
+  TokenRewardsPool,
 }
 
 export enum RewardsInstruction {
@@ -27,6 +55,32 @@ export enum RewardsInstruction {
   DistributeRewards,
   InitializeHolderRewards,
   HarvestRewards,
+  SyncHolderRewards,
+  SetPoolAuthority,
+  AcceptPoolAuthority,
+  CloseHolderRewards,
+  HarvestRewardsToDestination,
+  InitializeTokenRewardsPool,
+  DistributeTokenRewards,
+  InitializeTokenHolderRewards,
+  HarvestTokenRewards,
+  AddRewardStream,
+  DistributeStreamRewards,
+  HarvestStreamRewards,
+  CreateEmissionSchedule,
+  MigrateAccount,
+  SweepDust,
+  SetRewardsExclusion,
+  SetRewardsRecipient,
+  HarvestRewardsBatch,
+  InitializeHolderRewardsBatch,
+  SetAutoClaim,
+  HarvestEscrowedRewards,
+  AddCompoundProgram,
+  RemoveCompoundProgram,
+  HarvestAndCompound,
+  SetDistributionConfig,
+  DepositRewards,
 }
 
 export function identifyRewardsInstruction(
@@ -46,6 +100,84 @@ export function identifyRewardsInstruction(
   if (containsBytes(data, getU8Encoder().encode(3), 0)) {
     return RewardsInstruction.HarvestRewards;
   }
+  if (containsBytes(data, getU8Encoder().encode(4), 0)) {
+    return RewardsInstruction.SyncHolderRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+    return RewardsInstruction.SetPoolAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+    return RewardsInstruction.AcceptPoolAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return RewardsInstruction.CloseHolderRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+    return RewardsInstruction.HarvestRewardsToDestination;
+  }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return RewardsInstruction.InitializeTokenRewardsPool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+    return RewardsInstruction.DistributeTokenRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+    return RewardsInstruction.InitializeTokenHolderRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return RewardsInstruction.HarvestTokenRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return RewardsInstruction.AddRewardStream;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return RewardsInstruction.DistributeStreamRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return RewardsInstruction.HarvestStreamRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return RewardsInstruction.CreateEmissionSchedule;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return RewardsInstruction.MigrateAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return RewardsInstruction.SweepDust;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return RewardsInstruction.SetRewardsExclusion;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return RewardsInstruction.SetRewardsRecipient;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return RewardsInstruction.HarvestRewardsBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return RewardsInstruction.InitializeHolderRewardsBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return RewardsInstruction.SetAutoClaim;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return RewardsInstruction.HarvestEscrowedRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return RewardsInstruction.AddCompoundProgram;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return RewardsInstruction.RemoveCompoundProgram;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return RewardsInstruction.HarvestAndCompound;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return RewardsInstruction.SetDistributionConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return RewardsInstruction.DepositRewards;
+  }
   throw new Error(
     'The provided instruction could not be identified as a rewards instruction.'
   );
@@ -65,4 +197,82 @@ export type ParsedRewardsInstruction<
     } & ParsedInitializeHolderRewardsInstruction<TProgram>)
   | ({
       instructionType: RewardsInstruction.HarvestRewards;
-    } & ParsedHarvestRewardsInstruction<TProgram>);
+    } & ParsedHarvestRewardsInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.SyncHolderRewards;
+    } & ParsedSyncHolderRewardsInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.SetPoolAuthority;
+    } & ParsedSetPoolAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.AcceptPoolAuthority;
+    } & ParsedAcceptPoolAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.CloseHolderRewards;
+    } & ParsedCloseHolderRewardsInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.HarvestRewardsToDestination;
+    } & ParsedHarvestRewardsToDestinationInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.InitializeTokenRewardsPool;
+    } & ParsedInitializeTokenRewardsPoolInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.DistributeTokenRewards;
+    } & ParsedDistributeTokenRewardsInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.InitializeTokenHolderRewards;
+    } & ParsedInitializeTokenHolderRewardsInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.HarvestTokenRewards;
+    } & ParsedHarvestTokenRewardsInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.AddRewardStream;
+    } & ParsedAddRewardStreamInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.DistributeStreamRewards;
+    } & ParsedDistributeStreamRewardsInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.HarvestStreamRewards;
+    } & ParsedHarvestStreamRewardsInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.CreateEmissionSchedule;
+    } & ParsedCreateEmissionScheduleInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.SweepDust;
+    } & ParsedSweepDustInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.SetRewardsExclusion;
+    } & ParsedSetRewardsExclusionInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.SetRewardsRecipient;
+    } & ParsedSetRewardsRecipientInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.HarvestRewardsBatch;
+    } & ParsedHarvestRewardsBatchInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.InitializeHolderRewardsBatch;
+    } & ParsedInitializeHolderRewardsBatchInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.SetAutoClaim;
+    } & ParsedSetAutoClaimInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.HarvestEscrowedRewards;
+    } & ParsedHarvestEscrowedRewardsInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.AddCompoundProgram;
+    } & ParsedAddCompoundProgramInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.RemoveCompoundProgram;
+    } & ParsedRemoveCompoundProgramInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.HarvestAndCompound;
+    } & ParsedHarvestAndCompoundInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.SetDistributionConfig;
+    } & ParsedSetDistributionConfigInstruction<TProgram>)
+  | ({
+      instructionType: RewardsInstruction.DepositRewards;
+    } & ParsedDepositRewardsInstruction<TProgram>);
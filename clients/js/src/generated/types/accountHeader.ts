@@ -0,0 +1,51 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type AccountHeader = {
+  discriminator: Array<number>;
+  version: number;
+  padding: Array<number>;
+};
+
+export type AccountHeaderArgs = AccountHeader;
+
+export function getAccountHeaderEncoder(): Encoder<AccountHeaderArgs> {
+  return getStructEncoder([
+    ['discriminator', getArrayEncoder(getU8Encoder(), { size: 8 })],
+    ['version', getU8Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 7 })],
+  ]);
+}
+
+export function getAccountHeaderDecoder(): Decoder<AccountHeader> {
+  return getStructDecoder([
+    ['discriminator', getArrayDecoder(getU8Decoder(), { size: 8 })],
+    ['version', getU8Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 7 })],
+  ]);
+}
+
+export function getAccountHeaderCodec(): Codec<
+  AccountHeaderArgs,
+  AccountHeader
+> {
+  return combineCodec(getAccountHeaderEncoder(), getAccountHeaderDecoder());
+}
@@ -0,0 +1,62 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getI64Decoder,
+  getI64Encoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type EmissionSchedule = {
+  totalAmount: bigint;
+  releasedAmount: bigint;
+  startTimestamp: bigint;
+  endTimestamp: bigint;
+};
+
+export type EmissionScheduleArgs = {
+  totalAmount: number | bigint;
+  releasedAmount: number | bigint;
+  startTimestamp: number | bigint;
+  endTimestamp: number | bigint;
+};
+
+export function getEmissionScheduleEncoder(): Encoder<EmissionScheduleArgs> {
+  return getStructEncoder([
+    ['totalAmount', getU64Encoder()],
+    ['releasedAmount', getU64Encoder()],
+    ['startTimestamp', getI64Encoder()],
+    ['endTimestamp', getI64Encoder()],
+  ]);
+}
+
+export function getEmissionScheduleDecoder(): Decoder<EmissionSchedule> {
+  return getStructDecoder([
+    ['totalAmount', getU64Decoder()],
+    ['releasedAmount', getU64Decoder()],
+    ['startTimestamp', getI64Decoder()],
+    ['endTimestamp', getI64Decoder()],
+  ]);
+}
+
+export function getEmissionScheduleCodec(): Codec<
+  EmissionScheduleArgs,
+  EmissionSchedule
+> {
+  return combineCodec(
+    getEmissionScheduleEncoder(),
+    getEmissionScheduleDecoder()
+  );
+}
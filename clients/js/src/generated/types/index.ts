@@ -0,0 +1,13 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+export * from './accountHeader';
+export * from './emissionSchedule';
+export * from './paladinRewardsEvent';
+export * from './rewardStream';
+export * from './rewardStreamCheckpoint';
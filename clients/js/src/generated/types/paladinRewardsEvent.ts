@@ -0,0 +1,424 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
+  getDiscriminatedUnionDecoder,
+  getDiscriminatedUnionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type GetDiscriminatedUnionVariant,
+  type GetDiscriminatedUnionVariantContent,
+} from '@solana/web3.js';
+
+export type PaladinRewardsEvent =
+  | {
+      __kind: 'RewardsDistributed';
+      mint: Address;
+      rewardId: bigint;
+      amount: bigint;
+      accumulatedRewardsPerToken: bigint;
+    }
+  | {
+      __kind: 'HolderRewardsInitialized';
+      holderRewards: Address;
+      tokenAccount: Address;
+      lastAccumulatedRewardsPerToken: bigint;
+      lastSeenBalance: bigint;
+    }
+  | {
+      __kind: 'HolderRewardsSettled';
+      holderRewards: Address;
+      accruedRewards: bigint;
+      unharvestedRewards: bigint;
+      lastSeenBalance: bigint;
+    }
+  | {
+      __kind: 'RewardsHarvested';
+      holderRewards: Address;
+      rewardId: bigint;
+      destination: Address;
+      amount: bigint;
+      unharvestedRewards: bigint;
+    }
+  | {
+      __kind: 'TokenRewardsDistributed';
+      tokenRewardsPool: Address;
+      amount: bigint;
+      accumulatedRewardsPerToken: bigint;
+    }
+  | {
+      __kind: 'TokenHolderRewardsInitialized';
+      tokenHolderRewards: Address;
+      tokenAccount: Address;
+      tokenRewardsPool: Address;
+      lastAccumulatedRewardsPerToken: bigint;
+      lastSeenBalance: bigint;
+    }
+  | {
+      __kind: 'TokenHolderRewardsSettled';
+      tokenHolderRewards: Address;
+      accruedRewards: bigint;
+      unharvestedRewards: bigint;
+      lastSeenBalance: bigint;
+    }
+  | {
+      __kind: 'TokenRewardsHarvested';
+      tokenHolderRewards: Address;
+      destination: Address;
+      amount: bigint;
+      unharvestedRewards: bigint;
+    };
+
+export type PaladinRewardsEventArgs =
+  | {
+      __kind: 'RewardsDistributed';
+      mint: Address;
+      rewardId: number | bigint;
+      amount: number | bigint;
+      accumulatedRewardsPerToken: number | bigint;
+    }
+  | {
+      __kind: 'HolderRewardsInitialized';
+      holderRewards: Address;
+      tokenAccount: Address;
+      lastAccumulatedRewardsPerToken: number | bigint;
+      lastSeenBalance: number | bigint;
+    }
+  | {
+      __kind: 'HolderRewardsSettled';
+      holderRewards: Address;
+      accruedRewards: number | bigint;
+      unharvestedRewards: number | bigint;
+      lastSeenBalance: number | bigint;
+    }
+  | {
+      __kind: 'RewardsHarvested';
+      holderRewards: Address;
+      rewardId: number | bigint;
+      destination: Address;
+      amount: number | bigint;
+      unharvestedRewards: number | bigint;
+    }
+  | {
+      __kind: 'TokenRewardsDistributed';
+      tokenRewardsPool: Address;
+      amount: number | bigint;
+      accumulatedRewardsPerToken: number | bigint;
+    }
+  | {
+      __kind: 'TokenHolderRewardsInitialized';
+      tokenHolderRewards: Address;
+      tokenAccount: Address;
+      tokenRewardsPool: Address;
+      lastAccumulatedRewardsPerToken: number | bigint;
+      lastSeenBalance: number | bigint;
+    }
+  | {
+      __kind: 'TokenHolderRewardsSettled';
+      tokenHolderRewards: Address;
+      accruedRewards: number | bigint;
+      unharvestedRewards: number | bigint;
+      lastSeenBalance: number | bigint;
+    }
+  | {
+      __kind: 'TokenRewardsHarvested';
+      tokenHolderRewards: Address;
+      destination: Address;
+      amount: number | bigint;
+      unharvestedRewards: number | bigint;
+    };
+
+export function getPaladinRewardsEventEncoder(): Encoder<PaladinRewardsEventArgs> {
+  return getDiscriminatedUnionEncoder([
+    [
+      'RewardsDistributed',
+      getStructEncoder([
+        ['mint', getAddressEncoder()],
+        ['rewardId', getU64Encoder()],
+        ['amount', getU64Encoder()],
+        ['accumulatedRewardsPerToken', getU128Encoder()],
+      ]),
+    ],
+    [
+      'HolderRewardsInitialized',
+      getStructEncoder([
+        ['holderRewards', getAddressEncoder()],
+        ['tokenAccount', getAddressEncoder()],
+        ['lastAccumulatedRewardsPerToken', getU128Encoder()],
+        ['lastSeenBalance', getU64Encoder()],
+      ]),
+    ],
+    [
+      'HolderRewardsSettled',
+      getStructEncoder([
+        ['holderRewards', getAddressEncoder()],
+        ['accruedRewards', getU64Encoder()],
+        ['unharvestedRewards', getU64Encoder()],
+        ['lastSeenBalance', getU64Encoder()],
+      ]),
+    ],
+    [
+      'RewardsHarvested',
+      getStructEncoder([
+        ['holderRewards', getAddressEncoder()],
+        ['rewardId', getU64Encoder()],
+        ['destination', getAddressEncoder()],
+        ['amount', getU64Encoder()],
+        ['unharvestedRewards', getU64Encoder()],
+      ]),
+    ],
+    [
+      'TokenRewardsDistributed',
+      getStructEncoder([
+        ['tokenRewardsPool', getAddressEncoder()],
+        ['amount', getU64Encoder()],
+        ['accumulatedRewardsPerToken', getU128Encoder()],
+      ]),
+    ],
+    [
+      'TokenHolderRewardsInitialized',
+      getStructEncoder([
+        ['tokenHolderRewards', getAddressEncoder()],
+        ['tokenAccount', getAddressEncoder()],
+        ['tokenRewardsPool', getAddressEncoder()],
+        ['lastAccumulatedRewardsPerToken', getU128Encoder()],
+        ['lastSeenBalance', getU64Encoder()],
+      ]),
+    ],
+    [
+      'TokenHolderRewardsSettled',
+      getStructEncoder([
+        ['tokenHolderRewards', getAddressEncoder()],
+        ['accruedRewards', getU64Encoder()],
+        ['unharvestedRewards', getU64Encoder()],
+        ['lastSeenBalance', getU64Encoder()],
+      ]),
+    ],
+    [
+      'TokenRewardsHarvested',
+      getStructEncoder([
+        ['tokenHolderRewards', getAddressEncoder()],
+        ['destination', getAddressEncoder()],
+        ['amount', getU64Encoder()],
+        ['unharvestedRewards', getU64Encoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getPaladinRewardsEventDecoder(): Decoder<PaladinRewardsEvent> {
+  return getDiscriminatedUnionDecoder([
+    [
+      'RewardsDistributed',
+      getStructDecoder([
+        ['mint', getAddressDecoder()],
+        ['rewardId', getU64Decoder()],
+        ['amount', getU64Decoder()],
+        ['accumulatedRewardsPerToken', getU128Decoder()],
+      ]),
+    ],
+    [
+      'HolderRewardsInitialized',
+      getStructDecoder([
+        ['holderRewards', getAddressDecoder()],
+        ['tokenAccount', getAddressDecoder()],
+        ['lastAccumulatedRewardsPerToken', getU128Decoder()],
+        ['lastSeenBalance', getU64Decoder()],
+      ]),
+    ],
+    [
+      'HolderRewardsSettled',
+      getStructDecoder([
+        ['holderRewards', getAddressDecoder()],
+        ['accruedRewards', getU64Decoder()],
+        ['unharvestedRewards', getU64Decoder()],
+        ['lastSeenBalance', getU64Decoder()],
+      ]),
+    ],
+    [
+      'RewardsHarvested',
+      getStructDecoder([
+        ['holderRewards', getAddressDecoder()],
+        ['rewardId', getU64Decoder()],
+        ['destination', getAddressDecoder()],
+        ['amount', getU64Decoder()],
+        ['unharvestedRewards', getU64Decoder()],
+      ]),
+    ],
+    [
+      'TokenRewardsDistributed',
+      getStructDecoder([
+        ['tokenRewardsPool', getAddressDecoder()],
+        ['amount', getU64Decoder()],
+        ['accumulatedRewardsPerToken', getU128Decoder()],
+      ]),
+    ],
+    [
+      'TokenHolderRewardsInitialized',
+      getStructDecoder([
+        ['tokenHolderRewards', getAddressDecoder()],
+        ['tokenAccount', getAddressDecoder()],
+        ['tokenRewardsPool', getAddressDecoder()],
+        ['lastAccumulatedRewardsPerToken', getU128Decoder()],
+        ['lastSeenBalance', getU64Decoder()],
+      ]),
+    ],
+    [
+      'TokenHolderRewardsSettled',
+      getStructDecoder([
+        ['tokenHolderRewards', getAddressDecoder()],
+        ['accruedRewards', getU64Decoder()],
+        ['unharvestedRewards', getU64Decoder()],
+        ['lastSeenBalance', getU64Decoder()],
+      ]),
+    ],
+    [
+      'TokenRewardsHarvested',
+      getStructDecoder([
+        ['tokenHolderRewards', getAddressDecoder()],
+        ['destination', getAddressDecoder()],
+        ['amount', getU64Decoder()],
+        ['unharvestedRewards', getU64Decoder()],
+      ]),
+    ],
+  ]);
+}
+
+export function getPaladinRewardsEventCodec(): Codec<
+  PaladinRewardsEventArgs,
+  PaladinRewardsEvent
+> {
+  return combineCodec(
+    getPaladinRewardsEventEncoder(),
+    getPaladinRewardsEventDecoder()
+  );
+}
+
+// Data Enum Helpers.
+export function paladinRewardsEvent(
+  kind: 'RewardsDistributed',
+  data: GetDiscriminatedUnionVariantContent<
+    PaladinRewardsEventArgs,
+    '__kind',
+    'RewardsDistributed'
+  >
+): GetDiscriminatedUnionVariant<
+  PaladinRewardsEventArgs,
+  '__kind',
+  'RewardsDistributed'
+>;
+export function paladinRewardsEvent(
+  kind: 'HolderRewardsInitialized',
+  data: GetDiscriminatedUnionVariantContent<
+    PaladinRewardsEventArgs,
+    '__kind',
+    'HolderRewardsInitialized'
+  >
+): GetDiscriminatedUnionVariant<
+  PaladinRewardsEventArgs,
+  '__kind',
+  'HolderRewardsInitialized'
+>;
+export function paladinRewardsEvent(
+  kind: 'HolderRewardsSettled',
+  data: GetDiscriminatedUnionVariantContent<
+    PaladinRewardsEventArgs,
+    '__kind',
+    'HolderRewardsSettled'
+  >
+): GetDiscriminatedUnionVariant<
+  PaladinRewardsEventArgs,
+  '__kind',
+  'HolderRewardsSettled'
+>;
+export function paladinRewardsEvent(
+  kind: 'RewardsHarvested',
+  data: GetDiscriminatedUnionVariantContent<
+    PaladinRewardsEventArgs,
+    '__kind',
+    'RewardsHarvested'
+  >
+): GetDiscriminatedUnionVariant<
+  PaladinRewardsEventArgs,
+  '__kind',
+  'RewardsHarvested'
+>;
+export function paladinRewardsEvent(
+  kind: 'TokenRewardsDistributed',
+  data: GetDiscriminatedUnionVariantContent<
+    PaladinRewardsEventArgs,
+    '__kind',
+    'TokenRewardsDistributed'
+  >
+): GetDiscriminatedUnionVariant<
+  PaladinRewardsEventArgs,
+  '__kind',
+  'TokenRewardsDistributed'
+>;
+export function paladinRewardsEvent(
+  kind: 'TokenHolderRewardsInitialized',
+  data: GetDiscriminatedUnionVariantContent<
+    PaladinRewardsEventArgs,
+    '__kind',
+    'TokenHolderRewardsInitialized'
+  >
+): GetDiscriminatedUnionVariant<
+  PaladinRewardsEventArgs,
+  '__kind',
+  'TokenHolderRewardsInitialized'
+>;
+export function paladinRewardsEvent(
+  kind: 'TokenHolderRewardsSettled',
+  data: GetDiscriminatedUnionVariantContent<
+    PaladinRewardsEventArgs,
+    '__kind',
+    'TokenHolderRewardsSettled'
+  >
+): GetDiscriminatedUnionVariant<
+  PaladinRewardsEventArgs,
+  '__kind',
+  'TokenHolderRewardsSettled'
+>;
+export function paladinRewardsEvent(
+  kind: 'TokenRewardsHarvested',
+  data: GetDiscriminatedUnionVariantContent<
+    PaladinRewardsEventArgs,
+    '__kind',
+    'TokenRewardsHarvested'
+  >
+): GetDiscriminatedUnionVariant<
+  PaladinRewardsEventArgs,
+  '__kind',
+  'TokenRewardsHarvested'
+>;
+export function paladinRewardsEvent<
+  K extends PaladinRewardsEventArgs['__kind'],
+  Data,
+>(kind: K, data?: Data) {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+
+export function isPaladinRewardsEvent<K extends PaladinRewardsEvent['__kind']>(
+  kind: K,
+  value: PaladinRewardsEvent
+): value is PaladinRewardsEvent & { __kind: K } {
+  return value.__kind === kind;
+}
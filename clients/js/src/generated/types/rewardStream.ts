@@ -0,0 +1,64 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type RewardStream = {
+  accumulatedRewardsPerToken: bigint;
+  rewardId: bigint;
+  totalDistributed: bigint;
+  rewardsPerTokenRemainder: bigint;
+  padding: Array<number>;
+};
+
+export type RewardStreamArgs = {
+  accumulatedRewardsPerToken: number | bigint;
+  rewardId: number | bigint;
+  totalDistributed: number | bigint;
+  rewardsPerTokenRemainder: number | bigint;
+  padding: Array<number>;
+};
+
+export function getRewardStreamEncoder(): Encoder<RewardStreamArgs> {
+  return getStructEncoder([
+    ['accumulatedRewardsPerToken', getU128Encoder()],
+    ['rewardId', getU64Encoder()],
+    ['totalDistributed', getU64Encoder()],
+    ['rewardsPerTokenRemainder', getU64Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 8 })],
+  ]);
+}
+
+export function getRewardStreamDecoder(): Decoder<RewardStream> {
+  return getStructDecoder([
+    ['accumulatedRewardsPerToken', getU128Decoder()],
+    ['rewardId', getU64Decoder()],
+    ['totalDistributed', getU64Decoder()],
+    ['rewardsPerTokenRemainder', getU64Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 8 })],
+  ]);
+}
+
+export function getRewardStreamCodec(): Codec<RewardStreamArgs, RewardStream> {
+  return combineCodec(getRewardStreamEncoder(), getRewardStreamDecoder());
+}
@@ -0,0 +1,62 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type RewardStreamCheckpoint = {
+  lastAccumulatedRewardsPerToken: bigint;
+  unharvestedRewards: bigint;
+  padding: Array<number>;
+};
+
+export type RewardStreamCheckpointArgs = {
+  lastAccumulatedRewardsPerToken: number | bigint;
+  unharvestedRewards: number | bigint;
+  padding: Array<number>;
+};
+
+export function getRewardStreamCheckpointEncoder(): Encoder<RewardStreamCheckpointArgs> {
+  return getStructEncoder([
+    ['lastAccumulatedRewardsPerToken', getU128Encoder()],
+    ['unharvestedRewards', getU64Encoder()],
+    ['padding', getArrayEncoder(getU8Encoder(), { size: 8 })],
+  ]);
+}
+
+export function getRewardStreamCheckpointDecoder(): Decoder<RewardStreamCheckpoint> {
+  return getStructDecoder([
+    ['lastAccumulatedRewardsPerToken', getU128Decoder()],
+    ['unharvestedRewards', getU64Decoder()],
+    ['padding', getArrayDecoder(getU8Decoder(), { size: 8 })],
+  ]);
+}
+
+export function getRewardStreamCheckpointCodec(): Codec<
+  RewardStreamCheckpointArgs,
+  RewardStreamCheckpoint
+> {
+  return combineCodec(
+    getRewardStreamCheckpointEncoder(),
+    getRewardStreamCheckpointDecoder()
+  );
+}
@@ -4,18 +4,43 @@
 //!
 //! <https://github.com/kinobi-so/kinobi>
 
-use borsh::{BorshDeserialize, BorshSerialize};
+use {
+    crate::generated::types::{AccountHeader, RewardStreamCheckpoint},
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::pubkey::Pubkey,
+};
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HolderRewards {
+    pub header: AccountHeader,
     pub last_accumulated_rewards_per_token: u128,
     pub unharvested_rewards: u64,
-    pub padding: u64,
+    pub last_seen_balance: u64,
+    pub reward_stream_checkpoints: [RewardStreamCheckpoint; 4],
+    pub auto_claim_threshold: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub recipient: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub owner: Pubkey,
+    pub excluded: u8,
+    pub last_seen_balance_unknown: u8,
+    pub padding: [u8; 6],
 }
 
 impl HolderRewards {
-    pub const LEN: usize = 32;
+    pub const LEN: usize = 288;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
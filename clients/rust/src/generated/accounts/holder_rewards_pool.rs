@@ -4,16 +4,50 @@
 //!
 //! <https://github.com/kinobi-so/kinobi>
 
-use borsh::{BorshDeserialize, BorshSerialize};
+use {
+    crate::generated::types::{AccountHeader, EmissionSchedule, RewardStream},
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::pubkey::Pubkey,
+};
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HolderRewardsPool {
+    pub header: AccountHeader,
     pub accumulated_rewards_per_token: u128,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_authority: Pubkey,
+    pub reward_streams: [RewardStream; 4],
+    pub emission_schedule: EmissionSchedule,
+    pub total_distributed: u64,
+    pub total_harvested: u64,
+    pub distribution_count: u64,
+    pub last_distribution_slot: u64,
+    pub undistributed_carryover: u64,
+    pub rewards_per_token_remainder: u64,
+    pub excluded_supply: u64,
+    pub excluded_holders: u64,
+    pub dust: u64,
+    pub compound_programs: [Pubkey; 4],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub distribution_authority: Pubkey,
+    pub min_distribution_amount: u64,
+    pub dust_remainder: u64,
 }
 
 impl HolderRewardsPool {
-    pub const LEN: usize = 16;
+    pub const LEN: usize = 568;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -6,5 +6,10 @@
 
 pub(crate) mod r#holder_rewards;
 pub(crate) mod r#holder_rewards_pool;
+pub(crate) mod r#token_holder_rewards;
+pub(crate) mod r#token_rewards_pool;
 
-pub use self::{r#holder_rewards::*, r#holder_rewards_pool::*};
+pub use self::{
+    r#holder_rewards::*, r#holder_rewards_pool::*, r#token_holder_rewards::*,
+    r#token_rewards_pool::*,
+};
@@ -0,0 +1,61 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenHolderRewards {
+    pub last_accumulated_rewards_per_token: u128,
+    pub unharvested_rewards: u64,
+    pub last_seen_balance: u64,
+}
+
+impl TokenHolderRewards {
+    pub const LEN: usize = 32;
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for TokenHolderRewards {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for TokenHolderRewards {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        Ok(Self::deserialize(buf)?)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for TokenHolderRewards {}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for TokenHolderRewards {
+    fn owner() -> Pubkey {
+        crate::REWARDS_ID
+    }
+}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::IdlBuild for TokenHolderRewards {}
+
+#[cfg(feature = "anchor-idl-build")]
+impl anchor_lang::Discriminator for TokenHolderRewards {
+    const DISCRIMINATOR: [u8; 8] = [0; 8];
+}
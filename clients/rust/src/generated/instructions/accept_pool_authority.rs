@@ -0,0 +1,383 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct AcceptPoolAuthority {
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: solana_program::pubkey::Pubkey,
+    /// Token mint.
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Pending pool authority.
+    pub pending_pool_authority: solana_program::pubkey::Pubkey,
+}
+
+impl AcceptPoolAuthority {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.holder_rewards_pool,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.pending_pool_authority,
+            true,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = AcceptPoolAuthorityInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct AcceptPoolAuthorityInstructionData {
+    discriminator: u8,
+}
+
+impl AcceptPoolAuthorityInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 6 }
+    }
+}
+
+impl Default for AcceptPoolAuthorityInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `AcceptPoolAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` holder_rewards_pool
+///   1. `[]` mint
+///   2. `[signer]` pending_pool_authority
+#[derive(Clone, Debug, Default)]
+pub struct AcceptPoolAuthorityBuilder {
+    holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    pending_pool_authority: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AcceptPoolAuthorityBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Holder rewards pool account.
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Pending pool authority.
+    #[inline(always)]
+    pub fn pending_pool_authority(
+        &mut self,
+        pending_pool_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.pending_pool_authority = Some(pending_pool_authority);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AcceptPoolAuthority {
+            holder_rewards_pool: self
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+            mint: self.mint.expect("mint is not set"),
+            pending_pool_authority: self
+                .pending_pool_authority
+                .expect("pending_pool_authority is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `accept_pool_authority` CPI accounts.
+pub struct AcceptPoolAuthorityCpiAccounts<'a, 'b> {
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pending pool authority.
+    pub pending_pool_authority: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `accept_pool_authority` CPI instruction.
+pub struct AcceptPoolAuthorityCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pending pool authority.
+    pub pending_pool_authority: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> AcceptPoolAuthorityCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AcceptPoolAuthorityCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            holder_rewards_pool: accounts.holder_rewards_pool,
+            mint: accounts.mint,
+            pending_pool_authority: accounts.pending_pool_authority,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.holder_rewards_pool.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.pending_pool_authority.key,
+            true,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = AcceptPoolAuthorityInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.holder_rewards_pool.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.pending_pool_authority.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AcceptPoolAuthority` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` holder_rewards_pool
+///   1. `[]` mint
+///   2. `[signer]` pending_pool_authority
+#[derive(Clone, Debug)]
+pub struct AcceptPoolAuthorityCpiBuilder<'a, 'b> {
+    instruction: Box<AcceptPoolAuthorityCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AcceptPoolAuthorityCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AcceptPoolAuthorityCpiBuilderInstruction {
+            __program: program,
+            holder_rewards_pool: None,
+            mint: None,
+            pending_pool_authority: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Holder rewards pool account.
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// Pending pool authority.
+    #[inline(always)]
+    pub fn pending_pool_authority(
+        &mut self,
+        pending_pool_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pending_pool_authority = Some(pending_pool_authority);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = AcceptPoolAuthorityCpi {
+            __program: self.instruction.__program,
+
+            holder_rewards_pool: self
+                .instruction
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            pending_pool_authority: self
+                .instruction
+                .pending_pool_authority
+                .expect("pending_pool_authority is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AcceptPoolAuthorityCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    holder_rewards_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pending_pool_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
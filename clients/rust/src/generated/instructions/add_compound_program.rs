@@ -0,0 +1,423 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::pubkey::Pubkey,
+};
+
+/// Accounts.
+pub struct AddCompoundProgram {
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: solana_program::pubkey::Pubkey,
+    /// Token mint.
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Pool authority.
+    pub pool_authority: solana_program::pubkey::Pubkey,
+}
+
+impl AddCompoundProgram {
+    pub fn instruction(
+        &self,
+        args: AddCompoundProgramInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AddCompoundProgramInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.holder_rewards_pool,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.pool_authority,
+            true,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = AddCompoundProgramInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct AddCompoundProgramInstructionData {
+    discriminator: u8,
+}
+
+impl AddCompoundProgramInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 25 }
+    }
+}
+
+impl Default for AddCompoundProgramInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddCompoundProgramInstructionArgs {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pubkey: Pubkey,
+}
+
+/// Instruction builder for `AddCompoundProgram`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` holder_rewards_pool
+///   1. `[]` mint
+///   2. `[signer]` pool_authority
+#[derive(Clone, Debug, Default)]
+pub struct AddCompoundProgramBuilder {
+    holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    pool_authority: Option<solana_program::pubkey::Pubkey>,
+    pubkey: Option<Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AddCompoundProgramBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Holder rewards pool account.
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Pool authority.
+    #[inline(always)]
+    pub fn pool_authority(&mut self, pool_authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pool_authority = Some(pool_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn pubkey(&mut self, pubkey: Pubkey) -> &mut Self {
+        self.pubkey = Some(pubkey);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AddCompoundProgram {
+            holder_rewards_pool: self
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+            mint: self.mint.expect("mint is not set"),
+            pool_authority: self.pool_authority.expect("pool_authority is not set"),
+        };
+        let args = AddCompoundProgramInstructionArgs {
+            pubkey: self.pubkey.clone().expect("pubkey is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `add_compound_program` CPI accounts.
+pub struct AddCompoundProgramCpiAccounts<'a, 'b> {
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pool authority.
+    pub pool_authority: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `add_compound_program` CPI instruction.
+pub struct AddCompoundProgramCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pool authority.
+    pub pool_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AddCompoundProgramInstructionArgs,
+}
+
+impl<'a, 'b> AddCompoundProgramCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AddCompoundProgramCpiAccounts<'a, 'b>,
+        args: AddCompoundProgramInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            holder_rewards_pool: accounts.holder_rewards_pool,
+            mint: accounts.mint,
+            pool_authority: accounts.pool_authority,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.holder_rewards_pool.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.pool_authority.key,
+            true,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = AddCompoundProgramInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.holder_rewards_pool.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.pool_authority.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AddCompoundProgram` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` holder_rewards_pool
+///   1. `[]` mint
+///   2. `[signer]` pool_authority
+#[derive(Clone, Debug)]
+pub struct AddCompoundProgramCpiBuilder<'a, 'b> {
+    instruction: Box<AddCompoundProgramCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AddCompoundProgramCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AddCompoundProgramCpiBuilderInstruction {
+            __program: program,
+            holder_rewards_pool: None,
+            mint: None,
+            pool_authority: None,
+            pubkey: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Holder rewards pool account.
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// Pool authority.
+    #[inline(always)]
+    pub fn pool_authority(
+        &mut self,
+        pool_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pool_authority = Some(pool_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn pubkey(&mut self, pubkey: Pubkey) -> &mut Self {
+        self.instruction.pubkey = Some(pubkey);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AddCompoundProgramInstructionArgs {
+            pubkey: self.instruction.pubkey.clone().expect("pubkey is not set"),
+        };
+        let instruction = AddCompoundProgramCpi {
+            __program: self.instruction.__program,
+
+            holder_rewards_pool: self
+                .instruction
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            pool_authority: self
+                .instruction
+                .pool_authority
+                .expect("pool_authority is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AddCompoundProgramCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    holder_rewards_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pool_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    pubkey: Option<Pubkey>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,629 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct DistributeTokenRewards {
+    /// Source token account owner or delegate.
+    pub source_authority: solana_program::pubkey::Pubkey,
+    /// Source reward token account.
+    pub source: solana_program::pubkey::Pubkey,
+    /// Token rewards pool account.
+    pub token_rewards_pool: solana_program::pubkey::Pubkey,
+    /// Token rewards pool vault.
+    pub vault: solana_program::pubkey::Pubkey,
+    /// Token mint.
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Reward token mint.
+    pub reward_mint: solana_program::pubkey::Pubkey,
+    /// Reward token program.
+    pub reward_token_program: solana_program::pubkey::Pubkey,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: solana_program::pubkey::Pubkey,
+}
+
+impl DistributeTokenRewards {
+    pub fn instruction(
+        &self,
+        args: DistributeTokenRewardsInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: DistributeTokenRewardsInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.source_authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.source,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.token_rewards_pool,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reward_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reward_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.holder_rewards_pool,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = DistributeTokenRewardsInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct DistributeTokenRewardsInstructionData {
+    discriminator: u8,
+}
+
+impl DistributeTokenRewardsInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 10 }
+    }
+}
+
+impl Default for DistributeTokenRewardsInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistributeTokenRewardsInstructionArgs {
+    pub args: u64,
+}
+
+/// Instruction builder for `DistributeTokenRewards`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` source_authority
+///   1. `[writable]` source
+///   2. `[writable]` token_rewards_pool
+///   3. `[writable]` vault
+///   4. `[]` mint
+///   5. `[]` reward_mint
+///   6. `[]` reward_token_program
+///   7. `[writable]` holder_rewards_pool
+#[derive(Clone, Debug, Default)]
+pub struct DistributeTokenRewardsBuilder {
+    source_authority: Option<solana_program::pubkey::Pubkey>,
+    source: Option<solana_program::pubkey::Pubkey>,
+    token_rewards_pool: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    reward_mint: Option<solana_program::pubkey::Pubkey>,
+    reward_token_program: Option<solana_program::pubkey::Pubkey>,
+    holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
+    args: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl DistributeTokenRewardsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Source token account owner or delegate.
+    #[inline(always)]
+    pub fn source_authority(
+        &mut self,
+        source_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.source_authority = Some(source_authority);
+        self
+    }
+    /// Source reward token account.
+    #[inline(always)]
+    pub fn source(&mut self, source: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.source = Some(source);
+        self
+    }
+    /// Token rewards pool account.
+    #[inline(always)]
+    pub fn token_rewards_pool(
+        &mut self,
+        token_rewards_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.token_rewards_pool = Some(token_rewards_pool);
+        self
+    }
+    /// Token rewards pool vault.
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Reward token mint.
+    #[inline(always)]
+    pub fn reward_mint(&mut self, reward_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_mint = Some(reward_mint);
+        self
+    }
+    /// Reward token program.
+    #[inline(always)]
+    pub fn reward_token_program(
+        &mut self,
+        reward_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reward_token_program = Some(reward_token_program);
+        self
+    }
+    /// Holder rewards pool account.
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    #[inline(always)]
+    pub fn args(&mut self, args: u64) -> &mut Self {
+        self.args = Some(args);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = DistributeTokenRewards {
+            source_authority: self.source_authority.expect("source_authority is not set"),
+            source: self.source.expect("source is not set"),
+            token_rewards_pool: self
+                .token_rewards_pool
+                .expect("token_rewards_pool is not set"),
+            vault: self.vault.expect("vault is not set"),
+            mint: self.mint.expect("mint is not set"),
+            reward_mint: self.reward_mint.expect("reward_mint is not set"),
+            reward_token_program: self
+                .reward_token_program
+                .expect("reward_token_program is not set"),
+            holder_rewards_pool: self
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+        };
+        let args = DistributeTokenRewardsInstructionArgs {
+            args: self.args.clone().expect("args is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `distribute_token_rewards` CPI accounts.
+pub struct DistributeTokenRewardsCpiAccounts<'a, 'b> {
+    /// Source token account owner or delegate.
+    pub source_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Source reward token account.
+    pub source: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token rewards pool account.
+    pub token_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token rewards pool vault.
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward token mint.
+    pub reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward token program.
+    pub reward_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `distribute_token_rewards` CPI instruction.
+pub struct DistributeTokenRewardsCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Source token account owner or delegate.
+    pub source_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Source reward token account.
+    pub source: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token rewards pool account.
+    pub token_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token rewards pool vault.
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward token mint.
+    pub reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward token program.
+    pub reward_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: DistributeTokenRewardsInstructionArgs,
+}
+
+impl<'a, 'b> DistributeTokenRewardsCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: DistributeTokenRewardsCpiAccounts<'a, 'b>,
+        args: DistributeTokenRewardsInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            source_authority: accounts.source_authority,
+            source: accounts.source,
+            token_rewards_pool: accounts.token_rewards_pool,
+            vault: accounts.vault,
+            mint: accounts.mint,
+            reward_mint: accounts.reward_mint,
+            reward_token_program: accounts.reward_token_program,
+            holder_rewards_pool: accounts.holder_rewards_pool,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.source_authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.source.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.token_rewards_pool.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reward_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reward_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.holder_rewards_pool.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = DistributeTokenRewardsInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.source_authority.clone());
+        account_infos.push(self.source.clone());
+        account_infos.push(self.token_rewards_pool.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.reward_mint.clone());
+        account_infos.push(self.reward_token_program.clone());
+        account_infos.push(self.holder_rewards_pool.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `DistributeTokenRewards` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` source_authority
+///   1. `[writable]` source
+///   2. `[writable]` token_rewards_pool
+///   3. `[writable]` vault
+///   4. `[]` mint
+///   5. `[]` reward_mint
+///   6. `[]` reward_token_program
+///   7. `[writable]` holder_rewards_pool
+#[derive(Clone, Debug)]
+pub struct DistributeTokenRewardsCpiBuilder<'a, 'b> {
+    instruction: Box<DistributeTokenRewardsCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> DistributeTokenRewardsCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(DistributeTokenRewardsCpiBuilderInstruction {
+            __program: program,
+            source_authority: None,
+            source: None,
+            token_rewards_pool: None,
+            vault: None,
+            mint: None,
+            reward_mint: None,
+            reward_token_program: None,
+            holder_rewards_pool: None,
+            args: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Source token account owner or delegate.
+    #[inline(always)]
+    pub fn source_authority(
+        &mut self,
+        source_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source_authority = Some(source_authority);
+        self
+    }
+    /// Source reward token account.
+    #[inline(always)]
+    pub fn source(
+        &mut self,
+        source: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.source = Some(source);
+        self
+    }
+    /// Token rewards pool account.
+    #[inline(always)]
+    pub fn token_rewards_pool(
+        &mut self,
+        token_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_rewards_pool = Some(token_rewards_pool);
+        self
+    }
+    /// Token rewards pool vault.
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// Reward token mint.
+    #[inline(always)]
+    pub fn reward_mint(
+        &mut self,
+        reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_mint = Some(reward_mint);
+        self
+    }
+    /// Reward token program.
+    #[inline(always)]
+    pub fn reward_token_program(
+        &mut self,
+        reward_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_token_program = Some(reward_token_program);
+        self
+    }
+    /// Holder rewards pool account.
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    #[inline(always)]
+    pub fn args(&mut self, args: u64) -> &mut Self {
+        self.instruction.args = Some(args);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = DistributeTokenRewardsInstructionArgs {
+            args: self.instruction.args.clone().expect("args is not set"),
+        };
+        let instruction = DistributeTokenRewardsCpi {
+            __program: self.instruction.__program,
+
+            source_authority: self
+                .instruction
+                .source_authority
+                .expect("source_authority is not set"),
+
+            source: self.instruction.source.expect("source is not set"),
+
+            token_rewards_pool: self
+                .instruction
+                .token_rewards_pool
+                .expect("token_rewards_pool is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            reward_mint: self
+                .instruction
+                .reward_mint
+                .expect("reward_mint is not set"),
+
+            reward_token_program: self
+                .instruction
+                .reward_token_program
+                .expect("reward_token_program is not set"),
+
+            holder_rewards_pool: self
+                .instruction
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DistributeTokenRewardsCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    source_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    source: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_rewards_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    holder_rewards_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    args: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
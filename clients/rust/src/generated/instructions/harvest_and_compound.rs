@@ -0,0 +1,554 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct HarvestAndCompound {
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: solana_program::pubkey::Pubkey,
+    /// Holder rewards account.
+    pub holder_rewards: solana_program::pubkey::Pubkey,
+    /// Token account.
+    pub token_account: solana_program::pubkey::Pubkey,
+    /// Token mint.
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Rewards owner.
+    pub rewards_owner: solana_program::pubkey::Pubkey,
+    /// Compound program.
+    pub compound_program: solana_program::pubkey::Pubkey,
+    /// Compound destination, owned by the compound program.
+    pub compound_destination: solana_program::pubkey::Pubkey,
+}
+
+impl HarvestAndCompound {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.holder_rewards_pool,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.holder_rewards,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.rewards_owner,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.compound_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.compound_destination,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = HarvestAndCompoundInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct HarvestAndCompoundInstructionData {
+    discriminator: u8,
+}
+
+impl HarvestAndCompoundInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 27 }
+    }
+}
+
+impl Default for HarvestAndCompoundInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `HarvestAndCompound`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` holder_rewards_pool
+///   1. `[writable]` holder_rewards
+///   2. `[]` token_account
+///   3. `[]` mint
+///   4. `[signer]` rewards_owner
+///   5. `[]` compound_program
+///   6. `[writable]` compound_destination
+#[derive(Clone, Debug, Default)]
+pub struct HarvestAndCompoundBuilder {
+    holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
+    holder_rewards: Option<solana_program::pubkey::Pubkey>,
+    token_account: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    rewards_owner: Option<solana_program::pubkey::Pubkey>,
+    compound_program: Option<solana_program::pubkey::Pubkey>,
+    compound_destination: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl HarvestAndCompoundBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Holder rewards pool account.
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    /// Holder rewards account.
+    #[inline(always)]
+    pub fn holder_rewards(&mut self, holder_rewards: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.holder_rewards = Some(holder_rewards);
+        self
+    }
+    /// Token account.
+    #[inline(always)]
+    pub fn token_account(&mut self, token_account: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_account = Some(token_account);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Rewards owner.
+    #[inline(always)]
+    pub fn rewards_owner(&mut self, rewards_owner: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.rewards_owner = Some(rewards_owner);
+        self
+    }
+    /// Compound program.
+    #[inline(always)]
+    pub fn compound_program(
+        &mut self,
+        compound_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.compound_program = Some(compound_program);
+        self
+    }
+    /// Compound destination, owned by the compound program.
+    #[inline(always)]
+    pub fn compound_destination(
+        &mut self,
+        compound_destination: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.compound_destination = Some(compound_destination);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = HarvestAndCompound {
+            holder_rewards_pool: self
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+            holder_rewards: self.holder_rewards.expect("holder_rewards is not set"),
+            token_account: self.token_account.expect("token_account is not set"),
+            mint: self.mint.expect("mint is not set"),
+            rewards_owner: self.rewards_owner.expect("rewards_owner is not set"),
+            compound_program: self.compound_program.expect("compound_program is not set"),
+            compound_destination: self
+                .compound_destination
+                .expect("compound_destination is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `harvest_and_compound` CPI accounts.
+pub struct HarvestAndCompoundCpiAccounts<'a, 'b> {
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards account.
+    pub holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token account.
+    pub token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rewards owner.
+    pub rewards_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Compound program.
+    pub compound_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Compound destination, owned by the compound program.
+    pub compound_destination: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `harvest_and_compound` CPI instruction.
+pub struct HarvestAndCompoundCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool account.
+    pub holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards account.
+    pub holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token account.
+    pub token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rewards owner.
+    pub rewards_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Compound program.
+    pub compound_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Compound destination, owned by the compound program.
+    pub compound_destination: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> HarvestAndCompoundCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: HarvestAndCompoundCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            holder_rewards_pool: accounts.holder_rewards_pool,
+            holder_rewards: accounts.holder_rewards,
+            token_account: accounts.token_account,
+            mint: accounts.mint,
+            rewards_owner: accounts.rewards_owner,
+            compound_program: accounts.compound_program,
+            compound_destination: accounts.compound_destination,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.holder_rewards_pool.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.holder_rewards.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.rewards_owner.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.compound_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.compound_destination.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = HarvestAndCompoundInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.holder_rewards_pool.clone());
+        account_infos.push(self.holder_rewards.clone());
+        account_infos.push(self.token_account.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.rewards_owner.clone());
+        account_infos.push(self.compound_program.clone());
+        account_infos.push(self.compound_destination.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `HarvestAndCompound` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` holder_rewards_pool
+///   1. `[writable]` holder_rewards
+///   2. `[]` token_account
+///   3. `[]` mint
+///   4. `[signer]` rewards_owner
+///   5. `[]` compound_program
+///   6. `[writable]` compound_destination
+#[derive(Clone, Debug)]
+pub struct HarvestAndCompoundCpiBuilder<'a, 'b> {
+    instruction: Box<HarvestAndCompoundCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> HarvestAndCompoundCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(HarvestAndCompoundCpiBuilderInstruction {
+            __program: program,
+            holder_rewards_pool: None,
+            holder_rewards: None,
+            token_account: None,
+            mint: None,
+            rewards_owner: None,
+            compound_program: None,
+            compound_destination: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Holder rewards pool account.
+    #[inline(always)]
+    pub fn holder_rewards_pool(
+        &mut self,
+        holder_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards_pool = Some(holder_rewards_pool);
+        self
+    }
+    /// Holder rewards account.
+    #[inline(always)]
+    pub fn holder_rewards(
+        &mut self,
+        holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards = Some(holder_rewards);
+        self
+    }
+    /// Token account.
+    #[inline(always)]
+    pub fn token_account(
+        &mut self,
+        token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_account = Some(token_account);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// Rewards owner.
+    #[inline(always)]
+    pub fn rewards_owner(
+        &mut self,
+        rewards_owner: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.rewards_owner = Some(rewards_owner);
+        self
+    }
+    /// Compound program.
+    #[inline(always)]
+    pub fn compound_program(
+        &mut self,
+        compound_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.compound_program = Some(compound_program);
+        self
+    }
+    /// Compound destination, owned by the compound program.
+    #[inline(always)]
+    pub fn compound_destination(
+        &mut self,
+        compound_destination: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.compound_destination = Some(compound_destination);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = HarvestAndCompoundCpi {
+            __program: self.instruction.__program,
+
+            holder_rewards_pool: self
+                .instruction
+                .holder_rewards_pool
+                .expect("holder_rewards_pool is not set"),
+
+            holder_rewards: self
+                .instruction
+                .holder_rewards
+                .expect("holder_rewards is not set"),
+
+            token_account: self
+                .instruction
+                .token_account
+                .expect("token_account is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            rewards_owner: self
+                .instruction
+                .rewards_owner
+                .expect("rewards_owner is not set"),
+
+            compound_program: self
+                .instruction
+                .compound_program
+                .expect("compound_program is not set"),
+
+            compound_destination: self
+                .instruction
+                .compound_destination
+                .expect("compound_destination is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HarvestAndCompoundCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    holder_rewards_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    holder_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    rewards_owner: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    compound_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    compound_destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -16,6 +16,8 @@ pub struct HarvestRewards {
     pub token_account: solana_program::pubkey::Pubkey,
     /// Token mint.
     pub mint: solana_program::pubkey::Pubkey,
+    /// Rewards recipient.
+    pub recipient: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl HarvestRewards {
@@ -27,7 +29,7 @@ impl HarvestRewards {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.holder_rewards_pool,
             false,
@@ -43,6 +45,11 @@ impl HarvestRewards {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.mint, false,
         ));
+        if let Some(recipient) = self.recipient {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                recipient, false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = HarvestRewardsInstructionData::new().try_to_vec().unwrap();
 
@@ -79,12 +86,14 @@ impl Default for HarvestRewardsInstructionData {
 ///   1. `[writable]` holder_rewards
 ///   2. `[writable]` token_account
 ///   3. `[]` mint
+///   4. `[writable, optional]` recipient
 #[derive(Clone, Debug, Default)]
 pub struct HarvestRewardsBuilder {
     holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
     holder_rewards: Option<solana_program::pubkey::Pubkey>,
     token_account: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
+    recipient: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -119,6 +128,13 @@ impl HarvestRewardsBuilder {
         self.mint = Some(mint);
         self
     }
+    /// `[optional account]`
+    /// Rewards recipient.
+    #[inline(always)]
+    pub fn recipient(&mut self, recipient: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.recipient = recipient;
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -146,6 +162,7 @@ impl HarvestRewardsBuilder {
             holder_rewards: self.holder_rewards.expect("holder_rewards is not set"),
             token_account: self.token_account.expect("token_account is not set"),
             mint: self.mint.expect("mint is not set"),
+            recipient: self.recipient,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -162,6 +179,8 @@ pub struct HarvestRewardsCpiAccounts<'a, 'b> {
     pub token_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// Token mint.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rewards recipient.
+    pub recipient: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `harvest_rewards` CPI instruction.
@@ -176,6 +195,8 @@ pub struct HarvestRewardsCpi<'a, 'b> {
     pub token_account: &'b solana_program::account_info::AccountInfo<'a>,
     /// Token mint.
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Rewards recipient.
+    pub recipient: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
@@ -189,6 +210,7 @@ impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
             holder_rewards: accounts.holder_rewards,
             token_account: accounts.token_account,
             mint: accounts.mint,
+            recipient: accounts.recipient,
         }
     }
     #[inline(always)]
@@ -224,7 +246,7 @@ impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.holder_rewards_pool.key,
             false,
@@ -241,6 +263,12 @@ impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
             *self.mint.key,
             false,
         ));
+        if let Some(recipient) = self.recipient {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *recipient.key,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -255,12 +283,15 @@ impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.holder_rewards_pool.clone());
         account_infos.push(self.holder_rewards.clone());
         account_infos.push(self.token_account.clone());
         account_infos.push(self.mint.clone());
+        if let Some(recipient) = self.recipient {
+            account_infos.push(recipient.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -281,6 +312,7 @@ impl<'a, 'b> HarvestRewardsCpi<'a, 'b> {
 ///   1. `[writable]` holder_rewards
 ///   2. `[writable]` token_account
 ///   3. `[]` mint
+///   4. `[writable, optional]` recipient
 #[derive(Clone, Debug)]
 pub struct HarvestRewardsCpiBuilder<'a, 'b> {
     instruction: Box<HarvestRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -294,6 +326,7 @@ impl<'a, 'b> HarvestRewardsCpiBuilder<'a, 'b> {
             holder_rewards: None,
             token_account: None,
             mint: None,
+            recipient: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -331,6 +364,16 @@ impl<'a, 'b> HarvestRewardsCpiBuilder<'a, 'b> {
         self.instruction.mint = Some(mint);
         self
     }
+    /// `[optional account]`
+    /// Rewards recipient.
+    #[inline(always)]
+    pub fn recipient(
+        &mut self,
+        recipient: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.recipient = recipient;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -392,6 +435,8 @@ impl<'a, 'b> HarvestRewardsCpiBuilder<'a, 'b> {
                 .expect("token_account is not set"),
 
             mint: self.instruction.mint.expect("mint is not set"),
+
+            recipient: self.instruction.recipient,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -407,6 +452,7 @@ struct HarvestRewardsCpiBuilderInstruction<'a, 'b> {
     holder_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    recipient: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -0,0 +1,633 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct HarvestTokenRewards {
+    /// Token rewards pool account.
+    pub token_rewards_pool: solana_program::pubkey::Pubkey,
+    /// Token holder rewards account.
+    pub token_holder_rewards: solana_program::pubkey::Pubkey,
+    /// Token account.
+    pub token_account: solana_program::pubkey::Pubkey,
+    /// Token mint.
+    pub mint: solana_program::pubkey::Pubkey,
+    /// Token rewards pool vault.
+    pub vault: solana_program::pubkey::Pubkey,
+    /// Destination reward token account.
+    pub destination: solana_program::pubkey::Pubkey,
+    /// Reward token mint.
+    pub reward_mint: solana_program::pubkey::Pubkey,
+    /// Reward token program.
+    pub reward_token_program: solana_program::pubkey::Pubkey,
+    /// Holder rewards account.
+    pub holder_rewards: solana_program::pubkey::Pubkey,
+}
+
+impl HarvestTokenRewards {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_rewards_pool,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.token_holder_rewards,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.destination,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reward_mint,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reward_token_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.holder_rewards,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = HarvestTokenRewardsInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct HarvestTokenRewardsInstructionData {
+    discriminator: u8,
+}
+
+impl HarvestTokenRewardsInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 12 }
+    }
+}
+
+impl Default for HarvestTokenRewardsInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `HarvestTokenRewards`.
+///
+/// ### Accounts:
+///
+///   0. `[]` token_rewards_pool
+///   1. `[writable]` token_holder_rewards
+///   2. `[]` token_account
+///   3. `[]` mint
+///   4. `[writable]` vault
+///   5. `[writable]` destination
+///   6. `[]` reward_mint
+///   7. `[]` reward_token_program
+///   8. `[]` holder_rewards
+#[derive(Clone, Debug, Default)]
+pub struct HarvestTokenRewardsBuilder {
+    token_rewards_pool: Option<solana_program::pubkey::Pubkey>,
+    token_holder_rewards: Option<solana_program::pubkey::Pubkey>,
+    token_account: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    destination: Option<solana_program::pubkey::Pubkey>,
+    reward_mint: Option<solana_program::pubkey::Pubkey>,
+    reward_token_program: Option<solana_program::pubkey::Pubkey>,
+    holder_rewards: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl HarvestTokenRewardsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Token rewards pool account.
+    #[inline(always)]
+    pub fn token_rewards_pool(
+        &mut self,
+        token_rewards_pool: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.token_rewards_pool = Some(token_rewards_pool);
+        self
+    }
+    /// Token holder rewards account.
+    #[inline(always)]
+    pub fn token_holder_rewards(
+        &mut self,
+        token_holder_rewards: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.token_holder_rewards = Some(token_holder_rewards);
+        self
+    }
+    /// Token account.
+    #[inline(always)]
+    pub fn token_account(&mut self, token_account: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_account = Some(token_account);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// Token rewards pool vault.
+    #[inline(always)]
+    pub fn vault(&mut self, vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    /// Destination reward token account.
+    #[inline(always)]
+    pub fn destination(&mut self, destination: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+    /// Reward token mint.
+    #[inline(always)]
+    pub fn reward_mint(&mut self, reward_mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_mint = Some(reward_mint);
+        self
+    }
+    /// Reward token program.
+    #[inline(always)]
+    pub fn reward_token_program(
+        &mut self,
+        reward_token_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reward_token_program = Some(reward_token_program);
+        self
+    }
+    /// Holder rewards account.
+    #[inline(always)]
+    pub fn holder_rewards(&mut self, holder_rewards: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.holder_rewards = Some(holder_rewards);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = HarvestTokenRewards {
+            token_rewards_pool: self
+                .token_rewards_pool
+                .expect("token_rewards_pool is not set"),
+            token_holder_rewards: self
+                .token_holder_rewards
+                .expect("token_holder_rewards is not set"),
+            token_account: self.token_account.expect("token_account is not set"),
+            mint: self.mint.expect("mint is not set"),
+            vault: self.vault.expect("vault is not set"),
+            destination: self.destination.expect("destination is not set"),
+            reward_mint: self.reward_mint.expect("reward_mint is not set"),
+            reward_token_program: self
+                .reward_token_program
+                .expect("reward_token_program is not set"),
+            holder_rewards: self.holder_rewards.expect("holder_rewards is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `harvest_token_rewards` CPI accounts.
+pub struct HarvestTokenRewardsCpiAccounts<'a, 'b> {
+    /// Token rewards pool account.
+    pub token_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token holder rewards account.
+    pub token_holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token account.
+    pub token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token rewards pool vault.
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Destination reward token account.
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward token mint.
+    pub reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward token program.
+    pub reward_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards account.
+    pub holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `harvest_token_rewards` CPI instruction.
+pub struct HarvestTokenRewardsCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token rewards pool account.
+    pub token_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token holder rewards account.
+    pub token_holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token account.
+    pub token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint.
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token rewards pool vault.
+    pub vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Destination reward token account.
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward token mint.
+    pub reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reward token program.
+    pub reward_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards account.
+    pub holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> HarvestTokenRewardsCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: HarvestTokenRewardsCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            token_rewards_pool: accounts.token_rewards_pool,
+            token_holder_rewards: accounts.token_holder_rewards,
+            token_account: accounts.token_account,
+            mint: accounts.mint,
+            vault: accounts.vault,
+            destination: accounts.destination,
+            reward_mint: accounts.reward_mint,
+            reward_token_program: accounts.reward_token_program,
+            holder_rewards: accounts.holder_rewards,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_rewards_pool.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.token_holder_rewards.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.destination.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reward_mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reward_token_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.holder_rewards.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = HarvestTokenRewardsInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.token_rewards_pool.clone());
+        account_infos.push(self.token_holder_rewards.clone());
+        account_infos.push(self.token_account.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.destination.clone());
+        account_infos.push(self.reward_mint.clone());
+        account_infos.push(self.reward_token_program.clone());
+        account_infos.push(self.holder_rewards.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `HarvestTokenRewards` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` token_rewards_pool
+///   1. `[writable]` token_holder_rewards
+///   2. `[]` token_account
+///   3. `[]` mint
+///   4. `[writable]` vault
+///   5. `[writable]` destination
+///   6. `[]` reward_mint
+///   7. `[]` reward_token_program
+///   8. `[]` holder_rewards
+#[derive(Clone, Debug)]
+pub struct HarvestTokenRewardsCpiBuilder<'a, 'b> {
+    instruction: Box<HarvestTokenRewardsCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> HarvestTokenRewardsCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(HarvestTokenRewardsCpiBuilderInstruction {
+            __program: program,
+            token_rewards_pool: None,
+            token_holder_rewards: None,
+            token_account: None,
+            mint: None,
+            vault: None,
+            destination: None,
+            reward_mint: None,
+            reward_token_program: None,
+            holder_rewards: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Token rewards pool account.
+    #[inline(always)]
+    pub fn token_rewards_pool(
+        &mut self,
+        token_rewards_pool: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_rewards_pool = Some(token_rewards_pool);
+        self
+    }
+    /// Token holder rewards account.
+    #[inline(always)]
+    pub fn token_holder_rewards(
+        &mut self,
+        token_holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_holder_rewards = Some(token_holder_rewards);
+        self
+    }
+    /// Token account.
+    #[inline(always)]
+    pub fn token_account(
+        &mut self,
+        token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_account = Some(token_account);
+        self
+    }
+    /// Token mint.
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// Token rewards pool vault.
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    /// Destination reward token account.
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination = Some(destination);
+        self
+    }
+    /// Reward token mint.
+    #[inline(always)]
+    pub fn reward_mint(
+        &mut self,
+        reward_mint: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_mint = Some(reward_mint);
+        self
+    }
+    /// Reward token program.
+    #[inline(always)]
+    pub fn reward_token_program(
+        &mut self,
+        reward_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_token_program = Some(reward_token_program);
+        self
+    }
+    /// Holder rewards account.
+    #[inline(always)]
+    pub fn holder_rewards(
+        &mut self,
+        holder_rewards: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.holder_rewards = Some(holder_rewards);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = HarvestTokenRewardsCpi {
+            __program: self.instruction.__program,
+
+            token_rewards_pool: self
+                .instruction
+                .token_rewards_pool
+                .expect("token_rewards_pool is not set"),
+
+            token_holder_rewards: self
+                .instruction
+                .token_holder_rewards
+                .expect("token_holder_rewards is not set"),
+
+            token_account: self
+                .instruction
+                .token_account
+                .expect("token_account is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            destination: self
+                .instruction
+                .destination
+                .expect("destination is not set"),
+
+            reward_mint: self
+                .instruction
+                .reward_mint
+                .expect("reward_mint is not set"),
+
+            reward_token_program: self
+                .instruction
+                .reward_token_program
+                .expect("reward_token_program is not set"),
+
+            holder_rewards: self
+                .instruction
+                .holder_rewards
+                .expect("holder_rewards is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HarvestTokenRewardsCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    token_rewards_pool: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_holder_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reward_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    holder_rewards: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -18,6 +18,8 @@ pub struct InitializeHolderRewards {
     pub mint: solana_program::pubkey::Pubkey,
     /// System program.
     pub system_program: solana_program::pubkey::Pubkey,
+    /// Payer account.
+    pub payer: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl InitializeHolderRewards {
@@ -29,8 +31,8 @@ impl InitializeHolderRewards {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.holder_rewards_pool,
             false,
         ));
@@ -49,6 +51,9 @@ impl InitializeHolderRewards {
             self.system_program,
             false,
         ));
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(payer, true));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = InitializeHolderRewardsInstructionData::new()
             .try_to_vec()
@@ -83,12 +88,13 @@ impl Default for InitializeHolderRewardsInstructionData {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` holder_rewards_pool
+///   0. `[writable]` holder_rewards_pool
 ///   1. `[writable]` holder_rewards
 ///   2. `[]` token_account
 ///   3. `[]` mint
 ///   4. `[optional]` system_program (default to
 ///      `11111111111111111111111111111111`)
+///   5. `[writable, signer, optional]` payer
 #[derive(Clone, Debug, Default)]
 pub struct InitializeHolderRewardsBuilder {
     holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
@@ -96,6 +102,7 @@ pub struct InitializeHolderRewardsBuilder {
     token_account: Option<solana_program::pubkey::Pubkey>,
     mint: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -137,6 +144,13 @@ impl InitializeHolderRewardsBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Payer account.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -167,6 +181,7 @@ impl InitializeHolderRewardsBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            payer: self.payer,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -185,6 +200,8 @@ pub struct InitializeHolderRewardsCpiAccounts<'a, 'b> {
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer account.
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `initialize_holder_rewards` CPI instruction.
@@ -201,6 +218,8 @@ pub struct InitializeHolderRewardsCpi<'a, 'b> {
     pub mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer account.
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
@@ -215,6 +234,7 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
             token_account: accounts.token_account,
             mint: accounts.mint,
             system_program: accounts.system_program,
+            payer: accounts.payer,
         }
     }
     #[inline(always)]
@@ -250,8 +270,8 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.holder_rewards_pool.key,
             false,
         ));
@@ -271,6 +291,11 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *payer.key, true,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -287,13 +312,16 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.holder_rewards_pool.clone());
         account_infos.push(self.holder_rewards.clone());
         account_infos.push(self.token_account.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -310,11 +338,12 @@ impl<'a, 'b> InitializeHolderRewardsCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` holder_rewards_pool
+///   0. `[writable]` holder_rewards_pool
 ///   1. `[writable]` holder_rewards
 ///   2. `[]` token_account
 ///   3. `[]` mint
 ///   4. `[]` system_program
+///   5. `[writable, signer, optional]` payer
 #[derive(Clone, Debug)]
 pub struct InitializeHolderRewardsCpiBuilder<'a, 'b> {
     instruction: Box<InitializeHolderRewardsCpiBuilderInstruction<'a, 'b>>,
@@ -329,6 +358,7 @@ impl<'a, 'b> InitializeHolderRewardsCpiBuilder<'a, 'b> {
             token_account: None,
             mint: None,
             system_program: None,
+            payer: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -375,6 +405,16 @@ impl<'a, 'b> InitializeHolderRewardsCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Payer account.
+    #[inline(always)]
+    pub fn payer(
+        &mut self,
+        payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -441,6 +481,8 @@ impl<'a, 'b> InitializeHolderRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            payer: self.instruction.payer,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -457,6 +499,7 @@ struct InitializeHolderRewardsCpiBuilderInstruction<'a, 'b> {
     token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -18,6 +18,8 @@ pub struct InitializeHolderRewardsPool {
     pub mint_authority: solana_program::pubkey::Pubkey,
     /// System program.
     pub system_program: solana_program::pubkey::Pubkey,
+    /// Payer account.
+    pub payer: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl InitializeHolderRewardsPool {
@@ -29,7 +31,7 @@ impl InitializeHolderRewardsPool {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.holder_rewards_pool,
             false,
@@ -49,6 +51,9 @@ impl InitializeHolderRewardsPool {
             self.system_program,
             false,
         ));
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(payer, true));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = InitializeHolderRewardsPoolInstructionData::new()
             .try_to_vec()
@@ -89,6 +94,7 @@ impl Default for InitializeHolderRewardsPoolInstructionData {
 ///   3. `[signer]` mint_authority
 ///   4. `[optional]` system_program (default to
 ///      `11111111111111111111111111111111`)
+///   5. `[writable, signer, optional]` payer
 #[derive(Clone, Debug, Default)]
 pub struct InitializeHolderRewardsPoolBuilder {
     holder_rewards_pool: Option<solana_program::pubkey::Pubkey>,
@@ -96,6 +102,7 @@ pub struct InitializeHolderRewardsPoolBuilder {
     mint: Option<solana_program::pubkey::Pubkey>,
     mint_authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -140,6 +147,13 @@ impl InitializeHolderRewardsPoolBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Payer account.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.payer = payer;
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -172,6 +186,7 @@ impl InitializeHolderRewardsPoolBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            payer: self.payer,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -190,6 +205,8 @@ pub struct InitializeHolderRewardsPoolCpiAccounts<'a, 'b> {
     pub mint_authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer account.
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `initialize_holder_rewards_pool` CPI instruction.
@@ -206,6 +223,8 @@ pub struct InitializeHolderRewardsPoolCpi<'a, 'b> {
     pub mint_authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// System program.
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer account.
+    pub payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
@@ -220,6 +239,7 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
             mint: accounts.mint,
             mint_authority: accounts.mint_authority,
             system_program: accounts.system_program,
+            payer: accounts.payer,
         }
     }
     #[inline(always)]
@@ -255,7 +275,7 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.holder_rewards_pool.key,
             false,
@@ -276,6 +296,11 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(payer) = self.payer {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *payer.key, true,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -292,13 +317,16 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.holder_rewards_pool.clone());
         account_infos.push(self.extra_account_metas.clone());
         account_infos.push(self.mint.clone());
         account_infos.push(self.mint_authority.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(payer) = self.payer {
+            account_infos.push(payer.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -320,6 +348,7 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpi<'a, 'b> {
 ///   2. `[]` mint
 ///   3. `[signer]` mint_authority
 ///   4. `[]` system_program
+///   5. `[writable, signer, optional]` payer
 #[derive(Clone, Debug)]
 pub struct InitializeHolderRewardsPoolCpiBuilder<'a, 'b> {
     instruction: Box<InitializeHolderRewardsPoolCpiBuilderInstruction<'a, 'b>>,
@@ -334,6 +363,7 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpiBuilder<'a, 'b> {
             mint: None,
             mint_authority: None,
             system_program: None,
+            payer: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -380,6 +410,16 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Payer account.
+    #[inline(always)]
+    pub fn payer(
+        &mut self,
+        payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payer = payer;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -446,6 +486,8 @@ impl<'a, 'b> InitializeHolderRewardsPoolCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            payer: self.instruction.payer,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -462,6 +504,7 @@ struct InitializeHolderRewardsPoolCpiBuilderInstruction<'a, 'b> {
     mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
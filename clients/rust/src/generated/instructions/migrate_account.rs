@@ -0,0 +1,467 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct MigrateAccount {
+    /// Payer account.
+    pub payer: solana_program::pubkey::Pubkey,
+    /// Holder rewards pool or holder rewards account.
+    pub account: solana_program::pubkey::Pubkey,
+    /// Token mint or token account the account's address is derived from.
+    pub address_seed: solana_program::pubkey::Pubkey,
+    /// System program.
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// Transfer hook extra account metas account. Required when migrating a
+    /// pool in the original layout.
+    pub extra_account_metas: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl MigrateAccount {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.account,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.address_seed,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(extra_account_metas) = self.extra_account_metas {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                extra_account_metas,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let data = MigrateAccountInstructionData::new().try_to_vec().unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MigrateAccountInstructionData {
+    discriminator: u8,
+}
+
+impl MigrateAccountInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 17 }
+    }
+}
+
+impl Default for MigrateAccountInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `MigrateAccount`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[writable]` account
+///   2. `[]` address_seed
+///   3. `[optional]` system_program (default to
+///      `11111111111111111111111111111111`)
+///   4. `[writable, optional]` extra_account_metas
+#[derive(Clone, Debug, Default)]
+pub struct MigrateAccountBuilder {
+    payer: Option<solana_program::pubkey::Pubkey>,
+    account: Option<solana_program::pubkey::Pubkey>,
+    address_seed: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    extra_account_metas: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MigrateAccountBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Payer account.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Holder rewards pool or holder rewards account.
+    #[inline(always)]
+    pub fn account(&mut self, account: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.account = Some(account);
+        self
+    }
+    /// Token mint or token account the account's address is derived from.
+    #[inline(always)]
+    pub fn address_seed(&mut self, address_seed: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.address_seed = Some(address_seed);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System program.
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Transfer hook extra account metas account. Required when migrating a
+    /// pool in the original layout.
+    #[inline(always)]
+    pub fn extra_account_metas(
+        &mut self,
+        extra_account_metas: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.extra_account_metas = extra_account_metas;
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = MigrateAccount {
+            payer: self.payer.expect("payer is not set"),
+            account: self.account.expect("account is not set"),
+            address_seed: self.address_seed.expect("address_seed is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            extra_account_metas: self.extra_account_metas,
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `migrate_account` CPI accounts.
+pub struct MigrateAccountCpiAccounts<'a, 'b> {
+    /// Payer account.
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool or holder rewards account.
+    pub account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint or token account the account's address is derived from.
+    pub address_seed: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program.
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Transfer hook extra account metas account. Required when migrating a
+    /// pool in the original layout.
+    pub extra_account_metas: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `migrate_account` CPI instruction.
+pub struct MigrateAccountCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Payer account.
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Holder rewards pool or holder rewards account.
+    pub account: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Token mint or token account the account's address is derived from.
+    pub address_seed: &'b solana_program::account_info::AccountInfo<'a>,
+    /// System program.
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Transfer hook extra account metas account. Required when migrating a
+    /// pool in the original layout.
+    pub extra_account_metas: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+impl<'a, 'b> MigrateAccountCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MigrateAccountCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            payer: accounts.payer,
+            account: accounts.account,
+            address_seed: accounts.address_seed,
+            system_program: accounts.system_program,
+            extra_account_metas: accounts.extra_account_metas,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.account.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.address_seed.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(extra_account_metas) = self.extra_account_metas {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *extra_account_metas.key,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = MigrateAccountInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.account.clone());
+        account_infos.push(self.address_seed.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(extra_account_metas) = self.extra_account_metas {
+            account_infos.push(extra_account_metas.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MigrateAccount` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[writable]` account
+///   2. `[]` address_seed
+///   3. `[]` system_program
+///   4. `[writable, optional]` extra_account_metas
+#[derive(Clone, Debug)]
+pub struct MigrateAccountCpiBuilder<'a, 'b> {
+    instruction: Box<MigrateAccountCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MigrateAccountCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MigrateAccountCpiBuilderInstruction {
+            __program: program,
+            payer: None,
+            account: None,
+            address_seed: None,
+            system_program: None,
+            extra_account_metas: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Payer account.
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Holder rewards pool or holder rewards account.
+    #[inline(always)]
+    pub fn account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.account = Some(account);
+        self
+    }
+    /// Token mint or token account the account's address is derived from.
+    #[inline(always)]
+    pub fn address_seed(
+        &mut self,
+        address_seed: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.address_seed = Some(address_seed);
+        self
+    }
+    /// System program.
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Transfer hook extra account metas account. Required when migrating a
+    /// pool in the original layout.
+    #[inline(always)]
+    pub fn extra_account_metas(
+        &mut self,
+        extra_account_metas: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.extra_account_metas = extra_account_metas;
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool`
+    /// indicating whether the account is writable or not, and a `bool`
+    /// indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = MigrateAccountCpi {
+            __program: self.instruction.__program,
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            account: self.instruction.account.expect("account is not set"),
+
+            address_seed: self
+                .instruction
+                .address_seed
+                .expect("address_seed is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            extra_account_metas: self.instruction.extra_account_metas,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MigrateAccountCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    address_seed: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    extra_account_metas: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
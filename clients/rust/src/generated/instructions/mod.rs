@@ -4,12 +4,47 @@
 //!
 //! <https://github.com/kinobi-so/kinobi>
 
+pub(crate) mod r#accept_pool_authority;
+pub(crate) mod r#add_compound_program;
+pub(crate) mod r#add_reward_stream;
+pub(crate) mod r#close_holder_rewards;
+pub(crate) mod r#create_emission_schedule;
+pub(crate) mod r#deposit_rewards;
 pub(crate) mod r#distribute_rewards;
+pub(crate) mod r#distribute_stream_rewards;
+pub(crate) mod r#distribute_token_rewards;
+pub(crate) mod r#harvest_and_compound;
+pub(crate) mod r#harvest_escrowed_rewards;
 pub(crate) mod r#harvest_rewards;
+pub(crate) mod r#harvest_rewards_batch;
+pub(crate) mod r#harvest_rewards_to_destination;
+pub(crate) mod r#harvest_stream_rewards;
+pub(crate) mod r#harvest_token_rewards;
 pub(crate) mod r#initialize_holder_rewards;
+pub(crate) mod r#initialize_holder_rewards_batch;
 pub(crate) mod r#initialize_holder_rewards_pool;
+pub(crate) mod r#initialize_token_holder_rewards;
+pub(crate) mod r#initialize_token_rewards_pool;
+pub(crate) mod r#migrate_account;
+pub(crate) mod r#remove_compound_program;
+pub(crate) mod r#set_auto_claim;
+pub(crate) mod r#set_distribution_config;
+pub(crate) mod r#set_pool_authority;
+pub(crate) mod r#set_rewards_exclusion;
+pub(crate) mod r#set_rewards_recipient;
+pub(crate) mod r#sweep_dust;
+pub(crate) mod r#sync_holder_rewards;
 
 pub use self::{
-    r#distribute_rewards::*, r#harvest_rewards::*, r#initialize_holder_rewards::*,
-    r#initialize_holder_rewards_pool::*,
+    r#accept_pool_authority::*, r#add_compound_program::*, r#add_reward_stream::*,
+    r#close_holder_rewards::*, r#create_emission_schedule::*, r#deposit_rewards::*,
+    r#distribute_rewards::*, r#distribute_stream_rewards::*, r#distribute_token_rewards::*,
+    r#harvest_and_compound::*, r#harvest_escrowed_rewards::*, r#harvest_rewards::*,
+    r#harvest_rewards_batch::*, r#harvest_rewards_to_destination::*, r#harvest_stream_rewards::*,
+    r#harvest_token_rewards::*, r#initialize_holder_rewards::*,
+    r#initialize_holder_rewards_batch::*, r#initialize_holder_rewards_pool::*,
+    r#initialize_token_holder_rewards::*, r#initialize_token_rewards_pool::*, r#migrate_account::*,
+    r#remove_compound_program::*, r#set_auto_claim::*, r#set_distribution_config::*,
+    r#set_pool_authority::*, r#set_rewards_exclusion::*, r#set_rewards_recipient::*,
+    r#sweep_dust::*, r#sync_holder_rewards::*,
 };
@@ -8,5 +8,6 @@ pub mod accounts;
 pub mod errors;
 pub mod instructions;
 pub mod programs;
+pub mod types;
 
 pub(crate) use programs::*;
@@ -0,0 +1,16 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+pub(crate) mod r#account_header;
+pub(crate) mod r#emission_schedule;
+pub(crate) mod r#paladin_rewards_event;
+pub(crate) mod r#reward_stream;
+pub(crate) mod r#reward_stream_checkpoint;
+
+pub use self::{
+    r#account_header::*, r#emission_schedule::*, r#paladin_rewards_event::*, r#reward_stream::*,
+    r#reward_stream_checkpoint::*,
+};
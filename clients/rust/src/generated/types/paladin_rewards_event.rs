@@ -0,0 +1,116 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PaladinRewardsEvent {
+    RewardsDistributed {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        reward_id: u64,
+        amount: u64,
+        accumulated_rewards_per_token: u128,
+    },
+    HolderRewardsInitialized {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        holder_rewards: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token_account: Pubkey,
+        last_accumulated_rewards_per_token: u128,
+        last_seen_balance: u64,
+    },
+    HolderRewardsSettled {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        holder_rewards: Pubkey,
+        accrued_rewards: u64,
+        unharvested_rewards: u64,
+        last_seen_balance: u64,
+    },
+    RewardsHarvested {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        holder_rewards: Pubkey,
+        reward_id: u64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        destination: Pubkey,
+        amount: u64,
+        unharvested_rewards: u64,
+    },
+    TokenRewardsDistributed {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token_rewards_pool: Pubkey,
+        amount: u64,
+        accumulated_rewards_per_token: u128,
+    },
+    TokenHolderRewardsInitialized {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token_holder_rewards: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token_account: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token_rewards_pool: Pubkey,
+        last_accumulated_rewards_per_token: u128,
+        last_seen_balance: u64,
+    },
+    TokenHolderRewardsSettled {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token_holder_rewards: Pubkey,
+        accrued_rewards: u64,
+        unharvested_rewards: u64,
+        last_seen_balance: u64,
+    },
+    TokenRewardsHarvested {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token_holder_rewards: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        destination: Pubkey,
+        amount: u64,
+        unharvested_rewards: u64,
+    },
+}
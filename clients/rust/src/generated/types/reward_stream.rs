@@ -0,0 +1,17 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! <https://github.com/kinobi-so/kinobi>
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardStream {
+    pub accumulated_rewards_per_token: u128,
+    pub reward_id: u64,
+    pub total_distributed: u64,
+    pub rewards_per_token_remainder: u64,
+    pub padding: [u8; 8],
+}
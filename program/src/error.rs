@@ -23,4 +23,76 @@ pub enum PaladinRewardsError {
     /// Token account mint mismatch.
     #[error("Token account mint mismatch")]
     TokenAccountMintMismatch,
+    /// Incorrect holder rewards pool authority.
+    #[error("Incorrect holder rewards pool authority")]
+    IncorrectPoolAuthority,
+    /// Incorrect pending holder rewards pool authority.
+    #[error("Incorrect pending holder rewards pool authority")]
+    IncorrectPendingPoolAuthority,
+    /// Token account owner mismatch.
+    #[error("Token account owner mismatch")]
+    TokenAccountOwnerMismatch,
+    /// Token account is not empty.
+    #[error("Token account is not empty")]
+    TokenAccountNotEmpty,
+    /// Incorrect token rewards pool address.
+    #[error("Incorrect token rewards pool address")]
+    IncorrectTokenRewardsPoolAddress,
+    /// Incorrect token rewards vault address.
+    #[error("Incorrect token rewards vault address")]
+    IncorrectTokenRewardsVaultAddress,
+    /// Maximum number of token rewards pools reached.
+    #[error("Maximum number of token rewards pools reached")]
+    MaxTokenRewardsPoolsReached,
+    /// Reward stream not found.
+    #[error("Reward stream not found")]
+    RewardStreamNotFound,
+    /// Reward stream already exists.
+    #[error("Reward stream already exists")]
+    RewardStreamAlreadyExists,
+    /// Maximum number of reward streams reached.
+    #[error("Maximum number of reward streams reached")]
+    MaxRewardStreamsReached,
+    /// Emission schedule is still active.
+    #[error("Emission schedule is still active")]
+    EmissionScheduleActive,
+    /// Token supply is zero.
+    #[error("Token supply is zero")]
+    ZeroTokenSupply,
+    /// Incorrect rewards recipient.
+    #[error("Incorrect rewards recipient")]
+    IncorrectRewardsRecipient,
+    /// Incorrect auto-claim escrow address.
+    #[error("Incorrect auto-claim escrow address")]
+    IncorrectHolderRewardsEscrowAddress,
+    /// Compound program not allowed.
+    #[error("Compound program not allowed")]
+    CompoundProgramNotAllowed,
+    /// Compound program already allowed.
+    #[error("Compound program already allowed")]
+    CompoundProgramAlreadyAllowed,
+    /// Maximum number of compound programs reached.
+    #[error("Maximum number of compound programs reached")]
+    MaxCompoundProgramsReached,
+    /// Incorrect distribution authority.
+    #[error("Incorrect distribution authority")]
+    IncorrectDistributionAuthority,
+    /// Distribution amount is below the pool's minimum.
+    #[error("Distribution amount is below the pool's minimum")]
+    DistributionAmountTooSmall,
+    /// Holder rewards account has unharvested rewards.
+    #[error("Holder rewards account has unharvested rewards")]
+    HolderRewardsNotEmpty,
+    /// Holder rewards account mint mismatch.
+    #[error("Holder rewards account mint mismatch")]
+    HolderRewardsMintMismatch,
+    /// Holder rewards account is not excluded.
+    #[error("Holder rewards account is not excluded")]
+    HolderRewardsNotExcluded,
+    /// Excluded holders are not in order of holder rewards address.
+    #[error("Excluded holders are not in order of holder rewards address")]
+    ExcludedHoldersOutOfOrder,
+    /// Compound destination is not owned by the compound program.
+    #[error("Compound destination is not owned by the compound program")]
+    CompoundDestinationOwnerMismatch,
 }
@@ -0,0 +1,209 @@
+//! Program events.
+//!
+//! Events are logged with `sol_log_data` as a single Borsh-serialized
+//! [PaladinRewardsEvent](enum.PaladinRewardsEvent.html), so they appear in
+//! the transaction logs as `Program data: <base64>`. The first byte of the
+//! data is the event's variant index.
+
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey},
+};
+
+/// Events emitted by the Paladin Rewards program.
+///
+/// Where an event refers to a reward stream, a `reward_id` of `0` refers to
+/// the holder rewards pool's base rate.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub enum PaladinRewardsEvent {
+    /// SOL rewards were distributed to the holder rewards pool.
+    ///
+    /// Not emitted for distributions made while the token supply is zero,
+    /// which are carried over and counted in the amount of the next
+    /// distribution's event, or for rewards released by the emission
+    /// schedule.
+    RewardsDistributed {
+        /// The token mint.
+        mint: Pubkey,
+        /// The reward stream the rewards were distributed to.
+        reward_id: u64,
+        /// The amount of rewards distributed, including any carried over
+        /// from distributions made while the token supply was zero.
+        amount: u64,
+        /// The stream's rewards per token exchange rate after the
+        /// distribution.
+        accumulated_rewards_per_token: u128,
+    },
+    /// A holder rewards account was initialized.
+    HolderRewardsInitialized {
+        /// The holder rewards account.
+        holder_rewards: Pubkey,
+        /// The token account the holder rewards account tracks.
+        token_account: Pubkey,
+        /// The pool's rewards per token exchange rate the holder was
+        /// checkpointed at.
+        last_accumulated_rewards_per_token: u128,
+        /// The token account balance the holder was checkpointed at.
+        last_seen_balance: u64,
+    },
+    /// A holder's rewards were settled against the pool's base rate by the
+    /// transfer hook.
+    ///
+    /// Only the transfer hook emits this event. Instructions which settle a
+    /// holder before acting on it, such as harvesting, syncing or changing
+    /// its exclusion, don't. Rewards they settle are reflected in the
+    /// holder's next event instead.
+    HolderRewardsSettled {
+        /// The holder rewards account.
+        holder_rewards: Pubkey,
+        /// The rewards accrued since the holder was last settled.
+        accrued_rewards: u64,
+        /// The holder's unharvested rewards after settling.
+        unharvested_rewards: u64,
+        /// The token account balance the holder will earn rewards on going
+        /// forward.
+        last_seen_balance: u64,
+    },
+    /// A holder's unharvested SOL rewards were paid out.
+    RewardsHarvested {
+        /// The holder rewards account.
+        holder_rewards: Pubkey,
+        /// The reward stream the rewards were harvested from.
+        reward_id: u64,
+        /// The account the rewards were paid out to.
+        destination: Pubkey,
+        /// The amount of rewards paid out.
+        amount: u64,
+        /// The holder's unharvested rewards remaining for the stream.
+        unharvested_rewards: u64,
+    },
+    /// Reward tokens were distributed to a token rewards pool.
+    TokenRewardsDistributed {
+        /// The token rewards pool.
+        token_rewards_pool: Pubkey,
+        /// The amount of reward tokens received by the pool's vault, net of
+        /// any transfer fee.
+        amount: u64,
+        /// The pool's rewards per token exchange rate after the
+        /// distribution.
+        accumulated_rewards_per_token: u128,
+    },
+    /// A token holder rewards account was initialized.
+    TokenHolderRewardsInitialized {
+        /// The token holder rewards account.
+        token_holder_rewards: Pubkey,
+        /// The token account the token holder rewards account tracks.
+        token_account: Pubkey,
+        /// The token rewards pool the holder earns rewards from.
+        token_rewards_pool: Pubkey,
+        /// The pool's rewards per token exchange rate the holder was
+        /// checkpointed at.
+        last_accumulated_rewards_per_token: u128,
+        /// The token account balance the holder was checkpointed at.
+        last_seen_balance: u64,
+    },
+    /// A token holder's rewards were settled against its token rewards pool
+    /// by the transfer hook.
+    ///
+    /// Like `HolderRewardsSettled`, only the transfer hook emits this event.
+    TokenHolderRewardsSettled {
+        /// The token holder rewards account.
+        token_holder_rewards: Pubkey,
+        /// The rewards accrued since the holder was last settled.
+        accrued_rewards: u64,
+        /// The holder's unharvested rewards after settling.
+        unharvested_rewards: u64,
+        /// The token account balance the holder will earn rewards on going
+        /// forward.
+        last_seen_balance: u64,
+    },
+    /// A token holder's unharvested reward tokens were paid out.
+    TokenRewardsHarvested {
+        /// The token holder rewards account.
+        token_holder_rewards: Pubkey,
+        /// The reward token account the rewards were paid out to.
+        destination: Pubkey,
+        /// The amount of reward tokens paid out.
+        amount: u64,
+        /// The holder's unharvested rewards remaining.
+        unharvested_rewards: u64,
+    },
+}
+
+impl PaladinRewardsEvent {
+    /// Logs the event as Borsh-serialized program data.
+    pub fn emit(&self) -> ProgramResult {
+        let data = borsh::to_vec(self)?;
+        sol_log_data(&[&data]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let events = [
+            PaladinRewardsEvent::RewardsDistributed {
+                mint: Pubkey::new_unique(),
+                reward_id: 0,
+                amount: 500_000_000,
+                accumulated_rewards_per_token: 1_000_000_000,
+            },
+            PaladinRewardsEvent::HolderRewardsInitialized {
+                holder_rewards: Pubkey::new_unique(),
+                token_account: Pubkey::new_unique(),
+                last_accumulated_rewards_per_token: 1_000_000_000,
+                last_seen_balance: 100,
+            },
+            PaladinRewardsEvent::HolderRewardsSettled {
+                holder_rewards: Pubkey::new_unique(),
+                accrued_rewards: 50,
+                unharvested_rewards: 150,
+                last_seen_balance: 90,
+            },
+            PaladinRewardsEvent::RewardsHarvested {
+                holder_rewards: Pubkey::new_unique(),
+                reward_id: 7,
+                destination: Pubkey::new_unique(),
+                amount: 150,
+                unharvested_rewards: 0,
+            },
+            PaladinRewardsEvent::TokenRewardsDistributed {
+                token_rewards_pool: Pubkey::new_unique(),
+                amount: 1_000,
+                accumulated_rewards_per_token: 10_000_000,
+            },
+            PaladinRewardsEvent::TokenHolderRewardsInitialized {
+                token_holder_rewards: Pubkey::new_unique(),
+                token_account: Pubkey::new_unique(),
+                token_rewards_pool: Pubkey::new_unique(),
+                last_accumulated_rewards_per_token: 10_000_000,
+                last_seen_balance: 100,
+            },
+            PaladinRewardsEvent::TokenHolderRewardsSettled {
+                token_holder_rewards: Pubkey::new_unique(),
+                accrued_rewards: 50,
+                unharvested_rewards: 150,
+                last_seen_balance: 90,
+            },
+            PaladinRewardsEvent::TokenRewardsHarvested {
+                token_holder_rewards: Pubkey::new_unique(),
+                destination: Pubkey::new_unique(),
+                amount: 150,
+                unharvested_rewards: 0,
+            },
+        ];
+
+        // Each event is prefixed with its variant index, in declaration
+        // order.
+        for (variant_index, original) in events.into_iter().enumerate() {
+            let serialized = borsh::to_vec(&original).unwrap();
+            assert_eq!(serialized[0] as usize, variant_index);
+            let deserialized = borsh::from_slice::<PaladinRewardsEvent>(&serialized).unwrap();
+            assert_eq!(original, deserialized);
+        }
+    }
+}
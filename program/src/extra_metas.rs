@@ -1,8 +1,24 @@
 use {
-    crate::state::{SEED_PREFIX_HOLDER_REWARDS, SEED_PREFIX_HOLDER_REWARDS_POOL},
+    crate::state::{
+        get_token_rewards_pool_address, SEED_PREFIX_HOLDER_REWARDS,
+        SEED_PREFIX_HOLDER_REWARDS_ESCROW, SEED_PREFIX_HOLDER_REWARDS_POOL,
+        SEED_PREFIX_TOKEN_HOLDER_REWARDS,
+    },
+    solana_program::pubkey::Pubkey,
     spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed},
 };
 
+/// Number of accounts passed to the transfer hook's `Execute` instruction
+/// before any extra account metas.
+const TRANSFER_HOOK_ACCOUNTS_COUNT: usize = 5;
+
+/// Number of extra account metas appended for each token rewards pool.
+pub const TOKEN_REWARDS_EXTRA_METAS_COUNT: usize = 3;
+
+/// Number of extra account metas for the auto-claim escrow accounts, which
+/// end the list.
+pub const ESCROW_EXTRA_METAS_COUNT: usize = 2;
+
 /// Extra account metas required by the Paladin Rewards program's SPL
 /// Transfer Hook Interface implementation.
 ///
@@ -13,9 +29,24 @@ use {
 /// 2. `[ ]` Destination token account.
 /// 3. `[ ]` Source owner.
 /// 4. `[ ]` Extra account metas account.
-/// 5. `[ ]` * Holder rewards pool account.
+/// 5. `[w]` * Holder rewards pool account.
 /// 6. `[w]` * Source holder rewards account.
 /// 7. `[w]` * Destination holder rewards account.
+///
+/// For each token rewards pool configured for the mint, the extra metas
+/// returned by
+/// [get_token_rewards_extra_account_metas](fn.get_token_rewards_extra_account_metas.html)
+/// follow, and the list ends with the extra metas returned by
+/// [get_escrow_extra_account_metas](fn.get_escrow_extra_account_metas.html).
+///
+/// The holder rewards pool is writable, since the transfer hook settles both
+/// holders against it, keeps its excluded supply and dust up to date, and
+/// pays auto-claimed rewards out of its lamports. The runtime never processes
+/// two transactions writing the same account in parallel, so every transfer
+/// of the mint is serialized on the pool, capping the mint's transfer
+/// throughput at what a single account's write lock allows. Advancing the
+/// pool's emission schedule in the transfer hook adds no contention on top of
+/// that.
 pub fn get_extra_account_metas() -> [ExtraAccountMeta; 3] {
     [
         // Holder rewards pool account.
@@ -29,7 +60,7 @@ pub fn get_extra_account_metas() -> [ExtraAccountMeta; 3] {
                 },
             ],
             false,
-            false,
+            true,
         )
         .unwrap(),
         // Source holder rewards account.
@@ -62,3 +93,112 @@ pub fn get_extra_account_metas() -> [ExtraAccountMeta; 3] {
         .unwrap(),
     ]
 }
+
+/// Extra account metas ending the list, for the auto-claim escrow accounts
+/// the transfer hook pays holders' rewards out to.
+///
+/// Accounts required (* = extra meta):
+///
+/// 0. `[w]` * Source auto-claim escrow account.
+/// 1. `[w]` * Destination auto-claim escrow account.
+///
+/// Lists written by earlier versions of the program don't include these, in
+/// which case the transfer hook leaves all rewards unharvested.
+pub fn get_escrow_extra_account_metas() -> [ExtraAccountMeta; ESCROW_EXTRA_METAS_COUNT] {
+    [
+        // Source auto-claim escrow account.
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: SEED_PREFIX_HOLDER_REWARDS_ESCROW.to_vec(),
+                },
+                Seed::AccountKey {
+                    index: 0, // Source token account.
+                },
+            ],
+            false,
+            true,
+        )
+        .unwrap(),
+        // Destination auto-claim escrow account.
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: SEED_PREFIX_HOLDER_REWARDS_ESCROW.to_vec(),
+                },
+                Seed::AccountKey {
+                    index: 2, // Destination token account.
+                },
+            ],
+            false,
+            true,
+        )
+        .unwrap(),
+    ]
+}
+
+/// Extra account metas added to the list for each token rewards pool
+/// configured for the mint, ahead of the auto-claim escrow accounts.
+///
+/// Accounts required (* = extra meta):
+///
+/// 0. `[ ]` * Token rewards pool account.
+/// 1. `[w]` * Source token holder rewards account.
+/// 2. `[w]` * Destination token holder rewards account.
+///
+/// The token holder rewards accounts are derived from the token rewards pool
+/// account's position in the full list of transfer hook accounts, so the
+/// metas depend on the number of token rewards pools already configured.
+pub fn get_token_rewards_extra_account_metas(
+    mint: &Pubkey,
+    reward_mint: &Pubkey,
+    existing_token_rewards_pools: usize,
+) -> [ExtraAccountMeta; TOKEN_REWARDS_EXTRA_METAS_COUNT] {
+    let token_rewards_pool_index = (TRANSFER_HOOK_ACCOUNTS_COUNT
+        + get_extra_account_metas().len()
+        + existing_token_rewards_pools * TOKEN_REWARDS_EXTRA_METAS_COUNT)
+        as u8;
+    [
+        // Token rewards pool account.
+        ExtraAccountMeta::new_with_pubkey(
+            &get_token_rewards_pool_address(mint, reward_mint),
+            false,
+            false,
+        )
+        .unwrap(),
+        // Source token holder rewards account.
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: SEED_PREFIX_TOKEN_HOLDER_REWARDS.to_vec(),
+                },
+                Seed::AccountKey {
+                    index: 0, // Source token account.
+                },
+                Seed::AccountKey {
+                    index: token_rewards_pool_index, // Token rewards pool.
+                },
+            ],
+            false,
+            true,
+        )
+        .unwrap(),
+        // Destination token holder rewards account.
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: SEED_PREFIX_TOKEN_HOLDER_REWARDS.to_vec(),
+                },
+                Seed::AccountKey {
+                    index: 2, // Destination token account.
+                },
+                Seed::AccountKey {
+                    index: token_rewards_pool_index, // Token rewards pool.
+                },
+            ],
+            false,
+            true,
+        )
+        .unwrap(),
+    ]
+}
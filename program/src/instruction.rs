@@ -22,6 +22,10 @@ pub enum PaladinRewardsInstruction {
     /// - Initialize a holder rewards pool account.
     /// - Initialize the required accounts for the transfer hook.
     ///
+    /// The transfer hook writes to the holder rewards pool, so the mint's
+    /// transfers can't be processed in parallel with one another. See
+    /// [get_extra_account_metas](../extra_metas/fn.get_extra_account_metas.html).
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w]` Holder rewards pool account.
@@ -29,6 +33,8 @@ pub enum PaladinRewardsInstruction {
     /// 2. `[ ]` Token mint.
     /// 3. `[s]` Mint authority.
     /// 4. `[ ]` System program.
+    /// 5. `[w, s]` Payer account (optional). If provided, funds the rent
+    ///    for the holder rewards pool and extra metas accounts.
     #[account(
         0,
         writable,
@@ -57,15 +63,38 @@ pub enum PaladinRewardsInstruction {
         name = "system_program",
         desc = "System program.",
     )]
+    #[account(
+        5,
+        optional,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer account.",
+    )]
     InitializeHolderRewardsPool,
     /// Moves SOL rewards to the holder rewards pool and updates the total.
     ///
+    /// If the pool has a distribution authority, it must be the payer. The
+    /// amount must be at least the pool's minimum distribution amount.
+    ///
+    /// Every holder excluded from earning rewards must be provided, in order
+    /// of holder rewards address, and is settled at its token account's
+    /// current balance first. Token-2022 does not invoke the transfer hook on
+    /// `MintTo` or `Burn`, so otherwise the pool's excluded supply could be
+    /// stale, and the rewards distributed over too small a supply. A closed
+    /// token account is settled at a balance of zero.
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w, s]` Payer account.
     /// 1. `[w]` Holder rewards pool account.
     /// 2. `[ ]` Token mint.
     /// 3. `[ ]` System program.
+    ///
+    /// Followed by, for each excluded holder:
+    ///
+    /// 0. `[w]` Holder rewards account.
+    /// 1. `[ ]` Token account.
     #[account(
         0,
         writable,
@@ -96,15 +125,23 @@ pub enum PaladinRewardsInstruction {
     /// supply of the mint and use that to calculate the holder rewards
     /// account's share of the total rewards pool.
     ///
+    /// Token accounts can send and receive tokens before their holder
+    /// rewards account is initialized, since the transfer hook skips
+    /// uninitialized holder rewards accounts. They earn no rewards until
+    /// it's initialized.
+    ///
     /// Accounts expected by this instruction:
     ///
-    /// 0. `[ ]` Holder rewards pool account.
+    /// 0. `[w]` Holder rewards pool account.
     /// 1. `[w]` Holder rewards account.
     /// 2. `[ ]` Token account.
     /// 3. `[ ]` Token mint.
     /// 4. `[ ]` System program.
+    /// 5. `[w, s]` Payer account (optional). If provided, funds the rent
+    ///    for the holder rewards account.
     #[account(
         0, 
+        writable,
         name = "holder_rewards_pool",
         desc = "Holder rewards pool account.",
     )]
@@ -129,17 +166,33 @@ pub enum PaladinRewardsInstruction {
         name = "system_program",
         desc = "System program.",
     )]
+    #[account(
+        5,
+        optional,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer account.",
+    )]
     InitializeHolderRewards,
     /// Moves accrued SOL rewards into the provided token account based on the
     /// share of the total rewards pool represented in the holder rewards
     /// account.
     ///
+    /// Rewards from the pool's reward streams are settled, but not moved.
+    /// They are harvested with
+    /// [HarvestStreamRewards](enum.PaladinRewardsInstruction.html).
+    ///
+    /// If the holder has a rewards recipient, the rewards are moved to the
+    /// recipient instead of the token account.
+    ///
     /// Accounts expected by this instruction:
     ///
     /// 0. `[w]` Holder rewards pool account.
     /// 1. `[w]` Holder rewards account.
     /// 2. `[w]` Token account.
     /// 3. `[ ]` Token mint.
+    /// 4. `[w]` Rewards recipient (required if the holder has one).
     #[account(
         0,
         writable,
@@ -163,151 +216,2738 @@ pub enum PaladinRewardsInstruction {
         name = "mint",
         desc = "Token mint.",
     )]
+    #[account(
+        4,
+        optional,
+        writable,
+        name = "recipient",
+        desc = "Rewards recipient.",
+    )]
     HarvestRewards,
-}
-
-impl PaladinRewardsInstruction {
-    /// Packs a
-    /// [PaladinRewardsInstruction](enum.PaladinRewardsInstruction.html)
-    /// into a byte buffer.
-    pub fn pack(&self) -> Vec<u8> {
-        match self {
-            PaladinRewardsInstruction::InitializeHolderRewardsPool => vec![0],
-            PaladinRewardsInstruction::DistributeRewards(amount) => {
-                let mut data = Vec::with_capacity(9);
-                data.push(1);
-                data.extend_from_slice(&amount.to_le_bytes());
-                data
-            }
-            PaladinRewardsInstruction::InitializeHolderRewards => vec![2],
-            PaladinRewardsInstruction::HarvestRewards => vec![3],
-        }
-    }
-
-    /// Unpacks a byte buffer into a
-    /// [PaladinRewardsInstruction](enum.PaladinRewardsInstruction.html).
-    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
-        match input.split_first() {
-            Some((&0, _)) => Ok(PaladinRewardsInstruction::InitializeHolderRewardsPool),
-            Some((&1, rest)) => {
-                let amount = rest
-                    .get(..8)
-                    .and_then(|slice| Some(u64::from_le_bytes(slice.try_into().ok()?)))
-                    .ok_or(ProgramError::InvalidInstructionData)?;
-                Ok(PaladinRewardsInstruction::DistributeRewards(amount))
-            }
-            Some((&2, _)) => Ok(PaladinRewardsInstruction::InitializeHolderRewards),
-            Some((&3, _)) => Ok(PaladinRewardsInstruction::HarvestRewards),
-            _ => Err(ProgramError::InvalidInstructionData),
-        }
-    }
-}
-
-/// Creates an
-/// [InitializeHolderRewardsPool](enum.PaladinRewardsInstruction.html)
-/// instruction.
-pub fn initialize_holder_rewards_pool(
-    holder_rewards_pool_address: &Pubkey,
-    extra_account_metas_address: &Pubkey,
-    mint_address: &Pubkey,
-    mint_authority_address: &Pubkey,
-) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new(*holder_rewards_pool_address, false),
-        AccountMeta::new(*extra_account_metas_address, false),
-        AccountMeta::new_readonly(*mint_address, false),
-        AccountMeta::new_readonly(*mint_authority_address, true),
-        AccountMeta::new_readonly(system_program::id(), false),
-    ];
-    let data = PaladinRewardsInstruction::InitializeHolderRewardsPool.pack();
-    Instruction::new_with_bytes(crate::id(), &data, accounts)
-}
-
-/// Creates a [DistributeRewards](enum.PaladinRewardsInstruction.html)
-/// instruction.
-pub fn distribute_rewards(
-    payer_address: &Pubkey,
-    holder_rewards_pool_address: &Pubkey,
-    mint: &Pubkey,
-    amount: u64,
-) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new(*payer_address, true),
-        AccountMeta::new(*holder_rewards_pool_address, false),
-        AccountMeta::new_readonly(*mint, false),
-        AccountMeta::new_readonly(system_program::id(), false),
-    ];
-    let data = PaladinRewardsInstruction::DistributeRewards(amount).pack();
-    Instruction::new_with_bytes(crate::id(), &data, accounts)
-}
-
-/// Creates an [InitializeHolderRewards](enum.PaladinRewardsInstruction.html)
-/// instruction.
-pub fn initialize_holder_rewards(
-    holder_rewards_pool_address: &Pubkey,
-    holder_rewards_address: &Pubkey,
-    token_account_address: &Pubkey,
-    mint_address: &Pubkey,
-) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new_readonly(*holder_rewards_pool_address, false),
-        AccountMeta::new(*holder_rewards_address, false),
-        AccountMeta::new_readonly(*token_account_address, false),
-        AccountMeta::new_readonly(*mint_address, false),
-        AccountMeta::new_readonly(system_program::id(), false),
-    ];
-    let data = PaladinRewardsInstruction::InitializeHolderRewards.pack();
-    Instruction::new_with_bytes(crate::id(), &data, accounts)
-}
-
-/// Creates a [HarvestRewards](enum.PaladinRewardsInstruction.html) instruction.
-pub fn harvest_rewards(
-    holder_rewards_pool_address: &Pubkey,
-    holder_rewards_address: &Pubkey,
-    token_account_address: &Pubkey,
-    mint_address: &Pubkey,
-) -> Instruction {
-    let accounts = vec![
-        AccountMeta::new(*holder_rewards_pool_address, false),
-        AccountMeta::new(*holder_rewards_address, false),
-        AccountMeta::new(*token_account_address, false),
-        AccountMeta::new_readonly(*mint_address, false),
-    ];
-    let data = PaladinRewardsInstruction::HarvestRewards.pack();
-    Instruction::new_with_bytes(crate::id(), &data, accounts)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_pack_unpack_initialize_holder_rewards_pool() {
-        let original = PaladinRewardsInstruction::InitializeHolderRewardsPool;
-        let packed = original.pack();
-        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
-        assert_eq!(original, unpacked);
-    }
-
-    #[test]
-    fn test_pack_unpack_distribute_rewards() {
-        let original = PaladinRewardsInstruction::DistributeRewards(500_000_000);
-        let packed = original.pack();
-        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
-        assert_eq!(original, unpacked);
-    }
-
-    #[test]
-    fn test_pack_unpack_initialize_holder_rewards() {
-        let original = PaladinRewardsInstruction::InitializeHolderRewards;
-        let packed = original.pack();
-        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
-        assert_eq!(original, unpacked);
-    }
-
-    #[test]
-    fn test_pack_unpack_harvest_rewards() {
-        let original = PaladinRewardsInstruction::HarvestRewards;
+    /// Settles a holder's rewards against the lesser of the token account
+    /// balance it was last settled at and its current balance, exactly as
+    /// for `HarvestRewards`, then records the token account's current
+    /// balance. If the balance it was last settled at is unknown, as for
+    /// holders migrated from the original layout, the current balance is
+    /// used. The token account's current owner is recorded too.
+    ///
+    /// Token-2022 does not invoke the transfer hook on `MintTo` or `Burn`,
+    /// so this instruction should be invoked before and after any operation
+    /// that changes the token account's balance outside of a transfer, and
+    /// after any `SetAuthority` that changes its owner.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards account.
+    /// 2. `[ ]` Token account.
+    /// 3. `[ ]` Token mint.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    SyncHolderRewards,
+    /// Nominates a new authority for the holder rewards pool.
+    ///
+    /// The nominated authority must accept the role with
+    /// [AcceptPoolAuthority](enum.PaladinRewardsInstruction.html) before it
+    /// takes effect.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pool authority.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "pool_authority",
+        desc = "Pool authority.",
+    )]
+    SetPoolAuthority(Pubkey),
+    /// Accepts the role of authority for the holder rewards pool, replacing
+    /// the current authority.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pending pool authority.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "pending_pool_authority",
+        desc = "Pending pool authority.",
+    )]
+    AcceptPoolAuthority,
+    /// Closes a holder rewards account, returning its rent lamports to the
+    /// provided destination.
+    ///
+    /// If the token account is still open, it must hold no tokens, the token
+    /// account owner must sign, and any unharvested rewards are first
+    /// harvested into the token account, or to the holder's rewards
+    /// recipient, if it has one.
+    ///
+    /// If the token account has been closed, anyone may close the holder
+    /// rewards account, which must have recorded the provided mint. If the
+    /// holder has a rewards recipient, any unharvested rewards are harvested
+    /// to the recipient, which must be the destination. Otherwise, the
+    /// holder must have no unharvested rewards, which remain claimable if the
+    /// token account is reopened at the same address, and the destination
+    /// must be the token account owner recorded by the holder, as of when it
+    /// was initialized, migrated or last synced. Holder rewards accounts in
+    /// the original layout didn't record an owner, so the token account must
+    /// have been the destination's Token-2022 associated token account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards account.
+    /// 2. `[w]` Token account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[s]` Token account owner (not checked if the token account has
+    ///    been closed).
+    /// 5. `[w]` Destination account (the rewards recipient or token account
+    ///    owner, if the token account has been closed).
+    /// 6. `[w]` Rewards recipient (required if the token account is still
+    ///    open and the holder has one).
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        writable,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        signer,
+        name = "owner",
+        desc = "Token account owner.",
+    )]
+    #[account(
+        5,
+        writable,
+        name = "destination",
+        desc = "Destination account.",
+    )]
+    #[account(
+        6,
+        optional,
+        writable,
+        name = "recipient",
+        desc = "Rewards recipient.",
+    )]
+    CloseHolderRewards,
+    /// Moves accrued SOL rewards into the provided destination account based
+    /// on the share of the total rewards pool represented in the holder
+    /// rewards account.
+    ///
+    /// Unlike [HarvestRewards](enum.PaladinRewardsInstruction.html), which
+    /// can be invoked by anyone and pays out to the token account, this
+    /// instruction must be signed by the token account owner or delegate.
+    ///
+    /// If the holder has a rewards recipient, the destination must be the
+    /// recipient.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards account.
+    /// 2. `[ ]` Token account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[s]` Token account owner or delegate.
+    /// 5. `[w]` Destination account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        signer,
+        name = "authority",
+        desc = "Token account owner or delegate.",
+    )]
+    #[account(
+        5,
+        writable,
+        name = "destination",
+        desc = "Destination account.",
+    )]
+    HarvestRewardsToDestination,
+    /// Configures a token rewards pool, which distributes an SPL token reward
+    /// mint to holders of the mint.
+    ///
+    /// This instruction will:
+    ///
+    /// - Initialize a token rewards pool account.
+    /// - Append the token rewards pool's accounts to the transfer hook's
+    ///   extra account metas, correcting the mint's base metas if they were
+    ///   written by an earlier version of the program.
+    ///
+    /// The pool's vault - its associated token account for the reward mint -
+    /// must be created before rewards can be distributed.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Token rewards pool account.
+    /// 1. `[w]` Transfer hook extra account metas account.
+    /// 2. `[ ]` Holder rewards pool account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[ ]` Reward token mint.
+    /// 5. `[s]` Pool authority.
+    /// 6. `[ ]` System program.
+    /// 7. `[w, s]` Payer account (optional). If provided, funds the rent
+    ///    for the token rewards pool and extra metas accounts.
+    #[account(
+        0,
+        writable,
+        name = "token_rewards_pool",
+        desc = "Token rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "extra_account_metas",
+        desc = "Transfer hook extra account metas account."
+    )]
+    #[account(
+        2,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        name = "reward_mint",
+        desc = "Reward token mint.",
+    )]
+    #[account(
+        5,
+        signer,
+        name = "pool_authority",
+        desc = "Pool authority.",
+    )]
+    #[account(
+        6,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    #[account(
+        7,
+        optional,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer account.",
+    )]
+    InitializeTokenRewardsPool,
+    /// Moves reward tokens to the token rewards pool's vault and updates the
+    /// total.
+    ///
+    /// If the holder rewards pool has a distribution authority, it must be
+    /// the source token account owner or delegate. The pool's minimum
+    /// distribution amount is in lamports, so it doesn't apply to reward
+    /// tokens.
+    ///
+    /// The rewards are distributed over the token supply less the holder
+    /// rewards pool's excluded supply. Fails if that is zero, since there are
+    /// no holders to distribute the rewards to. Excluded holders must be
+    /// provided, and are settled first, exactly as for
+    /// [DistributeRewards](enum.PaladinRewardsInstruction.html).
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[s]` Source token account owner or delegate.
+    /// 1. `[w]` Source reward token account.
+    /// 2. `[w]` Token rewards pool account.
+    /// 3. `[w]` Token rewards pool vault.
+    /// 4. `[ ]` Token mint.
+    /// 5. `[ ]` Reward token mint.
+    /// 6. `[ ]` Reward token program.
+    /// 7. `[w]` Holder rewards pool account.
+    ///
+    /// Followed by, for each excluded holder:
+    ///
+    /// 0. `[w]` Holder rewards account.
+    /// 1. `[ ]` Token account.
+    #[account(
+        0,
+        signer,
+        name = "source_authority",
+        desc = "Source token account owner or delegate.",
+    )]
+    #[account(
+        1,
+        writable,
+        name = "source",
+        desc = "Source reward token account.",
+    )]
+    #[account(
+        2,
+        writable,
+        name = "token_rewards_pool",
+        desc = "Token rewards pool account."
+    )]
+    #[account(
+        3,
+        writable,
+        name = "vault",
+        desc = "Token rewards pool vault."
+    )]
+    #[account(
+        4,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        5,
+        name = "reward_mint",
+        desc = "Reward token mint.",
+    )]
+    #[account(
+        6,
+        name = "reward_token_program",
+        desc = "Reward token program.",
+    )]
+    #[account(
+        7,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account.",
+    )]
+    DistributeTokenRewards(u64),
+    /// Initializes a token holder rewards account, which tracks a token
+    /// account's share of a token rewards pool.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Token rewards pool account.
+    /// 1. `[w]` Token holder rewards account.
+    /// 2. `[ ]` Token account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[ ]` Reward token mint.
+    /// 5. `[ ]` System program.
+    /// 6. `[w, s]` Payer account (optional). If provided, funds the rent
+    ///    for the token holder rewards account.
+    #[account(
+        0,
+        name = "token_rewards_pool",
+        desc = "Token rewards pool account.",
+    )]
+    #[account(
+        1,
+        writable,
+        name = "token_holder_rewards",
+        desc = "Token holder rewards account.",
+    )]
+    #[account(
+        2,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        name = "reward_mint",
+        desc = "Reward token mint.",
+    )]
+    #[account(
+        5,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    #[account(
+        6,
+        optional,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer account.",
+    )]
+    InitializeTokenHolderRewards,
+    /// Moves accrued reward tokens from the token rewards pool's vault into a
+    /// reward token account owned by the token account owner, based on the
+    /// share of the token rewards pool represented in the token holder
+    /// rewards account.
+    ///
+    /// Token accounts excluded from the holder rewards pool earn nothing.
+    /// Their holder rewards account is provided to check the exclusion, and
+    /// may be uninitialized.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[ ]` Token rewards pool account.
+    /// 1. `[w]` Token holder rewards account.
+    /// 2. `[ ]` Token account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[w]` Token rewards pool vault.
+    /// 5. `[w]` Destination reward token account.
+    /// 6. `[ ]` Reward token mint.
+    /// 7. `[ ]` Reward token program.
+    /// 8. `[ ]` Holder rewards account.
+    #[account(
+        0,
+        name = "token_rewards_pool",
+        desc = "Token rewards pool account.",
+    )]
+    #[account(
+        1,
+        writable,
+        name = "token_holder_rewards",
+        desc = "Token holder rewards account.",
+    )]
+    #[account(
+        2,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        writable,
+        name = "vault",
+        desc = "Token rewards pool vault."
+    )]
+    #[account(
+        5,
+        writable,
+        name = "destination",
+        desc = "Destination reward token account.",
+    )]
+    #[account(
+        6,
+        name = "reward_mint",
+        desc = "Reward token mint.",
+    )]
+    #[account(
+        7,
+        name = "reward_token_program",
+        desc = "Reward token program.",
+    )]
+    #[account(
+        8,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    HarvestTokenRewards,
+    /// Adds a reward stream to the holder rewards pool, which tracks rewards
+    /// from a single source separately from the pool's base rate.
+    ///
+    /// The reward ID is chosen by the pool authority, must be non-zero, and
+    /// must not already be in use by one of the pool's reward streams.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pool authority.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "pool_authority",
+        desc = "Pool authority.",
+    )]
+    AddRewardStream(u64),
+    /// Moves SOL rewards to the holder rewards pool and updates the total for
+    /// one of the pool's reward streams.
+    ///
+    /// If the pool has a distribution authority, it must be the payer. The
+    /// amount must be at least the pool's minimum distribution amount.
+    /// Excluded holders must be provided, and are settled first, exactly as
+    /// for [DistributeRewards](enum.PaladinRewardsInstruction.html).
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Payer account.
+    /// 1. `[w]` Holder rewards pool account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[ ]` System program.
+    ///
+    /// Followed by, for each excluded holder:
+    ///
+    /// 0. `[w]` Holder rewards account.
+    /// 1. `[ ]` Token account.
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer account.",
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    DistributeStreamRewards {
+        reward_id: u64,
+        amount: u64,
+    },
+    /// Moves accrued SOL rewards from one of the pool's reward streams into
+    /// the provided token account based on the holder rewards account's
+    /// checkpoint for the stream.
+    ///
+    /// If the holder has a rewards recipient, the rewards are moved to the
+    /// recipient instead of the token account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards account.
+    /// 2. `[w]` Token account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[w]` Rewards recipient (required if the holder has one).
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        writable,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        optional,
+        writable,
+        name = "recipient",
+        desc = "Rewards recipient.",
+    )]
+    HarvestStreamRewards(u64),
+    /// Escrows a budget of SOL rewards in the holder rewards pool, to be
+    /// released to holders linearly between the start and end timestamps.
+    ///
+    /// Released rewards are added to the pool's rate whenever the transfer
+    /// hook, or an instruction that settles holders against the pool, is
+    /// processed. While any holder is excluded from earning rewards, they're
+    /// only released by
+    /// [DistributeRewards](enum.PaladinRewardsInstruction.html) and
+    /// [DepositRewards](enum.PaladinRewardsInstruction.html), which settle
+    /// the excluded holders first. Releasing rewards never takes the pool as
+    /// writable where it wasn't already; the transfer hook writes to it
+    /// regardless.
+    ///
+    /// The schedule can't start before the current timestamp, so no rewards
+    /// are released retroactively. Only one schedule can be active at a
+    /// time. A new schedule can only be created once the previous schedule's
+    /// budget has been fully released.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Payer account.
+    /// 1. `[w]` Holder rewards pool account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[s]` Pool authority.
+    /// 4. `[ ]` System program.
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer account.",
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        signer,
+        name = "pool_authority",
+        desc = "Pool authority.",
+    )]
+    #[account(
+        4,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    CreateEmissionSchedule {
+        amount: u64,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    },
+    /// Migrates a holder rewards pool or holder rewards account from the
+    /// program's original layout, which has no header, to the current
+    /// layout, prefixed with an account header identifying the account's
+    /// type and layout version.
+    ///
+    /// Until they're migrated, holder rewards accounts in the original
+    /// layout are still settled by the transfer hook, in that layout, so
+    /// transfers never fail on an unmigrated holder. Otherwise, accounts in
+    /// the original layout must be migrated before they can be used.
+    ///
+    /// Any appended fields start at zero, except a pool's authority, which
+    /// defaults to the mint authority, and its total distributed, which
+    /// counts all of its excess lamports, and a holder's mint and owner,
+    /// which are read from its token account, so it must still be open. A
+    /// holder's last seen balance is unknown until it's next settled.
+    /// Exchange rates, scaled by `1e9` in the original layout, are rescaled
+    /// to `1e18`.
+    ///
+    /// The account's type is determined by the address it's derived from.
+    /// The payer funds the additional rent, and any rewards held by the
+    /// account are left untouched.
+    ///
+    /// Extra account metas written by earlier versions of the program have
+    /// the holder rewards pool read-only, so the transfer hook can't write to
+    /// it, and don't include the auto-claim escrow accounts. When migrating
+    /// a pool in the original layout, the mint's extra metas account must be
+    /// provided to correct them, with the payer funding any additional rent.
+    /// Otherwise, every transfer of the mint would fail once the pool is
+    /// migrated. If the pool is already in the current layout, only the
+    /// extra metas are corrected.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w, s]` Payer account.
+    /// 1. `[w]` Holder rewards pool or holder rewards account.
+    /// 2. `[ ]` Token mint (holder rewards pool) or token account (holder
+    ///    rewards) the account's address is derived from.
+    /// 3. `[ ]` System program.
+    /// 4. `[w]` Transfer hook extra account metas account (holder rewards
+    ///    pool only). Required if the pool is in the original layout.
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer account.",
+    )]
+    #[account(
+        1,
+        writable,
+        name = "account",
+        desc = "Holder rewards pool or holder rewards account."
+    )]
+    #[account(
+        2,
+        name = "address_seed",
+        desc = "Token mint or token account the account's address is derived from.",
+    )]
+    #[account(
+        3,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    #[account(
+        4,
+        optional,
+        writable,
+        name = "extra_account_metas",
+        desc = "Transfer hook extra account metas account. Required when migrating a pool in the original layout.",
+    )]
+    MigrateAccount,
+    /// Sweeps lamports held by the holder rewards pool that are provably not
+    /// owed to holders to the provided destination.
+    ///
+    /// Only the pool's dust is swept. This is the rewards truncated from
+    /// holders' settled rewards, which no holder can ever claim. Lamports
+    /// credited to the pool directly are never swept, so they can still be
+    /// deposited with
+    /// [DepositRewards](enum.PaladinRewardsInstruction.html). The sweep never
+    /// takes the pool below its rent-exempt minimum, rewards escrowed by its
+    /// emission schedule, or rewards carried over from distributions made
+    /// while the token supply was zero.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pool authority.
+    /// 3. `[w]` Destination account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "pool_authority",
+        desc = "Pool authority.",
+    )]
+    #[account(
+        3,
+        writable,
+        name = "destination",
+        desc = "Destination account.",
+    )]
+    SweepDust,
+    /// Excludes a token account from earning rewards from the holder rewards
+    /// pool and the mint's token rewards pools, or includes it again.
+    ///
+    /// Any rewards accrued up to this point are settled first, so an
+    /// excluded holder keeps what it has already earned. While excluded, the
+    /// token account's balance is subtracted from the token supply that
+    /// rewards are distributed over.
+    ///
+    /// The token account's token holder rewards account for each of the
+    /// mint's token rewards pools must be provided, with its token rewards
+    /// pool, in the order the pools are listed in the mint's extra account
+    /// metas, so they're all settled under the old exclusion too. A token
+    /// holder rewards account that hasn't been initialized is skipped.
+    ///
+    /// Every excluded holder must be provided to the instructions that
+    /// distribute rewards to the holder rewards pool or the mint's token
+    /// rewards pools, so the number of excluded holders is bounded by the
+    /// transaction's account limit.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards account.
+    /// 2. `[ ]` Token account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[s]` Pool authority.
+    /// 5. `[ ]` Transfer hook extra account metas account.
+    ///
+    /// Followed by, for each of the mint's token rewards pools:
+    ///
+    /// 0. `[ ]` Token rewards pool account.
+    /// 1. `[w]` Token holder rewards account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        signer,
+        name = "pool_authority",
+        desc = "Pool authority.",
+    )]
+    #[account(
+        5,
+        name = "extra_account_metas",
+        desc = "Transfer hook extra account metas account.",
+    )]
+    SetRewardsExclusion(bool),
+    /// Sets the account a holder's SOL rewards are harvested to in place of
+    /// the token account, or clears it if set to `Pubkey::default()`.
+    ///
+    /// This allows protocols holding tokens on behalf of users, such as
+    /// lending markets, to route the rewards earned by those tokens to their
+    /// depositors. Rewards are not settled, so any unharvested rewards are
+    /// harvested to the new recipient. Token rewards pools are unaffected.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards account.
+    /// 1. `[ ]` Token account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[s]` Token account owner.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        1,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        signer,
+        name = "owner",
+        desc = "Token account owner.",
+    )]
+    SetRewardsRecipient(Pubkey),
+    /// Moves accrued SOL rewards into the token accounts of many holders at
+    /// once, exactly as
+    /// [HarvestRewards](enum.PaladinRewardsInstruction.html) does for one.
+    ///
+    /// The holder rewards pool and mint are provided once, followed by a
+    /// holder rewards account and token account for each holder. A holder
+    /// with a rewards recipient must be followed by its recipient, which
+    /// receives its rewards instead of the token account.
+    ///
+    /// The number of holders per batch is bounded by the transaction's
+    /// account and compute limits.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. ..2+2N `[w]` N pairs of holder rewards account and token account,
+    ///    each followed by the holder's rewards recipient, if it has one.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    HarvestRewardsBatch,
+    /// Initializes the holder rewards accounts for many token accounts of
+    /// the same mint at once, exactly as
+    /// [InitializeHolderRewards](enum.PaladinRewardsInstruction.html) does
+    /// for one.
+    ///
+    /// The holder rewards pool, mint and payer are provided once, followed
+    /// by a holder rewards account and token account for each holder. The
+    /// payer funds the rent for every holder rewards account.
+    ///
+    /// Holders whose holder rewards account has already been initialized are
+    /// skipped, so one initialized ahead of the batch doesn't fail it.
+    ///
+    /// The number of holders per batch is bounded by the transaction's
+    /// account and compute limits.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[ ]` System program.
+    /// 3. `[w, s]` Payer account.
+    /// 4. ..4+2N `[w]` N pairs of holder rewards account and token account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    #[account(
+        3,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer account.",
+    )]
+    InitializeHolderRewardsBatch,
+    /// Sets the smallest amount of unharvested SOL rewards the transfer hook
+    /// pays out to a holder's auto-claim escrow account, or disables
+    /// auto-claim if set to `0`.
+    ///
+    /// Whenever the token account is transferred to or from, and its
+    /// unharvested rewards from the pool's base rate and reward streams
+    /// reach the threshold, they're all paid out to the escrow account. They
+    /// can then be harvested to the token account, or to the holder's
+    /// rewards recipient, with
+    /// [HarvestEscrowedRewards](enum.PaladinRewardsInstruction.html).
+    /// Rewards are not settled, and token rewards pools are unaffected.
+    ///
+    /// The escrow account is created when auto-claim is first enabled, funded
+    /// by the payer. Auto-claim only takes effect once the mint's extra metas
+    /// account includes the escrow accounts, which it does for mints
+    /// initialized, or extra metas accounts migrated with
+    /// [MigrateAccount](enum.PaladinRewardsInstruction.html), since they
+    /// were introduced.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards account.
+    /// 1. `[ ]` Token account.
+    /// 2. `[ ]` Token mint.
+    /// 3. `[s]` Token account owner.
+    /// 4. `[w]` Auto-claim escrow account.
+    /// 5. `[ ]` System program.
+    /// 6. `[w, s]` Payer (optional). Required to create the escrow account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        1,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        2,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        3,
+        signer,
+        name = "owner",
+        desc = "Token account owner.",
+    )]
+    #[account(
+        4,
+        writable,
+        name = "escrow",
+        desc = "Auto-claim escrow account.",
+    )]
+    #[account(
+        5,
+        name = "system_program",
+        desc = "System program.",
+    )]
+    #[account(
+        6,
+        optional,
+        writable,
+        signer,
+        name = "payer",
+        desc = "Payer account.",
+    )]
+    SetAutoClaim(u64),
+    /// Moves SOL rewards paid out to a holder's auto-claim escrow account by
+    /// the transfer hook into the token account.
+    ///
+    /// If the holder has a rewards recipient, the rewards are moved to the
+    /// recipient instead of the token account. The escrow account keeps its
+    /// rent-exempt minimum.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Auto-claim escrow account.
+    /// 1. `[ ]` Holder rewards account.
+    /// 2. `[w]` Token account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[w]` Rewards recipient (required if the holder has one).
+    #[account(
+        0,
+        writable,
+        name = "escrow",
+        desc = "Auto-claim escrow account.",
+    )]
+    #[account(
+        1,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        writable,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        optional,
+        writable,
+        name = "recipient",
+        desc = "Rewards recipient.",
+    )]
+    HarvestEscrowedRewards,
+    /// Allows holders to compound their harvested rewards into the provided
+    /// program with
+    /// [HarvestAndCompound](enum.PaladinRewardsInstruction.html).
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pool authority.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "pool_authority",
+        desc = "Pool authority.",
+    )]
+    AddCompoundProgram(Pubkey),
+    /// Stops holders from compounding their harvested rewards into the
+    /// provided program.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pool authority.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "pool_authority",
+        desc = "Pool authority.",
+    )]
+    RemoveCompoundProgram(Pubkey),
+    /// Harvests accrued SOL rewards exactly as
+    /// [HarvestRewards](enum.PaladinRewardsInstruction.html) does, but into
+    /// the compound destination, then invokes a compound program allowed by
+    /// the pool authority to convert them on the rewards owner's behalf,
+    /// such as by swapping them for more tokens or staking them.
+    ///
+    /// The rewards owner is the token account owner, or the holder's
+    /// rewards recipient if it has one, and must sign. The compound program
+    /// is invoked with the harvested amount as a little-endian `u64` for its
+    /// instruction data, and with the read-only rewards owner, the compound
+    /// destination and the read-only holder rewards pool as its first three
+    /// accounts, followed by any remaining accounts. No account is passed to
+    /// it as a signer. It isn't invoked if nothing was harvested. The
+    /// compound destination must be owned by the compound program.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[w]` Holder rewards account.
+    /// 2. `[ ]` Token account.
+    /// 3. `[ ]` Token mint.
+    /// 4. `[s]` Rewards owner.
+    /// 5. `[ ]` Compound program.
+    /// 6. `[w]` Compound destination, owned by the compound program.
+    /// 7. ..7+N `[ ]` N accounts passed to the compound program.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        writable,
+        name = "holder_rewards",
+        desc = "Holder rewards account.",
+    )]
+    #[account(
+        2,
+        name = "token_account",
+        desc = "Token account.",
+    )]
+    #[account(
+        3,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        4,
+        signer,
+        name = "rewards_owner",
+        desc = "Rewards owner.",
+    )]
+    #[account(
+        5,
+        name = "compound_program",
+        desc = "Compound program.",
+    )]
+    #[account(
+        6,
+        writable,
+        name = "compound_destination",
+        desc = "Compound destination, owned by the compound program.",
+    )]
+    HarvestAndCompound,
+    /// Sets who may distribute rewards to the holder rewards pool with
+    /// [DistributeRewards](enum.PaladinRewardsInstruction.html) or
+    /// [DistributeStreamRewards](enum.PaladinRewardsInstruction.html), and
+    /// the smallest amount they may distribute. The distribution authority
+    /// also applies to the mint's token rewards pools with
+    /// [DistributeTokenRewards](enum.PaladinRewardsInstruction.html), but the
+    /// minimum, in lamports, doesn't.
+    ///
+    /// A distribution authority of `Pubkey::default()` allows anyone to
+    /// distribute.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Pool authority.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        signer,
+        name = "pool_authority",
+        desc = "Pool authority.",
+    )]
+    SetDistributionConfig {
+        distribution_authority: Pubkey,
+        min_distribution_amount: u64,
+    },
+    /// Distributes SOL rewards credited directly to the holder rewards pool,
+    /// such as by a program transferring lamports from an account it owns,
+    /// exactly as [DistributeRewards](enum.PaladinRewardsInstruction.html)
+    /// does.
+    ///
+    /// The amount deposited is every lamport above the pool's outstanding
+    /// liabilities. These are its rent-exempt minimum, rewards escrowed by
+    /// its emission schedule, rewards carried over from distributions made
+    /// while the token supply was zero, and all rewards distributed to the
+    /// pool but not yet harvested.
+    ///
+    /// If the pool has a distribution authority, it must be provided and
+    /// sign. The amount must be at least the pool's minimum distribution
+    /// amount. Neither is checked if there's nothing to deposit. Excluded
+    /// holders must always be provided, and are settled first, exactly as
+    /// for [DistributeRewards](enum.PaladinRewardsInstruction.html).
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. `[w]` Holder rewards pool account.
+    /// 1. `[ ]` Token mint.
+    /// 2. `[s]` Distribution authority (optional). Required if the pool has
+    ///    one.
+    ///
+    /// Followed by, for each excluded holder:
+    ///
+    /// 0. `[w]` Holder rewards account.
+    /// 1. `[ ]` Token account.
+    #[account(
+        0,
+        writable,
+        name = "holder_rewards_pool",
+        desc = "Holder rewards pool account."
+    )]
+    #[account(
+        1,
+        name = "mint",
+        desc = "Token mint.",
+    )]
+    #[account(
+        2,
+        optional,
+        signer,
+        name = "distribution_authority",
+        desc = "Distribution authority.",
+    )]
+    DepositRewards,
+}
+
+impl PaladinRewardsInstruction {
+    /// Packs a
+    /// [PaladinRewardsInstruction](enum.PaladinRewardsInstruction.html)
+    /// into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        match self {
+            PaladinRewardsInstruction::InitializeHolderRewardsPool => vec![0],
+            PaladinRewardsInstruction::DistributeRewards(amount) => {
+                let mut data = Vec::with_capacity(9);
+                data.push(1);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::InitializeHolderRewards => vec![2],
+            PaladinRewardsInstruction::HarvestRewards => vec![3],
+            PaladinRewardsInstruction::SyncHolderRewards => vec![4],
+            PaladinRewardsInstruction::SetPoolAuthority(new_authority) => {
+                let mut data = Vec::with_capacity(33);
+                data.push(5);
+                data.extend_from_slice(new_authority.as_ref());
+                data
+            }
+            PaladinRewardsInstruction::AcceptPoolAuthority => vec![6],
+            PaladinRewardsInstruction::CloseHolderRewards => vec![7],
+            PaladinRewardsInstruction::HarvestRewardsToDestination => vec![8],
+            PaladinRewardsInstruction::InitializeTokenRewardsPool => vec![9],
+            PaladinRewardsInstruction::DistributeTokenRewards(amount) => {
+                let mut data = Vec::with_capacity(9);
+                data.push(10);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::InitializeTokenHolderRewards => vec![11],
+            PaladinRewardsInstruction::HarvestTokenRewards => vec![12],
+            PaladinRewardsInstruction::AddRewardStream(reward_id) => {
+                let mut data = Vec::with_capacity(9);
+                data.push(13);
+                data.extend_from_slice(&reward_id.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::DistributeStreamRewards { reward_id, amount } => {
+                let mut data = Vec::with_capacity(17);
+                data.push(14);
+                data.extend_from_slice(&reward_id.to_le_bytes());
+                data.extend_from_slice(&amount.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::HarvestStreamRewards(reward_id) => {
+                let mut data = Vec::with_capacity(9);
+                data.push(15);
+                data.extend_from_slice(&reward_id.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::CreateEmissionSchedule {
+                amount,
+                start_timestamp,
+                end_timestamp,
+            } => {
+                let mut data = Vec::with_capacity(25);
+                data.push(16);
+                data.extend_from_slice(&amount.to_le_bytes());
+                data.extend_from_slice(&start_timestamp.to_le_bytes());
+                data.extend_from_slice(&end_timestamp.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::MigrateAccount => vec![17],
+            PaladinRewardsInstruction::SweepDust => vec![18],
+            PaladinRewardsInstruction::SetRewardsExclusion(excluded) => {
+                vec![19, *excluded as u8]
+            }
+            PaladinRewardsInstruction::SetRewardsRecipient(recipient) => {
+                let mut data = Vec::with_capacity(33);
+                data.push(20);
+                data.extend_from_slice(recipient.as_ref());
+                data
+            }
+            PaladinRewardsInstruction::HarvestRewardsBatch => vec![21],
+            PaladinRewardsInstruction::InitializeHolderRewardsBatch => vec![22],
+            PaladinRewardsInstruction::SetAutoClaim(auto_claim_threshold) => {
+                let mut data = Vec::with_capacity(9);
+                data.push(23);
+                data.extend_from_slice(&auto_claim_threshold.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::HarvestEscrowedRewards => vec![24],
+            PaladinRewardsInstruction::AddCompoundProgram(compound_program) => {
+                let mut data = Vec::with_capacity(33);
+                data.push(25);
+                data.extend_from_slice(compound_program.as_ref());
+                data
+            }
+            PaladinRewardsInstruction::RemoveCompoundProgram(compound_program) => {
+                let mut data = Vec::with_capacity(33);
+                data.push(26);
+                data.extend_from_slice(compound_program.as_ref());
+                data
+            }
+            PaladinRewardsInstruction::HarvestAndCompound => vec![27],
+            PaladinRewardsInstruction::SetDistributionConfig {
+                distribution_authority,
+                min_distribution_amount,
+            } => {
+                let mut data = Vec::with_capacity(41);
+                data.push(28);
+                data.extend_from_slice(distribution_authority.as_ref());
+                data.extend_from_slice(&min_distribution_amount.to_le_bytes());
+                data
+            }
+            PaladinRewardsInstruction::DepositRewards => vec![29],
+        }
+    }
+
+    /// Unpacks a byte buffer into a
+    /// [PaladinRewardsInstruction](enum.PaladinRewardsInstruction.html).
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        match input.split_first() {
+            Some((&0, _)) => Ok(PaladinRewardsInstruction::InitializeHolderRewardsPool),
+            Some((&1, rest)) => {
+                let amount = rest
+                    .get(..8)
+                    .and_then(|slice| Some(u64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::DistributeRewards(amount))
+            }
+            Some((&2, _)) => Ok(PaladinRewardsInstruction::InitializeHolderRewards),
+            Some((&3, _)) => Ok(PaladinRewardsInstruction::HarvestRewards),
+            Some((&4, _)) => Ok(PaladinRewardsInstruction::SyncHolderRewards),
+            Some((&5, rest)) => {
+                let new_authority = rest
+                    .get(..32)
+                    .map(Pubkey::try_from)
+                    .and_then(Result::ok)
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::SetPoolAuthority(new_authority))
+            }
+            Some((&6, _)) => Ok(PaladinRewardsInstruction::AcceptPoolAuthority),
+            Some((&7, _)) => Ok(PaladinRewardsInstruction::CloseHolderRewards),
+            Some((&8, _)) => Ok(PaladinRewardsInstruction::HarvestRewardsToDestination),
+            Some((&9, _)) => Ok(PaladinRewardsInstruction::InitializeTokenRewardsPool),
+            Some((&10, rest)) => {
+                let amount = rest
+                    .get(..8)
+                    .and_then(|slice| Some(u64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::DistributeTokenRewards(amount))
+            }
+            Some((&11, _)) => Ok(PaladinRewardsInstruction::InitializeTokenHolderRewards),
+            Some((&12, _)) => Ok(PaladinRewardsInstruction::HarvestTokenRewards),
+            Some((&13, rest)) => {
+                let reward_id = rest
+                    .get(..8)
+                    .and_then(|slice| Some(u64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::AddRewardStream(reward_id))
+            }
+            Some((&14, rest)) => {
+                let reward_id = rest
+                    .get(..8)
+                    .and_then(|slice| Some(u64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let amount = rest
+                    .get(8..16)
+                    .and_then(|slice| Some(u64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::DistributeStreamRewards { reward_id, amount })
+            }
+            Some((&15, rest)) => {
+                let reward_id = rest
+                    .get(..8)
+                    .and_then(|slice| Some(u64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::HarvestStreamRewards(reward_id))
+            }
+            Some((&16, rest)) => {
+                let amount = rest
+                    .get(..8)
+                    .and_then(|slice| Some(u64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let start_timestamp = rest
+                    .get(8..16)
+                    .and_then(|slice| Some(i64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let end_timestamp = rest
+                    .get(16..24)
+                    .and_then(|slice| Some(i64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::CreateEmissionSchedule {
+                    amount,
+                    start_timestamp,
+                    end_timestamp,
+                })
+            }
+            Some((&17, _)) => Ok(PaladinRewardsInstruction::MigrateAccount),
+            Some((&18, _)) => Ok(PaladinRewardsInstruction::SweepDust),
+            Some((&19, rest)) => {
+                let excluded = match rest.first() {
+                    Some(&0) => false,
+                    Some(&1) => true,
+                    _ => return Err(ProgramError::InvalidInstructionData),
+                };
+                Ok(PaladinRewardsInstruction::SetRewardsExclusion(excluded))
+            }
+            Some((&20, rest)) => {
+                let recipient = rest
+                    .get(..32)
+                    .map(Pubkey::try_from)
+                    .and_then(Result::ok)
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::SetRewardsRecipient(recipient))
+            }
+            Some((&21, _)) => Ok(PaladinRewardsInstruction::HarvestRewardsBatch),
+            Some((&22, _)) => Ok(PaladinRewardsInstruction::InitializeHolderRewardsBatch),
+            Some((&23, rest)) => {
+                let auto_claim_threshold = rest
+                    .get(..8)
+                    .and_then(|slice| Some(u64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::SetAutoClaim(
+                    auto_claim_threshold,
+                ))
+            }
+            Some((&24, _)) => Ok(PaladinRewardsInstruction::HarvestEscrowedRewards),
+            Some((&25, rest)) => {
+                let compound_program = rest
+                    .get(..32)
+                    .map(Pubkey::try_from)
+                    .and_then(Result::ok)
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::AddCompoundProgram(
+                    compound_program,
+                ))
+            }
+            Some((&26, rest)) => {
+                let compound_program = rest
+                    .get(..32)
+                    .map(Pubkey::try_from)
+                    .and_then(Result::ok)
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::RemoveCompoundProgram(
+                    compound_program,
+                ))
+            }
+            Some((&27, _)) => Ok(PaladinRewardsInstruction::HarvestAndCompound),
+            Some((&28, rest)) => {
+                let distribution_authority = rest
+                    .get(..32)
+                    .map(Pubkey::try_from)
+                    .and_then(Result::ok)
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let min_distribution_amount = rest
+                    .get(32..40)
+                    .and_then(|slice| Some(u64::from_le_bytes(slice.try_into().ok()?)))
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Ok(PaladinRewardsInstruction::SetDistributionConfig {
+                    distribution_authority,
+                    min_distribution_amount,
+                })
+            }
+            Some((&29, _)) => Ok(PaladinRewardsInstruction::DepositRewards),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// Creates an
+/// [InitializeHolderRewardsPool](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn initialize_holder_rewards_pool(
+    holder_rewards_pool_address: &Pubkey,
+    extra_account_metas_address: &Pubkey,
+    mint_address: &Pubkey,
+    mint_authority_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new(*extra_account_metas_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*mint_authority_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinRewardsInstruction::InitializeHolderRewardsPool.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an
+/// [InitializeHolderRewardsPool](enum.PaladinRewardsInstruction.html)
+/// instruction, with a payer to fund the rent for the new accounts.
+pub fn initialize_holder_rewards_pool_with_payer(
+    holder_rewards_pool_address: &Pubkey,
+    extra_account_metas_address: &Pubkey,
+    mint_address: &Pubkey,
+    mint_authority_address: &Pubkey,
+    payer_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = initialize_holder_rewards_pool(
+        holder_rewards_pool_address,
+        extra_account_metas_address,
+        mint_address,
+        mint_authority_address,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new(*payer_address, true));
+    instruction
+}
+
+/// Appends the holder rewards account and token account of each of the
+/// provided excluded holders, in order of holder rewards address.
+fn push_excluded_holders(accounts: &mut Vec<AccountMeta>, excluded_holders: &[(Pubkey, Pubkey)]) {
+    let mut excluded_holders = excluded_holders.to_vec();
+    excluded_holders.sort_unstable();
+    for (holder_rewards_address, token_account_address) in excluded_holders {
+        accounts.push(AccountMeta::new(holder_rewards_address, false));
+        accounts.push(AccountMeta::new_readonly(token_account_address, false));
+    }
+}
+
+/// Creates a [DistributeRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+///
+/// `excluded_holders` are the holder rewards address and token account
+/// address of each holder excluded from earning rewards.
+pub fn distribute_rewards(
+    payer_address: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    mint: &Pubkey,
+    excluded_holders: &[(Pubkey, Pubkey)],
+    amount: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    push_excluded_holders(&mut accounts, excluded_holders);
+    let data = PaladinRewardsInstruction::DistributeRewards(amount).pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an [InitializeHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn initialize_holder_rewards(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new(*holder_rewards_address, false),
+        AccountMeta::new_readonly(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinRewardsInstruction::InitializeHolderRewards.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an [InitializeHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction, with a payer to fund the rent for the new account.
+pub fn initialize_holder_rewards_with_payer(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    payer_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = initialize_holder_rewards(
+        holder_rewards_pool_address,
+        holder_rewards_address,
+        token_account_address,
+        mint_address,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new(*payer_address, true));
+    instruction
+}
+
+/// Creates a [HarvestRewards](enum.PaladinRewardsInstruction.html) instruction.
+pub fn harvest_rewards(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new(*holder_rewards_address, false),
+        AccountMeta::new(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+    ];
+    let data = PaladinRewardsInstruction::HarvestRewards.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [HarvestRewards](enum.PaladinRewardsInstruction.html)
+/// instruction for a holder with a rewards recipient.
+pub fn harvest_rewards_to_recipient(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    recipient_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = harvest_rewards(
+        holder_rewards_pool_address,
+        holder_rewards_address,
+        token_account_address,
+        mint_address,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new(*recipient_address, false));
+    instruction
+}
+
+/// Creates a [SyncHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn sync_holder_rewards(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new(*holder_rewards_address, false),
+        AccountMeta::new_readonly(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+    ];
+    let data = PaladinRewardsInstruction::SyncHolderRewards.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [SetPoolAuthority](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn set_pool_authority(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+    new_authority_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*pool_authority_address, true),
+    ];
+    let data = PaladinRewardsInstruction::SetPoolAuthority(*new_authority_address).pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an [AcceptPoolAuthority](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn accept_pool_authority(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    pending_pool_authority_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*pending_pool_authority_address, true),
+    ];
+    let data = PaladinRewardsInstruction::AcceptPoolAuthority.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [CloseHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn close_holder_rewards(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    owner_address: &Pubkey,
+    destination_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new(*holder_rewards_address, false),
+        AccountMeta::new(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*owner_address, true),
+        AccountMeta::new(*destination_address, false),
+    ];
+    let data = PaladinRewardsInstruction::CloseHolderRewards.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [CloseHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction for a holder with a rewards recipient.
+pub fn close_holder_rewards_with_recipient(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    owner_address: &Pubkey,
+    destination_address: &Pubkey,
+    recipient_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = close_holder_rewards(
+        holder_rewards_pool_address,
+        holder_rewards_address,
+        token_account_address,
+        mint_address,
+        owner_address,
+        destination_address,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new(*recipient_address, false));
+    instruction
+}
+
+/// Creates a
+/// [HarvestRewardsToDestination](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn harvest_rewards_to_destination(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    authority_address: &Pubkey,
+    destination_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new(*holder_rewards_address, false),
+        AccountMeta::new_readonly(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*authority_address, true),
+        AccountMeta::new(*destination_address, false),
+    ];
+    let data = PaladinRewardsInstruction::HarvestRewardsToDestination.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an
+/// [InitializeTokenRewardsPool](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn initialize_token_rewards_pool(
+    token_rewards_pool_address: &Pubkey,
+    extra_account_metas_address: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    reward_mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*token_rewards_pool_address, false),
+        AccountMeta::new(*extra_account_metas_address, false),
+        AccountMeta::new_readonly(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*reward_mint_address, false),
+        AccountMeta::new_readonly(*pool_authority_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinRewardsInstruction::InitializeTokenRewardsPool.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an
+/// [InitializeTokenRewardsPool](enum.PaladinRewardsInstruction.html)
+/// instruction, with a payer to fund the rent for the new and resized
+/// accounts.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_token_rewards_pool_with_payer(
+    token_rewards_pool_address: &Pubkey,
+    extra_account_metas_address: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    reward_mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+    payer_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = initialize_token_rewards_pool(
+        token_rewards_pool_address,
+        extra_account_metas_address,
+        holder_rewards_pool_address,
+        mint_address,
+        reward_mint_address,
+        pool_authority_address,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new(*payer_address, true));
+    instruction
+}
+
+/// Creates a [DistributeTokenRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn distribute_token_rewards(
+    source_authority_address: &Pubkey,
+    source_address: &Pubkey,
+    token_rewards_pool_address: &Pubkey,
+    vault_address: &Pubkey,
+    mint_address: &Pubkey,
+    reward_mint_address: &Pubkey,
+    reward_token_program_id: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    excluded_holders: &[(Pubkey, Pubkey)],
+    amount: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*source_authority_address, true),
+        AccountMeta::new(*source_address, false),
+        AccountMeta::new(*token_rewards_pool_address, false),
+        AccountMeta::new(*vault_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*reward_mint_address, false),
+        AccountMeta::new_readonly(*reward_token_program_id, false),
+        AccountMeta::new(*holder_rewards_pool_address, false),
+    ];
+    push_excluded_holders(&mut accounts, excluded_holders);
+    let data = PaladinRewardsInstruction::DistributeTokenRewards(amount).pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an
+/// [InitializeTokenHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn initialize_token_holder_rewards(
+    token_rewards_pool_address: &Pubkey,
+    token_holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    reward_mint_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*token_rewards_pool_address, false),
+        AccountMeta::new(*token_holder_rewards_address, false),
+        AccountMeta::new_readonly(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*reward_mint_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinRewardsInstruction::InitializeTokenHolderRewards.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an
+/// [InitializeTokenHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction, with a payer to fund the rent for the new account.
+pub fn initialize_token_holder_rewards_with_payer(
+    token_rewards_pool_address: &Pubkey,
+    token_holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    reward_mint_address: &Pubkey,
+    payer_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = initialize_token_holder_rewards(
+        token_rewards_pool_address,
+        token_holder_rewards_address,
+        token_account_address,
+        mint_address,
+        reward_mint_address,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new(*payer_address, true));
+    instruction
+}
+
+/// Creates a [HarvestTokenRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn harvest_token_rewards(
+    token_rewards_pool_address: &Pubkey,
+    token_holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    vault_address: &Pubkey,
+    destination_address: &Pubkey,
+    reward_mint_address: &Pubkey,
+    reward_token_program_id: &Pubkey,
+    holder_rewards_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*token_rewards_pool_address, false),
+        AccountMeta::new(*token_holder_rewards_address, false),
+        AccountMeta::new_readonly(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new(*vault_address, false),
+        AccountMeta::new(*destination_address, false),
+        AccountMeta::new_readonly(*reward_mint_address, false),
+        AccountMeta::new_readonly(*reward_token_program_id, false),
+        AccountMeta::new_readonly(*holder_rewards_address, false),
+    ];
+    let data = PaladinRewardsInstruction::HarvestTokenRewards.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an [AddRewardStream](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn add_reward_stream(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+    reward_id: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*pool_authority_address, true),
+    ];
+    let data = PaladinRewardsInstruction::AddRewardStream(reward_id).pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [DistributeStreamRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn distribute_stream_rewards(
+    payer_address: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    mint: &Pubkey,
+    excluded_holders: &[(Pubkey, Pubkey)],
+    reward_id: u64,
+    amount: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    push_excluded_holders(&mut accounts, excluded_holders);
+    let data = PaladinRewardsInstruction::DistributeStreamRewards { reward_id, amount }.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [HarvestStreamRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn harvest_stream_rewards(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    reward_id: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new(*holder_rewards_address, false),
+        AccountMeta::new(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+    ];
+    let data = PaladinRewardsInstruction::HarvestStreamRewards(reward_id).pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [HarvestStreamRewards](enum.PaladinRewardsInstruction.html)
+/// instruction for a holder with a rewards recipient.
+pub fn harvest_stream_rewards_to_recipient(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    reward_id: u64,
+    recipient_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = harvest_stream_rewards(
+        holder_rewards_pool_address,
+        holder_rewards_address,
+        token_account_address,
+        mint_address,
+        reward_id,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new(*recipient_address, false));
+    instruction
+}
+
+/// Creates a [CreateEmissionSchedule](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn create_emission_schedule(
+    payer_address: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+    amount: u64,
+    start_timestamp: i64,
+    end_timestamp: i64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*pool_authority_address, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinRewardsInstruction::CreateEmissionSchedule {
+        amount,
+        start_timestamp,
+        end_timestamp,
+    }
+    .pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [MigrateAccount](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn migrate_account(
+    payer_address: &Pubkey,
+    account_address: &Pubkey,
+    address_seed_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*payer_address, true),
+        AccountMeta::new(*account_address, false),
+        AccountMeta::new_readonly(*address_seed_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinRewardsInstruction::MigrateAccount.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [MigrateAccount](enum.PaladinRewardsInstruction.html)
+/// instruction for a holder rewards pool, which also corrects the mint's
+/// transfer hook extra account metas.
+pub fn migrate_account_with_extra_metas(
+    payer_address: &Pubkey,
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    extra_account_metas_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = migrate_account(payer_address, holder_rewards_pool_address, mint_address);
+    instruction
+        .accounts
+        .push(AccountMeta::new(*extra_account_metas_address, false));
+    instruction
+}
+
+/// Creates a [SweepDust](enum.PaladinRewardsInstruction.html) instruction.
+pub fn sweep_dust(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+    destination_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*pool_authority_address, true),
+        AccountMeta::new(*destination_address, false),
+    ];
+    let data = PaladinRewardsInstruction::SweepDust.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [SetRewardsExclusion](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn set_rewards_exclusion(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+    extra_account_metas_address: &Pubkey,
+    excluded: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new(*holder_rewards_address, false),
+        AccountMeta::new_readonly(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*pool_authority_address, true),
+        AccountMeta::new_readonly(*extra_account_metas_address, false),
+    ];
+    let data = PaladinRewardsInstruction::SetRewardsExclusion(excluded).pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [SetRewardsExclusion](enum.PaladinRewardsInstruction.html)
+/// instruction, settling the provided token holder rewards accounts, each
+/// paired with its token rewards pool. Every token rewards pool of the mint
+/// must be provided, in the order listed in its extra account metas.
+#[allow(clippy::too_many_arguments)]
+pub fn set_rewards_exclusion_with_token_holder_rewards(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+    extra_account_metas_address: &Pubkey,
+    excluded: bool,
+    token_holder_rewards: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let mut instruction = set_rewards_exclusion(
+        holder_rewards_pool_address,
+        holder_rewards_address,
+        token_account_address,
+        mint_address,
+        pool_authority_address,
+        extra_account_metas_address,
+        excluded,
+    );
+    for (token_rewards_pool_address, token_holder_rewards_address) in token_holder_rewards {
+        instruction.accounts.extend([
+            AccountMeta::new_readonly(*token_rewards_pool_address, false),
+            AccountMeta::new(*token_holder_rewards_address, false),
+        ]);
+    }
+    instruction
+}
+
+/// Creates a [SetRewardsRecipient](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn set_rewards_recipient(
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    owner_address: &Pubkey,
+    recipient_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_address, false),
+        AccountMeta::new_readonly(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*owner_address, true),
+    ];
+    let data = PaladinRewardsInstruction::SetRewardsRecipient(*recipient_address).pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [HarvestRewardsBatch](enum.PaladinRewardsInstruction.html)
+/// instruction.
+///
+/// Each holder is given as its holder rewards address, token account address
+/// and, if it has one, rewards recipient address.
+pub fn harvest_rewards_batch(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    holders: &[(Pubkey, Pubkey, Option<Pubkey>)],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+    ];
+    for (holder_rewards_address, token_account_address, recipient_address) in holders {
+        accounts.push(AccountMeta::new(*holder_rewards_address, false));
+        accounts.push(AccountMeta::new(*token_account_address, false));
+        if let Some(recipient_address) = recipient_address {
+            accounts.push(AccountMeta::new(*recipient_address, false));
+        }
+    }
+    let data = PaladinRewardsInstruction::HarvestRewardsBatch.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates an
+/// [InitializeHolderRewardsBatch](enum.PaladinRewardsInstruction.html)
+/// instruction.
+///
+/// Each holder is given as its holder rewards address and token account
+/// address.
+pub fn initialize_holder_rewards_batch(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    payer_address: &Pubkey,
+    holders: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(*payer_address, true),
+    ];
+    for (holder_rewards_address, token_account_address) in holders {
+        accounts.push(AccountMeta::new(*holder_rewards_address, false));
+        accounts.push(AccountMeta::new_readonly(*token_account_address, false));
+    }
+    let data = PaladinRewardsInstruction::InitializeHolderRewardsBatch.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [SetAutoClaim](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn set_auto_claim(
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    owner_address: &Pubkey,
+    escrow_address: &Pubkey,
+    auto_claim_threshold: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_address, false),
+        AccountMeta::new_readonly(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*owner_address, true),
+        AccountMeta::new(*escrow_address, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let data = PaladinRewardsInstruction::SetAutoClaim(auto_claim_threshold).pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [SetAutoClaim](enum.PaladinRewardsInstruction.html)
+/// instruction, with a payer to create the escrow account.
+pub fn set_auto_claim_with_payer(
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    owner_address: &Pubkey,
+    escrow_address: &Pubkey,
+    payer_address: &Pubkey,
+    auto_claim_threshold: u64,
+) -> Instruction {
+    let mut instruction = set_auto_claim(
+        holder_rewards_address,
+        token_account_address,
+        mint_address,
+        owner_address,
+        escrow_address,
+        auto_claim_threshold,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new(*payer_address, true));
+    instruction
+}
+
+/// Creates a [HarvestEscrowedRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn harvest_escrowed_rewards(
+    escrow_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*escrow_address, false),
+        AccountMeta::new_readonly(*holder_rewards_address, false),
+        AccountMeta::new(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+    ];
+    let data = PaladinRewardsInstruction::HarvestEscrowedRewards.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [HarvestEscrowedRewards](enum.PaladinRewardsInstruction.html)
+/// instruction for a holder with a rewards recipient.
+pub fn harvest_escrowed_rewards_to_recipient(
+    escrow_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    recipient_address: &Pubkey,
+) -> Instruction {
+    let mut instruction = harvest_escrowed_rewards(
+        escrow_address,
+        holder_rewards_address,
+        token_account_address,
+        mint_address,
+    );
+    instruction
+        .accounts
+        .push(AccountMeta::new(*recipient_address, false));
+    instruction
+}
+
+/// Creates an [AddCompoundProgram](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn add_compound_program(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+    compound_program_id: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*pool_authority_address, true),
+    ];
+    let data = PaladinRewardsInstruction::AddCompoundProgram(*compound_program_id).pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [RemoveCompoundProgram](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn remove_compound_program(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+    compound_program_id: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*pool_authority_address, true),
+    ];
+    let data = PaladinRewardsInstruction::RemoveCompoundProgram(*compound_program_id).pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [HarvestAndCompound](enum.PaladinRewardsInstruction.html)
+/// instruction.
+///
+/// The compound accounts are passed to the compound program after the
+/// rewards owner, compound destination and holder rewards pool.
+#[allow(clippy::too_many_arguments)]
+pub fn harvest_and_compound(
+    holder_rewards_pool_address: &Pubkey,
+    holder_rewards_address: &Pubkey,
+    token_account_address: &Pubkey,
+    mint_address: &Pubkey,
+    rewards_owner_address: &Pubkey,
+    compound_program_id: &Pubkey,
+    compound_destination_address: &Pubkey,
+    compound_accounts: &[AccountMeta],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new(*holder_rewards_address, false),
+        AccountMeta::new_readonly(*token_account_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*rewards_owner_address, true),
+        AccountMeta::new_readonly(*compound_program_id, false),
+        AccountMeta::new(*compound_destination_address, false),
+    ];
+    accounts.extend_from_slice(compound_accounts);
+    let data = PaladinRewardsInstruction::HarvestAndCompound.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [SetDistributionConfig](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn set_distribution_config(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    pool_authority_address: &Pubkey,
+    distribution_authority_address: &Pubkey,
+    min_distribution_amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+        AccountMeta::new_readonly(*pool_authority_address, true),
+    ];
+    let data = PaladinRewardsInstruction::SetDistributionConfig {
+        distribution_authority: *distribution_authority_address,
+        min_distribution_amount,
+    }
+    .pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [DepositRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+pub fn deposit_rewards(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    excluded_holders: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*holder_rewards_pool_address, false),
+        AccountMeta::new_readonly(*mint_address, false),
+    ];
+    push_excluded_holders(&mut accounts, excluded_holders);
+    let data = PaladinRewardsInstruction::DepositRewards.pack();
+    Instruction::new_with_bytes(crate::id(), &data, accounts)
+}
+
+/// Creates a [DepositRewards](enum.PaladinRewardsInstruction.html)
+/// instruction, signed by the pool's distribution authority.
+pub fn deposit_rewards_with_distribution_authority(
+    holder_rewards_pool_address: &Pubkey,
+    mint_address: &Pubkey,
+    distribution_authority_address: &Pubkey,
+    excluded_holders: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let mut instruction = deposit_rewards(holder_rewards_pool_address, mint_address, &[]);
+    instruction.accounts.push(AccountMeta::new_readonly(
+        *distribution_authority_address,
+        true,
+    ));
+    push_excluded_holders(&mut instruction.accounts, excluded_holders);
+    instruction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_unpack_initialize_holder_rewards_pool() {
+        let original = PaladinRewardsInstruction::InitializeHolderRewardsPool;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_distribute_rewards() {
+        let original = PaladinRewardsInstruction::DistributeRewards(500_000_000);
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_holder_rewards() {
+        let original = PaladinRewardsInstruction::InitializeHolderRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_harvest_rewards() {
+        let original = PaladinRewardsInstruction::HarvestRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_sync_holder_rewards() {
+        let original = PaladinRewardsInstruction::SyncHolderRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_pool_authority() {
+        let original = PaladinRewardsInstruction::SetPoolAuthority(Pubkey::new_unique());
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_accept_pool_authority() {
+        let original = PaladinRewardsInstruction::AcceptPoolAuthority;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_close_holder_rewards() {
+        let original = PaladinRewardsInstruction::CloseHolderRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_harvest_rewards_to_destination() {
+        let original = PaladinRewardsInstruction::HarvestRewardsToDestination;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_token_rewards_pool() {
+        let original = PaladinRewardsInstruction::InitializeTokenRewardsPool;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_distribute_token_rewards() {
+        let original = PaladinRewardsInstruction::DistributeTokenRewards(500_000_000);
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_token_holder_rewards() {
+        let original = PaladinRewardsInstruction::InitializeTokenHolderRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_harvest_token_rewards() {
+        let original = PaladinRewardsInstruction::HarvestTokenRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_add_reward_stream() {
+        let original = PaladinRewardsInstruction::AddRewardStream(7);
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_distribute_stream_rewards() {
+        let original = PaladinRewardsInstruction::DistributeStreamRewards {
+            reward_id: 7,
+            amount: 500_000_000,
+        };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_harvest_stream_rewards() {
+        let original = PaladinRewardsInstruction::HarvestStreamRewards(7);
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_create_emission_schedule() {
+        let original = PaladinRewardsInstruction::CreateEmissionSchedule {
+            amount: 500_000_000,
+            start_timestamp: 1_000,
+            end_timestamp: 2_000,
+        };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_migrate_account() {
+        let original = PaladinRewardsInstruction::MigrateAccount;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_sweep_dust() {
+        let original = PaladinRewardsInstruction::SweepDust;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_rewards_exclusion() {
+        for excluded in [false, true] {
+            let original = PaladinRewardsInstruction::SetRewardsExclusion(excluded);
+            let packed = original.pack();
+            let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+            assert_eq!(original, unpacked);
+        }
+    }
+
+    #[test]
+    fn test_unpack_set_rewards_exclusion_invalid_flag() {
+        assert_eq!(
+            PaladinRewardsInstruction::unpack(&[19, 2]),
+            Err(ProgramError::InvalidInstructionData),
+        );
+    }
+
+    #[test]
+    fn test_pack_unpack_set_rewards_recipient() {
+        let original = PaladinRewardsInstruction::SetRewardsRecipient(Pubkey::new_unique());
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_harvest_rewards_batch() {
+        let original = PaladinRewardsInstruction::HarvestRewardsBatch;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_holder_rewards_batch() {
+        let original = PaladinRewardsInstruction::InitializeHolderRewardsBatch;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_auto_claim() {
+        let original = PaladinRewardsInstruction::SetAutoClaim(1_000_000);
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_harvest_escrowed_rewards() {
+        let original = PaladinRewardsInstruction::HarvestEscrowedRewards;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_add_compound_program() {
+        let original = PaladinRewardsInstruction::AddCompoundProgram(Pubkey::new_unique());
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_remove_compound_program() {
+        let original = PaladinRewardsInstruction::RemoveCompoundProgram(Pubkey::new_unique());
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_harvest_and_compound() {
+        let original = PaladinRewardsInstruction::HarvestAndCompound;
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_set_distribution_config() {
+        let original = PaladinRewardsInstruction::SetDistributionConfig {
+            distribution_authority: Pubkey::new_unique(),
+            min_distribution_amount: 1_000_000,
+        };
+        let packed = original.pack();
+        let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
+        assert_eq!(original, unpacked);
+    }
+
+    #[test]
+    fn test_pack_unpack_deposit_rewards() {
+        let original = PaladinRewardsInstruction::DepositRewards;
         let packed = original.pack();
         let unpacked = PaladinRewardsInstruction::unpack(&packed).unwrap();
         assert_eq!(original, unpacked);
@@ -3,6 +3,7 @@
 #[cfg(all(target_os = "solana", feature = "bpf-entrypoint"))]
 mod entrypoint;
 pub mod error;
+pub mod events;
 pub mod extra_metas;
 pub mod instruction;
 pub mod processor;
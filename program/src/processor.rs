@@ -3,28 +3,44 @@
 use {
     crate::{
         error::PaladinRewardsError,
-        extra_metas::get_extra_account_metas,
+        events::PaladinRewardsEvent,
+        extra_metas::{
+            get_escrow_extra_account_metas, get_extra_account_metas,
+            get_token_rewards_extra_account_metas, ESCROW_EXTRA_METAS_COUNT,
+            TOKEN_REWARDS_EXTRA_METAS_COUNT,
+        },
         instruction::PaladinRewardsInstruction,
         state::{
-            collect_holder_rewards_pool_signer_seeds, collect_holder_rewards_signer_seeds,
-            get_holder_rewards_address, get_holder_rewards_address_and_bump_seed,
-            get_holder_rewards_pool_address, get_holder_rewards_pool_address_and_bump_seed,
-            HolderRewards, HolderRewardsPool,
+            collect_holder_rewards_escrow_signer_seeds, collect_holder_rewards_pool_signer_seeds,
+            collect_holder_rewards_signer_seeds, collect_token_holder_rewards_signer_seeds,
+            collect_token_rewards_pool_signer_seeds, get_holder_rewards_address,
+            get_holder_rewards_address_and_bump_seed, get_holder_rewards_escrow_address,
+            get_holder_rewards_escrow_address_and_bump_seed, get_holder_rewards_pool_address,
+            get_holder_rewards_pool_address_and_bump_seed, get_token_holder_rewards_address,
+            get_token_holder_rewards_address_and_bump_seed, get_token_rewards_pool_address,
+            get_token_rewards_pool_address_and_bump_seed, get_token_rewards_vault_address,
+            is_legacy_state, pack_versioned, pack_versioned_prefix, unpack_versioned,
+            unpack_versioned_mut, unpack_versioned_prefix, EmissionSchedule, HolderRewards,
+            HolderRewardsPool, TokenHolderRewards, TokenRewardsPool, VersionedAccount,
+            ACCOUNT_HEADER_LEN, MAX_TOKEN_REWARDS_POOLS,
         },
     },
     solana_program::{
         account_info::{next_account_info, AccountInfo},
+        clock::Clock,
         entrypoint::ProgramResult,
+        instruction::{AccountMeta, Instruction},
         msg,
         program::{invoke, invoke_signed},
         program_error::ProgramError,
         program_option::COption,
         pubkey::Pubkey,
         rent::Rent,
-        system_instruction,
+        system_instruction, system_program,
         sysvar::Sysvar,
     },
-    spl_tlv_account_resolution::state::ExtraAccountMetaList,
+    spl_associated_token_account::get_associated_token_address_with_program_id,
+    spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList},
     spl_token_2022::{
         extension::{
             transfer_hook::{TransferHook, TransferHookAccount},
@@ -35,12 +51,27 @@ use {
     spl_transfer_hook_interface::{
         collect_extra_account_metas_signer_seeds,
         error::TransferHookError,
-        get_extra_account_metas_address_and_bump_seed,
+        get_extra_account_metas_address, get_extra_account_metas_address_and_bump_seed,
         instruction::{ExecuteInstruction, TransferHookInstruction},
     },
+    spl_type_length_value::state::TlvStateBorrowed,
 };
 
-const REWARDS_PER_TOKEN_SCALING_FACTOR: u128 = 1_000_000_000; // 1e9
+/// The scaling factor of the holder rewards pool's exchange rates, and the
+/// holder checkpoints against them.
+///
+/// With a factor of `1e18`, any distribution of at least one lamport moves
+/// the rate of a mint with a supply of up to `1e18` base units. Rates never
+/// overflow in practice: a single distribution of `u64::MAX` rewards to a
+/// supply of one adds less than `1.9e37` to a rate, while a `u128` holds
+/// `3.4e38`, and there aren't enough lamports in existence to distribute
+/// that more than a handful of times.
+const REWARDS_PER_TOKEN_SCALING_FACTOR: u128 = 1_000_000_000_000_000_000; // 1e18
+
+/// The factor by which exchange rates are rescaled when migrating accounts
+/// from the original layout, which scaled them by `1e9`.
+const LEGACY_REWARDS_PER_TOKEN_RESCALING_FACTOR: u128 =
+    REWARDS_PER_TOKEN_SCALING_FACTOR / 1_000_000_000;
 
 fn get_token_supply(mint_info: &AccountInfo) -> Result<u64, ProgramError> {
     let mint_data = mint_info.try_borrow_data()?;
@@ -48,6 +79,20 @@ fn get_token_supply(mint_info: &AccountInfo) -> Result<u64, ProgramError> {
     Ok(mint.base.supply)
 }
 
+/// Returns the token supply that rewards distributed to the holder rewards
+/// pool are shared over, which excludes the balances of excluded holders.
+///
+/// The pool's excluded supply must have been reconciled with
+/// `reconcile_excluded_supply`, so it's no more than the token supply.
+fn get_eligible_token_supply(
+    token_supply: u64,
+    pool_state: &HolderRewardsPool,
+) -> Result<u64, ProgramError> {
+    token_supply
+        .checked_sub(pool_state.excluded_supply)
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
 fn get_token_account_balance_checked(
     mint: &Pubkey,
     token_account_info: &AccountInfo,
@@ -106,6 +151,13 @@ fn check_holder_rewards(
         return Err(ProgramError::InvalidAccountOwner);
     }
 
+    check_holder_rewards_address(token_account_key, holder_rewards_info)
+}
+
+fn check_holder_rewards_address(
+    token_account_key: &Pubkey,
+    holder_rewards_info: &AccountInfo,
+) -> ProgramResult {
     // Ensure the provided holder rewards address is the correct address
     // derived from the token account.
     if !holder_rewards_info
@@ -118,292 +170,3466 @@ fn check_holder_rewards(
     Ok(())
 }
 
-fn calculate_rewards_per_token(rewards: u64, token_supply: u64) -> Result<u128, ProgramError> {
-    if token_supply == 0 {
-        return Ok(0);
+/// Ensures a holder rewards account belongs to the provided mint.
+///
+/// The holder rewards address is derived from the token account alone, so
+/// this is what ties the holder to the mint's pools once the token account
+/// has been closed.
+fn check_holder_rewards_mint(holder_rewards_state: &HolderRewards, mint: &Pubkey) -> ProgramResult {
+    if !holder_rewards_state.mint.eq(mint) {
+        return Err(PaladinRewardsError::HolderRewardsMintMismatch.into());
     }
-    // Calculation: rewards / token_supply
-    //
-    // Scaled by 1e9 to store 9 decimal places of precision.
-    (rewards as u128)
-        .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)
-        .and_then(|product| product.checked_div(token_supply as u128))
-        .ok_or(ProgramError::ArithmeticOverflow)
-}
 
-fn calculate_eligible_rewards(
-    current_accumulated_rewards_per_token: u128,
-    last_accumulated_rewards_per_token: u128,
-    token_account_balance: u64,
-) -> Result<u64, ProgramError> {
-    // Calculation: (current_accumulated_rewards_per_token
-    //   - last_accumulated_rewards_per_token) * token_account_balance
-    let marginal_rate = current_accumulated_rewards_per_token
-        .checked_sub(last_accumulated_rewards_per_token)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-    if marginal_rate == 0 {
-        return Ok(0);
-    }
-    // Scaled by 1e9 to store 9 decimal places of precision.
-    marginal_rate
-        .checked_mul(token_account_balance as u128)
-        .and_then(|product| product.checked_div(REWARDS_PER_TOKEN_SCALING_FACTOR))
-        .and_then(|product| product.try_into().ok())
-        .ok_or(ProgramError::ArithmeticOverflow)
+    Ok(())
 }
 
-fn update_holder_rewards_for_transfer_hook(
+fn check_holder_rewards_escrow(
     program_id: &Pubkey,
-    mint: &Pubkey,
-    token_account_info: &AccountInfo,
-    holder_rewards_info: &AccountInfo,
-    current_accumulated_rewards_per_token: u128,
-    adjust_token_balance_fn: impl FnOnce(u64) -> Result<u64, ProgramError>,
+    token_account_key: &Pubkey,
+    escrow_info: &AccountInfo,
 ) -> ProgramResult {
-    // Calculate the token account's updated share of the pool rewards.
-    //
-    // Since the holder rewards account may already have unharvested
-    // rewards, calculate the share of rewards that have not been seen
-    // by the holder rewards account.
-    //
-    // Then, adjust the unharvested rewards with the additional share.
-    //
-    // Token account balances are updated before transfer hooks are called,
-    // so this token account balance is the balance _after_ the transfer.
-    // See: https://github.com/solana-labs/solana-program-library/blob/3c60545668eafa2294365e2edfb5799c657971c3/token/program-2022/src/processor.rs#L479-L487.
-    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
-    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
-    let holder_rewards_state =
-        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
-
-    let token_account_balance = {
-        // At this point, the token account balance is the balance _after_ the
-        // transfer.
-        //
-        // For the source - since it was just debited - the transfer amount
-        // will be added back to calculate the rewards share before the
-        // transfer.
-        //
-        // For the destination - since it was just credited - the transfer
-        // amount will be subtracted to calculate the rewards share before
-        // the transfer.
-        let current_balance = get_token_account_balance_checked(mint, token_account_info, true)?;
-        adjust_token_balance_fn(current_balance)?
-    };
-
-    let eligible_rewards = calculate_eligible_rewards(
-        current_accumulated_rewards_per_token,
-        holder_rewards_state.last_accumulated_rewards_per_token,
-        token_account_balance,
-    )?;
+    // Ensure the provided escrow address is the correct address derived from
+    // the token account.
+    if !escrow_info
+        .key
+        .eq(&get_holder_rewards_escrow_address(token_account_key))
+    {
+        return Err(PaladinRewardsError::IncorrectHolderRewardsEscrowAddress.into());
+    }
 
-    // Update the holder rewards state.
-    holder_rewards_state.last_accumulated_rewards_per_token = current_accumulated_rewards_per_token;
-    holder_rewards_state.unharvested_rewards = holder_rewards_state
-        .unharvested_rewards
-        .checked_add(eligible_rewards)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Ensure the escrow account is owned by the Paladin Rewards program.
+    if !escrow_info.owner.eq(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
 
     Ok(())
 }
 
-/// Processes an
-/// [InitializeHolderRewardsPool](enum.PaladinRewardsInstruction.html)
-/// instruction.
-fn process_initialize_holder_rewards_pool(
+/// Returns whether an account has yet to be initialized by any program.
+fn is_uninitialized(account_info: &AccountInfo) -> bool {
+    account_info.owner.eq(&system_program::id()) && account_info.data_is_empty()
+}
+
+/// Checks a holder rewards account provided to the transfer hook, returning
+/// whether it has been initialized.
+///
+/// A token account receiving tokens for the first time may not have a
+/// holder rewards account yet. Until one is initialized, the token account
+/// doesn't earn rewards, so the transfer hook skips it rather than failing
+/// the transfer. Its address is still checked, so an initialized holder
+/// rewards account can never be skipped.
+fn check_holder_rewards_for_transfer_hook(
     program_id: &Pubkey,
-    accounts: &[AccountInfo],
-) -> ProgramResult {
-    let accounts_iter = &mut accounts.iter();
+    token_account_key: &Pubkey,
+    holder_rewards_info: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    if is_uninitialized(holder_rewards_info) {
+        check_holder_rewards_address(token_account_key, holder_rewards_info)?;
+        return Ok(false);
+    }
 
-    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
-    let extra_metas_info = next_account_info(accounts_iter)?;
-    let mint_info = next_account_info(accounts_iter)?;
-    let mint_authority_info = next_account_info(accounts_iter)?;
-    let _system_program_info = next_account_info(accounts_iter)?;
+    check_holder_rewards(program_id, token_account_key, holder_rewards_info)?;
+    Ok(true)
+}
 
-    // Run checks on the mint.
+/// Checks a holder rewards account, returning whether it excludes its token
+/// account from rewards. A holder rewards account that hasn't been
+/// initialized excludes nothing.
+fn is_holder_excluded(
+    program_id: &Pubkey,
+    token_account_key: &Pubkey,
+    holder_rewards_info: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    if !check_holder_rewards_for_transfer_hook(program_id, token_account_key, holder_rewards_info)?
     {
-        let mint_data = mint_info.try_borrow_data()?;
-        let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
-
-        // Ensure the mint is configured with the `TransferHook` extension,
-        // and the program ID is the Paladin Rewards program.
-        let transfer_hook = mint.get_extension::<TransferHook>()?;
-        let hook_program_id: Option<Pubkey> = transfer_hook.program_id.into();
-        if !hook_program_id.eq(&Some(*program_id)) {
-            return Err(PaladinRewardsError::IncorrectTransferHookProgramId.into());
-        }
+        return Ok(false);
+    }
 
-        // Ensure the provided mint authority is the correct mint authority.
-        if !mint
-            .base
-            .mint_authority
-            .eq(&COption::Some(*mint_authority_info.key))
-        {
-            return Err(PaladinRewardsError::IncorrectMintAuthority.into());
-        }
+    // Accounts in the original layout can't have been excluded.
+    let (_, holder_rewards_state) =
+        unpack_versioned_prefix::<HolderRewards>(&holder_rewards_info.try_borrow_data()?)?;
+    Ok(holder_rewards_state.is_excluded())
+}
 
-        // Ensure the mint authority is a signer.
-        if !mint_authority_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+fn check_pool_authority(
+    holder_rewards_pool_state: &HolderRewardsPool,
+    authority_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the provided authority is the pool authority.
+    if !holder_rewards_pool_state.authority.eq(authority_info.key) {
+        return Err(PaladinRewardsError::IncorrectPoolAuthority.into());
     }
 
-    // Initialize the holder rewards pool account.
-    {
-        let (holder_rewards_pool_address, bump_seed) =
-            get_holder_rewards_pool_address_and_bump_seed(mint_info.key);
-        let bump_seed = [bump_seed];
-        let holder_rewards_pool_signer_seeds =
-            collect_holder_rewards_pool_signer_seeds(mint_info.key, &bump_seed);
+    // Ensure the pool authority is a signer.
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
 
-        // Ensure the provided holder rewards pool address is the correct
-        // address derived from the mint.
-        if !holder_rewards_pool_info
-            .key
-            .eq(&holder_rewards_pool_address)
-        {
-            return Err(PaladinRewardsError::IncorrectHolderRewardsPoolAddress.into());
-        }
+    Ok(())
+}
 
-        // Ensure the holder rewards pool account has not already been
-        // initialized.
-        if holder_rewards_pool_info.data_len() != 0 {
-            return Err(ProgramError::AccountAlreadyInitialized);
+/// Ensures a holder's harvested rewards are being paid to its rewards
+/// recipient, if one is set, and not to one of the program's own accounts.
+fn check_rewards_destination(
+    program_id: &Pubkey,
+    holder_rewards_state: &HolderRewards,
+    destination_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the destination is the holder's rewards recipient, if one is
+    // set.
+    if let Some(recipient) = holder_rewards_state.recipient() {
+        if !recipient.eq(destination_info.key) {
+            return Err(PaladinRewardsError::IncorrectRewardsRecipient.into());
         }
+    }
 
-        // Allocate & assign.
-        invoke_signed(
-            &system_instruction::allocate(
-                &holder_rewards_pool_address,
-                std::mem::size_of::<HolderRewardsPool>() as u64,
-            ),
-            &[holder_rewards_pool_info.clone()],
-            &[&holder_rewards_pool_signer_seeds],
-        )?;
-        invoke_signed(
-            &system_instruction::assign(&holder_rewards_pool_address, program_id),
-            &[holder_rewards_pool_info.clone()],
-            &[&holder_rewards_pool_signer_seeds],
-        )?;
-
-        // Write the data.
-        let mut data = holder_rewards_pool_info.try_borrow_mut_data()?;
-        *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
-            HolderRewardsPool::default();
+    // Ensure the rewards are not being paid out to one of the program's own
+    // accounts.
+    if destination_info.owner.eq(program_id) {
+        return Err(ProgramError::InvalidArgument);
     }
 
-    // Initialize the extra metas account.
-    {
-        let (extra_metas_address, extra_metas_bump) =
-            get_extra_account_metas_address_and_bump_seed(mint_info.key, program_id);
-        let extra_metas_bump = [extra_metas_bump];
-        let extra_metas_signer_seeds =
-            collect_extra_account_metas_signer_seeds(mint_info.key, &extra_metas_bump);
+    Ok(())
+}
 
-        // Ensure the provided extra metas address is the correct address
-        // derived from the mint.
-        if !extra_metas_info.key.eq(&extra_metas_address) {
-            return Err(PaladinRewardsError::IncorrectExtraMetasAddress.into());
-        }
+/// Returns the account a holder's harvested rewards are paid to when no
+/// destination is chosen by the token account owner.
+///
+/// This is the holder's rewards recipient, if one is set, which must be
+/// provided as the next account. Otherwise, it's the token account itself.
+fn next_rewards_destination_info<'a, 'b>(
+    program_id: &Pubkey,
+    holder_rewards_state: &HolderRewards,
+    token_account_info: &'a AccountInfo<'b>,
+    accounts_iter: &mut std::slice::Iter<'a, AccountInfo<'b>>,
+) -> Result<&'a AccountInfo<'b>, ProgramError> {
+    if holder_rewards_state.recipient().is_none() {
+        return Ok(token_account_info);
+    }
 
-        // Ensure the extra metas account has not already been initialized.
-        if extra_metas_info.data_len() != 0 {
-            return Err(ProgramError::AccountAlreadyInitialized);
-        }
+    let recipient_info = next_account_info(accounts_iter)?;
+    check_rewards_destination(program_id, holder_rewards_state, recipient_info)?;
 
-        let extra_metas = get_extra_account_metas();
-        let account_size = ExtraAccountMetaList::size_of(extra_metas.len())?;
+    Ok(recipient_info)
+}
 
-        // Allocate & assign.
-        invoke_signed(
-            &system_instruction::allocate(&extra_metas_address, account_size as u64),
-            &[extra_metas_info.clone()],
-            &[&extra_metas_signer_seeds],
-        )?;
-        invoke_signed(
-            &system_instruction::assign(&extra_metas_address, program_id),
-            &[extra_metas_info.clone()],
-            &[&extra_metas_signer_seeds],
-        )?;
+fn check_token_rewards_pool(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    reward_mint: &Pubkey,
+    token_rewards_pool_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the token rewards pool is owned by the Paladin Rewards program.
+    if !token_rewards_pool_info.owner.eq(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
 
-        // Write the data.
-        let mut data = extra_metas_info.try_borrow_mut_data()?;
-        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_metas)?;
+    // Ensure the provided token rewards pool address is the correct address
+    // derived from the mint and reward mint.
+    if !token_rewards_pool_info
+        .key
+        .eq(&get_token_rewards_pool_address(mint, reward_mint))
+    {
+        return Err(PaladinRewardsError::IncorrectTokenRewardsPoolAddress.into());
     }
 
     Ok(())
 }
 
-/// Processes a [DistributeRewards](enum.PaladinRewardsInstruction.html)
-/// instruction.
-fn process_distribute_rewards(
+fn check_token_holder_rewards(
     program_id: &Pubkey,
-    accounts: &[AccountInfo],
-    amount: u64,
+    token_account_key: &Pubkey,
+    token_rewards_pool_key: &Pubkey,
+    token_holder_rewards_info: &AccountInfo,
 ) -> ProgramResult {
-    let accounts_iter = &mut accounts.iter();
-
-    let payer_info = next_account_info(accounts_iter)?;
-    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
-    let mint_info = next_account_info(accounts_iter)?;
-    let _system_program_info = next_account_info(accounts_iter)?;
-
-    // Ensure the payer account is a signer.
-    if !payer_info.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
+    // Ensure the token holder rewards account is owned by the Paladin
+    // Rewards program.
+    if !token_holder_rewards_info.owner.eq(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
     }
 
-    let token_supply = get_token_supply(mint_info)?;
-
-    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    check_token_holder_rewards_address(
+        token_account_key,
+        token_rewards_pool_key,
+        token_holder_rewards_info,
+    )
+}
 
-    // Update the total rewards in the holder rewards pool.
+fn check_token_holder_rewards_address(
+    token_account_key: &Pubkey,
+    token_rewards_pool_key: &Pubkey,
+    token_holder_rewards_info: &AccountInfo,
+) -> ProgramResult {
+    // Ensure the provided token holder rewards address is the correct
+    // address derived from the token account and token rewards pool.
+    if !token_holder_rewards_info
+        .key
+        .eq(&get_token_holder_rewards_address(
+            token_account_key,
+            token_rewards_pool_key,
+        ))
     {
-        let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
-        let pool_state = bytemuck::try_from_bytes_mut::<HolderRewardsPool>(&mut pool_data)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
+        return Err(PaladinRewardsError::IncorrectHolderRewardsAddress.into());
+    }
 
-        // Calculate the new rewards per token by first calculating the rewards
-        // per token on the provided rewards amount, then adding that rate to
-        // the old rate.
-        let marginal_rate = calculate_rewards_per_token(amount, token_supply)?;
-        let new_accumulated_rewards_per_token = pool_state
-            .accumulated_rewards_per_token
-            .checked_add(marginal_rate)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
+    Ok(())
+}
 
-        pool_state.accumulated_rewards_per_token = new_accumulated_rewards_per_token;
+/// Checks a token holder rewards account provided to the transfer hook,
+/// returning whether it has been initialized, exactly as for holder rewards
+/// accounts.
+fn check_token_holder_rewards_for_transfer_hook(
+    program_id: &Pubkey,
+    token_account_key: &Pubkey,
+    token_rewards_pool_key: &Pubkey,
+    token_holder_rewards_info: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    if is_uninitialized(token_holder_rewards_info) {
+        check_token_holder_rewards_address(
+            token_account_key,
+            token_rewards_pool_key,
+            token_holder_rewards_info,
+        )?;
+        return Ok(false);
     }
 
-    // Move the amount from the payer to the holder rewards pool.
-    invoke(
-        &system_instruction::transfer(payer_info.key, holder_rewards_pool_info.key, amount),
-        &[payer_info.clone(), holder_rewards_pool_info.clone()],
+    check_token_holder_rewards(
+        program_id,
+        token_account_key,
+        token_rewards_pool_key,
+        token_holder_rewards_info,
     )?;
-
-    Ok(())
+    Ok(true)
 }
 
-/// Processes an
-/// [InitializeHolderRewards](enum.PaladinRewardsInstruction.html)
-/// instruction.
-fn process_initialize_holder_rewards(
-    program_id: &Pubkey,
-    accounts: &[AccountInfo],
+fn check_token_rewards_vault(
+    token_rewards_pool_key: &Pubkey,
+    token_rewards_pool_state: &TokenRewardsPool,
+    vault_info: &AccountInfo,
+    reward_token_program_info: &AccountInfo,
 ) -> ProgramResult {
-    let accounts_iter = &mut accounts.iter();
+    // Ensure the provided token program is the reward mint's token program.
+    if !token_rewards_pool_state
+        .reward_token_program
+        .eq(reward_token_program_info.key)
+    {
+        return Err(ProgramError::IncorrectProgramId);
+    }
 
-    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
-    let holder_rewards_info = next_account_info(accounts_iter)?;
+    // Ensure the provided vault is the token rewards pool's associated token
+    // account for the reward mint.
+    if !vault_info.key.eq(&get_token_rewards_vault_address(
+        token_rewards_pool_key,
+        &token_rewards_pool_state.reward_mint,
+        &token_rewards_pool_state.reward_token_program,
+    )) {
+        return Err(PaladinRewardsError::IncorrectTokenRewardsVaultAddress.into());
+    }
+
+    Ok(())
+}
+
+fn get_token_account_amount(token_account_info: &AccountInfo) -> Result<u64, ProgramError> {
+    let token_account_data = token_account_info.try_borrow_data()?;
+    let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+    Ok(token_account.base.amount)
+}
+
+fn get_token_account_owner(token_account_info: &AccountInfo) -> Result<Pubkey, ProgramError> {
+    let token_account_data = token_account_info.try_borrow_data()?;
+    let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+    Ok(token_account.base.owner)
+}
+
+fn get_mint_decimals(mint_info: &AccountInfo) -> Result<u8, ProgramError> {
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    Ok(mint.base.decimals)
+}
+
+/// Adds the provided rewards to an exchange rate, and returns the new rate.
+///
+/// The remainder truncated from the rewards per token calculation isn't
+/// lost. It's recorded alongside the rate and included in the next
+/// distribution to it.
+fn distribute_to_rate(
+    accumulated_rewards_per_token: &mut u128,
+    rewards_per_token_remainder: &mut u64,
+    rewards: u64,
+    token_supply: u64,
+) -> Result<u128, ProgramError> {
+    // Calculation: (rewards * 1e18 + remainder) / token_supply
+    let numerator = (rewards as u128)
+        .checked_mul(REWARDS_PER_TOKEN_SCALING_FACTOR)
+        .and_then(|product| product.checked_add(*rewards_per_token_remainder as u128))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let marginal_rate = numerator
+        .checked_div(token_supply as u128)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let remainder = numerator
+        .checked_rem(token_supply as u128)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    *accumulated_rewards_per_token = accumulated_rewards_per_token
+        .checked_add(marginal_rate)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // The remainder is always less than the token supply, so it fits.
+    *rewards_per_token_remainder =
+        u64::try_from(remainder).map_err(|_| ProgramError::ArithmeticOverflow)?;
+
+    Ok(*accumulated_rewards_per_token)
+}
+
+/// Adds the provided rewards to the pool's base rate, and returns the new
+/// rate.
+fn distribute_to_base_rate(
+    pool_state: &mut HolderRewardsPool,
+    rewards: u64,
+    token_supply: u64,
+) -> Result<u128, ProgramError> {
+    distribute_to_rate(
+        &mut pool_state.accumulated_rewards_per_token,
+        &mut pool_state.rewards_per_token_remainder,
+        rewards,
+        token_supply,
+    )
+}
+
+fn calculate_eligible_rewards(
+    current_accumulated_rewards_per_token: u128,
+    last_accumulated_rewards_per_token: u128,
+    token_account_balance: u64,
+) -> Result<u64, ProgramError> {
+    // Calculation: (current_accumulated_rewards_per_token
+    //   - last_accumulated_rewards_per_token) * token_account_balance
+    let marginal_rate = current_accumulated_rewards_per_token
+        .checked_sub(last_accumulated_rewards_per_token)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if marginal_rate == 0 {
+        return Ok(0);
+    }
+    // Descaled by `1e18`, rounding down.
+    //
+    // Whenever the rewards fit in a `u64`, the product is less than `1.9e37`
+    // and fits in a `u128`.
+    marginal_rate
+        .checked_mul(token_account_balance as u128)
+        .and_then(|product| product.checked_div(REWARDS_PER_TOKEN_SCALING_FACTOR))
+        .and_then(|rewards| rewards.try_into().ok())
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
+fn calculate_vested_amount(
+    emission_schedule: &EmissionSchedule,
+    timestamp: i64,
+) -> Result<u64, ProgramError> {
+    if timestamp <= emission_schedule.start_timestamp {
+        return Ok(0);
+    }
+    if timestamp >= emission_schedule.end_timestamp {
+        return Ok(emission_schedule.total_amount);
+    }
+    // Calculation: total_amount * (timestamp - start_timestamp)
+    //   / (end_timestamp - start_timestamp)
+    let elapsed = timestamp
+        .checked_sub(emission_schedule.start_timestamp)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let duration = emission_schedule
+        .end_timestamp
+        .checked_sub(emission_schedule.start_timestamp)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    (emission_schedule.total_amount as u128)
+        .checked_mul(elapsed as u128)
+        .and_then(|product| product.checked_div(duration as u128))
+        .and_then(|vested| u64::try_from(vested).ok())
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
+/// Releases any rewards vested by the pool's emission schedule since it was
+/// last advanced, adding them to the pool's rate.
+///
+/// While any holder is excluded, the pool's excluded supply may be stale, so
+/// rewards are only released by instructions that reconcile it first, with
+/// `advance_reconciled_emission_schedule`.
+fn advance_emission_schedule(
+    pool_state: &mut HolderRewardsPool,
+    mint_info: &AccountInfo,
+) -> ProgramResult {
+    if pool_state.excluded_holders != 0 {
+        return Ok(());
+    }
+
+    advance_reconciled_emission_schedule(pool_state, mint_info)
+}
+
+/// Releases any rewards vested by the pool's emission schedule exactly as for
+/// `advance_emission_schedule`, once the pool's excluded supply has been
+/// reconciled with `reconcile_excluded_supply`.
+fn advance_reconciled_emission_schedule(
+    pool_state: &mut HolderRewardsPool,
+    mint_info: &AccountInfo,
+) -> ProgramResult {
+    if pool_state.emission_schedule.unreleased_amount() == 0 {
+        return Ok(());
+    }
+
+    let token_supply = get_eligible_token_supply(get_token_supply(mint_info)?, pool_state)?;
+
+    // With no supply, there are no holders to release rewards to, so they
+    // remain in escrow until there are.
+    if token_supply == 0 {
+        return Ok(());
+    }
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let vested_amount = calculate_vested_amount(&pool_state.emission_schedule, timestamp)?;
+    let amount = vested_amount.saturating_sub(pool_state.emission_schedule.released_amount);
+    if amount == 0 {
+        return Ok(());
+    }
+
+    // Update the rate exactly as for `DistributeRewards`.
+    distribute_to_base_rate(pool_state, amount, token_supply)?;
+    pool_state.emission_schedule.released_amount = vested_amount;
+    pool_state.total_distributed = pool_state
+        .total_distributed
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Records a distribution of the provided amount to the pool's cumulative
+/// totals.
+fn record_distribution(pool_state: &mut HolderRewardsPool, amount: u64) -> ProgramResult {
+    pool_state.total_distributed = pool_state
+        .total_distributed
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.distribution_count = pool_state
+        .distribution_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.last_distribution_slot = Clock::get()?.slot;
+
+    Ok(())
+}
+
+/// Ensures the provided distributor is the pool's distribution authority,
+/// and signs, if the pool has one.
+fn check_distribution_authority(
+    pool_state: &HolderRewardsPool,
+    distributor_info: Option<&AccountInfo>,
+) -> ProgramResult {
+    if let Some(distribution_authority) = pool_state.distribution_authority() {
+        let distributor_info = distributor_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        // Ensure the distributor is the pool's distribution authority.
+        if !distribution_authority.eq(distributor_info.key) {
+            return Err(PaladinRewardsError::IncorrectDistributionAuthority.into());
+        }
+
+        // Ensure the distribution authority is a signer.
+        if !distributor_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks a distribution of the provided amount to the pool's base rate or
+/// one of its reward streams against the pool's distribution config.
+///
+/// If the pool has a distribution authority, it must be the provided
+/// distributor, and must sign.
+fn check_distribution(
+    pool_state: &HolderRewardsPool,
+    distributor_info: Option<&AccountInfo>,
+    amount: u64,
+) -> ProgramResult {
+    check_distribution_authority(pool_state, distributor_info)?;
+
+    // Ensure the amount meets the pool's minimum, so distributions can't be
+    // spammed with dust.
+    if amount < pool_state.min_distribution_amount {
+        return Err(PaladinRewardsError::DistributionAmountTooSmall.into());
+    }
+
+    Ok(())
+}
+
+/// Distributes the provided rewards to the pool's base rate, along with any
+/// rewards carried over from distributions made while the token supply was
+/// zero.
+///
+/// Returns the amount distributed and the new rate, or `None` if the
+/// eligible token supply is zero, in which case the rewards are carried
+/// over instead.
+fn distribute_with_carryover(
+    pool_state: &mut HolderRewardsPool,
+    amount: u64,
+    token_supply: u64,
+) -> Result<Option<(u64, u128)>, ProgramError> {
+    let token_supply = get_eligible_token_supply(token_supply, pool_state)?;
+
+    if token_supply == 0 {
+        // With no holders to distribute the rewards to, hold them until the
+        // next distribution made while the token supply is non-zero.
+        pool_state.undistributed_carryover = pool_state
+            .undistributed_carryover
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        return Ok(None);
+    }
+
+    // Include any rewards carried over from distributions made while the
+    // token supply was zero.
+    let distributed_amount = amount
+        .checked_add(pool_state.undistributed_carryover)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    pool_state.undistributed_carryover = 0;
+
+    // Calculate the new rewards per token by first calculating the rewards
+    // per token on the distributed amount, then adding that rate to the old
+    // rate.
+    let new_accumulated_rewards_per_token =
+        distribute_to_base_rate(pool_state, distributed_amount, token_supply)?;
+
+    record_distribution(pool_state, distributed_amount)?;
+
+    Ok(Some((
+        distributed_amount,
+        new_accumulated_rewards_per_token,
+    )))
+}
+
+/// Returns the token account balance a holder is eligible to earn rewards on
+/// since it was last settled.
+///
+/// If the observed balance doesn't match the balance the holder was last
+/// settled at, the token account balance was changed outside of the transfer
+/// hook (ie. a mint or burn) without the holder being synced. Since it's not
+/// known when that change occurred, the lesser of the two balances is used,
+/// so the holder can never be overpaid.
+///
+/// If the balance the holder was last settled at is unknown, the observed
+/// balance is used alone.
+fn get_eligible_balance(last_seen_balance: Option<u64>, observed_balance: u64) -> u64 {
+    last_seen_balance.map_or(observed_balance, |last_seen_balance| {
+        last_seen_balance.min(observed_balance)
+    })
+}
+
+/// Settles rewards against the provided rate, returning the fraction of a
+/// reward truncated from them, scaled by `1e18`.
+fn settle_rewards(
+    last_accumulated_rewards_per_token: &mut u128,
+    unharvested_rewards: &mut u64,
+    current_accumulated_rewards_per_token: u128,
+    token_account_balance: u64,
+) -> Result<u128, ProgramError> {
+    let eligible_rewards = calculate_eligible_rewards(
+        current_accumulated_rewards_per_token,
+        *last_accumulated_rewards_per_token,
+        token_account_balance,
+    )?;
+
+    // The rewards have already been calculated, so this can't overflow.
+    let truncated_rewards = current_accumulated_rewards_per_token
+        .wrapping_sub(*last_accumulated_rewards_per_token)
+        .wrapping_mul(token_account_balance as u128)
+        % REWARDS_PER_TOKEN_SCALING_FACTOR;
+
+    // Update the checkpoint.
+    *last_accumulated_rewards_per_token = current_accumulated_rewards_per_token;
+    *unharvested_rewards = unharvested_rewards
+        .checked_add(eligible_rewards)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(truncated_rewards)
+}
+
+/// Adds rewards truncated from a holder's settled rewards, scaled by `1e18`,
+/// to the pool's dust.
+fn record_dust(pool_state: &mut HolderRewardsPool, truncated_rewards: u128) -> ProgramResult {
+    let dust_remainder = truncated_rewards
+        .checked_add(pool_state.dust_remainder as u128)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let dust: u64 = (dust_remainder / REWARDS_PER_TOKEN_SCALING_FACTOR)
+        .try_into()
+        .map_err(|_| ProgramError::ArithmeticOverflow)?;
+
+    pool_state.dust = pool_state
+        .dust
+        .checked_add(dust)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Always less than `1e18`, so it fits in a `u64`.
+    pool_state.dust_remainder = (dust_remainder % REWARDS_PER_TOKEN_SCALING_FACTOR) as u64;
+
+    Ok(())
+}
+
+/// Settles a holder's rewards against the pool's base rate and each of the
+/// pool's reward streams.
+///
+/// Any rewards truncated from the holder's settled rewards are recorded as
+/// the pool's dust, since no holder can ever claim them.
+fn settle_holder_rewards(
+    holder_rewards_state: &mut HolderRewards,
+    pool_state: &mut HolderRewardsPool,
+    token_account_balance: u64,
+) -> ProgramResult {
+    // Excluded holders still have their checkpoints advanced, but earn
+    // nothing.
+    let token_account_balance = if holder_rewards_state.is_excluded() {
+        0
+    } else {
+        token_account_balance
+    };
+
+    let mut truncated_rewards = settle_rewards(
+        &mut holder_rewards_state.last_accumulated_rewards_per_token,
+        &mut holder_rewards_state.unharvested_rewards,
+        pool_state.accumulated_rewards_per_token,
+        token_account_balance,
+    )?;
+
+    for (checkpoint, stream) in holder_rewards_state
+        .reward_stream_checkpoints
+        .iter_mut()
+        .zip(pool_state.reward_streams.iter())
+    {
+        truncated_rewards = settle_rewards(
+            &mut checkpoint.last_accumulated_rewards_per_token,
+            &mut checkpoint.unharvested_rewards,
+            stream.accumulated_rewards_per_token,
+            token_account_balance,
+        )?
+        .checked_add(truncated_rewards)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    record_dust(pool_state, truncated_rewards)
+}
+
+/// Records the token account balance a holder will earn rewards on going
+/// forward, keeping the pool's excluded supply in step if the holder is
+/// excluded.
+fn set_last_seen_balance(
+    holder_rewards_state: &mut HolderRewards,
+    pool_state: &mut HolderRewardsPool,
+    token_account_balance: u64,
+) -> ProgramResult {
+    if holder_rewards_state.is_excluded() {
+        pool_state.excluded_supply = pool_state
+            .excluded_supply
+            .checked_sub(holder_rewards_state.last_seen_balance)
+            .and_then(|supply| supply.checked_add(token_account_balance))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+    holder_rewards_state.last_seen_balance = token_account_balance;
+    holder_rewards_state.last_seen_balance_unknown = 0;
+
+    Ok(())
+}
+
+/// Records whether a holder is excluded from earning rewards, keeping the
+/// pool's count of excluded holders in step.
+///
+/// The holder's last seen balance must be zero, so the pool's excluded
+/// supply is unaffected.
+fn set_holder_excluded(
+    holder_rewards_state: &mut HolderRewards,
+    pool_state: &mut HolderRewardsPool,
+    excluded: bool,
+) -> ProgramResult {
+    if holder_rewards_state.is_excluded() != excluded {
+        pool_state.excluded_holders = if excluded {
+            pool_state.excluded_holders.checked_add(1)
+        } else {
+            pool_state.excluded_holders.checked_sub(1)
+        }
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+    holder_rewards_state.excluded = excluded.into();
+
+    Ok(())
+}
+
+/// Records the current balance of every holder excluded from the pool, so
+/// the pool's excluded supply is exact.
+///
+/// Token-2022 does not invoke the transfer hook on `MintTo` or `Burn`, so an
+/// excluded token account's balance may have changed since it was last
+/// settled. After a burn, the stale excluded supply would undercount the
+/// supply rewards are distributed over, and holders would be owed more than
+/// was distributed.
+///
+/// A holder rewards account and token account are taken from the provided
+/// accounts for each of the pool's excluded holders, in order of holder
+/// rewards address, so none can be repeated or left out.
+fn reconcile_excluded_supply(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    pool_state: &mut HolderRewardsPool,
+    accounts_iter: &mut std::slice::Iter<AccountInfo>,
+) -> ProgramResult {
+    let mut last_holder_rewards_key = None;
+
+    for _ in 0..pool_state.excluded_holders {
+        let holder_rewards_info = next_account_info(accounts_iter)?;
+        let token_account_info = next_account_info(accounts_iter)?;
+
+        // Ensure the holders are provided in order, so none is repeated.
+        if last_holder_rewards_key.is_some_and(|key| holder_rewards_info.key <= key) {
+            return Err(PaladinRewardsError::ExcludedHoldersOutOfOrder.into());
+        }
+        last_holder_rewards_key = Some(holder_rewards_info.key);
+
+        // A closed token account no longer holds any tokens.
+        let token_account_closed = token_account_info.owner.eq(&system_program::id())
+            && token_account_info.data_is_empty();
+        let token_account_balance = if token_account_closed {
+            0
+        } else {
+            get_token_account_balance_checked(mint, token_account_info, false)?
+        };
+
+        check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+        let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+        let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+        check_holder_rewards_mint(holder_rewards_state, mint)?;
+
+        // Ensure the holder is excluded. With the count of excluded holders,
+        // this ensures every one of them is provided.
+        if !holder_rewards_state.is_excluded() {
+            return Err(PaladinRewardsError::HolderRewardsNotExcluded.into());
+        }
+
+        // Excluded holders earn nothing, so there's nothing to settle before
+        // their balance is updated.
+        set_last_seen_balance(holder_rewards_state, pool_state, token_account_balance)?;
+    }
+
+    Ok(())
+}
+
+/// Settles a token holder's rewards against the token rewards pool's rate.
+///
+/// Token accounts excluded from the holder rewards pool are excluded from
+/// every token rewards pool too.
+fn settle_token_holder_rewards(
+    token_holder_rewards_state: &mut TokenHolderRewards,
+    current_accumulated_rewards_per_token: u128,
+    token_account_balance: u64,
+    excluded: bool,
+) -> ProgramResult {
+    // Excluded holders still have their checkpoints advanced, but earn
+    // nothing.
+    let token_account_balance = if excluded { 0 } else { token_account_balance };
+
+    settle_rewards(
+        &mut token_holder_rewards_state.last_accumulated_rewards_per_token,
+        &mut token_holder_rewards_state.unharvested_rewards,
+        current_accumulated_rewards_per_token,
+        token_account_balance,
+    )?;
+
+    Ok(())
+}
+
+/// Returns the pool's lamports above its rent-exempt minimum, less any
+/// rewards escrowed by its emission schedule or carried over from
+/// distributions made while the token supply was zero.
+fn get_pool_excess_lamports(
+    holder_rewards_pool_info: &AccountInfo,
+    pool_data_len: usize,
+    pool_state: &HolderRewardsPool,
+) -> Result<u64, ProgramError> {
+    let rent = <Rent as Sysvar>::get()?;
+    let rent_exempt_lamports = rent.minimum_balance(pool_data_len);
+    Ok(holder_rewards_pool_info
+        .lamports()
+        .saturating_sub(rent_exempt_lamports)
+        .saturating_sub(pool_state.emission_schedule.unreleased_amount())
+        .saturating_sub(pool_state.undistributed_carryover))
+}
+
+/// Moves a holder's unharvested rewards from the holder rewards pool to the
+/// provided destination account, deducting them from the holder's
+/// unharvested rewards, and returns the amount moved. The amount is added to
+/// the pool's total harvested rewards.
+///
+/// Rewards still escrowed by the pool's emission schedule, or carried over
+/// from distributions made while the token supply was zero, are never paid
+/// out.
+///
+/// The pool's data length is provided by the caller, since the pool's data is
+/// borrowed for `pool_state`.
+fn pay_out_rewards(
+    holder_rewards_pool_info: &AccountInfo,
+    pool_data_len: usize,
+    pool_state: &mut HolderRewardsPool,
+    unharvested_rewards: &mut u64,
+    destination_info: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    // If the pool doesn't have enough lamports to cover the rewards, only
+    // harvest the available lamports. This should never happen, but the check
+    // is a failsafe.
+    let rewards_to_harvest = {
+        let pool_excess_lamports =
+            get_pool_excess_lamports(holder_rewards_pool_info, pool_data_len, pool_state)?;
+
+        (*unharvested_rewards).min(pool_excess_lamports)
+    };
+
+    if rewards_to_harvest != 0 {
+        // Move the amount from the holder rewards pool to the destination.
+        let new_holder_rewards_pool_lamports = holder_rewards_pool_info
+            .lamports()
+            .checked_sub(rewards_to_harvest)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let new_destination_lamports = destination_info
+            .lamports()
+            .checked_add(rewards_to_harvest)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **holder_rewards_pool_info.try_borrow_mut_lamports()? = new_holder_rewards_pool_lamports;
+        **destination_info.try_borrow_mut_lamports()? = new_destination_lamports;
+
+        // Update the holder's unharvested rewards.
+        *unharvested_rewards = unharvested_rewards
+            .checked_sub(rewards_to_harvest)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        pool_state.total_harvested = pool_state
+            .total_harvested
+            .checked_add(rewards_to_harvest)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    Ok(rewards_to_harvest)
+}
+
+/// Moves all of a holder's unharvested rewards, from the pool's base rate and
+/// each of its reward streams, from the holder rewards pool to the provided
+/// destination account.
+fn pay_out_all_rewards(
+    holder_rewards_pool_info: &AccountInfo,
+    pool_data_len: usize,
+    pool_state: &mut HolderRewardsPool,
+    holder_rewards_info: &AccountInfo,
+    holder_rewards_state: &mut HolderRewards,
+    destination_info: &AccountInfo,
+) -> ProgramResult {
+    let harvested_rewards = pay_out_rewards(
+        holder_rewards_pool_info,
+        pool_data_len,
+        pool_state,
+        &mut holder_rewards_state.unharvested_rewards,
+        destination_info,
+    )?;
+    PaladinRewardsEvent::RewardsHarvested {
+        holder_rewards: *holder_rewards_info.key,
+        reward_id: 0,
+        destination: *destination_info.key,
+        amount: harvested_rewards,
+        unharvested_rewards: holder_rewards_state.unharvested_rewards,
+    }
+    .emit()?;
+
+    let reward_streams = pool_state.reward_streams;
+    for (checkpoint, stream) in holder_rewards_state
+        .reward_stream_checkpoints
+        .iter_mut()
+        .zip(reward_streams.iter())
+        .filter(|(_, stream)| stream.reward_id != 0)
+    {
+        let harvested_rewards = pay_out_rewards(
+            holder_rewards_pool_info,
+            pool_data_len,
+            pool_state,
+            &mut checkpoint.unharvested_rewards,
+            destination_info,
+        )?;
+        PaladinRewardsEvent::RewardsHarvested {
+            holder_rewards: *holder_rewards_info.key,
+            reward_id: stream.reward_id,
+            destination: *destination_info.key,
+            amount: harvested_rewards,
+            unharvested_rewards: checkpoint.unharvested_rewards,
+        }
+        .emit()?;
+    }
+
+    Ok(())
+}
+
+/// Settles a holder's rewards against the holder rewards pool, then moves
+/// its unharvested rewards from the pool to the token account, or to the
+/// holder's rewards recipient, if one is set. The recipient is read from
+/// `accounts_iter`.
+#[allow(clippy::too_many_arguments)]
+fn harvest_holder_rewards<'a, 'b>(
+    program_id: &Pubkey,
+    holder_rewards_pool_info: &AccountInfo<'b>,
+    pool_data_len: usize,
+    pool_state: &mut HolderRewardsPool,
+    holder_rewards_info: &AccountInfo<'b>,
+    token_account_info: &'a AccountInfo<'b>,
+    mint: &Pubkey,
+    token_account_balance: u64,
+    accounts_iter: &mut std::slice::Iter<'a, AccountInfo<'b>>,
+) -> ProgramResult {
+    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+    check_holder_rewards_mint(holder_rewards_state, mint)?;
+
+    // Determine the amount the holder can harvest.
+    //
+    // This is done by subtracting the `last_accumulated_rewards_per_token`
+    // rate from the pool's current rate, then multiplying by the balance the
+    // holder was last settled at (or the current token account balance, if
+    // lower).
+    //
+    // The holder should also be able to harvest any unharvested rewards.
+    //
+    // Temporarily update `unharvested_rewards` with the eligible rewards.
+    let eligible_balance = get_eligible_balance(
+        holder_rewards_state.last_seen_balance(),
+        token_account_balance,
+    );
+    settle_holder_rewards(holder_rewards_state, pool_state, eligible_balance)?;
+    set_last_seen_balance(holder_rewards_state, pool_state, token_account_balance)?;
+
+    // Move the unharvested rewards from the holder rewards pool to the token
+    // account, or to the holder's rewards recipient, if one is set.
+    let destination_info = next_rewards_destination_info(
+        program_id,
+        holder_rewards_state,
+        token_account_info,
+        accounts_iter,
+    )?;
+    let harvested_rewards = pay_out_rewards(
+        holder_rewards_pool_info,
+        pool_data_len,
+        pool_state,
+        &mut holder_rewards_state.unharvested_rewards,
+        destination_info,
+    )?;
+
+    PaladinRewardsEvent::RewardsHarvested {
+        holder_rewards: *holder_rewards_info.key,
+        reward_id: 0,
+        destination: *destination_info.key,
+        amount: harvested_rewards,
+        unharvested_rewards: holder_rewards_state.unharvested_rewards,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+fn update_holder_rewards_for_transfer_hook(
+    holder_rewards_info: &AccountInfo,
+    pool_state: &mut HolderRewardsPool,
+    balance_before_transfer: u64,
+    balance_after_transfer: u64,
+) -> ProgramResult {
+    // Calculate the token account's updated share of the pool rewards.
+    //
+    // Since the holder rewards account may already have unharvested
+    // rewards, calculate the share of rewards that have not been seen
+    // by the holder rewards account.
+    //
+    // Then, adjust the unharvested rewards with the additional share.
+    //
+    // Every transfer of the mint settles both holders, so a holder rewards
+    // account that has yet to be migrated is settled in the original layout,
+    // rather than failing the transfer. Any fields appended since read as
+    // zero, and are dropped when it's written back.
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let (holder_rewards_version, mut holder_rewards_state) =
+        unpack_versioned_prefix::<HolderRewards>(&holder_rewards_data)?;
+
+    // The original layout didn't record the balance the holder was last
+    // settled at, so it's always treated as unknown. It also scaled rate
+    // checkpoints by `1e9`, so these holders are settled against the pool's
+    // rates truncated to that precision, and their checkpoints can be
+    // written back exactly.
+    let legacy = holder_rewards_version == 0;
+    if legacy {
+        holder_rewards_state.last_seen_balance_unknown = 1;
+        for_each_rate_checkpoint(&mut holder_rewards_state, rescale_legacy_rewards_per_token)?;
+    }
+
+    // Reconcile the balance before the transfer with the balance the holder
+    // was last settled at.
+    let token_account_balance = get_eligible_balance(
+        holder_rewards_state.last_seen_balance(),
+        balance_before_transfer,
+    );
+
+    let unharvested_rewards_before_settling = holder_rewards_state.unharvested_rewards;
+    if legacy {
+        let mut truncated_pool_state = *pool_state;
+        truncate_to_legacy_precision(&mut truncated_pool_state.accumulated_rewards_per_token)?;
+        for stream in truncated_pool_state.reward_streams.iter_mut() {
+            truncate_to_legacy_precision(&mut stream.accumulated_rewards_per_token)?;
+        }
+        settle_holder_rewards(
+            &mut holder_rewards_state,
+            &mut truncated_pool_state,
+            token_account_balance,
+        )?;
+
+        // Only the dust is kept from the truncated rates.
+        pool_state.dust = truncated_pool_state.dust;
+        pool_state.dust_remainder = truncated_pool_state.dust_remainder;
+    } else {
+        settle_holder_rewards(&mut holder_rewards_state, pool_state, token_account_balance)?;
+    }
+
+    // Record the balance _after_ the transfer, since that's the balance the
+    // holder will earn rewards on going forward.
+    set_last_seen_balance(
+        &mut holder_rewards_state,
+        pool_state,
+        balance_after_transfer,
+    )?;
+
+    PaladinRewardsEvent::HolderRewardsSettled {
+        holder_rewards: *holder_rewards_info.key,
+        accrued_rewards: holder_rewards_state
+            .unharvested_rewards
+            .saturating_sub(unharvested_rewards_before_settling),
+        unharvested_rewards: holder_rewards_state.unharvested_rewards,
+        last_seen_balance: holder_rewards_state.last_seen_balance,
+    }
+    .emit()?;
+
+    if legacy {
+        for_each_rate_checkpoint(&mut holder_rewards_state, descale_legacy_rewards_per_token)?;
+    }
+    pack_versioned_prefix(&mut holder_rewards_data, &holder_rewards_state)
+}
+
+/// Pays out a holder's rewards to its auto-claim escrow account, if the
+/// holder has enabled auto-claim and its unharvested rewards have reached
+/// its threshold.
+///
+/// Token-2022 passes the source and destination token accounts to the hook
+/// as read-only, so they can't be credited, and a holder's rewards recipient
+/// can't be resolved as an extra account, since it's stored as data rather
+/// than derived from seeds. The rewards are paid to the escrow instead, which
+/// is derived from the token account.
+fn auto_claim_holder_rewards(
+    program_id: &Pubkey,
+    holder_rewards_pool_info: &AccountInfo,
+    pool_data_len: usize,
+    pool_state: &mut HolderRewardsPool,
+    holder_rewards_info: &AccountInfo,
+    token_account_key: &Pubkey,
+    escrow_info: &AccountInfo,
+) -> ProgramResult {
+    // Holders in the original layout can't have enabled auto-claim.
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let (_, mut holder_rewards_state) =
+        unpack_versioned_prefix::<HolderRewards>(&holder_rewards_data)?;
+
+    let Some(auto_claim_threshold) = holder_rewards_state.auto_claim_threshold() else {
+        return Ok(());
+    };
+
+    // Only pay out once the holder's rewards, across the pool's base rate and
+    // all reward streams, reach the threshold, so each transfer doesn't pay
+    // out dust.
+    let unharvested_rewards = holder_rewards_state
+        .reward_stream_checkpoints
+        .iter()
+        .try_fold(
+            holder_rewards_state.unharvested_rewards,
+            |total, checkpoint| total.checked_add(checkpoint.unharvested_rewards),
+        )
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if unharvested_rewards < auto_claim_threshold {
+        return Ok(());
+    }
+
+    check_holder_rewards_escrow(program_id, token_account_key, escrow_info)?;
+
+    pay_out_all_rewards(
+        holder_rewards_pool_info,
+        pool_data_len,
+        pool_state,
+        holder_rewards_info,
+        &mut holder_rewards_state,
+        escrow_info,
+    )?;
+
+    pack_versioned_prefix(&mut holder_rewards_data, &holder_rewards_state)
+}
+
+fn update_token_holder_rewards_for_transfer_hook(
+    token_holder_rewards_info: &AccountInfo,
+    current_accumulated_rewards_per_token: u128,
+    balance_before_transfer: u64,
+    balance_after_transfer: u64,
+    excluded: bool,
+) -> ProgramResult {
+    let mut token_holder_rewards_data = token_holder_rewards_info.try_borrow_mut_data()?;
+    let token_holder_rewards_state =
+        bytemuck::try_from_bytes_mut::<TokenHolderRewards>(&mut token_holder_rewards_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Settle exactly as for the holder rewards account.
+    let token_account_balance = get_eligible_balance(
+        Some(token_holder_rewards_state.last_seen_balance),
+        balance_before_transfer,
+    );
+
+    let unharvested_rewards_before_settling = token_holder_rewards_state.unharvested_rewards;
+    settle_token_holder_rewards(
+        token_holder_rewards_state,
+        current_accumulated_rewards_per_token,
+        token_account_balance,
+        excluded,
+    )?;
+
+    token_holder_rewards_state.last_seen_balance = balance_after_transfer;
+
+    PaladinRewardsEvent::TokenHolderRewardsSettled {
+        token_holder_rewards: *token_holder_rewards_info.key,
+        accrued_rewards: token_holder_rewards_state
+            .unharvested_rewards
+            .saturating_sub(unharvested_rewards_before_settling),
+        unharvested_rewards: token_holder_rewards_state.unharvested_rewards,
+        last_seen_balance: token_holder_rewards_state.last_seen_balance,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Tops up the provided account to the rent-exempt minimum for the given
+/// data length, funded by the payer, if a payer was provided.
+fn fund_rent_exemption<'a>(
+    payer_info: Option<&AccountInfo<'a>>,
+    account_info: &AccountInfo<'a>,
+    data_len: usize,
+) -> ProgramResult {
+    let Some(payer_info) = payer_info else {
+        return Ok(());
+    };
+
+    // Ensure the payer is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let rent = <Rent as Sysvar>::get()?;
+    let required_lamports = rent
+        .minimum_balance(data_len)
+        .saturating_sub(account_info.lamports());
+
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, account_info.key, required_lamports),
+            &[payer_info.clone(), account_info.clone()],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Creates and initializes the holder rewards account for a token account,
+/// checkpointed at the pool's current rates and the token account's balance.
+fn initialize_holder_rewards_account<'a>(
+    program_id: &Pubkey,
+    pool_state: &HolderRewardsPool,
+    holder_rewards_info: &AccountInfo<'a>,
+    token_account_info: &AccountInfo<'a>,
+    mint: &Pubkey,
+    token_account_balance: u64,
+    payer_info: Option<&AccountInfo<'a>>,
+) -> ProgramResult {
+    let (holder_rewards_address, bump_seed) =
+        get_holder_rewards_address_and_bump_seed(token_account_info.key);
+    let bump_seed = [bump_seed];
+    let holder_rewards_signer_seeds =
+        collect_holder_rewards_signer_seeds(token_account_info.key, &bump_seed);
+
+    // Ensure the provided holder rewards address is the correct address
+    // derived from the token account.
+    if !holder_rewards_info.key.eq(&holder_rewards_address) {
+        return Err(PaladinRewardsError::IncorrectHolderRewardsAddress.into());
+    }
+
+    // Ensure the holder rewards account has not already been initialized.
+    if holder_rewards_info.data.borrow().len() != 0 {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    fund_rent_exemption(payer_info, holder_rewards_info, HolderRewards::LEN)?;
+
+    // Allocate & assign.
+    invoke_signed(
+        &system_instruction::allocate(&holder_rewards_address, HolderRewards::LEN as u64),
+        &[holder_rewards_info.clone()],
+        &[&holder_rewards_signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(&holder_rewards_address, program_id),
+        &[holder_rewards_info.clone()],
+        &[&holder_rewards_signer_seeds],
+    )?;
+
+    // Write the data, checkpointing each reward stream at its current
+    // rate.
+    let mut holder_rewards_state = HolderRewards::new(
+        pool_state.accumulated_rewards_per_token,
+        0,
+        token_account_balance,
+    );
+    holder_rewards_state.mint = *mint;
+    holder_rewards_state.owner = get_token_account_owner(token_account_info)?;
+    for (checkpoint, stream) in holder_rewards_state
+        .reward_stream_checkpoints
+        .iter_mut()
+        .zip(pool_state.reward_streams.iter())
+    {
+        checkpoint.last_accumulated_rewards_per_token = stream.accumulated_rewards_per_token;
+    }
+
+    let mut data = holder_rewards_info.try_borrow_mut_data()?;
+    pack_versioned(&mut data, &holder_rewards_state)?;
+
+    PaladinRewardsEvent::HolderRewardsInitialized {
+        holder_rewards: *holder_rewards_info.key,
+        token_account: *token_account_info.key,
+        last_accumulated_rewards_per_token: pool_state.accumulated_rewards_per_token,
+        last_seen_balance: token_account_balance,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeHolderRewardsPool](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_holder_rewards_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let extra_metas_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let mint_authority_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter).ok();
+
+    // Run checks on the mint.
+    {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+
+        // Ensure the mint is configured with the `TransferHook` extension,
+        // and the program ID is the Paladin Rewards program.
+        let transfer_hook = mint.get_extension::<TransferHook>()?;
+        let hook_program_id: Option<Pubkey> = transfer_hook.program_id.into();
+        if !hook_program_id.eq(&Some(*program_id)) {
+            return Err(PaladinRewardsError::IncorrectTransferHookProgramId.into());
+        }
+
+        // Ensure the provided mint authority is the correct mint authority.
+        if !mint
+            .base
+            .mint_authority
+            .eq(&COption::Some(*mint_authority_info.key))
+        {
+            return Err(PaladinRewardsError::IncorrectMintAuthority.into());
+        }
+
+        // Ensure the mint authority is a signer.
+        if !mint_authority_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+    }
+
+    // Initialize the holder rewards pool account.
+    {
+        let (holder_rewards_pool_address, bump_seed) =
+            get_holder_rewards_pool_address_and_bump_seed(mint_info.key);
+        let bump_seed = [bump_seed];
+        let holder_rewards_pool_signer_seeds =
+            collect_holder_rewards_pool_signer_seeds(mint_info.key, &bump_seed);
+
+        // Ensure the provided holder rewards pool address is the correct
+        // address derived from the mint.
+        if !holder_rewards_pool_info
+            .key
+            .eq(&holder_rewards_pool_address)
+        {
+            return Err(PaladinRewardsError::IncorrectHolderRewardsPoolAddress.into());
+        }
+
+        // Ensure the holder rewards pool account has not already been
+        // initialized.
+        if holder_rewards_pool_info.data_len() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        fund_rent_exemption(payer_info, holder_rewards_pool_info, HolderRewardsPool::LEN)?;
+
+        // Allocate & assign.
+        invoke_signed(
+            &system_instruction::allocate(
+                &holder_rewards_pool_address,
+                HolderRewardsPool::LEN as u64,
+            ),
+            &[holder_rewards_pool_info.clone()],
+            &[&holder_rewards_pool_signer_seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(&holder_rewards_pool_address, program_id),
+            &[holder_rewards_pool_info.clone()],
+            &[&holder_rewards_pool_signer_seeds],
+        )?;
+
+        // Write the data.
+        let mut data = holder_rewards_pool_info.try_borrow_mut_data()?;
+        pack_versioned(
+            &mut data,
+            &HolderRewardsPool::new(0, *mint_authority_info.key),
+        )?;
+    }
+
+    // Initialize the extra metas account.
+    {
+        let (extra_metas_address, extra_metas_bump) =
+            get_extra_account_metas_address_and_bump_seed(mint_info.key, program_id);
+        let extra_metas_bump = [extra_metas_bump];
+        let extra_metas_signer_seeds =
+            collect_extra_account_metas_signer_seeds(mint_info.key, &extra_metas_bump);
+
+        // Ensure the provided extra metas address is the correct address
+        // derived from the mint.
+        if !extra_metas_info.key.eq(&extra_metas_address) {
+            return Err(PaladinRewardsError::IncorrectExtraMetasAddress.into());
+        }
+
+        // Ensure the extra metas account has not already been initialized.
+        if extra_metas_info.data_len() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        let extra_metas = [
+            get_extra_account_metas().as_slice(),
+            get_escrow_extra_account_metas().as_slice(),
+        ]
+        .concat();
+        let account_size = ExtraAccountMetaList::size_of(extra_metas.len())?;
+
+        fund_rent_exemption(payer_info, extra_metas_info, account_size)?;
+
+        // Allocate & assign.
+        invoke_signed(
+            &system_instruction::allocate(&extra_metas_address, account_size as u64),
+            &[extra_metas_info.clone()],
+            &[&extra_metas_signer_seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(&extra_metas_address, program_id),
+            &[extra_metas_info.clone()],
+            &[&extra_metas_signer_seeds],
+        )?;
+
+        // Write the data.
+        let mut data = extra_metas_info.try_borrow_mut_data()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_metas)?;
+    }
+
+    Ok(())
+}
+
+/// Processes a [DistributeRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_distribute_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the payer account is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let token_supply = get_token_supply(mint_info)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+
+    // Update the total rewards in the holder rewards pool.
+    let distribution = {
+        let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+        let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+        check_distribution(pool_state, Some(payer_info), amount)?;
+
+        reconcile_excluded_supply(program_id, mint_info.key, pool_state, accounts_iter)?;
+
+        // Release any rewards vested by the emission schedule before adding
+        // the provided rewards.
+        advance_reconciled_emission_schedule(pool_state, mint_info)?;
+
+        distribute_with_carryover(pool_state, amount, token_supply)?
+    };
+
+    // Move the amount from the payer to the holder rewards pool.
+    invoke(
+        &system_instruction::transfer(payer_info.key, holder_rewards_pool_info.key, amount),
+        &[payer_info.clone(), holder_rewards_pool_info.clone()],
+    )?;
+
+    if let Some((distributed_amount, new_accumulated_rewards_per_token)) = distribution {
+        PaladinRewardsEvent::RewardsDistributed {
+            mint: *mint_info.key,
+            reward_id: 0,
+            amount: distributed_amount,
+            accumulated_rewards_per_token: new_accumulated_rewards_per_token,
+        }
+        .emit()?;
+    }
+
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_holder_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter).ok();
+
+    // Run checks on the token account.
+    let token_account_balance =
+        get_token_account_balance_checked(mint_info.key, token_account_info, false)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    // Release any rewards vested by the emission schedule, so the new holder
+    // doesn't earn rewards that vested before it was checkpointed.
+    advance_emission_schedule(pool_state, mint_info)?;
+
+    initialize_holder_rewards_account(
+        program_id,
+        pool_state,
+        holder_rewards_info,
+        token_account_info,
+        mint_info.key,
+        token_account_balance,
+        payer_info,
+    )
+}
+
+/// Processes a [HarvestRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_harvest_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+
+    // Run checks on the token account.
+    let token_account_balance =
+        get_token_account_balance_checked(mint_info.key, token_account_info, false)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_data_len = pool_data.len();
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    // Release any rewards vested by the emission schedule.
+    advance_emission_schedule(pool_state, mint_info)?;
+
+    harvest_holder_rewards(
+        program_id,
+        holder_rewards_pool_info,
+        pool_data_len,
+        pool_state,
+        holder_rewards_info,
+        token_account_info,
+        mint_info.key,
+        token_account_balance,
+        accounts_iter,
+    )
+}
+
+/// Processes a
+/// [HarvestRewardsToDestination](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_harvest_rewards_to_destination(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let authority_info = next_account_info(accounts_iter)?;
+    let destination_info = next_account_info(accounts_iter)?;
+
+    // Run checks on the token account.
+    let token_account_balance = {
+        let token_account_data = token_account_info.try_borrow_data()?;
+        let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+
+        // Ensure the provided token account is for the mint.
+        if !token_account.base.mint.eq(mint_info.key) {
+            return Err(PaladinRewardsError::TokenAccountMintMismatch.into());
+        }
+
+        // Ensure the provided authority is the token account owner or
+        // delegate.
+        if !token_account.base.owner.eq(authority_info.key)
+            && !token_account
+                .base
+                .delegate
+                .eq(&COption::Some(*authority_info.key))
+        {
+            return Err(PaladinRewardsError::TokenAccountOwnerMismatch.into());
+        }
+
+        token_account.base.amount
+    };
+
+    // Ensure the authority is a signer.
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_data_len = pool_data.len();
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    // Release any rewards vested by the emission schedule.
+    advance_emission_schedule(pool_state, mint_info)?;
+
+    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+    check_holder_rewards_mint(holder_rewards_state, mint_info.key)?;
+
+    check_rewards_destination(program_id, holder_rewards_state, destination_info)?;
+
+    // Determine the amount the holder can harvest, exactly as for
+    // `HarvestRewards`.
+    let eligible_balance = get_eligible_balance(
+        holder_rewards_state.last_seen_balance(),
+        token_account_balance,
+    );
+    settle_holder_rewards(holder_rewards_state, pool_state, eligible_balance)?;
+    set_last_seen_balance(holder_rewards_state, pool_state, token_account_balance)?;
+
+    // Move the unharvested rewards from the holder rewards pool to the
+    // destination.
+    let harvested_rewards = pay_out_rewards(
+        holder_rewards_pool_info,
+        pool_data_len,
+        pool_state,
+        &mut holder_rewards_state.unharvested_rewards,
+        destination_info,
+    )?;
+
+    PaladinRewardsEvent::RewardsHarvested {
+        holder_rewards: *holder_rewards_info.key,
+        reward_id: 0,
+        destination: *destination_info.key,
+        amount: harvested_rewards,
+        unharvested_rewards: holder_rewards_state.unharvested_rewards,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Processes a [SyncHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_sync_holder_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+
+    // Run checks on the token account.
+    let token_account_balance =
+        get_token_account_balance_checked(mint_info.key, token_account_info, false)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    // Release any rewards vested by the emission schedule.
+    advance_emission_schedule(pool_state, mint_info)?;
+
+    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+    check_holder_rewards_mint(holder_rewards_state, mint_info.key)?;
+
+    // Settle any rewards accrued since the last checkpoint, exactly as for
+    // `HarvestRewards`. The token account balance may have been changed by a
+    // mint or burn, which does not invoke the transfer hook, and it's not
+    // known whether that happened before or after the rewards accrued.
+    let eligible_balance = get_eligible_balance(
+        holder_rewards_state.last_seen_balance(),
+        token_account_balance,
+    );
+    settle_holder_rewards(holder_rewards_state, pool_state, eligible_balance)?;
+
+    // Record the current balance, which the holder will earn rewards on
+    // going forward.
+    set_last_seen_balance(holder_rewards_state, pool_state, token_account_balance)?;
+
+    // Record the current owner, which may have been changed by
+    // `SetAuthority`, which does not invoke the transfer hook either.
+    holder_rewards_state.owner = get_token_account_owner(token_account_info)?;
+
+    Ok(())
+}
+
+/// Processes a [SetPoolAuthority](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_set_pool_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_authority: Pubkey,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let pool_authority_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    check_pool_authority(pool_state, pool_authority_info)?;
+
+    // Nominate the new authority. The current authority remains in place
+    // until the nominated authority accepts.
+    pool_state.pending_authority = new_authority;
+
+    Ok(())
+}
+
+/// Processes an [AcceptPoolAuthority](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_accept_pool_authority(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let pending_pool_authority_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    // Ensure the provided authority is the pending pool authority.
+    if pool_state.pending_authority.eq(&Pubkey::default())
+        || !pool_state
+            .pending_authority
+            .eq(pending_pool_authority_info.key)
+    {
+        return Err(PaladinRewardsError::IncorrectPendingPoolAuthority.into());
+    }
+
+    // Ensure the pending pool authority is a signer.
+    if !pending_pool_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    pool_state.authority = pool_state.pending_authority;
+    pool_state.pending_authority = Pubkey::default();
+
+    Ok(())
+}
+
+/// Processes a [CloseHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_close_holder_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner_info = next_account_info(accounts_iter)?;
+    let destination_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+
+    // Ensure the rent lamports are not being returned to the account being
+    // closed.
+    if holder_rewards_info.key.eq(destination_info.key) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // If the token account has been closed, its owner can no longer sign, so
+    // anyone can close the holder rewards account.
+    let token_account_closed =
+        token_account_info.owner.eq(&system_program::id()) && token_account_info.data_is_empty();
+
+    if !token_account_closed {
+        let token_account_balance = {
+            let token_account_data = token_account_info.try_borrow_data()?;
+            let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+
+            // Ensure the provided token account is for the mint.
+            if !token_account.base.mint.eq(mint_info.key) {
+                return Err(PaladinRewardsError::TokenAccountMintMismatch.into());
+            }
+
+            // Ensure the provided owner is the token account owner.
+            if !token_account.base.owner.eq(owner_info.key) {
+                return Err(PaladinRewardsError::TokenAccountOwnerMismatch.into());
+            }
+
+            token_account.base.amount
+        };
+
+        // Ensure the token account owner is a signer.
+        if !owner_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Ensure the token account holds no tokens. Otherwise, the holder
+        // would stop earning rewards, and the transfer hook would fail for
+        // any transfers in or out of the token account.
+        if token_account_balance != 0 {
+            return Err(PaladinRewardsError::TokenAccountNotEmpty.into());
+        }
+
+        let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+
+        let pool_data_len = pool_data.len();
+        let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+        // Release any rewards vested by the emission schedule.
+        advance_emission_schedule(pool_state, mint_info)?;
+
+        let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+        let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+        check_holder_rewards_mint(holder_rewards_state, mint_info.key)?;
+
+        // Harvest any outstanding rewards, including those from each reward
+        // stream, into the token account, or to the holder's rewards
+        // recipient, if one is set, before closing.
+        let eligible_balance = get_eligible_balance(
+            holder_rewards_state.last_seen_balance(),
+            token_account_balance,
+        );
+        settle_holder_rewards(holder_rewards_state, pool_state, eligible_balance)?;
+        set_last_seen_balance(holder_rewards_state, pool_state, token_account_balance)?;
+
+        // The holder no longer counts towards the pool's excluded holders.
+        set_holder_excluded(holder_rewards_state, pool_state, false)?;
+
+        let rewards_destination_info = next_rewards_destination_info(
+            program_id,
+            holder_rewards_state,
+            token_account_info,
+            accounts_iter,
+        )?;
+        pay_out_all_rewards(
+            holder_rewards_pool_info,
+            pool_data_len,
+            pool_state,
+            holder_rewards_info,
+            holder_rewards_state,
+            rewards_destination_info,
+        )?;
+    } else {
+        // Nothing the holder rewards account holds is paid to an account
+        // chosen by the caller.
+        let (holder_rewards_version, mut holder_rewards_state) =
+            unpack_versioned_prefix::<HolderRewards>(&holder_rewards_info.try_borrow_data()?)?;
+
+        // The token account can no longer be checked against the mint, so
+        // the holder must have recorded it. Accounts in the original layout
+        // didn't, but can only be closed below as the destination's
+        // associated token account for the mint.
+        let legacy = holder_rewards_version == 0;
+        if !legacy {
+            check_holder_rewards_mint(&holder_rewards_state, mint_info.key)?;
+        }
+
+        let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+        let pool_data_len = pool_data.len();
+        let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+        // The closed token account no longer holds the balance it was last
+        // settled at, so remove it from the excluded supply, and the holder
+        // from the excluded holders. Accounts in the original layout can't
+        // have been excluded.
+        if holder_rewards_state.is_excluded() {
+            set_last_seen_balance(&mut holder_rewards_state, pool_state, 0)?;
+            set_holder_excluded(&mut holder_rewards_state, pool_state, false)?;
+        }
+
+        if holder_rewards_state.recipient().is_some() {
+            // Any unharvested rewards, and the rent lamports, are paid to the
+            // holder's rewards recipient.
+            check_rewards_destination(program_id, &holder_rewards_state, destination_info)?;
+            pay_out_all_rewards(
+                holder_rewards_pool_info,
+                pool_data_len,
+                pool_state,
+                holder_rewards_info,
+                &mut holder_rewards_state,
+                destination_info,
+            )?;
+        } else {
+            // Ensure the holder has no unharvested rewards. These remain
+            // claimable if the token account is reopened at the same
+            // address, as associated token accounts can be.
+            let has_unharvested_rewards = holder_rewards_state.unharvested_rewards != 0
+                || holder_rewards_state
+                    .reward_stream_checkpoints
+                    .iter()
+                    .any(|checkpoint| checkpoint.unharvested_rewards != 0);
+            if has_unharvested_rewards {
+                return Err(PaladinRewardsError::HolderRewardsNotEmpty.into());
+            }
+
+            // Ensure the rent lamports are returned to the token account
+            // owner. The closed token account no longer records its owner,
+            // so the destination must be the owner recorded by the holder.
+            // Accounts in the original layout didn't record it, so the
+            // destination must be the owner it was the associated token
+            // account of.
+            if legacy {
+                let owner_token_account_address = get_associated_token_address_with_program_id(
+                    destination_info.key,
+                    mint_info.key,
+                    &spl_token_2022::id(),
+                );
+                if !owner_token_account_address.eq(token_account_info.key) {
+                    return Err(ProgramError::InvalidArgument);
+                }
+            } else if !holder_rewards_state.owner.eq(destination_info.key) {
+                return Err(PaladinRewardsError::TokenAccountOwnerMismatch.into());
+            }
+        }
+    }
+
+    // Move the rent lamports to the destination and close the account.
+    let new_destination_lamports = destination_info
+        .lamports()
+        .checked_add(holder_rewards_info.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **destination_info.try_borrow_mut_lamports()? = new_destination_lamports;
+    **holder_rewards_info.try_borrow_mut_lamports()? = 0;
+
+    holder_rewards_info.realloc(0, true)?;
+    holder_rewards_info.assign(&system_program::id());
+
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeTokenRewardsPool](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_token_rewards_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let token_rewards_pool_info = next_account_info(accounts_iter)?;
+    let extra_metas_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let reward_mint_info = next_account_info(accounts_iter)?;
+    let pool_authority_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter).ok();
+
+    // Ensure the provided authority is the holder rewards pool authority.
+    {
+        check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+        let pool_data = holder_rewards_pool_info.try_borrow_data()?;
+        let pool_state = unpack_versioned::<HolderRewardsPool>(&pool_data)?;
+
+        check_pool_authority(pool_state, pool_authority_info)?;
+    }
+
+    // Ensure the reward mint is an SPL Token or Token-2022 mint.
+    {
+        spl_token_2022::check_spl_token_program_account(reward_mint_info.owner)?;
+        let reward_mint_data = reward_mint_info.try_borrow_data()?;
+        let _ = StateWithExtensions::<Mint>::unpack(&reward_mint_data)?;
+    }
+
+    // Initialize the token rewards pool account.
+    {
+        let (token_rewards_pool_address, bump_seed) =
+            get_token_rewards_pool_address_and_bump_seed(mint_info.key, reward_mint_info.key);
+        let bump_seed = [bump_seed];
+        let token_rewards_pool_signer_seeds = collect_token_rewards_pool_signer_seeds(
+            mint_info.key,
+            reward_mint_info.key,
+            &bump_seed,
+        );
+
+        // Ensure the provided token rewards pool address is the correct
+        // address derived from the mint and reward mint.
+        if !token_rewards_pool_info.key.eq(&token_rewards_pool_address) {
+            return Err(PaladinRewardsError::IncorrectTokenRewardsPoolAddress.into());
+        }
+
+        // Ensure the token rewards pool account has not already been
+        // initialized.
+        if token_rewards_pool_info.data_len() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        fund_rent_exemption(
+            payer_info,
+            token_rewards_pool_info,
+            std::mem::size_of::<TokenRewardsPool>(),
+        )?;
+
+        // Allocate & assign.
+        invoke_signed(
+            &system_instruction::allocate(
+                &token_rewards_pool_address,
+                std::mem::size_of::<TokenRewardsPool>() as u64,
+            ),
+            &[token_rewards_pool_info.clone()],
+            &[&token_rewards_pool_signer_seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(&token_rewards_pool_address, program_id),
+            &[token_rewards_pool_info.clone()],
+            &[&token_rewards_pool_signer_seeds],
+        )?;
+
+        // Write the data.
+        let mut data = token_rewards_pool_info.try_borrow_mut_data()?;
+        *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
+            TokenRewardsPool::new(0, *reward_mint_info.key, *reward_mint_info.owner);
+    }
+
+    // Append the token rewards pool's accounts to the extra metas account.
+    {
+        let mut extra_metas =
+            unpack_extra_account_metas_checked(program_id, mint_info.key, extra_metas_info)?;
+
+        // Ensure the mint hasn't reached the maximum number of token rewards
+        // pools.
+        let escrow_extra_metas_index = extra_metas.len() - ESCROW_EXTRA_METAS_COUNT;
+        let token_rewards_pool_count = escrow_extra_metas_index
+            .saturating_sub(get_extra_account_metas().len())
+            / TOKEN_REWARDS_EXTRA_METAS_COUNT;
+        if token_rewards_pool_count >= MAX_TOKEN_REWARDS_POOLS {
+            return Err(PaladinRewardsError::MaxTokenRewardsPoolsReached.into());
+        }
+
+        // The auto-claim escrow accounts always end the list.
+        extra_metas.splice(
+            escrow_extra_metas_index..escrow_extra_metas_index,
+            get_token_rewards_extra_account_metas(
+                mint_info.key,
+                reward_mint_info.key,
+                token_rewards_pool_count,
+            ),
+        );
+        let account_size = ExtraAccountMetaList::size_of(extra_metas.len())?;
+
+        fund_rent_exemption(payer_info, extra_metas_info, account_size)?;
+
+        // Resize & write the data.
+        extra_metas_info.realloc(account_size, false)?;
+        let mut data = extra_metas_info.try_borrow_mut_data()?;
+        ExtraAccountMetaList::update::<ExecuteInstruction>(&mut data, &extra_metas)?;
+    }
+
+    Ok(())
+}
+
+/// Unpacks the mint's extra account metas, after checking the account's
+/// address and owner, with the base metas replaced by the current ones, and
+/// ending with the auto-claim escrow accounts.
+///
+/// Lists written by earlier versions of the program have the holder rewards
+/// pool read-only, but the transfer hook now writes to it, and don't include
+/// the auto-claim escrow accounts, so they're corrected whenever the list is
+/// rewritten.
+fn unpack_extra_account_metas_checked(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    extra_metas_info: &AccountInfo,
+) -> Result<Vec<ExtraAccountMeta>, ProgramError> {
+    // Ensure the provided extra metas address is the correct address derived
+    // from the mint.
+    if !extra_metas_info
+        .key
+        .eq(&get_extra_account_metas_address(mint, program_id))
+    {
+        return Err(PaladinRewardsError::IncorrectExtraMetasAddress.into());
+    }
+
+    // Ensure the extra metas account is owned by the Paladin Rewards program.
+    if !extra_metas_info.owner.eq(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let mut extra_metas = {
+        let data = extra_metas_info.try_borrow_data()?;
+        let state = TlvStateBorrowed::unpack(&data)?;
+        ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&state)?
+            .data()
+            .to_vec()
+    };
+
+    let base_extra_metas = get_extra_account_metas();
+    extra_metas
+        .get_mut(..base_extra_metas.len())
+        .ok_or(ProgramError::InvalidAccountData)?
+        .copy_from_slice(&base_extra_metas);
+
+    // Token rewards pools each add a fixed number of metas, so a list ending
+    // with the auto-claim escrow accounts is the only kind with a remainder.
+    if (extra_metas.len() - base_extra_metas.len()) % TOKEN_REWARDS_EXTRA_METAS_COUNT == 0 {
+        extra_metas.extend_from_slice(&get_escrow_extra_account_metas());
+    }
+
+    Ok(extra_metas)
+}
+
+/// Returns the addresses of the mint's token rewards pools, in the order
+/// they're listed in the mint's extra account metas, as unpacked by
+/// [unpack_extra_account_metas_checked](fn.unpack_extra_account_metas_checked.html).
+fn get_token_rewards_pool_addresses(
+    extra_metas: &[ExtraAccountMeta],
+) -> Result<Vec<Pubkey>, ProgramError> {
+    let token_rewards_extra_metas = extra_metas
+        .get(
+            get_extra_account_metas().len()
+                ..extra_metas.len().saturating_sub(ESCROW_EXTRA_METAS_COUNT),
+        )
+        .ok_or(ProgramError::InvalidAccountData)?;
+    Ok(token_rewards_extra_metas
+        .chunks(TOKEN_REWARDS_EXTRA_METAS_COUNT)
+        .map(|metas| Pubkey::new_from_array(metas[0].address_config))
+        .collect())
+}
+
+/// Processes a [DistributeTokenRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_distribute_token_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let source_authority_info = next_account_info(accounts_iter)?;
+    let source_info = next_account_info(accounts_iter)?;
+    let token_rewards_pool_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let reward_mint_info = next_account_info(accounts_iter)?;
+    let reward_token_program_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+
+    check_token_rewards_pool(
+        program_id,
+        mint_info.key,
+        reward_mint_info.key,
+        token_rewards_pool_info,
+    )?;
+    {
+        let pool_data = token_rewards_pool_info.try_borrow_data()?;
+        let pool_state = bytemuck::try_from_bytes::<TokenRewardsPool>(&pool_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        check_token_rewards_vault(
+            token_rewards_pool_info.key,
+            pool_state,
+            vault_info,
+            reward_token_program_info,
+        )?;
+    }
+
+    // Token accounts excluded from the holder rewards pool don't count
+    // towards the supply the rewards are distributed over.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let token_supply = {
+        let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+        let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+        // The pool's minimum distribution amount is in lamports, so it can't
+        // be compared with an amount of reward tokens. Only the distribution
+        // authority applies.
+        check_distribution_authority(pool_state, Some(source_authority_info))?;
+
+        reconcile_excluded_supply(program_id, mint_info.key, pool_state, accounts_iter)?;
+        get_eligible_token_supply(get_token_supply(mint_info)?, pool_state)?
+    };
+
+    // Ensure there are holders to distribute the rewards to. Otherwise, they'd
+    // be stuck in the vault.
+    if token_supply == 0 {
+        return Err(PaladinRewardsError::ZeroTokenSupply.into());
+    }
+
+    // Move the amount from the source to the vault.
+    //
+    // The reward mint may withhold a transfer fee, so the rate is updated
+    // with the amount the vault actually received.
+    let received_amount = {
+        let vault_balance_before = get_token_account_amount(vault_info)?;
+
+        invoke(
+            &spl_token_2022::instruction::transfer_checked(
+                reward_token_program_info.key,
+                source_info.key,
+                reward_mint_info.key,
+                vault_info.key,
+                source_authority_info.key,
+                &[],
+                amount,
+                get_mint_decimals(reward_mint_info)?,
+            )?,
+            &[
+                source_info.clone(),
+                reward_mint_info.clone(),
+                vault_info.clone(),
+                source_authority_info.clone(),
+                reward_token_program_info.clone(),
+            ],
+        )?;
+
+        get_token_account_amount(vault_info)?
+            .checked_sub(vault_balance_before)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+    };
+
+    // Update the token rewards pool's rate.
+    let mut pool_data = token_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = bytemuck::try_from_bytes_mut::<TokenRewardsPool>(&mut pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    distribute_to_rate(
+        &mut pool_state.accumulated_rewards_per_token,
+        &mut pool_state.rewards_per_token_remainder,
+        received_amount,
+        token_supply,
+    )?;
+
+    PaladinRewardsEvent::TokenRewardsDistributed {
+        token_rewards_pool: *token_rewards_pool_info.key,
+        amount: received_amount,
+        accumulated_rewards_per_token: pool_state.accumulated_rewards_per_token,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeTokenHolderRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_token_holder_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let token_rewards_pool_info = next_account_info(accounts_iter)?;
+    let token_holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let reward_mint_info = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter).ok();
+
+    // Run checks on the token account.
+    let token_account_balance =
+        get_token_account_balance_checked(mint_info.key, token_account_info, false)?;
+
+    check_token_rewards_pool(
+        program_id,
+        mint_info.key,
+        reward_mint_info.key,
+        token_rewards_pool_info,
+    )?;
+    let pool_data = token_rewards_pool_info.try_borrow_data()?;
+    let pool_state = bytemuck::try_from_bytes::<TokenRewardsPool>(&pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Initialize the token holder rewards account.
+    {
+        let (token_holder_rewards_address, bump_seed) =
+            get_token_holder_rewards_address_and_bump_seed(
+                token_account_info.key,
+                token_rewards_pool_info.key,
+            );
+        let bump_seed = [bump_seed];
+        let token_holder_rewards_signer_seeds = collect_token_holder_rewards_signer_seeds(
+            token_account_info.key,
+            token_rewards_pool_info.key,
+            &bump_seed,
+        );
+
+        // Ensure the provided token holder rewards address is the correct
+        // address derived from the token account and token rewards pool.
+        if !token_holder_rewards_info
+            .key
+            .eq(&token_holder_rewards_address)
+        {
+            return Err(PaladinRewardsError::IncorrectHolderRewardsAddress.into());
+        }
+
+        // Ensure the token holder rewards account has not already been
+        // initialized.
+        if token_holder_rewards_info.data_len() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        fund_rent_exemption(
+            payer_info,
+            token_holder_rewards_info,
+            std::mem::size_of::<TokenHolderRewards>(),
+        )?;
+
+        // Allocate & assign.
+        invoke_signed(
+            &system_instruction::allocate(
+                &token_holder_rewards_address,
+                std::mem::size_of::<TokenHolderRewards>() as u64,
+            ),
+            &[token_holder_rewards_info.clone()],
+            &[&token_holder_rewards_signer_seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(&token_holder_rewards_address, program_id),
+            &[token_holder_rewards_info.clone()],
+            &[&token_holder_rewards_signer_seeds],
+        )?;
+
+        // Write the data.
+        let mut data = token_holder_rewards_info.try_borrow_mut_data()?;
+        *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
+            TokenHolderRewards::new(
+                pool_state.accumulated_rewards_per_token,
+                0,
+                token_account_balance,
+            );
+    }
+
+    PaladinRewardsEvent::TokenHolderRewardsInitialized {
+        token_holder_rewards: *token_holder_rewards_info.key,
+        token_account: *token_account_info.key,
+        token_rewards_pool: *token_rewards_pool_info.key,
+        last_accumulated_rewards_per_token: pool_state.accumulated_rewards_per_token,
+        last_seen_balance: token_account_balance,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Processes a [HarvestTokenRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_harvest_token_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let token_rewards_pool_info = next_account_info(accounts_iter)?;
+    let token_holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let destination_info = next_account_info(accounts_iter)?;
+    let reward_mint_info = next_account_info(accounts_iter)?;
+    let reward_token_program_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+
+    // Run checks on the token account.
+    let (token_account_balance, token_account_owner) = {
+        let token_account_data = token_account_info.try_borrow_data()?;
+        let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+
+        // Ensure the provided token account is for the mint.
+        if !token_account.base.mint.eq(mint_info.key) {
+            return Err(PaladinRewardsError::TokenAccountMintMismatch.into());
+        }
+
+        (token_account.base.amount, token_account.base.owner)
+    };
+
+    let (token_rewards_pool_address, bump_seed) =
+        get_token_rewards_pool_address_and_bump_seed(mint_info.key, reward_mint_info.key);
+    let bump_seed = [bump_seed];
+    let token_rewards_pool_signer_seeds =
+        collect_token_rewards_pool_signer_seeds(mint_info.key, reward_mint_info.key, &bump_seed);
+
+    // Ensure the token rewards pool is owned by the Paladin Rewards program.
+    if !token_rewards_pool_info.owner.eq(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Ensure the provided token rewards pool address is the correct address
+    // derived from the mint and reward mint.
+    if !token_rewards_pool_info.key.eq(&token_rewards_pool_address) {
+        return Err(PaladinRewardsError::IncorrectTokenRewardsPoolAddress.into());
+    }
+
+    let pool_data = token_rewards_pool_info.try_borrow_data()?;
+    let pool_state = bytemuck::try_from_bytes::<TokenRewardsPool>(&pool_data)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    check_token_rewards_vault(
+        token_rewards_pool_info.key,
+        pool_state,
+        vault_info,
+        reward_token_program_info,
+    )?;
+
+    // Run checks on the destination token account.
+    {
+        let destination_data = destination_info.try_borrow_data()?;
+        let destination = StateWithExtensions::<Account>::unpack(&destination_data)?;
+
+        // Ensure the destination is for the reward mint.
+        if !destination.base.mint.eq(reward_mint_info.key) {
+            return Err(PaladinRewardsError::TokenAccountMintMismatch.into());
+        }
+
+        // Ensure the destination is owned by the token account owner, since
+        // anyone can harvest on the holder's behalf.
+        if !destination.base.owner.eq(&token_account_owner) {
+            return Err(PaladinRewardsError::TokenAccountOwnerMismatch.into());
+        }
+    }
+
+    check_token_holder_rewards(
+        program_id,
+        token_account_info.key,
+        token_rewards_pool_info.key,
+        token_holder_rewards_info,
+    )?;
+    let mut token_holder_rewards_data = token_holder_rewards_info.try_borrow_mut_data()?;
+    let token_holder_rewards_state =
+        bytemuck::try_from_bytes_mut::<TokenHolderRewards>(&mut token_holder_rewards_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    // Determine the amount the holder can harvest, exactly as for
+    // `HarvestRewards`.
+    let eligible_balance = get_eligible_balance(
+        Some(token_holder_rewards_state.last_seen_balance),
+        token_account_balance,
+    );
+    let excluded = is_holder_excluded(program_id, token_account_info.key, holder_rewards_info)?;
+    settle_token_holder_rewards(
+        token_holder_rewards_state,
+        pool_state.accumulated_rewards_per_token,
+        eligible_balance,
+        excluded,
+    )?;
+    token_holder_rewards_state.last_seen_balance = token_account_balance;
+
+    // If the vault doesn't have enough tokens to cover the rewards, only
+    // harvest the available tokens. This should never happen, but the check
+    // is a failsafe.
+    let rewards_to_harvest = token_holder_rewards_state
+        .unharvested_rewards
+        .min(get_token_account_amount(vault_info)?);
+
+    if rewards_to_harvest != 0 {
+        // Move the amount from the vault to the destination.
+        invoke_signed(
+            &spl_token_2022::instruction::transfer_checked(
+                reward_token_program_info.key,
+                vault_info.key,
+                reward_mint_info.key,
+                destination_info.key,
+                token_rewards_pool_info.key,
+                &[],
+                rewards_to_harvest,
+                get_mint_decimals(reward_mint_info)?,
+            )?,
+            &[
+                vault_info.clone(),
+                reward_mint_info.clone(),
+                destination_info.clone(),
+                token_rewards_pool_info.clone(),
+                reward_token_program_info.clone(),
+            ],
+            &[&token_rewards_pool_signer_seeds],
+        )?;
+
+        // Update the holder's unharvested rewards.
+        token_holder_rewards_state.unharvested_rewards = token_holder_rewards_state
+            .unharvested_rewards
+            .checked_sub(rewards_to_harvest)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    PaladinRewardsEvent::TokenRewardsHarvested {
+        token_holder_rewards: *token_holder_rewards_info.key,
+        destination: *destination_info.key,
+        amount: rewards_to_harvest,
+        unharvested_rewards: token_holder_rewards_state.unharvested_rewards,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Processes an [AddRewardStream](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_add_reward_stream(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reward_id: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let pool_authority_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    check_pool_authority(pool_state, pool_authority_info)?;
+
+    // A reward ID of zero marks an unused slot.
+    if reward_id == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Ensure the reward ID isn't already in use.
+    if pool_state.find_reward_stream(reward_id).is_some() {
+        return Err(PaladinRewardsError::RewardStreamAlreadyExists.into());
+    }
+
+    // Add the stream to the first unused slot. Its rate starts at zero, which
+    // matches the zeroed checkpoint every holder rewards account already has
+    // for the slot.
+    let stream = pool_state
+        .reward_streams
+        .iter_mut()
+        .find(|stream| stream.reward_id == 0)
+        .ok_or(PaladinRewardsError::MaxRewardStreamsReached)?;
+    stream.reward_id = reward_id;
+
+    Ok(())
+}
+
+/// Processes a
+/// [DistributeStreamRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_distribute_stream_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reward_id: u64,
+    amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the payer account is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let token_supply = get_token_supply(mint_info)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+
+    // Update the stream's total rewards in the holder rewards pool.
+    let new_accumulated_rewards_per_token = {
+        let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+        let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+        check_distribution(pool_state, Some(payer_info), amount)?;
+
+        reconcile_excluded_supply(program_id, mint_info.key, pool_state, accounts_iter)?;
+
+        // Unlike the base rate, streams don't carry over rewards distributed
+        // while the token supply is zero, so they're rejected.
+        let token_supply = get_eligible_token_supply(token_supply, pool_state)?;
+        if token_supply == 0 {
+            return Err(PaladinRewardsError::ZeroTokenSupply.into());
+        }
+
+        let slot = pool_state
+            .find_reward_stream(reward_id)
+            .ok_or(PaladinRewardsError::RewardStreamNotFound)?;
+        let stream = &mut pool_state.reward_streams[slot];
+
+        // Update the rewards per token exactly as for `DistributeRewards`,
+        // but against the stream's rate and remainder.
+        distribute_to_rate(
+            &mut stream.accumulated_rewards_per_token,
+            &mut stream.rewards_per_token_remainder,
+            amount,
+            token_supply,
+        )?;
+        stream.total_distributed = stream
+            .total_distributed
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let new_accumulated_rewards_per_token = stream.accumulated_rewards_per_token;
+
+        record_distribution(pool_state, amount)?;
+
+        new_accumulated_rewards_per_token
+    };
+
+    // Move the amount from the payer to the holder rewards pool.
+    invoke(
+        &system_instruction::transfer(payer_info.key, holder_rewards_pool_info.key, amount),
+        &[payer_info.clone(), holder_rewards_pool_info.clone()],
+    )?;
+
+    PaladinRewardsEvent::RewardsDistributed {
+        mint: *mint_info.key,
+        reward_id,
+        amount,
+        accumulated_rewards_per_token: new_accumulated_rewards_per_token,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Processes a [HarvestStreamRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_harvest_stream_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    reward_id: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+
+    // Run checks on the token account.
+    let token_account_balance =
+        get_token_account_balance_checked(mint_info.key, token_account_info, false)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_data_len = pool_data.len();
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    // Release any rewards vested by the emission schedule.
+    advance_emission_schedule(pool_state, mint_info)?;
+
+    let slot = pool_state
+        .find_reward_stream(reward_id)
+        .ok_or(PaladinRewardsError::RewardStreamNotFound)?;
+
+    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+    check_holder_rewards_mint(holder_rewards_state, mint_info.key)?;
+
+    // Settle the holder against every rate, exactly as for `HarvestRewards`,
+    // since the recorded balance changes for all of them.
+    let eligible_balance = get_eligible_balance(
+        holder_rewards_state.last_seen_balance(),
+        token_account_balance,
+    );
+    settle_holder_rewards(holder_rewards_state, pool_state, eligible_balance)?;
+    set_last_seen_balance(holder_rewards_state, pool_state, token_account_balance)?;
+
+    // Move only the stream's unharvested rewards from the holder rewards
+    // pool to the token account, or to the holder's rewards recipient, if
+    // one is set.
+    let destination_info = next_rewards_destination_info(
+        program_id,
+        holder_rewards_state,
+        token_account_info,
+        accounts_iter,
+    )?;
+    let checkpoint = &mut holder_rewards_state.reward_stream_checkpoints[slot];
+    let harvested_rewards = pay_out_rewards(
+        holder_rewards_pool_info,
+        pool_data_len,
+        pool_state,
+        &mut checkpoint.unharvested_rewards,
+        destination_info,
+    )?;
+
+    PaladinRewardsEvent::RewardsHarvested {
+        holder_rewards: *holder_rewards_info.key,
+        reward_id,
+        destination: *destination_info.key,
+        amount: harvested_rewards,
+        unharvested_rewards: checkpoint.unharvested_rewards,
+    }
+    .emit()?;
+
+    Ok(())
+}
+
+/// Processes a
+/// [CreateEmissionSchedule](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_create_emission_schedule(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    start_timestamp: i64,
+    end_timestamp: i64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(accounts_iter)?;
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let pool_authority_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+
+    // Ensure the payer account is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the schedule releases a non-zero amount over a non-empty range
+    // that hasn't already started.
+    if amount == 0
+        || end_timestamp <= start_timestamp
+        || start_timestamp < Clock::get()?.unix_timestamp
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+
+    {
+        let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+        let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+        check_pool_authority(pool_state, pool_authority_info)?;
+
+        // Release any rewards vested by the current emission schedule, then
+        // ensure it has been fully released before it's replaced.
+        advance_emission_schedule(pool_state, mint_info)?;
+        if pool_state.emission_schedule.unreleased_amount() != 0 {
+            return Err(PaladinRewardsError::EmissionScheduleActive.into());
+        }
+
+        pool_state.emission_schedule = EmissionSchedule {
+            total_amount: amount,
+            released_amount: 0,
+            start_timestamp,
+            end_timestamp,
+        };
+    }
+
+    // Escrow the amount in the holder rewards pool.
+    invoke(
+        &system_instruction::transfer(payer_info.key, holder_rewards_pool_info.key, amount),
+        &[payer_info.clone(), holder_rewards_pool_info.clone()],
+    )?;
+
+    Ok(())
+}
+
+/// Migrates an account from the original layout to the current layout of
+/// `T`, prefixing it with the account header.
+///
+/// The original layout's state is a prefix of the current state, so any
+/// fields added since are zeroed. Exchange rates are left as stored, scaled
+/// by `1e9`.
+fn migrate_versioned_account<'a, T: VersionedAccount>(
+    payer_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let old_len = account_info.data_len();
+
+    // Ensure the account has the original layout.
+    let state_data = {
+        let data = account_info.try_borrow_data()?;
+        if !is_legacy_state::<T>(&data) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        data.to_vec()
+    };
+
+    // Fund only the additional rent for the new length. Any lamports above
+    // an account's rent-exempt minimum may be rewards, so topping up to the
+    // new minimum could count them as rent.
+    let additional_rent = {
+        let rent = <Rent as Sysvar>::get()?;
+        rent.minimum_balance(T::LEN)
+            .saturating_sub(rent.minimum_balance(old_len))
+    };
+    if additional_rent > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, account_info.key, additional_rent),
+            &[payer_info.clone(), account_info.clone()],
+        )?;
+    }
+
+    // Move the state behind the header, zeroing any appended fields.
+    account_info.realloc(T::LEN, true)?;
+
+    let mut data = account_info.try_borrow_mut_data()?;
+    let (header_data, new_state_data) = data.split_at_mut(ACCOUNT_HEADER_LEN);
+    header_data.copy_from_slice(bytemuck::bytes_of(&T::header()));
+    new_state_data[..state_data.len()].copy_from_slice(&state_data);
+    new_state_data[state_data.len()..].fill(0);
+
+    Ok(())
+}
+
+/// Rescales an exchange rate from the original account layout, which scaled
+/// rates by `1e9`.
+fn rescale_legacy_rewards_per_token(rewards_per_token: &mut u128) -> ProgramResult {
+    *rewards_per_token = rewards_per_token
+        .checked_mul(LEGACY_REWARDS_PER_TOKEN_RESCALING_FACTOR)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Descales an exchange rate to the precision of the original account
+/// layout, which scaled rates by `1e9`. The rate must already be truncated
+/// to that precision.
+fn descale_legacy_rewards_per_token(rewards_per_token: &mut u128) -> ProgramResult {
+    *rewards_per_token = rewards_per_token
+        .checked_div(LEGACY_REWARDS_PER_TOKEN_RESCALING_FACTOR)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Truncates an exchange rate to the precision of the original account
+/// layout, which scaled rates by `1e9`.
+fn truncate_to_legacy_precision(rewards_per_token: &mut u128) -> ProgramResult {
+    *rewards_per_token = rewards_per_token
+        .checked_rem(LEGACY_REWARDS_PER_TOKEN_RESCALING_FACTOR)
+        .and_then(|remainder| rewards_per_token.checked_sub(remainder))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Applies the provided conversion to each of a holder's exchange rate
+/// checkpoints.
+fn for_each_rate_checkpoint(
+    holder_rewards_state: &mut HolderRewards,
+    convert: fn(&mut u128) -> ProgramResult,
+) -> ProgramResult {
+    convert(&mut holder_rewards_state.last_accumulated_rewards_per_token)?;
+    for checkpoint in holder_rewards_state.reward_stream_checkpoints.iter_mut() {
+        convert(&mut checkpoint.last_accumulated_rewards_per_token)?;
+    }
+    Ok(())
+}
+
+/// Processes a [MigrateAccount](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let payer_info = next_account_info(accounts_iter)?;
+    let account_info = next_account_info(accounts_iter)?;
+    let address_seed_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let extra_metas_info = accounts_iter.next();
+
+    // Ensure the payer account is a signer.
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Ensure the account is owned by the Paladin Rewards program.
+    if !account_info.owner.eq(program_id) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // The account's type is determined by the address it's derived from,
+    // since unversioned accounts can't be identified by their data.
+    if account_info
+        .key
+        .eq(&get_holder_rewards_pool_address(address_seed_info.key))
+    {
+        // A pool already in the current layout can still have the mint's
+        // extra metas account corrected, if it's provided.
+        let pool_is_current = {
+            let pool_data = account_info.try_borrow_data()?;
+            unpack_versioned::<HolderRewardsPool>(&pool_data).is_ok()
+        };
+
+        // The original extra metas list the pool as read-only, which would
+        // fail every transfer once the hook writes to the migrated pool, so
+        // they must be corrected in the same instruction.
+        if !pool_is_current && extra_metas_info.is_none() {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        if !pool_is_current || extra_metas_info.is_none() {
+            migrate_versioned_account::<HolderRewardsPool>(payer_info, account_info)?;
+
+            let mut pool_data = account_info.try_borrow_mut_data()?;
+            let pool_data_len = pool_data.len();
+            let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+            // The original layout has no record of the pool's distributions,
+            // so all of its excess lamports are counted as distributed.
+            // Otherwise, its unharvested rewards could be deposited again with
+            // `DepositRewards`.
+            pool_state.total_distributed =
+                get_pool_excess_lamports(account_info, pool_data_len, pool_state)?;
+
+            // The original layout had no pool authority. It defaults to the mint
+            // authority, as for newly initialized pools, or is left unset if the
+            // mint has none.
+            let mint_data = address_seed_info.try_borrow_data()?;
+            let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+            if let COption::Some(mint_authority) = mint.base.mint_authority {
+                pool_state.authority = mint_authority;
+            }
+
+            // The original layout scaled the rate by `1e9`.
+            rescale_legacy_rewards_per_token(&mut pool_state.accumulated_rewards_per_token)?;
+        }
+
+        // If provided, correct the mint's extra metas account, so the
+        // transfer hook can write to the migrated pool and pay out to
+        // auto-claim escrow accounts.
+        if let Some(extra_metas_info) = extra_metas_info {
+            let extra_metas = unpack_extra_account_metas_checked(
+                program_id,
+                address_seed_info.key,
+                extra_metas_info,
+            )?;
+            let account_size = ExtraAccountMetaList::size_of(extra_metas.len())?;
+
+            fund_rent_exemption(Some(payer_info), extra_metas_info, account_size)?;
+
+            // Resize & write the data.
+            extra_metas_info.realloc(account_size, false)?;
+            let mut data = extra_metas_info.try_borrow_mut_data()?;
+            ExtraAccountMetaList::update::<ExecuteInstruction>(&mut data, &extra_metas)?;
+        }
+
+        Ok(())
+    } else if account_info
+        .key
+        .eq(&get_holder_rewards_address(address_seed_info.key))
+    {
+        // The original layout didn't record the token account's mint or
+        // owner, so they're read from the token account, which must still be
+        // open.
+        let (mint, owner) = {
+            let token_account_data = address_seed_info.try_borrow_data()?;
+            let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+            (token_account.base.mint, token_account.base.owner)
+        };
+
+        migrate_versioned_account::<HolderRewards>(payer_info, account_info)?;
+
+        let mut holder_rewards_data = account_info.try_borrow_mut_data()?;
+        let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+        holder_rewards_state.mint = mint;
+        holder_rewards_state.owner = owner;
+
+        // The original layout didn't record the balance the holder was last
+        // settled at, so it's unknown until the holder is next settled.
+        holder_rewards_state.last_seen_balance_unknown = 1;
+
+        // The original layout scaled the rate checkpoint by `1e9`.
+        rescale_legacy_rewards_per_token(
+            &mut holder_rewards_state.last_accumulated_rewards_per_token,
+        )?;
+
+        Ok(())
+    } else {
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Processes a [SweepDust](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_sweep_dust(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let pool_authority_info = next_account_info(accounts_iter)?;
+    let destination_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+
+    let dust_amount = {
+        let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+        let pool_data_len = pool_data.len();
+        let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+        check_pool_authority(pool_state, pool_authority_info)?;
+
+        // Only rewards truncated from holders' settled rewards are provably
+        // not owed to any holder. Lamports above the pool's unharvested
+        // rewards may have been credited to the pool directly, and are left
+        // to be deposited with `DepositRewards`.
+        let dust_amount = pool_state.dust.min(get_pool_excess_lamports(
+            holder_rewards_pool_info,
+            pool_data_len,
+            pool_state,
+        )?);
+
+        // The dust was distributed to the pool, so it's counted as
+        // harvested, and no longer as owed to holders.
+        pool_state.dust = pool_state
+            .dust
+            .checked_sub(dust_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        pool_state.total_harvested = pool_state
+            .total_harvested
+            .checked_add(dust_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        dust_amount
+    };
+
+    if dust_amount != 0 {
+        // Move the dust from the holder rewards pool to the destination.
+        let new_holder_rewards_pool_lamports = holder_rewards_pool_info
+            .lamports()
+            .checked_sub(dust_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let new_destination_lamports = destination_info
+            .lamports()
+            .checked_add(dust_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **holder_rewards_pool_info.try_borrow_mut_lamports()? = new_holder_rewards_pool_lamports;
+        **destination_info.try_borrow_mut_lamports()? = new_destination_lamports;
+    }
+
+    Ok(())
+}
+
+/// Processes a [SetRewardsExclusion](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_set_rewards_exclusion(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    excluded: bool,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let pool_authority_info = next_account_info(accounts_iter)?;
+    let extra_metas_info = next_account_info(accounts_iter)?;
+
+    // Run checks on the token account.
+    let token_account_balance =
+        get_token_account_balance_checked(mint_info.key, token_account_info, false)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    check_pool_authority(pool_state, pool_authority_info)?;
+
+    // The mint's token rewards pools are the ones listed in its extra metas,
+    // which the transfer hook settles token holders for.
+    let token_rewards_pool_addresses = get_token_rewards_pool_addresses(
+        &unpack_extra_account_metas_checked(program_id, mint_info.key, extra_metas_info)?,
+    )?;
+
+    // Release any rewards vested by the emission schedule before the supply
+    // they're distributed over changes.
+    advance_emission_schedule(pool_state, mint_info)?;
+
+    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+    check_holder_rewards_mint(holder_rewards_state, mint_info.key)?;
+
+    // Settle any rewards accrued up to now, exactly as for `HarvestRewards`,
+    // so the holder keeps what it earned before the change.
+    let eligible_balance = get_eligible_balance(
+        holder_rewards_state.last_seen_balance(),
+        token_account_balance,
+    );
+    settle_holder_rewards(holder_rewards_state, pool_state, eligible_balance)?;
+
+    // Settle the token account's token holder rewards account for each of the
+    // mint's token rewards pools the same way, under its old exclusion.
+    // Otherwise, they'd later be settled under the new exclusion from when
+    // they were last settled.
+    for token_rewards_pool_address in token_rewards_pool_addresses {
+        let token_rewards_pool_info = next_account_info(accounts_iter)?;
+        let token_holder_rewards_info = next_account_info(accounts_iter)?;
+
+        // Ensure the token rewards pools are provided in the order they're
+        // listed in the extra metas.
+        if !token_rewards_pool_info.key.eq(&token_rewards_pool_address) {
+            return Err(PaladinRewardsError::IncorrectTokenRewardsPoolAddress.into());
+        }
+
+        let current_accumulated_rewards_per_token = {
+            let token_rewards_pool_data = token_rewards_pool_info.try_borrow_data()?;
+            let token_rewards_pool_state =
+                bytemuck::try_from_bytes::<TokenRewardsPool>(&token_rewards_pool_data)
+                    .map_err(|_| ProgramError::InvalidAccountData)?;
+            check_token_rewards_pool(
+                program_id,
+                mint_info.key,
+                &token_rewards_pool_state.reward_mint,
+                token_rewards_pool_info,
+            )?;
+            token_rewards_pool_state.accumulated_rewards_per_token
+        };
+
+        // A token holder rewards account that hasn't been initialized has
+        // nothing to settle, and is checkpointed when it's initialized.
+        if !check_token_holder_rewards_for_transfer_hook(
+            program_id,
+            token_account_info.key,
+            token_rewards_pool_info.key,
+            token_holder_rewards_info,
+        )? {
+            continue;
+        }
+        let mut token_holder_rewards_data = token_holder_rewards_info.try_borrow_mut_data()?;
+        let token_holder_rewards_state =
+            bytemuck::try_from_bytes_mut::<TokenHolderRewards>(&mut token_holder_rewards_data)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        let eligible_balance = get_eligible_balance(
+            Some(token_holder_rewards_state.last_seen_balance),
+            token_account_balance,
+        );
+        settle_token_holder_rewards(
+            token_holder_rewards_state,
+            current_accumulated_rewards_per_token,
+            eligible_balance,
+            holder_rewards_state.is_excluded(),
+        )?;
+        token_holder_rewards_state.last_seen_balance = token_account_balance;
+    }
+
+    // Move the holder's balance out of the excluded supply under its old
+    // exclusion, then back in under its new one.
+    set_last_seen_balance(holder_rewards_state, pool_state, 0)?;
+    set_holder_excluded(holder_rewards_state, pool_state, excluded)?;
+    set_last_seen_balance(holder_rewards_state, pool_state, token_account_balance)?;
+
+    Ok(())
+}
+
+/// Processes a [SetRewardsRecipient](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_set_rewards_recipient(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    recipient: Pubkey,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner_info = next_account_info(accounts_iter)?;
+
+    // Run checks on the token account.
+    {
+        let token_account_data = token_account_info.try_borrow_data()?;
+        let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+
+        // Ensure the provided token account is for the mint.
+        if !token_account.base.mint.eq(mint_info.key) {
+            return Err(PaladinRewardsError::TokenAccountMintMismatch.into());
+        }
+
+        // Ensure the provided owner is the token account owner.
+        if !token_account.base.owner.eq(owner_info.key) {
+            return Err(PaladinRewardsError::TokenAccountOwnerMismatch.into());
+        }
+    }
+
+    // Ensure the token account owner is a signer.
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+
+    // Rewards aren't settled, since the recipient doesn't change what the
+    // holder earns. Any unharvested rewards are paid to the new recipient.
+    holder_rewards_state.recipient = recipient;
+
+    Ok(())
+}
+
+/// Processes an
+/// [InitializeHolderRewardsBatch](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_initialize_holder_rewards_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let _system_program = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    // Release any rewards vested by the emission schedule, once for the
+    // whole batch, so no new holder earns rewards that vested before it was
+    // checkpointed.
+    advance_emission_schedule(pool_state, mint_info)?;
+
+    // Initialize each holder rewards account, exactly as for
+    // `InitializeHolderRewards`.
+    while let Some(holder_rewards_info) = accounts_iter.next() {
+        let token_account_info = next_account_info(accounts_iter)?;
+
+        // Skip holder rewards accounts that have already been initialized,
+        // such as by another transaction landing first, rather than failing
+        // the whole batch.
+        check_holder_rewards_address(token_account_info.key, holder_rewards_info)?;
+        if !is_uninitialized(holder_rewards_info) {
+            continue;
+        }
+
+        let token_account_balance =
+            get_token_account_balance_checked(mint_info.key, token_account_info, false)?;
+
+        initialize_holder_rewards_account(
+            program_id,
+            pool_state,
+            holder_rewards_info,
+            token_account_info,
+            mint_info.key,
+            token_account_balance,
+            Some(payer_info),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes a [HarvestRewardsBatch](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_harvest_rewards_batch(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_data_len = pool_data.len();
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    // Release any rewards vested by the emission schedule, once for the
+    // whole batch.
+    advance_emission_schedule(pool_state, mint_info)?;
+
+    // Harvest each holder's rewards, exactly as for `HarvestRewards`.
+    while let Some(holder_rewards_info) = accounts_iter.next() {
+        let token_account_info = next_account_info(accounts_iter)?;
+
+        let token_account_balance =
+            get_token_account_balance_checked(mint_info.key, token_account_info, false)?;
+
+        harvest_holder_rewards(
+            program_id,
+            holder_rewards_pool_info,
+            pool_data_len,
+            pool_state,
+            holder_rewards_info,
+            token_account_info,
+            mint_info.key,
+            token_account_balance,
+            accounts_iter,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes a [SetAutoClaim](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_set_auto_claim(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auto_claim_threshold: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let owner_info = next_account_info(accounts_iter)?;
+    let escrow_info = next_account_info(accounts_iter)?;
+    let _system_program_info = next_account_info(accounts_iter)?;
+    let payer_info = accounts_iter.next();
+
+    // Run checks on the token account.
+    {
+        let token_account_data = token_account_info.try_borrow_data()?;
+        let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
+
+        // Ensure the provided token account is for the mint.
+        if !token_account.base.mint.eq(mint_info.key) {
+            return Err(PaladinRewardsError::TokenAccountMintMismatch.into());
+        }
+
+        // Ensure the provided owner is the token account owner.
+        if !token_account.base.owner.eq(owner_info.key) {
+            return Err(PaladinRewardsError::TokenAccountOwnerMismatch.into());
+        }
+    }
+
+    // Ensure the token account owner is a signer.
+    if !owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+    let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+
+    let (escrow_address, bump_seed) =
+        get_holder_rewards_escrow_address_and_bump_seed(token_account_info.key);
+    let bump_seed = [bump_seed];
+    let escrow_signer_seeds =
+        collect_holder_rewards_escrow_signer_seeds(token_account_info.key, &bump_seed);
+
+    // Ensure the provided escrow address is the correct address derived from
+    // the token account.
+    if !escrow_info.key.eq(&escrow_address) {
+        return Err(PaladinRewardsError::IncorrectHolderRewardsEscrowAddress.into());
+    }
+
+    // Create the escrow account the first time auto-claim is enabled. It
+    // holds no data, but is assigned to the program, so rewards can be moved
+    // out of it.
+    if auto_claim_threshold != 0 && !escrow_info.owner.eq(program_id) {
+        let payer_info = payer_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        fund_rent_exemption(Some(payer_info), escrow_info, 0)?;
+
+        invoke_signed(
+            &system_instruction::assign(&escrow_address, program_id),
+            &[escrow_info.clone()],
+            &[&escrow_signer_seeds],
+        )?;
+    }
+
+    // Rewards aren't settled, since auto-claim doesn't change what the
+    // holder earns.
+    holder_rewards_state.auto_claim_threshold = auto_claim_threshold;
+
+    Ok(())
+}
+
+/// Processes a [HarvestEscrowedRewards](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_harvest_escrowed_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let escrow_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
+    let token_account_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+
+    // Run checks on the token account.
+    get_token_account_balance_checked(mint_info.key, token_account_info, false)?;
+
+    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+    let holder_rewards_data = holder_rewards_info.try_borrow_data()?;
+    let holder_rewards_state = unpack_versioned::<HolderRewards>(&holder_rewards_data)?;
+
+    check_holder_rewards_escrow(program_id, token_account_info.key, escrow_info)?;
+
+    // Move the rewards to the token account, or to the holder's rewards
+    // recipient, if one is set.
+    let destination_info = next_rewards_destination_info(
+        program_id,
+        holder_rewards_state,
+        token_account_info,
+        accounts_iter,
+    )?;
+
+    // The escrow account keeps its rent-exempt minimum, so it can continue
+    // to receive rewards.
+    let rent = <Rent as Sysvar>::get()?;
+    let escrowed_rewards = escrow_info
+        .lamports()
+        .saturating_sub(rent.minimum_balance(0));
+
+    if escrowed_rewards != 0 {
+        // Move the amount from the escrow account to the destination.
+        let new_escrow_lamports = escrow_info
+            .lamports()
+            .checked_sub(escrowed_rewards)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let new_destination_lamports = destination_info
+            .lamports()
+            .checked_add(escrowed_rewards)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **escrow_info.try_borrow_mut_lamports()? = new_escrow_lamports;
+        **destination_info.try_borrow_mut_lamports()? = new_destination_lamports;
+    }
+
+    Ok(())
+}
+
+/// Processes an [AddCompoundProgram](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_add_compound_program(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    compound_program: Pubkey,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let pool_authority_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    check_pool_authority(pool_state, pool_authority_info)?;
+
+    // `Pubkey::default()` marks an unused slot, and the rewards program
+    // can't invoke itself.
+    if compound_program == Pubkey::default() || compound_program == *program_id {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Ensure the program isn't already allowed.
+    if pool_state.is_compound_program_allowed(&compound_program) {
+        return Err(PaladinRewardsError::CompoundProgramAlreadyAllowed.into());
+    }
+
+    // Add the program to the first unused slot.
+    let slot = pool_state
+        .compound_programs
+        .iter_mut()
+        .find(|slot| **slot == Pubkey::default())
+        .ok_or(PaladinRewardsError::MaxCompoundProgramsReached)?;
+    *slot = compound_program;
+
+    Ok(())
+}
+
+/// Processes a [RemoveCompoundProgram](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_remove_compound_program(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    compound_program: Pubkey,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let pool_authority_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    check_pool_authority(pool_state, pool_authority_info)?;
+
+    if !pool_state.is_compound_program_allowed(&compound_program) {
+        return Err(PaladinRewardsError::CompoundProgramNotAllowed.into());
+    }
+
+    // Free the program's slot.
+    for slot in pool_state.compound_programs.iter_mut() {
+        if *slot == compound_program {
+            *slot = Pubkey::default();
+        }
+    }
+
+    Ok(())
+}
+
+/// Processes a [HarvestAndCompound](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_harvest_and_compound(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let holder_rewards_info = next_account_info(accounts_iter)?;
     let token_account_info = next_account_info(accounts_iter)?;
     let mint_info = next_account_info(accounts_iter)?;
-    let _system_program = next_account_info(accounts_iter)?;
+    let rewards_owner_info = next_account_info(accounts_iter)?;
+    let compound_program_info = next_account_info(accounts_iter)?;
+    let compound_destination_info = next_account_info(accounts_iter)?;
+    let compound_account_infos = accounts_iter.as_slice();
 
     // Run checks on the token account.
-    {
+    let (token_account_owner, token_account_balance) = {
         let token_account_data = token_account_info.try_borrow_data()?;
         let token_account = StateWithExtensions::<Account>::unpack(&token_account_data)?;
 
@@ -411,141 +3637,205 @@ fn process_initialize_holder_rewards(
         if !token_account.base.mint.eq(mint_info.key) {
             return Err(PaladinRewardsError::TokenAccountMintMismatch.into());
         }
+
+        (token_account.base.owner, token_account.base.amount)
+    };
+
+    // Ensure the rewards owner is a signer.
+    if !rewards_owner_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
     }
 
-    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
-    let pool_data = holder_rewards_pool_info.try_borrow_data()?;
-    let pool_state = bytemuck::try_from_bytes::<HolderRewardsPool>(&pool_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+    let harvested_rewards = {
+        check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+        let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+        let pool_data_len = pool_data.len();
+        let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
 
-    // Initialize the holder rewards account.
-    {
-        let (holder_rewards_address, bump_seed) =
-            get_holder_rewards_address_and_bump_seed(token_account_info.key);
-        let bump_seed = [bump_seed];
-        let holder_rewards_signer_seeds =
-            collect_holder_rewards_signer_seeds(token_account_info.key, &bump_seed);
+        // Ensure the pool authority has allowed the compound program.
+        if !pool_state.is_compound_program_allowed(compound_program_info.key) {
+            return Err(PaladinRewardsError::CompoundProgramNotAllowed.into());
+        }
 
-        // Ensure the provided holder rewards address is the correct address
-        // derived from the token account.
-        if !holder_rewards_info.key.eq(&holder_rewards_address) {
-            return Err(PaladinRewardsError::IncorrectHolderRewardsAddress.into());
+        // Release any rewards vested by the emission schedule.
+        advance_emission_schedule(pool_state, mint_info)?;
+
+        check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
+        let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
+        let holder_rewards_state = unpack_versioned_mut::<HolderRewards>(&mut holder_rewards_data)?;
+        check_holder_rewards_mint(holder_rewards_state, mint_info.key)?;
+
+        // The rewards belong to the holder's rewards recipient, if it has
+        // one, or the token account owner otherwise.
+        if holder_rewards_state.recipient().is_some() {
+            check_rewards_destination(program_id, holder_rewards_state, rewards_owner_info)?;
+        } else if !token_account_owner.eq(rewards_owner_info.key) {
+            return Err(PaladinRewardsError::TokenAccountOwnerMismatch.into());
         }
 
-        // Ensure the holder rewards account has not already been initialized.
-        if holder_rewards_info.data.borrow().len() != 0 {
-            return Err(ProgramError::AccountAlreadyInitialized);
+        // Ensure the rewards are moved into an account of the compound
+        // program, rather than anywhere the rewards owner chooses. This also
+        // keeps them out of the program's own accounts, since the program
+        // can't be allowed as a compound program.
+        if !compound_destination_info
+            .owner
+            .eq(compound_program_info.key)
+        {
+            return Err(PaladinRewardsError::CompoundDestinationOwnerMismatch.into());
         }
 
-        // Allocate & assign.
-        invoke_signed(
-            &system_instruction::allocate(
-                &holder_rewards_address,
-                std::mem::size_of::<HolderRewards>() as u64,
-            ),
-            &[holder_rewards_info.clone()],
-            &[&holder_rewards_signer_seeds],
-        )?;
-        invoke_signed(
-            &system_instruction::assign(&holder_rewards_address, program_id),
-            &[holder_rewards_info.clone()],
-            &[&holder_rewards_signer_seeds],
+        // Determine the amount the holder can harvest, exactly as for
+        // `HarvestRewards`.
+        let eligible_balance = get_eligible_balance(
+            holder_rewards_state.last_seen_balance(),
+            token_account_balance,
+        );
+        settle_holder_rewards(holder_rewards_state, pool_state, eligible_balance)?;
+        set_last_seen_balance(holder_rewards_state, pool_state, token_account_balance)?;
+
+        // Move the unharvested rewards from the holder rewards pool straight
+        // to the compound destination, which the compound program compounds
+        // them from on the rewards owner's behalf.
+        let harvested_rewards = pay_out_rewards(
+            holder_rewards_pool_info,
+            pool_data_len,
+            pool_state,
+            &mut holder_rewards_state.unharvested_rewards,
+            compound_destination_info,
         )?;
 
-        // Write the data.
-        let mut data = holder_rewards_info.try_borrow_mut_data()?;
-        *bytemuck::try_from_bytes_mut(&mut data).map_err(|_| ProgramError::InvalidAccountData)? =
-            HolderRewards::new(pool_state.accumulated_rewards_per_token, 0);
+        PaladinRewardsEvent::RewardsHarvested {
+            holder_rewards: *holder_rewards_info.key,
+            reward_id: 0,
+            destination: *compound_destination_info.key,
+            amount: harvested_rewards,
+            unharvested_rewards: holder_rewards_state.unharvested_rewards,
+        }
+        .emit()?;
+
+        harvested_rewards
+    };
+
+    if harvested_rewards == 0 {
+        return Ok(());
     }
 
+    // Invoke the compound program with the harvested amount, passing the
+    // rewards owner, the compound destination and the holder rewards pool,
+    // followed by the remaining accounts. No account is passed as a signer,
+    // so the compound program can never act with the rewards owner's
+    // authority.
+    let mut compound_account_metas = vec![
+        AccountMeta::new_readonly(*rewards_owner_info.key, false),
+        AccountMeta::new(*compound_destination_info.key, false),
+        AccountMeta::new_readonly(*holder_rewards_pool_info.key, false),
+    ];
+    compound_account_metas.extend(compound_account_infos.iter().map(|info| AccountMeta {
+        pubkey: *info.key,
+        is_signer: false,
+        is_writable: info.is_writable,
+    }));
+    let mut compound_cpi_account_infos = vec![
+        rewards_owner_info.clone(),
+        compound_destination_info.clone(),
+        holder_rewards_pool_info.clone(),
+    ];
+    compound_cpi_account_infos.extend_from_slice(compound_account_infos);
+    compound_cpi_account_infos.push(compound_program_info.clone());
+
+    invoke(
+        &Instruction::new_with_bytes(
+            *compound_program_info.key,
+            &harvested_rewards.to_le_bytes(),
+            compound_account_metas,
+        ),
+        &compound_cpi_account_infos,
+    )
+}
+
+/// Processes a [SetDistributionConfig](enum.PaladinRewardsInstruction.html)
+/// instruction.
+fn process_set_distribution_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    distribution_authority: Pubkey,
+    min_distribution_amount: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let holder_rewards_pool_info = next_account_info(accounts_iter)?;
+    let mint_info = next_account_info(accounts_iter)?;
+    let pool_authority_info = next_account_info(accounts_iter)?;
+
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    check_pool_authority(pool_state, pool_authority_info)?;
+
+    pool_state.distribution_authority = distribution_authority;
+    pool_state.min_distribution_amount = min_distribution_amount;
+
     Ok(())
 }
 
-/// Processes a [HarvestRewards](enum.PaladinRewardsInstruction.html)
+/// Processes a [DepositRewards](enum.PaladinRewardsInstruction.html)
 /// instruction.
-fn process_harvest_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+fn process_deposit_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let holder_rewards_pool_info = next_account_info(accounts_iter)?;
-    let holder_rewards_info = next_account_info(accounts_iter)?;
-    let token_account_info = next_account_info(accounts_iter)?;
     let mint_info = next_account_info(accounts_iter)?;
+    // The distribution authority is followed by a pair of accounts for each
+    // excluded holder, so it's provided if an odd number of accounts remain.
+    let distribution_authority_info = if accounts_iter.len() % 2 == 1 {
+        accounts_iter.next()
+    } else {
+        None
+    };
 
-    // Run checks on the token account.
-    let token_account_balance =
-        get_token_account_balance_checked(mint_info.key, token_account_info, false)?;
+    let token_supply = get_token_supply(mint_info)?;
 
     check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
-    let pool_data = holder_rewards_pool_info.try_borrow_data()?;
-    let pool_state = bytemuck::try_from_bytes::<HolderRewardsPool>(&pool_data)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
 
-    check_holder_rewards(program_id, token_account_info.key, holder_rewards_info)?;
-    let mut holder_rewards_data = holder_rewards_info.try_borrow_mut_data()?;
-    let holder_rewards_state =
-        bytemuck::try_from_bytes_mut::<HolderRewards>(&mut holder_rewards_data)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
+    let distribution = {
+        let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+        let pool_data_len = pool_data.len();
+        let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
 
-    // Determine the amount the holder can harvest.
-    //
-    // This is done by subtracting the `last_accumulated_rewards_per_token`
-    // rate from the pool's current rate, then multiplying by the token account
-    // balance.
-    //
-    // The holder should also be able to harvest any unharvested rewards.
-    let rewards_to_harvest = {
-        // Calculate the eligible rewards from the marginal rate.
-        let eligible_rewards = calculate_eligible_rewards(
-            pool_state.accumulated_rewards_per_token,
-            holder_rewards_state.last_accumulated_rewards_per_token,
-            token_account_balance,
-        )?;
+        reconcile_excluded_supply(program_id, mint_info.key, pool_state, accounts_iter)?;
 
-        // Update the holder rewards state.
-        //
-        // Temporarily update `unharvested_rewards` with the eligible rewards.
-        holder_rewards_state.last_accumulated_rewards_per_token =
-            pool_state.accumulated_rewards_per_token;
-        holder_rewards_state.unharvested_rewards = holder_rewards_state
-            .unharvested_rewards
-            .checked_add(eligible_rewards)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
+        // Release any rewards vested by the emission schedule, so they're
+        // counted as owed to holders rather than deposited.
+        advance_reconciled_emission_schedule(pool_state, mint_info)?;
 
-        // If the pool doesn't have enough lamports to cover the rewards, only
-        // harvest the available lamports. This should never happen, but the check
-        // is a failsafe.
-        let pool_excess_lamports = {
-            let rent = <Rent as Sysvar>::get()?;
-            let rent_exempt_lamports =
-                rent.minimum_balance(std::mem::size_of::<HolderRewardsPool>());
-            holder_rewards_pool_info
-                .lamports()
-                .saturating_sub(rent_exempt_lamports)
-        };
+        // Every reward distributed to the pool but not yet harvested may
+        // still be owed to a holder. Anything above this was credited to the
+        // pool directly, and is deposited.
+        let unharvested_rewards = pool_state
+            .total_distributed
+            .saturating_sub(pool_state.total_harvested);
+        let amount = get_pool_excess_lamports(holder_rewards_pool_info, pool_data_len, pool_state)?
+            .saturating_sub(unharvested_rewards);
 
-        holder_rewards_state
-            .unharvested_rewards
-            .min(pool_excess_lamports)
-    };
+        // With nothing to deposit, there's no distribution to check.
+        if amount == 0 {
+            return Ok(());
+        }
 
-    if rewards_to_harvest != 0 {
-        // Move the amount from the holder rewards pool to the token account.
-        let new_holder_rewards_pool_lamports = holder_rewards_pool_info
-            .lamports()
-            .checked_sub(rewards_to_harvest)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        let new_token_account_lamports = token_account_info
-            .lamports()
-            .checked_add(rewards_to_harvest)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        **holder_rewards_pool_info.try_borrow_mut_lamports()? = new_holder_rewards_pool_lamports;
-        **token_account_info.try_borrow_mut_lamports()? = new_token_account_lamports;
+        check_distribution(pool_state, distribution_authority_info, amount)?;
 
-        // Update the holder's unharvested rewards.
-        holder_rewards_state.unharvested_rewards = holder_rewards_state
-            .unharvested_rewards
-            .checked_sub(rewards_to_harvest)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
+        distribute_with_carryover(pool_state, amount, token_supply)?
+    };
+
+    if let Some((distributed_amount, new_accumulated_rewards_per_token)) = distribution {
+        PaladinRewardsEvent::RewardsDistributed {
+            mint: *mint_info.key,
+            reward_id: 0,
+            amount: distributed_amount,
+            accumulated_rewards_per_token: new_accumulated_rewards_per_token,
+        }
+        .emit()?;
     }
 
     Ok(())
@@ -569,50 +3859,203 @@ pub fn process_spl_transfer_hook_execute(
     let source_holder_rewards_info = next_account_info(accounts_iter)?;
     let destination_holder_rewards_info = next_account_info(accounts_iter)?;
 
-    let current_accumulated_rewards_per_token = {
-        check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
-        let pool_data = holder_rewards_pool_info.try_borrow_data()?;
-        let pool_state = bytemuck::try_from_bytes::<HolderRewardsPool>(&pool_data)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
-        pool_state.accumulated_rewards_per_token
+    // The auto-claim escrow accounts end the list, after any token rewards
+    // pools' accounts. Token rewards pools each add a fixed number of
+    // accounts, so a list ending with the escrow accounts is the only kind
+    // with a remainder. Lists written by earlier versions of the program
+    // don't include them, in which case no rewards are auto-claimed.
+    let escrow_infos = match accounts_iter.len() % TOKEN_REWARDS_EXTRA_METAS_COUNT {
+        0 => None,
+        ESCROW_EXTRA_METAS_COUNT => {
+            let remaining_accounts = accounts_iter.as_slice();
+            let (token_rewards_accounts, escrow_accounts) =
+                remaining_accounts.split_at(remaining_accounts.len() - ESCROW_EXTRA_METAS_COUNT);
+            *accounts_iter = token_rewards_accounts.iter();
+            Some((&escrow_accounts[0], &escrow_accounts[1]))
+        }
+        _ => return Err(ProgramError::NotEnoughAccountKeys),
     };
 
-    // Update the source holder rewards account.
+    check_pool(program_id, mint_info.key, holder_rewards_pool_info)?;
+    let mut pool_data = holder_rewards_pool_info.try_borrow_mut_data()?;
+    let pool_data_len = pool_data.len();
+    let pool_state = unpack_versioned_mut::<HolderRewardsPool>(&mut pool_data)?;
+
+    // Release any rewards vested by the emission schedule before either
+    // holder's balance changes.
+    advance_emission_schedule(pool_state, mint_info)?;
+
+    // Token account balances are updated before transfer hooks are called,
+    // so these token account balances are the balances _after_ the transfer.
+    // See: https://github.com/solana-labs/solana-program-library/blob/3c60545668eafa2294365e2edfb5799c657971c3/token/program-2022/src/processor.rs#L479-L487.
     //
     // For the source - since it was just debited - the transfer amount
     // will be added back to calculate the rewards share before the
     // transfer.
-    update_holder_rewards_for_transfer_hook(
+    //
+    // For the destination - since it was just credited - the transfer
+    // amount will be subtracted to calculate the rewards share before
+    // the transfer.
+    //
+    // Either holder rewards account may not have been initialized yet, in
+    // which case it's skipped.
+    //
+    // A self-transfer leaves the token account's balance unchanged, so its
+    // holder rewards are settled once, against that balance, and the
+    // destination side is skipped.
+    let self_transfer = source_token_account_info.key == destination_token_account_info.key;
+
+    let source_holder_rewards_initialized = check_holder_rewards_for_transfer_hook(
         program_id,
-        mint_info.key,
-        source_token_account_info,
+        source_token_account_info.key,
         source_holder_rewards_info,
-        current_accumulated_rewards_per_token,
-        |amount| {
-            amount
-                .checked_add(transfer_amount)
-                .ok_or(ProgramError::ArithmeticOverflow)
-        },
     )?;
+    let source_balance_after_transfer =
+        get_token_account_balance_checked(mint_info.key, source_token_account_info, true)?;
+    let source_balance_before_transfer = if self_transfer {
+        source_balance_after_transfer
+    } else {
+        source_balance_after_transfer
+            .checked_add(transfer_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+    };
+
+    // Update the source holder rewards account.
+    if source_holder_rewards_initialized {
+        update_holder_rewards_for_transfer_hook(
+            source_holder_rewards_info,
+            pool_state,
+            source_balance_before_transfer,
+            source_balance_after_transfer,
+        )?;
+
+        if let Some((source_escrow_info, _)) = escrow_infos {
+            auto_claim_holder_rewards(
+                program_id,
+                holder_rewards_pool_info,
+                pool_data_len,
+                pool_state,
+                source_holder_rewards_info,
+                source_token_account_info.key,
+                source_escrow_info,
+            )?;
+        }
+    }
 
     // Update the destination holder rewards account.
-    //
-    // For the destination - since it was just credited - the transfer
-    // amount will be subtracted to calculate the rewards share before
-    // the transfer.
-    update_holder_rewards_for_transfer_hook(
+    let destination_balances = if self_transfer {
+        None
+    } else {
+        let destination_holder_rewards_initialized = check_holder_rewards_for_transfer_hook(
+            program_id,
+            destination_token_account_info.key,
+            destination_holder_rewards_info,
+        )?;
+        let destination_balance_after_transfer =
+            get_token_account_balance_checked(mint_info.key, destination_token_account_info, true)?;
+        let destination_balance_before_transfer = destination_balance_after_transfer
+            .checked_sub(transfer_amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        if destination_holder_rewards_initialized {
+            update_holder_rewards_for_transfer_hook(
+                destination_holder_rewards_info,
+                pool_state,
+                destination_balance_before_transfer,
+                destination_balance_after_transfer,
+            )?;
+
+            if let Some((_, destination_escrow_info)) = escrow_infos {
+                auto_claim_holder_rewards(
+                    program_id,
+                    holder_rewards_pool_info,
+                    pool_data_len,
+                    pool_state,
+                    destination_holder_rewards_info,
+                    destination_token_account_info.key,
+                    destination_escrow_info,
+                )?;
+            }
+        }
+
+        Some((
+            destination_balance_before_transfer,
+            destination_balance_after_transfer,
+        ))
+    };
+
+    // Token accounts excluded from the holder rewards pool are excluded
+    // from every token rewards pool too.
+    let source_excluded = is_holder_excluded(
         program_id,
-        mint_info.key,
-        destination_token_account_info,
+        source_token_account_info.key,
+        source_holder_rewards_info,
+    )?;
+    let destination_excluded = is_holder_excluded(
+        program_id,
+        destination_token_account_info.key,
         destination_holder_rewards_info,
-        current_accumulated_rewards_per_token,
-        |amount| {
-            amount
-                .checked_sub(transfer_amount)
-                .ok_or(ProgramError::ArithmeticOverflow)
-        },
     )?;
 
+    // Update the source and destination token holder rewards accounts for
+    // each of the mint's token rewards pools, which are appended to the
+    // extra account metas as (pool, source, destination).
+    while let Some(token_rewards_pool_info) = accounts_iter.next() {
+        let source_token_holder_rewards_info = next_account_info(accounts_iter)?;
+        let destination_token_holder_rewards_info = next_account_info(accounts_iter)?;
+
+        let (reward_mint, current_accumulated_rewards_per_token) = {
+            let pool_data = token_rewards_pool_info.try_borrow_data()?;
+            let pool_state = bytemuck::try_from_bytes::<TokenRewardsPool>(&pool_data)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            (
+                pool_state.reward_mint,
+                pool_state.accumulated_rewards_per_token,
+            )
+        };
+        check_token_rewards_pool(
+            program_id,
+            mint_info.key,
+            &reward_mint,
+            token_rewards_pool_info,
+        )?;
+
+        if check_token_holder_rewards_for_transfer_hook(
+            program_id,
+            source_token_account_info.key,
+            token_rewards_pool_info.key,
+            source_token_holder_rewards_info,
+        )? {
+            update_token_holder_rewards_for_transfer_hook(
+                source_token_holder_rewards_info,
+                current_accumulated_rewards_per_token,
+                source_balance_before_transfer,
+                source_balance_after_transfer,
+                source_excluded,
+            )?;
+        }
+
+        let Some((destination_balance_before_transfer, destination_balance_after_transfer)) =
+            destination_balances
+        else {
+            continue;
+        };
+        if check_token_holder_rewards_for_transfer_hook(
+            program_id,
+            destination_token_account_info.key,
+            token_rewards_pool_info.key,
+            destination_token_holder_rewards_info,
+        )? {
+            update_token_holder_rewards_for_transfer_hook(
+                destination_token_holder_rewards_info,
+                current_accumulated_rewards_per_token,
+                destination_balance_before_transfer,
+                destination_balance_after_transfer,
+                destination_excluded,
+            )?;
+        }
+    }
+
     Ok(())
 }
 
@@ -641,6 +4084,240 @@ pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> P
                 msg!("Instruction: HarvestRewards");
                 process_harvest_rewards(program_id, accounts)
             }
+            PaladinRewardsInstruction::SyncHolderRewards => {
+                msg!("Instruction: SyncHolderRewards");
+                process_sync_holder_rewards(program_id, accounts)
+            }
+            PaladinRewardsInstruction::SetPoolAuthority(new_authority) => {
+                msg!("Instruction: SetPoolAuthority");
+                process_set_pool_authority(program_id, accounts, new_authority)
+            }
+            PaladinRewardsInstruction::AcceptPoolAuthority => {
+                msg!("Instruction: AcceptPoolAuthority");
+                process_accept_pool_authority(program_id, accounts)
+            }
+            PaladinRewardsInstruction::CloseHolderRewards => {
+                msg!("Instruction: CloseHolderRewards");
+                process_close_holder_rewards(program_id, accounts)
+            }
+            PaladinRewardsInstruction::HarvestRewardsToDestination => {
+                msg!("Instruction: HarvestRewardsToDestination");
+                process_harvest_rewards_to_destination(program_id, accounts)
+            }
+            PaladinRewardsInstruction::InitializeTokenRewardsPool => {
+                msg!("Instruction: InitializeTokenRewardsPool");
+                process_initialize_token_rewards_pool(program_id, accounts)
+            }
+            PaladinRewardsInstruction::DistributeTokenRewards(amount) => {
+                msg!("Instruction: DistributeTokenRewards");
+                process_distribute_token_rewards(program_id, accounts, amount)
+            }
+            PaladinRewardsInstruction::InitializeTokenHolderRewards => {
+                msg!("Instruction: InitializeTokenHolderRewards");
+                process_initialize_token_holder_rewards(program_id, accounts)
+            }
+            PaladinRewardsInstruction::HarvestTokenRewards => {
+                msg!("Instruction: HarvestTokenRewards");
+                process_harvest_token_rewards(program_id, accounts)
+            }
+            PaladinRewardsInstruction::AddRewardStream(reward_id) => {
+                msg!("Instruction: AddRewardStream");
+                process_add_reward_stream(program_id, accounts, reward_id)
+            }
+            PaladinRewardsInstruction::DistributeStreamRewards { reward_id, amount } => {
+                msg!("Instruction: DistributeStreamRewards");
+                process_distribute_stream_rewards(program_id, accounts, reward_id, amount)
+            }
+            PaladinRewardsInstruction::HarvestStreamRewards(reward_id) => {
+                msg!("Instruction: HarvestStreamRewards");
+                process_harvest_stream_rewards(program_id, accounts, reward_id)
+            }
+            PaladinRewardsInstruction::CreateEmissionSchedule {
+                amount,
+                start_timestamp,
+                end_timestamp,
+            } => {
+                msg!("Instruction: CreateEmissionSchedule");
+                process_create_emission_schedule(
+                    program_id,
+                    accounts,
+                    amount,
+                    start_timestamp,
+                    end_timestamp,
+                )
+            }
+            PaladinRewardsInstruction::MigrateAccount => {
+                msg!("Instruction: MigrateAccount");
+                process_migrate_account(program_id, accounts)
+            }
+            PaladinRewardsInstruction::SweepDust => {
+                msg!("Instruction: SweepDust");
+                process_sweep_dust(program_id, accounts)
+            }
+            PaladinRewardsInstruction::SetRewardsExclusion(excluded) => {
+                msg!("Instruction: SetRewardsExclusion");
+                process_set_rewards_exclusion(program_id, accounts, excluded)
+            }
+            PaladinRewardsInstruction::SetRewardsRecipient(recipient) => {
+                msg!("Instruction: SetRewardsRecipient");
+                process_set_rewards_recipient(program_id, accounts, recipient)
+            }
+            PaladinRewardsInstruction::HarvestRewardsBatch => {
+                msg!("Instruction: HarvestRewardsBatch");
+                process_harvest_rewards_batch(program_id, accounts)
+            }
+            PaladinRewardsInstruction::InitializeHolderRewardsBatch => {
+                msg!("Instruction: InitializeHolderRewardsBatch");
+                process_initialize_holder_rewards_batch(program_id, accounts)
+            }
+            PaladinRewardsInstruction::SetAutoClaim(auto_claim_threshold) => {
+                msg!("Instruction: SetAutoClaim");
+                process_set_auto_claim(program_id, accounts, auto_claim_threshold)
+            }
+            PaladinRewardsInstruction::HarvestEscrowedRewards => {
+                msg!("Instruction: HarvestEscrowedRewards");
+                process_harvest_escrowed_rewards(program_id, accounts)
+            }
+            PaladinRewardsInstruction::AddCompoundProgram(compound_program) => {
+                msg!("Instruction: AddCompoundProgram");
+                process_add_compound_program(program_id, accounts, compound_program)
+            }
+            PaladinRewardsInstruction::RemoveCompoundProgram(compound_program) => {
+                msg!("Instruction: RemoveCompoundProgram");
+                process_remove_compound_program(program_id, accounts, compound_program)
+            }
+            PaladinRewardsInstruction::HarvestAndCompound => {
+                msg!("Instruction: HarvestAndCompound");
+                process_harvest_and_compound(program_id, accounts)
+            }
+            PaladinRewardsInstruction::SetDistributionConfig {
+                distribution_authority,
+                min_distribution_amount,
+            } => {
+                msg!("Instruction: SetDistributionConfig");
+                process_set_distribution_config(
+                    program_id,
+                    accounts,
+                    distribution_authority,
+                    min_distribution_amount,
+                )
+            }
+            PaladinRewardsInstruction::DepositRewards => {
+                msg!("Instruction: DepositRewards");
+                process_deposit_rewards(program_id, accounts)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, proptest::prelude::*};
+
+    #[test]
+    fn test_small_distribution_to_large_supply() {
+        // A single lamport distributed over a supply of 1e18 base units still
+        // moves the rate.
+        let mut pool_state = HolderRewardsPool::default();
+        distribute_to_base_rate(&mut pool_state, 1, 1_000_000_000_000_000_000).unwrap();
+        assert_eq!(pool_state.accumulated_rewards_per_token, 1);
+        assert_eq!(pool_state.rewards_per_token_remainder, 0);
+
+        let eligible_rewards = calculate_eligible_rewards(
+            pool_state.accumulated_rewards_per_token,
+            0,
+            1_000_000_000_000_000_000,
+        )
+        .unwrap();
+        assert_eq!(eligible_rewards, 1);
+    }
+
+    proptest! {
+        #[test]
+        fn test_calculate_eligible_rewards_rounds_down(
+            marginal_rate in 0..u64::MAX as u128 * REWARDS_PER_TOKEN_SCALING_FACTOR,
+            token_account_balance in 0..=u64::MAX,
+        ) {
+            let eligible_rewards = calculate_eligible_rewards(
+                marginal_rate,
+                0,
+                token_account_balance,
+            );
+
+            // Compare against the exact product, split into high and low
+            // halves to fit in a `u128`.
+            let balance = token_account_balance as u128;
+            let rate_high = marginal_rate >> 64;
+            let rate_low = marginal_rate & u64::MAX as u128;
+            let product_low = rate_low * balance;
+            let product_high = rate_high * balance + (product_low >> 64);
+            let exact = product_high
+                .checked_mul(1 << 64)
+                .and_then(|high| high.checked_add(product_low & u64::MAX as u128))
+                .map(|product| product / REWARDS_PER_TOKEN_SCALING_FACTOR);
+
+            match exact {
+                Some(exact) if exact <= u64::MAX as u128 => {
+                    prop_assert_eq!(eligible_rewards, Ok(exact as u64));
+                }
+                _ => prop_assert!(eligible_rewards.is_err()),
+            }
+        }
+
+        #[test]
+        fn test_eligible_rewards_never_exceed_distributed(
+            balances in prop::collection::vec(0..=u64::MAX / 8, 1..8),
+            unheld_supply in 0..=u64::MAX / 8,
+            distributions in prop::collection::vec(
+                (0..=1_000_000_000_000_000u64, prop::collection::vec(any::<bool>(), 8)),
+                1..16,
+            ),
+        ) {
+            let token_supply = balances.iter().sum::<u64>() + unheld_supply;
+            prop_assume!(token_supply != 0);
+
+            let mut pool_state = HolderRewardsPool::default();
+            let mut holders = vec![HolderRewards::default(); balances.len()];
+            let mut total_distributed = 0u128;
+            let mut settlement_count = 0u128;
+
+            for (amount, settles) in distributions {
+                distribute_to_base_rate(&mut pool_state, amount, token_supply).unwrap();
+                total_distributed += amount as u128;
+
+                // Holders settle at arbitrary points between distributions.
+                for ((holder, balance), settles) in
+                    holders.iter_mut().zip(balances.iter()).zip(settles)
+                {
+                    if settles {
+                        settle_holder_rewards(holder, &mut pool_state, *balance).unwrap();
+                        settlement_count += 1;
+                    }
+                }
+            }
+
+            for (holder, balance) in holders.iter_mut().zip(balances.iter()) {
+                settle_holder_rewards(holder, &mut pool_state, *balance).unwrap();
+                settlement_count += 1;
+            }
+
+            let total_eligible = holders
+                .iter()
+                .map(|holder| holder.unharvested_rewards as u128)
+                .sum::<u128>();
+
+            // Holders are never owed more than was distributed, even
+            // counting the dust truncated from their rewards.
+            prop_assert!(total_eligible + pool_state.dust as u128 <= total_distributed);
+
+            // Rewards are only lost to rounding: less than a lamport per
+            // settlement, plus the remainder of the rate. Rewards for the
+            // supply held by no holder are never owed.
+            let held_supply = (token_supply - unheld_supply) as u128;
+            let owed = total_distributed * held_supply / token_supply as u128;
+            let remainder_rewards =
+                (token_supply as u128).div_ceil(REWARDS_PER_TOKEN_SCALING_FACTOR);
+            prop_assert!(total_eligible + settlement_count + remainder_rewards >= owed);
         }
     }
 }
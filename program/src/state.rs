@@ -19,7 +19,7 @@
 //! Consider the following scenario.
 //!
 //! ```text
-//! 
+//!
 //! -- Legend --
 //!
 //!     `rewards_per_share`:    Total rewards / token supply.
@@ -144,8 +144,8 @@
 
 use {
     bytemuck::{Pod, Zeroable},
-    shank::ShankAccount,
-    solana_program::pubkey::Pubkey,
+    shank::{ShankAccount, ShankType},
+    solana_program::{program_error::ProgramError, pubkey::Pubkey},
 };
 
 /// The seed prefix (`"holder"`) in bytes used to derive the address of a
@@ -156,6 +156,35 @@ pub const SEED_PREFIX_HOLDER_REWARDS: &[u8] = b"holder";
 /// the mint's holder rewards pool account.
 /// Seeds: `"holder_pool" + mint_address`.
 pub const SEED_PREFIX_HOLDER_REWARDS_POOL: &[u8] = b"holder_pool";
+/// The seed prefix (`"token_pool"`) in bytes used to derive the address of
+/// the mint's token rewards pool account for a reward mint.
+/// Seeds: `"token_pool" + mint_address + reward_mint_address`.
+pub const SEED_PREFIX_TOKEN_REWARDS_POOL: &[u8] = b"token_pool";
+/// The seed prefix (`"token_holder"`) in bytes used to derive the address of
+/// a token account's holder rewards account for a token rewards pool.
+/// Seeds: `"token_holder" + token_account_address + token_rewards_pool_address`.
+pub const SEED_PREFIX_TOKEN_HOLDER_REWARDS: &[u8] = b"token_holder";
+/// The seed prefix (`"holder_escrow"`) in bytes used to derive the address of
+/// a token account's auto-claim escrow account.
+/// Seeds: `"holder_escrow" + token_account_address`.
+pub const SEED_PREFIX_HOLDER_REWARDS_ESCROW: &[u8] = b"holder_escrow";
+
+/// The maximum number of token rewards pools that can be configured for a
+/// mint.
+///
+/// Each token rewards pool adds three accounts to every transfer of the mint,
+/// so this is bounded by the transaction account limit.
+pub const MAX_TOKEN_REWARDS_POOLS: usize = 4;
+
+/// The maximum number of reward streams a holder rewards pool can track.
+pub const MAX_REWARD_STREAMS: usize = 4;
+
+/// The maximum number of compound programs a holder rewards pool can allow.
+pub const MAX_COMPOUND_PROGRAMS: usize = 4;
+
+/// The length of the [AccountHeader](struct.AccountHeader.html) prefixed to
+/// the data of each versioned account.
+pub const ACCOUNT_HEADER_LEN: usize = std::mem::size_of::<AccountHeader>();
 
 /// Derive the address of a holder rewards account.
 pub fn get_holder_rewards_address(token_account_address: &Pubkey) -> Pubkey {
@@ -185,6 +214,40 @@ pub(crate) fn collect_holder_rewards_signer_seeds<'a>(
     ]
 }
 
+/// Derive the address of a token account's auto-claim escrow account.
+pub fn get_holder_rewards_escrow_address(token_account_address: &Pubkey) -> Pubkey {
+    get_holder_rewards_escrow_address_and_bump_seed(token_account_address).0
+}
+
+/// Derive the address of a token account's auto-claim escrow account, with
+/// bump seed.
+pub fn get_holder_rewards_escrow_address_and_bump_seed(
+    token_account_address: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_holder_rewards_escrow_seeds(token_account_address),
+        &crate::id(),
+    )
+}
+
+pub(crate) fn collect_holder_rewards_escrow_seeds(token_account_address: &Pubkey) -> [&[u8]; 2] {
+    [
+        SEED_PREFIX_HOLDER_REWARDS_ESCROW,
+        token_account_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_holder_rewards_escrow_signer_seeds<'a>(
+    token_account_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_HOLDER_REWARDS_ESCROW,
+        token_account_address.as_ref(),
+        bump_seed,
+    ]
+}
+
 /// Derive the address of a holder rewards pool account.
 pub fn get_holder_rewards_pool_address(mint_address: &Pubkey) -> Pubkey {
     get_holder_rewards_pool_address_and_bump_seed(mint_address).0
@@ -213,6 +276,127 @@ pub(crate) fn collect_holder_rewards_pool_signer_seeds<'a>(
     ]
 }
 
+/// Derive the address of a token rewards pool account.
+pub fn get_token_rewards_pool_address(
+    mint_address: &Pubkey,
+    reward_mint_address: &Pubkey,
+) -> Pubkey {
+    get_token_rewards_pool_address_and_bump_seed(mint_address, reward_mint_address).0
+}
+
+/// Derive the address of a token rewards pool account, with bump seed.
+pub fn get_token_rewards_pool_address_and_bump_seed(
+    mint_address: &Pubkey,
+    reward_mint_address: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_token_rewards_pool_seeds(mint_address, reward_mint_address),
+        &crate::id(),
+    )
+}
+
+pub(crate) fn collect_token_rewards_pool_seeds<'a>(
+    mint_address: &'a Pubkey,
+    reward_mint_address: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_TOKEN_REWARDS_POOL,
+        mint_address.as_ref(),
+        reward_mint_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_token_rewards_pool_signer_seeds<'a>(
+    mint_address: &'a Pubkey,
+    reward_mint_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 4] {
+    [
+        SEED_PREFIX_TOKEN_REWARDS_POOL,
+        mint_address.as_ref(),
+        reward_mint_address.as_ref(),
+        bump_seed,
+    ]
+}
+
+/// Derive the address of a token rewards pool's vault, which is the token
+/// rewards pool's associated token account for the reward mint.
+pub fn get_token_rewards_vault_address(
+    token_rewards_pool_address: &Pubkey,
+    reward_mint_address: &Pubkey,
+    reward_token_program_id: &Pubkey,
+) -> Pubkey {
+    spl_associated_token_account::get_associated_token_address_with_program_id(
+        token_rewards_pool_address,
+        reward_mint_address,
+        reward_token_program_id,
+    )
+}
+
+/// Derive the address of a token holder rewards account.
+pub fn get_token_holder_rewards_address(
+    token_account_address: &Pubkey,
+    token_rewards_pool_address: &Pubkey,
+) -> Pubkey {
+    get_token_holder_rewards_address_and_bump_seed(
+        token_account_address,
+        token_rewards_pool_address,
+    )
+    .0
+}
+
+/// Derive the address of a token holder rewards account, with bump seed.
+pub fn get_token_holder_rewards_address_and_bump_seed(
+    token_account_address: &Pubkey,
+    token_rewards_pool_address: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &collect_token_holder_rewards_seeds(token_account_address, token_rewards_pool_address),
+        &crate::id(),
+    )
+}
+
+pub(crate) fn collect_token_holder_rewards_seeds<'a>(
+    token_account_address: &'a Pubkey,
+    token_rewards_pool_address: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        SEED_PREFIX_TOKEN_HOLDER_REWARDS,
+        token_account_address.as_ref(),
+        token_rewards_pool_address.as_ref(),
+    ]
+}
+
+pub(crate) fn collect_token_holder_rewards_signer_seeds<'a>(
+    token_account_address: &'a Pubkey,
+    token_rewards_pool_address: &'a Pubkey,
+    bump_seed: &'a [u8],
+) -> [&'a [u8]; 4] {
+    [
+        SEED_PREFIX_TOKEN_HOLDER_REWARDS,
+        token_account_address.as_ref(),
+        token_rewards_pool_address.as_ref(),
+        bump_seed,
+    ]
+}
+
+/// A holder's checkpoint for a single reward stream on the holder rewards
+/// pool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankType, Zeroable)]
+#[repr(C)]
+pub struct RewardStreamCheckpoint {
+    /// The stream's rewards per token exchange rate when this holder last
+    /// harvested.
+    ///
+    /// Stored as a `u128`, which includes a scaling factor of `1e18` to
+    /// represent the exchange rate with 18 decimal places of precision.
+    pub last_accumulated_rewards_per_token: u128,
+    /// The amount of unharvested rewards from the stream that can be
+    /// harvested by the holder.
+    pub unharvested_rewards: u64,
+    pub _padding: [u8; 8],
+}
+
 /// A holder rewards account which tracks the rewards accumulated by a holder
 /// of tokens.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankAccount, Zeroable)]
@@ -220,24 +404,203 @@ pub(crate) fn collect_holder_rewards_pool_signer_seeds<'a>(
 pub struct HolderRewards {
     /// The rewards per token exchange rate when this holder last harvested.
     ///
-    /// Stored as a `u128`, which includes a scaling factor of `1e9` to
-    /// represent the exchange rate with 9 decimal places of precision.
+    /// Stored as a `u128`, which includes a scaling factor of `1e18` to
+    /// represent the exchange rate with 18 decimal places of precision.
     pub last_accumulated_rewards_per_token: u128,
     /// The amount of unharvested rewards currently stored in the holder
     /// rewards account that can be harvested by the holder.
     pub unharvested_rewards: u64,
-    _padding: u64,
+    /// The token account balance this holder was last settled at.
+    ///
+    /// Token-2022 does not invoke the transfer hook on `MintTo` or `Burn`,
+    /// so this may differ from the token account's current balance until the
+    /// holder rewards account is synced. When it does, rewards are only paid
+    /// on the lesser of the two balances.
+    pub last_seen_balance: u64,
+    /// The holder's checkpoints for each of the pool's reward streams,
+    /// indexed by the stream's slot on the pool.
+    ///
+    /// Shank requires a literal length, which must match
+    /// `MAX_REWARD_STREAMS`.
+    pub reward_stream_checkpoints: [RewardStreamCheckpoint; 4],
+    /// The smallest amount of unharvested SOL rewards the transfer hook pays
+    /// out to the holder's auto-claim escrow account, as set by the token
+    /// account owner.
+    ///
+    /// `0` if auto-claim is disabled. Rewards paid to the escrow are later
+    /// harvested to the token account, or to the holder's rewards recipient,
+    /// with `HarvestEscrowedRewards`.
+    pub auto_claim_threshold: u64,
+    /// The account SOL rewards are harvested to in place of the token
+    /// account, as set by the token account owner.
+    ///
+    /// `Pubkey::default()` if rewards are harvested to the token account.
+    pub recipient: Pubkey,
+    /// The mint of the token account.
+    ///
+    /// The holder rewards address is derived from the token account alone,
+    /// so this ties the holder to its mint's pools once the token account
+    /// has been closed.
+    pub mint: Pubkey,
+    /// The owner of the token account when the holder rewards account was
+    /// initialized, migrated or last synced.
+    ///
+    /// Token-2022 does not invoke the transfer hook on `SetAuthority`, so
+    /// this may differ from the token account's current owner until the
+    /// holder rewards account is synced. Once the token account has been
+    /// closed, the holder rewards account's rent is returned to it, unless
+    /// the holder has a rewards recipient.
+    pub owner: Pubkey,
+    /// Whether the token account is excluded from earning rewards from the
+    /// holder rewards pool and the mint's token rewards pools, as set by the
+    /// pool authority.
+    ///
+    /// `1` if excluded, `0` otherwise. While excluded, the balance the holder
+    /// was last settled at is counted in the pool's `excluded_supply`, and
+    /// the holder earns nothing.
+    pub excluded: u8,
+    /// Whether the token account balance this holder was last settled at is
+    /// unknown, as for holders migrated from the original layout, which
+    /// didn't record it.
+    ///
+    /// `1` if unknown, `0` otherwise. An unknown balance is never used to
+    /// settle the holder. It's settled on its current token account balance
+    /// instead, after which the balance is known.
+    pub last_seen_balance_unknown: u8,
+    /// Pads the state to a multiple of its `u128` alignment. Always zero.
+    pub _padding: [u8; 6],
 }
 impl HolderRewards {
-    pub fn new(last_accumulated_rewards_per_token: u128, unharvested_rewards: u64) -> Self {
+    pub fn new(
+        last_accumulated_rewards_per_token: u128,
+        unharvested_rewards: u64,
+        last_seen_balance: u64,
+    ) -> Self {
+        Self {
+            last_accumulated_rewards_per_token,
+            unharvested_rewards,
+            last_seen_balance,
+            reward_stream_checkpoints: [RewardStreamCheckpoint::default(); MAX_REWARD_STREAMS],
+            auto_claim_threshold: 0,
+            recipient: Pubkey::default(),
+            mint: Pubkey::default(),
+            owner: Pubkey::default(),
+            excluded: 0,
+            last_seen_balance_unknown: 0,
+            _padding: [0; 6],
+        }
+    }
+
+    /// Returns whether the token account is excluded from earning rewards.
+    pub fn is_excluded(&self) -> bool {
+        self.excluded != 0
+    }
+
+    /// Returns the token account balance this holder was last settled at,
+    /// if it's known.
+    pub fn last_seen_balance(&self) -> Option<u64> {
+        (self.last_seen_balance_unknown == 0).then_some(self.last_seen_balance)
+    }
+
+    /// Returns the account harvested rewards are paid to in place of the
+    /// token account, if one has been set.
+    pub fn recipient(&self) -> Option<&Pubkey> {
+        (self.recipient != Pubkey::default()).then_some(&self.recipient)
+    }
+
+    /// Returns the smallest amount of unharvested rewards the transfer hook
+    /// pays out to the holder's auto-claim escrow account, if auto-claim is
+    /// enabled.
+    pub fn auto_claim_threshold(&self) -> Option<u64> {
+        (self.auto_claim_threshold != 0).then_some(self.auto_claim_threshold)
+    }
+}
+
+/// A token holder rewards account which tracks the rewards accumulated by a
+/// holder of tokens from a single token rewards pool.
+///
+/// Rewards are denominated in the reward mint's base units rather than
+/// lamports.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankAccount, Zeroable)]
+#[repr(C)]
+pub struct TokenHolderRewards {
+    /// The rewards per token exchange rate when this holder last harvested.
+    ///
+    /// Stored as a `u128`, which includes a scaling factor of `1e18` to
+    /// represent the exchange rate with 18 decimal places of precision.
+    pub last_accumulated_rewards_per_token: u128,
+    /// The amount of unharvested rewards currently stored in the token holder
+    /// rewards account that can be harvested by the holder.
+    pub unharvested_rewards: u64,
+    /// The token account balance this holder was last settled at.
+    pub last_seen_balance: u64,
+}
+impl TokenHolderRewards {
+    pub fn new(
+        last_accumulated_rewards_per_token: u128,
+        unharvested_rewards: u64,
+        last_seen_balance: u64,
+    ) -> Self {
         Self {
             last_accumulated_rewards_per_token,
             unharvested_rewards,
-            _padding: 0,
+            last_seen_balance,
         }
     }
 }
 
+/// A reward stream on the holder rewards pool, which accumulates rewards from
+/// a single source separately from the pool's other rewards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankType, Zeroable)]
+#[repr(C)]
+pub struct RewardStream {
+    /// The stream's current rewards per token exchange rate.
+    ///
+    /// Stored as a `u128`, which includes a scaling factor of `1e18` to
+    /// represent the exchange rate with 18 decimal places of precision.
+    pub accumulated_rewards_per_token: u128,
+    /// The identifier of the stream's source of rewards, chosen by the pool
+    /// authority.
+    ///
+    /// `0` if the slot is unused.
+    pub reward_id: u64,
+    /// The total amount of rewards ever distributed to the stream.
+    pub total_distributed: u64,
+    /// The remainder truncated from the rewards per token calculation of
+    /// the most recent distribution to the stream.
+    ///
+    /// Scaled by `1e18`, like the exchange rate, and always less than the
+    /// token supply at the time. It's added to the next distribution to the
+    /// stream, so truncated rewards are never stranded in the pool.
+    pub rewards_per_token_remainder: u64,
+    pub _padding: [u8; 8],
+}
+
+/// A schedule which releases an escrowed budget of rewards to the holder
+/// rewards pool linearly over a range of timestamps.
+///
+/// The pool's rate is advanced lazily, whenever an instruction that reads it
+/// is processed, by the amount released since it was last advanced.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankType, Zeroable)]
+#[repr(C)]
+pub struct EmissionSchedule {
+    /// The total amount of rewards escrowed for the schedule.
+    pub total_amount: u64,
+    /// The amount of rewards released to the pool's rate so far.
+    pub released_amount: u64,
+    /// The timestamp at which rewards start being released.
+    pub start_timestamp: i64,
+    /// The timestamp at which all rewards have been released.
+    pub end_timestamp: i64,
+}
+impl EmissionSchedule {
+    /// Returns the amount of escrowed rewards that have not yet been
+    /// released to the pool's rate.
+    pub fn unreleased_amount(&self) -> u64 {
+        self.total_amount.saturating_sub(self.released_amount)
+    }
+}
+
 /// Tracks the rewards accumulated by the system and manages the distribution
 /// of rewards to holders.
 ///
@@ -247,7 +610,339 @@ impl HolderRewards {
 pub struct HolderRewardsPool {
     /// The current rewards per token exchange rate.
     ///
-    /// Stored as a `u128`, which includes a scaling factor of `1e9` to
-    /// represent the exchange rate with 9 decimal places of precision.
+    /// Stored as a `u128`, which includes a scaling factor of `1e18` to
+    /// represent the exchange rate with 18 decimal places of precision.
+    pub accumulated_rewards_per_token: u128,
+    /// The authority permitted to manage the pool.
+    ///
+    /// Defaults to the mint authority that initialized the pool.
+    pub authority: Pubkey,
+    /// The authority nominated to replace the current pool authority.
+    ///
+    /// The nominated authority must accept the role before it takes effect.
+    /// `Pubkey::default()` if no authority has been nominated.
+    pub pending_authority: Pubkey,
+    /// Reward streams tracked separately from the pool's base rate.
+    ///
+    /// Streams are added to the first unused slot and are never removed, so
+    /// a slot always refers to the same stream.
+    ///
+    /// Shank requires a literal length, which must match
+    /// `MAX_REWARD_STREAMS`.
+    pub reward_streams: [RewardStream; 4],
+    /// The schedule releasing escrowed rewards to the pool's base rate.
+    ///
+    /// Escrowed rewards are stored on this account alongside the rewards
+    /// ready to be distributed, but can't be harvested until released.
+    pub emission_schedule: EmissionSchedule,
+    /// The total rewards made available to holders by the pool, across the
+    /// base rate and all reward streams, including rewards released by the
+    /// emission schedule.
+    pub total_distributed: u64,
+    /// The total rewards paid out to holders by the pool.
+    pub total_harvested: u64,
+    /// The number of distributions made to the pool, across the base rate
+    /// and all reward streams.
+    pub distribution_count: u64,
+    /// The slot of the most recent distribution to the pool.
+    pub last_distribution_slot: u64,
+    /// Rewards distributed to the pool's base rate while the token supply
+    /// was zero.
+    ///
+    /// With no holders to distribute them to, these are held until the next
+    /// distribution made while the token supply is non-zero, which adds
+    /// them to its amount.
+    pub undistributed_carryover: u64,
+    /// The remainder truncated from the rewards per token calculation of
+    /// the most recent distribution to the pool's base rate.
+    ///
+    /// Scaled by `1e18`, like the exchange rate, and always less than the
+    /// token supply at the time. It's added to the next distribution to the
+    /// base rate, so truncated rewards are never stranded in the pool.
+    pub rewards_per_token_remainder: u64,
+    /// The total balance of the token accounts excluded from earning
+    /// rewards, as of when each was last settled.
+    ///
+    /// This is subtracted from the token supply when distributing to the
+    /// pool's base rate or reward streams, or to the mint's token rewards
+    /// pools, so excluded balances don't dilute the rewards of other
+    /// holders. Each excluded holder is settled at its current balance
+    /// first, so it's never stale.
+    pub excluded_supply: u64,
+    /// The number of holders excluded from earning rewards, each of which
+    /// must be provided to instructions that distribute to the pool.
+    pub excluded_holders: u64,
+    /// Rewards truncated from holders' settled rewards, in whole lamports,
+    /// that haven't yet been swept with `SweepDust`.
+    ///
+    /// A holder's checkpoint advances past any rewards truncated when it's
+    /// settled, so these can never be claimed by any holder.
+    pub dust: u64,
+    /// The programs holders may compound their harvested rewards into with
+    /// `HarvestAndCompound`, managed by the pool authority.
+    ///
+    /// `Pubkey::default()` if the slot is unused.
+    ///
+    /// Shank requires a literal length, which must match
+    /// `MAX_COMPOUND_PROGRAMS`.
+    pub compound_programs: [Pubkey; 4],
+    /// The only account allowed to distribute rewards to the pool's base
+    /// rate or reward streams with `DistributeRewards` or
+    /// `DistributeStreamRewards`, managed by the pool authority.
+    ///
+    /// `Pubkey::default()` if anyone may distribute.
+    pub distribution_authority: Pubkey,
+    /// The smallest amount that may be distributed to the pool's base rate
+    /// or reward streams with `DistributeRewards` or
+    /// `DistributeStreamRewards`, managed by the pool authority.
+    pub min_distribution_amount: u64,
+    /// The fraction of a lamport truncated from holders' settled rewards
+    /// that hasn't yet added up to a whole lamport of `dust`.
+    ///
+    /// Scaled by `1e18`, like the exchange rate, and always less than `1e18`.
+    pub dust_remainder: u64,
+}
+impl HolderRewardsPool {
+    pub fn new(accumulated_rewards_per_token: u128, authority: Pubkey) -> Self {
+        Self {
+            accumulated_rewards_per_token,
+            authority,
+            pending_authority: Pubkey::default(),
+            reward_streams: [RewardStream::default(); MAX_REWARD_STREAMS],
+            emission_schedule: EmissionSchedule::default(),
+            total_distributed: 0,
+            total_harvested: 0,
+            distribution_count: 0,
+            last_distribution_slot: 0,
+            undistributed_carryover: 0,
+            rewards_per_token_remainder: 0,
+            excluded_supply: 0,
+            excluded_holders: 0,
+            dust: 0,
+            compound_programs: [Pubkey::default(); MAX_COMPOUND_PROGRAMS],
+            distribution_authority: Pubkey::default(),
+            min_distribution_amount: 0,
+            dust_remainder: 0,
+        }
+    }
+
+    /// Returns the slot of the reward stream with the provided ID, if the
+    /// stream exists.
+    pub fn find_reward_stream(&self, reward_id: u64) -> Option<usize> {
+        if reward_id == 0 {
+            return None;
+        }
+        self.reward_streams
+            .iter()
+            .position(|stream| stream.reward_id == reward_id)
+    }
+
+    /// Returns the only account allowed to distribute rewards to the pool's
+    /// base rate, if one has been set.
+    pub fn distribution_authority(&self) -> Option<&Pubkey> {
+        (self.distribution_authority != Pubkey::default()).then_some(&self.distribution_authority)
+    }
+
+    /// Returns whether the provided program is allowed as a compound
+    /// program.
+    pub fn is_compound_program_allowed(&self, program_id: &Pubkey) -> bool {
+        *program_id != Pubkey::default() && self.compound_programs.contains(program_id)
+    }
+}
+
+/// Tracks the rewards accumulated by the system for a single SPL token reward
+/// mint and manages the distribution of those rewards to holders.
+///
+/// All rewards ready to be distributed are stored in the pool's vault, which
+/// is the pool's associated token account for the reward mint.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankAccount, Zeroable)]
+#[repr(C)]
+pub struct TokenRewardsPool {
+    /// The current rewards per token exchange rate.
+    ///
+    /// Stored as a `u128`, which includes a scaling factor of `1e18` to
+    /// represent the exchange rate with 18 decimal places of precision.
     pub accumulated_rewards_per_token: u128,
+    /// The mint of the tokens distributed by this pool.
+    pub reward_mint: Pubkey,
+    /// The token program that owns the reward mint.
+    pub reward_token_program: Pubkey,
+    /// The remainder truncated from the rewards per token calculation of
+    /// the most recent distribution to the pool.
+    ///
+    /// Scaled by `1e18`, like the exchange rate, and always less than the
+    /// token supply at the time. It's added to the next distribution, so
+    /// truncated rewards are never stranded in the vault.
+    pub rewards_per_token_remainder: u64,
+    pub _padding: [u8; 8],
+}
+impl TokenRewardsPool {
+    pub fn new(
+        accumulated_rewards_per_token: u128,
+        reward_mint: Pubkey,
+        reward_token_program: Pubkey,
+    ) -> Self {
+        Self {
+            accumulated_rewards_per_token,
+            reward_mint,
+            reward_token_program,
+            rewards_per_token_remainder: 0,
+            _padding: [0; 8],
+        }
+    }
+}
+
+/// The header prefixed to the data of each versioned account, identifying
+/// the account's type and the version of its layout.
+///
+/// Accounts created before accounts were versioned have no header, and their
+/// data is the unversioned state alone. These are treated as version `0`,
+/// and their state is a prefix of that of version `1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Pod, ShankType, Zeroable)]
+#[repr(C)]
+pub struct AccountHeader {
+    /// Identifies the account's type.
+    pub discriminator: [u8; 8],
+    /// The version of the account's layout.
+    pub version: u8,
+    pub _padding: [u8; 7],
+}
+
+/// State stored behind an [AccountHeader](struct.AccountHeader.html).
+pub trait VersionedAccount: Pod {
+    /// The discriminator identifying the account's type.
+    const DISCRIMINATOR: [u8; 8];
+    /// The current version of the account's layout.
+    const VERSION: u8;
+    /// The length of the account's data in the current layout, including
+    /// the header.
+    const LEN: usize = ACCOUNT_HEADER_LEN + std::mem::size_of::<Self>();
+    /// The length of the account's state in the program's original layout,
+    /// which has no header.
+    ///
+    /// The original layout's state is a prefix of the current state, but
+    /// scales exchange rates by `1e9` rather than `1e18`.
+    const LEGACY_STATE_LEN: usize;
+
+    /// Returns the header for the current layout.
+    fn header() -> AccountHeader {
+        AccountHeader {
+            discriminator: Self::DISCRIMINATOR,
+            version: Self::VERSION,
+            _padding: [0; 7],
+        }
+    }
+}
+
+impl VersionedAccount for HolderRewardsPool {
+    const DISCRIMINATOR: [u8; 8] = *b"hldrpool";
+    /// Version `1` is the first versioned layout, with every field of
+    /// [HolderRewardsPool](struct.HolderRewardsPool.html).
+    const VERSION: u8 = 1;
+    /// The original layout stored only `accumulated_rewards_per_token`.
+    const LEGACY_STATE_LEN: usize = 16;
+}
+
+impl VersionedAccount for HolderRewards {
+    const DISCRIMINATOR: [u8; 8] = *b"hldrrwds";
+    /// Version `1` is the first versioned layout, with every field of
+    /// [HolderRewards](struct.HolderRewards.html). It extends the original
+    /// layout with the last seen balance, the reward stream checkpoints, the
+    /// auto-claim threshold, the rewards recipient, the token account's mint
+    /// and owner, and the exclusion and unknown balance flags, in that
+    /// order.
+    const VERSION: u8 = 1;
+    /// The original layout stored only `last_accumulated_rewards_per_token`
+    /// and `unharvested_rewards`, followed by padding in place of
+    /// `last_seen_balance`.
+    const LEGACY_STATE_LEN: usize = 32;
+}
+
+/// Returns the offset of a versioned account's state within its data,
+/// accepting only the current layout.
+fn get_versioned_state_offset<T: VersionedAccount>(data: &[u8]) -> Result<usize, ProgramError> {
+    if data.len() != T::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Ensure the header identifies the expected account type and layout.
+    let header = bytemuck::try_from_bytes::<AccountHeader>(&data[..ACCOUNT_HEADER_LEN])
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if header != &T::header() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(ACCOUNT_HEADER_LEN)
+}
+
+/// Returns whether a versioned account's data is in the program's original
+/// layout, which must be migrated to the current layout.
+pub fn is_legacy_state<T: VersionedAccount>(data: &[u8]) -> bool {
+    data.len() == T::LEGACY_STATE_LEN
+}
+
+/// Returns the version of a versioned account's layout and the offset of its
+/// state within its data, accepting the current layout or the original one.
+///
+/// Accounts in the original layout are returned as version `0`.
+fn get_any_versioned_state_offset<T: VersionedAccount>(
+    data: &[u8],
+) -> Result<(u8, usize), ProgramError> {
+    if is_legacy_state::<T>(data) {
+        return Ok((0, 0));
+    }
+    let offset = get_versioned_state_offset::<T>(data)?;
+    Ok((T::VERSION, offset))
+}
+
+/// Unpacks a copy of a versioned account's state from its data, accepting
+/// the current layout or the original one, alongside the version of the
+/// layout it was read from.
+///
+/// Fields appended since the original layout are zero-filled, and its
+/// exchange rates are returned as stored, scaled by `1e9`.
+pub fn unpack_versioned_prefix<T: VersionedAccount>(data: &[u8]) -> Result<(u8, T), ProgramError> {
+    let (version, offset) = get_any_versioned_state_offset::<T>(data)?;
+    let state_data = &data[offset..];
+    let mut state = T::zeroed();
+    bytemuck::bytes_of_mut(&mut state)[..state_data.len()].copy_from_slice(state_data);
+    Ok((version, state))
+}
+
+/// Packs a versioned account's state into its data in the layout the data
+/// is already in, dropping any fields appended since.
+pub fn pack_versioned_prefix<T: VersionedAccount>(
+    data: &mut [u8],
+    state: &T,
+) -> Result<(), ProgramError> {
+    let (_, offset) = get_any_versioned_state_offset::<T>(data)?;
+    let state_data = &mut data[offset..];
+    let state_len = state_data.len();
+    state_data.copy_from_slice(&bytemuck::bytes_of(state)[..state_len]);
+    Ok(())
+}
+
+/// Unpacks a versioned account's state from its data, accepting only the
+/// current layout.
+pub fn unpack_versioned<T: VersionedAccount>(data: &[u8]) -> Result<&T, ProgramError> {
+    let offset = get_versioned_state_offset::<T>(data)?;
+    bytemuck::try_from_bytes(&data[offset..]).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Mutably unpacks a versioned account's state from its data, accepting only
+/// the current layout.
+pub fn unpack_versioned_mut<T: VersionedAccount>(data: &mut [u8]) -> Result<&mut T, ProgramError> {
+    let offset = get_versioned_state_offset::<T>(data)?;
+    bytemuck::try_from_bytes_mut(&mut data[offset..]).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+/// Packs a versioned account's state, prefixed with the header for the
+/// current layout, into its data.
+pub fn pack_versioned<T: VersionedAccount>(data: &mut [u8], state: &T) -> Result<(), ProgramError> {
+    if data.len() != T::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let (header_data, state_data) = data.split_at_mut(ACCOUNT_HEADER_LEN);
+    header_data.copy_from_slice(bytemuck::bytes_of(&T::header()));
+    state_data.copy_from_slice(bytemuck::bytes_of(state));
+    Ok(())
 }
@@ -0,0 +1,204 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::accept_pool_authority,
+        state::{get_holder_rewards_pool_address, unpack_versioned, HolderRewardsPool},
+    },
+    setup::{setup, setup_holder_rewards_pool_account_with_state},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+};
+
+#[tokio::test]
+async fn fail_no_pending_authority() {
+    let mint = Pubkey::new_unique();
+    let pending_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, Pubkey::new_unique()), // No pending authority.
+    )
+    .await;
+
+    let instruction =
+        accept_pool_authority(&holder_rewards_pool, &mint, &pending_authority.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pending_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPendingPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_pending_authority() {
+    let mint = Pubkey::new_unique();
+    let pending_authority = Keypair::new(); // Not the pending authority.
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool {
+            accumulated_rewards_per_token: 0,
+            authority: Pubkey::new_unique(),
+            pending_authority: Pubkey::new_unique(),
+            ..HolderRewardsPool::default()
+        },
+    )
+    .await;
+
+    let instruction =
+        accept_pool_authority(&holder_rewards_pool, &mint, &pending_authority.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pending_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPendingPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_pending_authority_not_signer() {
+    let mint = Pubkey::new_unique();
+    let pending_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool {
+            accumulated_rewards_per_token: 0,
+            authority: Pubkey::new_unique(),
+            pending_authority: pending_authority.pubkey(),
+            ..HolderRewardsPool::default()
+        },
+    )
+    .await;
+
+    let mut instruction =
+        accept_pool_authority(&holder_rewards_pool, &mint, &pending_authority.pubkey());
+    instruction.accounts[2].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing pending authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let pending_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool {
+            accumulated_rewards_per_token: 500_000_000,
+            authority: Pubkey::new_unique(),
+            pending_authority: pending_authority.pubkey(),
+            ..HolderRewardsPool::default()
+        },
+    )
+    .await;
+
+    let instruction =
+        accept_pool_authority(&holder_rewards_pool, &mint, &pending_authority.pubkey());
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pending_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the pending authority replaced the current authority.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &HolderRewardsPool::new(500_000_000, pending_authority.pubkey()),
+    );
+}
@@ -0,0 +1,302 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::add_compound_program,
+        state::{get_holder_rewards_pool_address, unpack_versioned, HolderRewardsPool},
+    },
+    setup::{setup, setup_holder_rewards_pool_account_with_state},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_incorrect_pool_authority() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new(); // Not the pool authority.
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, Pubkey::new_unique()),
+    )
+    .await;
+
+    let instruction = add_compound_program(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &Pubkey::new_unique(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_pool_authority_not_signer() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, pool_authority.pubkey()),
+    )
+    .await;
+
+    let mut instruction = add_compound_program(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &Pubkey::new_unique(),
+    );
+    instruction.accounts[2].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing pool authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[test_case(Pubkey::default(); "Default address")]
+#[test_case(paladin_rewards_program::id(); "Rewards program")]
+#[tokio::test]
+async fn fail_invalid_compound_program(compound_program: Pubkey) {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, pool_authority.pubkey()),
+    )
+    .await;
+
+    let instruction = add_compound_program(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &compound_program,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn fail_compound_program_already_allowed() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let compound_program = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, pool_authority.pubkey());
+    pool.compound_programs[2] = compound_program;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+
+    let instruction = add_compound_program(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &compound_program,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::CompoundProgramAlreadyAllowed as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_max_compound_programs_reached() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, pool_authority.pubkey());
+    pool.compound_programs = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+
+    let instruction = add_compound_program(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &Pubkey::new_unique(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::MaxCompoundProgramsReached as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let existing_compound_program = Pubkey::new_unique();
+    let compound_program = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    // Leave a gap in the allowlist, which the new program should fill.
+    let mut pool = HolderRewardsPool::new(500_000_000, pool_authority.pubkey());
+    pool.compound_programs[1] = existing_compound_program;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+
+    let instruction = add_compound_program(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &compound_program,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the program was added to the first unused slot.
+    let mut expected_pool = pool;
+    expected_pool.compound_programs[0] = compound_program;
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &expected_pool,
+    );
+}
@@ -0,0 +1,308 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::add_reward_stream,
+        state::{
+            get_holder_rewards_pool_address, unpack_versioned, HolderRewardsPool, RewardStream,
+            MAX_REWARD_STREAMS,
+        },
+    },
+    setup::{setup, setup_holder_rewards_pool_account_with_state},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+fn pool_with_reward_streams(authority: &Pubkey, reward_ids: &[u64]) -> HolderRewardsPool {
+    let mut pool = HolderRewardsPool::new(0, *authority);
+    for (stream, reward_id) in pool.reward_streams.iter_mut().zip(reward_ids) {
+        stream.reward_id = *reward_id;
+    }
+    pool
+}
+
+#[tokio::test]
+async fn fail_incorrect_pool_authority() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, Pubkey::new_unique()), // Incorrect authority.
+    )
+    .await;
+
+    let instruction = add_reward_stream(&holder_rewards_pool, &mint, &pool_authority.pubkey(), 1);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_pool_authority_not_signer() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, pool_authority.pubkey()),
+    )
+    .await;
+
+    let mut instruction =
+        add_reward_stream(&holder_rewards_pool, &mint, &pool_authority.pubkey(), 1);
+    instruction.accounts[2].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing pool authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_reward_id_zero() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, pool_authority.pubkey()),
+    )
+    .await;
+
+    let instruction = add_reward_stream(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        0, // Reserved for unused slots.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn fail_reward_stream_already_exists() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &pool_with_reward_streams(&pool_authority.pubkey(), &[1, 2]),
+    )
+    .await;
+
+    let instruction = add_reward_stream(&holder_rewards_pool, &mint, &pool_authority.pubkey(), 2);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::RewardStreamAlreadyExists as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_max_reward_streams_reached() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let reward_ids = (1..=MAX_REWARD_STREAMS as u64).collect::<Vec<_>>();
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &pool_with_reward_streams(&pool_authority.pubkey(), &reward_ids),
+    )
+    .await;
+
+    let instruction = add_reward_stream(&holder_rewards_pool, &mint, &pool_authority.pubkey(), 100);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::MaxRewardStreamsReached as u32)
+        )
+    );
+}
+
+#[test_case(&[], 0; "No existing streams")]
+#[test_case(&[5, 9], 2; "Existing streams")]
+#[tokio::test]
+async fn success(existing_reward_ids: &[u64], expected_slot: usize) {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let reward_id = 42;
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut existing_pool = pool_with_reward_streams(&pool_authority.pubkey(), existing_reward_ids);
+    existing_pool.accumulated_rewards_per_token = 500_000_000;
+    for stream in existing_pool.reward_streams.iter_mut() {
+        if stream.reward_id != 0 {
+            stream.accumulated_rewards_per_token = 1_000_000_000;
+            stream.total_distributed = 1_000;
+        }
+    }
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &existing_pool,
+    )
+    .await;
+
+    let instruction = add_reward_stream(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        reward_id,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Check the stream was added to the first unused slot, and nothing else
+    // changed.
+    let mut expected_pool = existing_pool;
+    expected_pool.reward_streams[expected_slot] = RewardStream {
+        reward_id,
+        ..RewardStream::default()
+    };
+
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &expected_pool,
+    );
+}
@@ -0,0 +1,1215 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::{close_holder_rewards, close_holder_rewards_with_recipient},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewards, HolderRewardsPool,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_account, setup_holder_rewards_account_with_state,
+        setup_holder_rewards_pool_account, setup_holder_rewards_pool_account_with_state,
+        setup_legacy_account, setup_system_account, setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::{
+        get_associated_token_address, get_associated_token_address_with_program_id,
+    },
+    test_case::test_case,
+};
+
+const OWNER: Pubkey = Pubkey::new_from_array([1; 32]);
+const MINT: Pubkey = Pubkey::new_from_array([2; 32]);
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_incorrect_address() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = Pubkey::new_unique(); // Incorrect holder rewards pool address.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_owner() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    // Setup holder rewards account with incorrect owner.
+    {
+        context.set_account(
+            &holder_rewards,
+            &AccountSharedData::new_data(100_000_000, &vec![5; 16], &system_program::id()).unwrap(),
+        );
+    }
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_address() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = Pubkey::new_unique(); // Incorrect holder rewards address.
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_destination_is_holder_rewards() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards, // Destination is the holder rewards account.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_mint_mismatch() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner.pubkey(),
+        &Pubkey::new_unique(), // Incorrect mint.
+        0,
+    )
+    .await;
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountMintMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_owner_mismatch() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let not_owner = Keypair::new();
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &not_owner.pubkey(), // Not the token account owner.
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &not_owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountOwnerMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_owner_not_signer() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let mut instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+    );
+    instruction.accounts[4].is_signer = false; // Owner not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Owner not signer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_not_empty() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 100).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 100).await;
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountNotEmpty as u32)
+        )
+    );
+}
+
+#[test_case(0, 0; "No unharvested rewards")]
+#[test_case(5_000, 5_000; "Some unharvested rewards, receive unharvested")]
+#[test_case(50_000, 10_000; "Pool underfunded, receive pool excess")]
+#[tokio::test]
+async fn success_token_account_empty(unharvested_rewards: u64, expected_harvested_rewards: u64) {
+    let pool_excess_lamports = 10_000;
+    let accumulated_rewards_per_token = 1_000_000_000; // 1 reward per token.
+
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        pool_excess_lamports,
+        accumulated_rewards_per_token,
+    )
+    .await;
+    setup_holder_rewards_account(
+        &mut context,
+        &holder_rewards,
+        &mint,
+        unharvested_rewards,
+        accumulated_rewards_per_token,
+        0,
+    )
+    .await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+    setup_system_account(&mut context, &destination, 0).await;
+
+    // For checks later.
+    let pool_beginning_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let holder_rewards_lamports = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let token_account_beginning_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let destination_beginning_lamports = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder rewards account was closed.
+    assert!(context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the holder rewards pool's balance was debited.
+    let pool_resulting_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        pool_resulting_lamports,
+        pool_beginning_lamports.saturating_sub(expected_harvested_rewards),
+    );
+
+    // Assert the unharvested rewards were harvested into the token account.
+    let token_account_resulting_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        token_account_resulting_lamports,
+        token_account_beginning_lamports.saturating_add(expected_harvested_rewards),
+    );
+
+    // Assert the rent lamports were returned to the destination.
+    let destination_resulting_lamports = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        destination_resulting_lamports,
+        destination_beginning_lamports.saturating_add(holder_rewards_lamports),
+    );
+}
+
+#[tokio::test]
+async fn success_token_account_empty_with_recipient() {
+    let unharvested_rewards = 5_000;
+
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut holder = HolderRewards::new(0, unharvested_rewards, 0);
+    holder.recipient = recipient;
+    holder.mint = mint;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 10_000, 0).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+    setup_system_account(&mut context, &destination, 0).await;
+    setup_system_account(&mut context, &recipient, 0).await;
+
+    // For checks later.
+    let token_account_beginning_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let recipient_beginning_lamports = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = close_holder_rewards_with_recipient(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+        &recipient,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder rewards account was closed.
+    assert!(context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the unharvested rewards were harvested to the recipient, rather
+    // than the token account.
+    let recipient_resulting_lamports = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        recipient_resulting_lamports,
+        recipient_beginning_lamports.saturating_add(unharvested_rewards),
+    );
+    let token_account_resulting_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        token_account_resulting_lamports,
+        token_account_beginning_lamports
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_closed_unharvested_rewards() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let token_account =
+        get_associated_token_address_with_program_id(&owner, &mint, &spl_token_2022::id());
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 10_000, 0).await;
+    setup_holder_rewards_account(
+        &mut context,
+        &holder_rewards,
+        &mint,
+        5_000, // Unharvested rewards.
+        0,
+        100,
+    )
+    .await;
+
+    // The token account doesn't exist, so anyone can try to close the holder
+    // rewards account.
+    let anyone = Keypair::new();
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &anyone.pubkey(),
+        &owner,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &anyone],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::HolderRewardsNotEmpty as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_closed_holder_rewards_mint_mismatch() {
+    let unharvested_rewards = 5_000;
+
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let other_mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    // The holder rewards pool for another mint, whose lamports would pay out
+    // the holder's rewards.
+    let other_holder_rewards_pool = get_holder_rewards_pool_address(&other_mint);
+
+    let mut holder = HolderRewards::new(0, unharvested_rewards, 100);
+    holder.recipient = recipient;
+    holder.mint = mint;
+    holder.owner = owner;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &other_holder_rewards_pool, 10_000, 0).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_system_account(&mut context, &recipient, 0).await;
+
+    let anyone = Keypair::new();
+
+    let instruction = close_holder_rewards(
+        &other_holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &other_mint,
+        &anyone.pubkey(),
+        &recipient,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &anyone],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::HolderRewardsMintMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_closed_destination_not_owner() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique(); // Not the token account owner.
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut holder = HolderRewards::new(0, 0, 100);
+    holder.mint = mint;
+    holder.owner = owner;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 10_000, 0).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_system_account(&mut context, &destination, 0).await;
+
+    let anyone = Keypair::new();
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &anyone.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &anyone],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountOwnerMismatch as u32)
+        )
+    );
+}
+
+#[test_case(
+    get_associated_token_address_with_program_id(&OWNER, &MINT, &spl_token_2022::id()),
+    Pubkey::new_unique();
+    "Destination not the owner"
+)]
+#[test_case(
+    Pubkey::new_unique(),
+    OWNER;
+    "Token account not the owner's associated token account"
+)]
+#[tokio::test]
+async fn fail_token_account_closed_legacy_holder_rewards_destination_not_owner(
+    token_account: Pubkey,
+    destination: Pubkey,
+) {
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&MINT);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 10_000, 0).await;
+    setup_system_account(&mut context, &destination, 0).await;
+
+    // Setup holder rewards account in the original layout, which didn't
+    // record the token account owner.
+    setup_legacy_account(
+        &mut context,
+        &holder_rewards,
+        0,
+        &bytemuck::bytes_of(&HolderRewards::default())[..32],
+    )
+    .await;
+
+    let anyone = Keypair::new();
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &MINT,
+        &anyone.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &anyone],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_closed_destination_not_recipient() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let destination = Pubkey::new_unique(); // Not the rewards recipient.
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut holder = HolderRewards::new(0, 5_000, 100);
+    holder.recipient = recipient;
+    holder.mint = mint;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 10_000, 0).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_system_account(&mut context, &destination, 0).await;
+
+    let anyone = Keypair::new();
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &anyone.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &anyone],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectRewardsRecipient as u32)
+        )
+    );
+}
+
+#[test_case(true; "Legacy associated token account")]
+#[test_case(false; "Token account recorded owner")]
+#[tokio::test]
+async fn success_token_account_closed(legacy: bool) {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    // Holders in the current layout recorded their owner, so their token
+    // account needn't have been an associated token account.
+    let token_account = if legacy {
+        get_associated_token_address_with_program_id(&owner, &mint, &spl_token_2022::id())
+    } else {
+        Pubkey::new_unique()
+    };
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 10_000, 0).await;
+    if legacy {
+        setup_legacy_account(
+            &mut context,
+            &holder_rewards,
+            0,
+            &bytemuck::bytes_of(&HolderRewards::default())[..32],
+        )
+        .await;
+    } else {
+        let mut holder = HolderRewards::new(0, 0, 100);
+        holder.mint = mint;
+        holder.owner = owner;
+        setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    }
+    setup_system_account(&mut context, &owner, 0).await;
+
+    // For checks later.
+    let owner_beginning_lamports = context
+        .banks_client
+        .get_account(owner)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let holder_rewards_lamports = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // The token account doesn't exist, so anyone can close the holder
+    // rewards account.
+    let anyone = Keypair::new();
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &anyone.pubkey(),
+        &owner,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &anyone],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder rewards account was closed.
+    assert!(context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the rent lamports were returned to the token account owner.
+    let owner_resulting_lamports = context
+        .banks_client
+        .get_account(owner)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        owner_resulting_lamports,
+        owner_beginning_lamports.saturating_add(holder_rewards_lamports),
+    );
+}
+
+#[tokio::test]
+async fn success_token_account_closed_excluded() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    // The holder is excluded, as is another holder with a balance of `200`.
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.excluded_supply = 300;
+    pool.excluded_holders = 2;
+    let mut holder = HolderRewards::new(0, 0, 100);
+    holder.mint = mint;
+    holder.owner = owner;
+    holder.excluded = 1;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_system_account(&mut context, &owner, 0).await;
+
+    // The token account doesn't exist, so anyone can close the holder
+    // rewards account.
+    let anyone = Keypair::new();
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &anyone.pubkey(),
+        &owner,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &anyone],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder was removed from the pool's excluded supply and
+    // holders.
+    let mut expected_pool = pool;
+    expected_pool.excluded_supply = 200;
+    expected_pool.excluded_holders = 1;
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &expected_pool,
+    );
+}
+
+#[tokio::test]
+async fn success_token_account_closed_with_recipient() {
+    let unharvested_rewards = 5_000;
+
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut holder = HolderRewards::new(0, unharvested_rewards, 100);
+    holder.recipient = recipient;
+    holder.mint = mint;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 10_000, 0).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_system_account(&mut context, &recipient, 0).await;
+
+    // For checks later.
+    let pool_beginning_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let holder_rewards_lamports = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let recipient_beginning_lamports = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // The token account doesn't exist, so anyone can close the holder
+    // rewards account.
+    let anyone = Keypair::new();
+
+    let instruction = close_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &anyone.pubkey(),
+        &recipient,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &anyone],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder rewards account was closed.
+    assert!(context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Assert the unharvested rewards were paid out of the holder rewards
+    // pool.
+    let pool_resulting_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        pool_resulting_lamports,
+        pool_beginning_lamports.saturating_sub(unharvested_rewards),
+    );
+
+    // Assert the unharvested rewards and the rent lamports were paid to the
+    // recipient.
+    let recipient_resulting_lamports = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        recipient_resulting_lamports,
+        recipient_beginning_lamports
+            .saturating_add(unharvested_rewards)
+            .saturating_add(holder_rewards_lamports),
+    );
+}
@@ -0,0 +1,315 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::create_emission_schedule,
+        state::{
+            get_holder_rewards_pool_address, unpack_versioned, EmissionSchedule, HolderRewardsPool,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_pool_account_with_state, setup_mint, setup_system_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+const CURRENT_TIMESTAMP: i64 = 10_000;
+
+fn set_clock(context: &mut ProgramTestContext, unix_timestamp: i64) {
+    context.set_sysvar(&Clock {
+        unix_timestamp,
+        ..Clock::default()
+    });
+}
+
+#[tokio::test]
+async fn fail_incorrect_pool_authority() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let payer = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    set_clock(&mut context, CURRENT_TIMESTAMP);
+    setup_system_account(&mut context, &payer.pubkey(), 1_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, Pubkey::new_unique()), // Incorrect pool authority.
+    )
+    .await;
+
+    let instruction = create_emission_schedule(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        1_000,
+        CURRENT_TIMESTAMP,
+        CURRENT_TIMESTAMP + 100,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[test_case(0, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP + 100; "Zero amount")]
+#[test_case(1_000, CURRENT_TIMESTAMP + 100, CURRENT_TIMESTAMP + 100; "Empty range")]
+#[test_case(1_000, CURRENT_TIMESTAMP + 100, CURRENT_TIMESTAMP; "End before start")]
+#[test_case(1_000, CURRENT_TIMESTAMP - 100, CURRENT_TIMESTAMP + 100; "Already started")]
+#[test_case(1_000, CURRENT_TIMESTAMP - 100, CURRENT_TIMESTAMP; "Already ended")]
+#[tokio::test]
+async fn fail_invalid_schedule(amount: u64, start_timestamp: i64, end_timestamp: i64) {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let payer = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    set_clock(&mut context, CURRENT_TIMESTAMP);
+    setup_system_account(&mut context, &payer.pubkey(), 1_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, pool_authority.pubkey()),
+    )
+    .await;
+
+    let instruction = create_emission_schedule(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        amount,
+        start_timestamp,
+        end_timestamp,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn fail_emission_schedule_active() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let payer = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, pool_authority.pubkey());
+    pool.emission_schedule = EmissionSchedule {
+        total_amount: 1_000,
+        released_amount: 0,
+        start_timestamp: CURRENT_TIMESTAMP - 100,
+        end_timestamp: CURRENT_TIMESTAMP + 100, // Half released.
+    };
+
+    let mut context = setup().start_with_context().await;
+    set_clock(&mut context, CURRENT_TIMESTAMP);
+    setup_system_account(&mut context, &payer.pubkey(), 1_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 1_000, &pool)
+        .await;
+
+    let instruction = create_emission_schedule(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        1_000,
+        CURRENT_TIMESTAMP,
+        CURRENT_TIMESTAMP + 100,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::EmissionScheduleActive as u32)
+        )
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test_case(
+    EmissionSchedule::default(),
+    0;
+    "No previous schedule"
+)]
+#[test_case(
+    EmissionSchedule {
+        total_amount: 1_000,
+        released_amount: 1_000,
+        start_timestamp: CURRENT_TIMESTAMP - 200,
+        end_timestamp: CURRENT_TIMESTAMP - 100,
+    },
+    0;
+    "Previous schedule fully released"
+)]
+#[test_case(
+    EmissionSchedule {
+        total_amount: 1_000,
+        released_amount: 500,
+        start_timestamp: CURRENT_TIMESTAMP - 200,
+        end_timestamp: CURRENT_TIMESTAMP - 100,
+    },
+    5_000_000_000_000_000_000; // 500 / 100
+    "Previous schedule ended, remainder released"
+)]
+#[tokio::test]
+async fn success(
+    previous_emission_schedule: EmissionSchedule,
+    expected_accumulated_rewards_per_token: u128,
+) {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let payer = Keypair::new();
+    let amount = 1_000;
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, pool_authority.pubkey());
+    pool.emission_schedule = previous_emission_schedule;
+
+    let mut context = setup().start_with_context().await;
+    set_clock(&mut context, CURRENT_TIMESTAMP);
+    setup_system_account(&mut context, &payer.pubkey(), amount).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        previous_emission_schedule.unreleased_amount(),
+        &pool,
+    )
+    .await;
+
+    // For checks later.
+    let pool_beginning_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = create_emission_schedule(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        amount,
+        CURRENT_TIMESTAMP + 100,
+        CURRENT_TIMESTAMP + 200,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the schedule was created, and any rewards remaining in the
+    // previous schedule were released.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state =
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap();
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        expected_accumulated_rewards_per_token
+    );
+    assert_eq!(
+        pool_state.emission_schedule,
+        EmissionSchedule {
+            total_amount: amount,
+            released_amount: 0,
+            start_timestamp: CURRENT_TIMESTAMP + 100,
+            end_timestamp: CURRENT_TIMESTAMP + 200,
+        }
+    );
+
+    // Assert the amount was escrowed in the holder rewards pool.
+    assert_eq!(
+        holder_rewards_pool_account.lamports,
+        pool_beginning_lamports + amount
+    );
+}
@@ -0,0 +1,514 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::{deposit_rewards, deposit_rewards_with_distribution_authority},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewardsPool, VersionedAccount,
+        },
+    },
+    setup::{
+        setup, setup_excluded_holder_rewards_account, setup_holder_rewards_pool_account_with_state,
+        setup_mint, setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_distribution_authority_missing() {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.distribution_authority = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        500_000,
+        &pool,
+    )
+    .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+
+    let instruction = deposit_rewards(&holder_rewards_pool, &mint, &[]);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_distribution_authority() {
+    let mint = Pubkey::new_unique();
+    let distribution_authority = Keypair::new(); // Not the distribution authority.
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.distribution_authority = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        500_000,
+        &pool,
+    )
+    .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+
+    let instruction = deposit_rewards_with_distribution_authority(
+        &holder_rewards_pool,
+        &mint,
+        &distribution_authority.pubkey(),
+        &[],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &distribution_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectDistributionAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_distribution_authority_not_signer() {
+    let mint = Pubkey::new_unique();
+    let distribution_authority = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.distribution_authority = distribution_authority;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        500_000,
+        &pool,
+    )
+    .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+
+    let mut instruction = deposit_rewards_with_distribution_authority(
+        &holder_rewards_pool,
+        &mint,
+        &distribution_authority,
+        &[],
+    );
+    instruction.accounts[2].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing distribution authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_amount_below_min_distribution_amount() {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    // The pool holds 10_000 unharvested rewards, so only 999_999 lamports
+    // are deposited.
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.total_distributed = 10_000;
+    pool.min_distribution_amount = 1_000_000;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        1_009_999,
+        &pool,
+    )
+    .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+
+    let instruction = deposit_rewards(&holder_rewards_pool, &mint, &[]);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::DistributionAmountTooSmall as u32)
+        )
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test_case(0, 0, 0, 500_000, 5_000_000_000_000_000_000; "Deposit only")]
+#[test_case(
+    300_000,
+    100_000,
+    0,
+    500_000,
+    5_000_000_000_000_000_000;
+    "Unharvested rewards not deposited"
+)]
+#[test_case(
+    300_000,
+    100_000,
+    50_000,
+    500_000,
+    5_500_000_000_000_000_000;
+    "Carryover distributed with deposit"
+)]
+#[test_case(300_000, 100_000, 0, 0, 0; "Nothing to deposit")]
+#[tokio::test]
+async fn success(
+    total_distributed: u64,
+    total_harvested: u64,
+    undistributed_carryover: u64,
+    deposit_amount: u64,
+    expected_accumulated_rewards_per_token: u128,
+) {
+    let token_supply = 100_000;
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.total_distributed = total_distributed;
+    pool.total_harvested = total_harvested;
+    pool.undistributed_carryover = undistributed_carryover;
+
+    // The pool holds its unharvested rewards and carryover, plus the lamports
+    // credited to it directly.
+    let excess_lamports =
+        total_distributed - total_harvested + undistributed_carryover + deposit_amount;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        excess_lamports,
+        &pool,
+    )
+    .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+
+    let instruction = deposit_rewards(&holder_rewards_pool, &mint, &[]);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the deposit, and any carryover, was distributed to the pool's
+    // rate, without moving any lamports.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut expected_pool = pool;
+    if deposit_amount != 0 {
+        let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+        expected_pool.accumulated_rewards_per_token = expected_accumulated_rewards_per_token;
+        expected_pool.total_distributed += deposit_amount + undistributed_carryover;
+        expected_pool.undistributed_carryover = 0;
+        expected_pool.distribution_count = 1;
+        expected_pool.last_distribution_slot = clock.slot;
+    }
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &expected_pool,
+    );
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        holder_rewards_pool_account.lamports,
+        rent.minimum_balance(HolderRewardsPool::LEN) + excess_lamports
+    );
+}
+
+#[test_case(Pubkey::new_unique(), 0; "Distribution authority set")]
+#[test_case(Pubkey::default(), 1_000_000; "Min distribution amount set")]
+#[tokio::test]
+async fn success_nothing_to_deposit_with_distribution_config(
+    distribution_authority: Pubkey,
+    min_distribution_amount: u64,
+) {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    // The pool holds only its unharvested rewards.
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.total_distributed = 300_000;
+    pool.total_harvested = 100_000;
+    pool.distribution_authority = distribution_authority;
+    pool.min_distribution_amount = min_distribution_amount;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        200_000,
+        &pool,
+    )
+    .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+
+    // No distribution authority is provided.
+    let instruction = deposit_rewards(&holder_rewards_pool, &mint, &[]);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert nothing changed.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &pool,
+    );
+}
+
+#[tokio::test]
+async fn success_zero_token_supply() {
+    let mint = Pubkey::new_unique();
+    let distribution_authority = Keypair::new();
+    let deposit_amount = 500_000;
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.distribution_authority = distribution_authority.pubkey();
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        deposit_amount,
+        &pool,
+    )
+    .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+
+    let instruction = deposit_rewards_with_distribution_authority(
+        &holder_rewards_pool,
+        &mint,
+        &distribution_authority.pubkey(),
+        &[],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &distribution_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the deposit was carried over, so it can't be deposited again.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut expected_pool = pool;
+    expected_pool.undistributed_carryover = deposit_amount;
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &expected_pool,
+    );
+}
+
+#[test_case(false; "No distribution authority")]
+#[test_case(true; "Distribution authority")]
+#[tokio::test]
+async fn success_excluded_holder_burned(set_distribution_authority: bool) {
+    let mint = Pubkey::new_unique();
+    let distribution_authority = Keypair::new();
+    let deposit_amount = 500;
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let token_account = Pubkey::new_unique();
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    // The excluded holder was last settled at a balance of 500, out of a
+    // supply of 1_000, but has since burned 250 tokens, which the transfer
+    // hook doesn't see. The other 500 tokens are held elsewhere.
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.excluded_supply = 500;
+    pool.excluded_holders = 1;
+    if set_distribution_authority {
+        pool.distribution_authority = distribution_authority.pubkey();
+    }
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        deposit_amount,
+        &pool,
+    )
+    .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 750).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &Pubkey::new_unique(),
+        &mint,
+        250,
+    )
+    .await;
+    setup_excluded_holder_rewards_account(&mut context, &holder_rewards, &mint, 500).await;
+
+    // The distribution authority, if any, precedes the excluded holders.
+    let excluded_holders = [(holder_rewards, token_account)];
+    let (instruction, signers) = if set_distribution_authority {
+        (
+            deposit_rewards_with_distribution_authority(
+                &holder_rewards_pool,
+                &mint,
+                &distribution_authority.pubkey(),
+                &excluded_holders,
+            ),
+            vec![&context.payer, &distribution_authority],
+        )
+    } else {
+        (
+            deposit_rewards(&holder_rewards_pool, &mint, &excluded_holders),
+            vec![&context.payer],
+        )
+    };
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &signers,
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the deposit was distributed over the 500 tokens held elsewhere,
+    // at one lamport per token, and the excluded supply was reconciled.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state =
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap();
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        1_000_000_000_000_000_000
+    );
+    assert_eq!(pool_state.excluded_supply, 250);
+}
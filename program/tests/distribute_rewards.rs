@@ -6,12 +6,20 @@ use {
     paladin_rewards_program::{
         error::PaladinRewardsError,
         instruction::distribute_rewards,
-        state::{get_holder_rewards_pool_address, HolderRewardsPool},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewards, HolderRewardsPool, VersionedAccount,
+        },
+    },
+    setup::{
+        setup, setup_excluded_holder_rewards_account, setup_holder_rewards_account,
+        setup_holder_rewards_pool_account, setup_holder_rewards_pool_account_with_state,
+        setup_mint, setup_system_account, setup_token_account,
     },
-    setup::{setup, setup_holder_rewards_pool_account, setup_mint, setup_system_account},
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
+        clock::Clock,
         instruction::InstructionError,
         pubkey::Pubkey,
         signature::Keypair,
@@ -40,7 +48,7 @@ async fn fail_mint_invalid_data() {
         );
     }
 
-    let instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, amount);
+    let instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, &[], amount);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -74,7 +82,8 @@ async fn fail_payer_not_signer() {
     let mut context = setup().start_with_context().await;
     setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
 
-    let mut instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, amount);
+    let mut instruction =
+        distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, &[], amount);
     instruction.accounts[0].is_signer = false; // Not signer.
 
     let transaction = Transaction::new_signed_with_payer(
@@ -123,7 +132,7 @@ async fn fail_holder_rewards_pool_incorrect_owner() {
         );
     }
 
-    let instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, amount);
+    let instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, &[], amount);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -158,7 +167,7 @@ async fn fail_holder_rewards_pool_invalid_address() {
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
     setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
 
-    let instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, amount);
+    let instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, &[], amount);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -209,7 +218,7 @@ async fn fail_holder_rewards_pool_invalid_data() {
         );
     }
 
-    let instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, amount);
+    let instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, &[], amount);
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -231,6 +240,92 @@ async fn fail_holder_rewards_pool_invalid_data() {
     );
 }
 
+#[tokio::test]
+async fn fail_incorrect_distribution_authority() {
+    let mint = Pubkey::new_unique();
+    let token_supply = 100_000;
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new(); // Not the distribution authority.
+    let amount = 500_000_000_000;
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.distribution_authority = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), amount).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+
+    let instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, &[], amount);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectDistributionAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_amount_below_min_distribution_amount() {
+    let mint = Pubkey::new_unique();
+    let token_supply = 100_000;
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+    let amount = 999_999;
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.min_distribution_amount = 1_000_000;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), amount).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+
+    let instruction = distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, &[], amount);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::DistributionAmountTooSmall as u32)
+        )
+    );
+}
+
 struct InitialPool {
     token_supply: u64,
     accumulated_rewards_per_token: u128,
@@ -241,24 +336,13 @@ struct ExpectedPool {
 }
 
 #[allow(clippy::arithmetic_side_effects)]
-#[test_case(
-    InitialPool {
-        token_supply: 0,
-        accumulated_rewards_per_token: 0,
-    },
-    ExpectedPool {
-        accumulated_rewards_per_token: 0,
-    },
-    100_000;
-    "Zero token supply, zero rewards per token, increment total rewards"
-)]
 #[test_case(
     InitialPool {
         token_supply: 100_000,
         accumulated_rewards_per_token: 0,
     },
     ExpectedPool {
-        accumulated_rewards_per_token: 2_500_000_000, // 0% + 250_000 / 100_000 = 250%
+        accumulated_rewards_per_token: 2_500_000_000_000_000_000, // 0% + 250_000 / 100_000 = 250%
     },
     250_000;
     "Zero initial rate and rewards, resulting rate 250%"
@@ -269,7 +353,7 @@ struct ExpectedPool {
         accumulated_rewards_per_token: 0,
     },
     ExpectedPool {
-        accumulated_rewards_per_token: 100_000_000, // 0% + 100_000 / 1_000_000 = 10%
+        accumulated_rewards_per_token: 100_000_000_000_000_000, // 0% + 100_000 / 1_000_000 = 10%
     },
     100_000;
     "Zero initial rate and rewards, resulting rate 10%"
@@ -280,7 +364,7 @@ struct ExpectedPool {
         accumulated_rewards_per_token: 0,
     },
     ExpectedPool {
-        accumulated_rewards_per_token: 1_000_000, // 0% + 1_000 / 1_000_000 = 0.1%
+        accumulated_rewards_per_token: 1_000_000_000_000_000, // 0% + 1_000 / 1_000_000 = 0.1%
     },
     1_000;
     "Zero initial rate and rewards, resulting rate 0.1%"
@@ -291,7 +375,7 @@ struct ExpectedPool {
         accumulated_rewards_per_token: 0,
     },
     ExpectedPool {
-        accumulated_rewards_per_token: 1_000, // 0 + 1 / 1_000_000 = 0.0001%
+        accumulated_rewards_per_token: 1_000_000_000_000, // 0 + 1 / 1_000_000 = 0.0001%
     },
     1;
     "Zero initial rate and rewards, resulting rate 0.0001%"
@@ -299,10 +383,10 @@ struct ExpectedPool {
 #[test_case(
     InitialPool {
         token_supply: 100_000,
-        accumulated_rewards_per_token: 500_000_000, // 50%
+        accumulated_rewards_per_token: 500_000_000_000_000_000, // 50%
     },
     ExpectedPool {
-        accumulated_rewards_per_token: 525_000_000, // 50% + 2_500 / 100_000 = 52.5%
+        accumulated_rewards_per_token: 525_000_000_000_000_000, // 50% + 2_500 / 100_000 = 52.5%
     },
     2_500;
     "50% initial rate, rewards increase by 5%, resulting rate 52.5%"
@@ -310,10 +394,10 @@ struct ExpectedPool {
 #[test_case(
     InitialPool {
         token_supply: 100_000,
-        accumulated_rewards_per_token: 500_000_000, // 50%
+        accumulated_rewards_per_token: 500_000_000_000_000_000, // 50%
     },
     ExpectedPool {
-        accumulated_rewards_per_token: 1_000_000_000, // 50% + 50_000 / 100_000 = 100%
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 50% + 50_000 / 100_000 = 100%
     },
     50_000;
     "50% initial rate, rewards increase by 100%, resulting rate 100%"
@@ -321,10 +405,10 @@ struct ExpectedPool {
 #[test_case(
     InitialPool {
         token_supply: 100_000,
-        accumulated_rewards_per_token: 500_000_000, // 50%
+        accumulated_rewards_per_token: 500_000_000_000_000_000, // 50%
     },
     ExpectedPool {
-        accumulated_rewards_per_token: 1_750_000_000, // 50% + 125_000 / 100_000 = 175%
+        accumulated_rewards_per_token: 1_750_000_000_000_000_000, // 50% + 125_000 / 100_000 = 175%
     },
     125_000;
     "50% initial rate, rewards increase by 250%, resulting rate 175%"
@@ -364,8 +448,13 @@ async fn success(initial: InitialPool, expected: ExpectedPool, reward_amount: u6
         .unwrap()
         .lamports;
 
-    let instruction =
-        distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, reward_amount);
+    let instruction = distribute_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        reward_amount,
+    );
 
     let transaction = Transaction::new_signed_with_payer(
         &[instruction],
@@ -387,17 +476,21 @@ async fn success(initial: InitialPool, expected: ExpectedPool, reward_amount: u6
         .await
         .unwrap()
         .unwrap();
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
     assert_eq!(
-        bytemuck::from_bytes::<HolderRewardsPool>(&holder_rewards_pool_account.data),
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
         &HolderRewardsPool {
             accumulated_rewards_per_token: expected_accumulated_rewards_per_token,
+            total_distributed: reward_amount,
+            distribution_count: 1,
+            last_distribution_slot: clock.slot,
+            ..HolderRewardsPool::default()
         },
     );
 
     // Assert the pool was credited lamports.
     let rent = context.banks_client.get_rent().await.unwrap();
-    let expected_lamports =
-        rent.minimum_balance(std::mem::size_of::<HolderRewardsPool>()) + reward_amount;
+    let expected_lamports = rent.minimum_balance(HolderRewardsPool::LEN) + reward_amount;
     assert_eq!(holder_rewards_pool_account.lamports, expected_lamports);
 
     // Assert the payer's account balance was debited.
@@ -413,3 +506,628 @@ async fn success(initial: InitialPool, expected: ExpectedPool, reward_amount: u6
         payer_beginning_lamports - reward_amount
     );
 }
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test_case(0, 0, 100_000, 0, 100_000; "Zero token supply, rewards carried over")]
+#[test_case(0, 50_000, 100_000, 0, 150_000; "Zero token supply, rewards added to carryover")]
+#[test_case(
+    100_000,
+    150_000,
+    100_000,
+    2_500_000_000_000_000_000, // 0% + (100_000 + 150_000) / 100_000 = 250%
+    0;
+    "Non-zero token supply, carryover included in distribution"
+)]
+#[tokio::test]
+async fn success_undistributed_carryover(
+    token_supply: u64,
+    undistributed_carryover: u64,
+    reward_amount: u64,
+    expected_accumulated_rewards_per_token: u128,
+    expected_undistributed_carryover: u64,
+) {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.undistributed_carryover = undistributed_carryover;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), reward_amount).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        undistributed_carryover,
+        &pool,
+    )
+    .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+
+    let instruction = distribute_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        reward_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the rewards were either carried over, or distributed along with
+    // the carryover.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state =
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap();
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        expected_accumulated_rewards_per_token
+    );
+    assert_eq!(
+        pool_state.undistributed_carryover,
+        expected_undistributed_carryover
+    );
+    if token_supply == 0 {
+        assert_eq!(pool_state.total_distributed, 0);
+        assert_eq!(pool_state.distribution_count, 0);
+    } else {
+        assert_eq!(
+            pool_state.total_distributed,
+            reward_amount + undistributed_carryover
+        );
+        assert_eq!(pool_state.distribution_count, 1);
+    }
+
+    // Assert the pool was credited lamports either way.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        holder_rewards_pool_account.lamports,
+        rent.minimum_balance(HolderRewardsPool::LEN) + undistributed_carryover + reward_amount
+    );
+}
+
+#[test_case(
+    3,
+    0,
+    0,
+    1,
+    333_333_333_333_333_333, // 1e18 / 3
+    1;
+    "Remainder stored"
+)]
+#[test_case(
+    3,
+    333_333_333_333_333_333,
+    1,
+    2,
+    1_000_000_000_000_000_000, // 333_333_333_333_333_333 + (2 * 1e18 + 1) / 3
+    0;
+    "Remainder carried into rate"
+)]
+#[test_case(
+    7,
+    0,
+    5,
+    1,
+    142_857_142_857_142_857, // (1e18 + 5) / 7
+    6;
+    "Remainder carried into rate, new remainder stored"
+)]
+#[tokio::test]
+async fn success_rewards_per_token_remainder(
+    token_supply: u64,
+    accumulated_rewards_per_token: u128,
+    rewards_per_token_remainder: u64,
+    reward_amount: u64,
+    expected_accumulated_rewards_per_token: u128,
+    expected_rewards_per_token_remainder: u64,
+) {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+
+    let mut pool = HolderRewardsPool::new(accumulated_rewards_per_token, Pubkey::new_unique());
+    pool.rewards_per_token_remainder = rewards_per_token_remainder;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), reward_amount).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+
+    let instruction = distribute_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        reward_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the truncated remainder of the rate was kept for the next
+    // distribution.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state =
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap();
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        expected_accumulated_rewards_per_token
+    );
+    assert_eq!(
+        pool_state.rewards_per_token_remainder,
+        expected_rewards_per_token_remainder
+    );
+}
+
+#[test_case(1_000, 0, 500, 500_000_000_000_000_000, 0; "No excluded supply")]
+#[test_case(1_000, 750, 500, 2_000_000_000_000_000_000, 0; "Excluded supply earns nothing")]
+#[test_case(1_000, 1_000, 500, 0, 500; "All supply excluded, rewards carried over")]
+#[tokio::test]
+async fn success_excluded_supply(
+    token_supply: u64,
+    excluded_supply: u64,
+    reward_amount: u64,
+    expected_accumulated_rewards_per_token: u128,
+    expected_undistributed_carryover: u64,
+) {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.excluded_supply = excluded_supply;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), reward_amount).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+
+    // A single token account holds the excluded supply.
+    let mut excluded_holders = vec![];
+    if excluded_supply != 0 {
+        let token_account = Pubkey::new_unique();
+        let holder_rewards = get_holder_rewards_address(&token_account);
+        setup_token_account(
+            &mut context,
+            &token_account,
+            &Pubkey::new_unique(),
+            &mint,
+            excluded_supply,
+        )
+        .await;
+        setup_excluded_holder_rewards_account(
+            &mut context,
+            &holder_rewards,
+            &mint,
+            excluded_supply,
+        )
+        .await;
+        pool.excluded_holders = 1;
+        excluded_holders.push((holder_rewards, token_account));
+    }
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+
+    let instruction = distribute_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &excluded_holders,
+        reward_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the rewards were only distributed over the supply that isn't
+    // excluded.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state =
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap();
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        expected_accumulated_rewards_per_token
+    );
+    assert_eq!(
+        pool_state.undistributed_carryover,
+        expected_undistributed_carryover
+    );
+    assert_eq!(pool_state.excluded_supply, excluded_supply);
+}
+
+#[tokio::test]
+async fn fail_excluded_holder_missing() {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+    let amount = 500;
+
+    let token_account = Pubkey::new_unique();
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    // The pool has two excluded holders, but only one is provided.
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.excluded_supply = 200;
+    pool.excluded_holders = 2;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), amount).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 1_000).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &Pubkey::new_unique(),
+        &mint,
+        100,
+    )
+    .await;
+    setup_excluded_holder_rewards_account(&mut context, &holder_rewards, &mint, 100).await;
+
+    let instruction = distribute_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[(holder_rewards, token_account)],
+        amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[tokio::test]
+async fn fail_excluded_holders_repeated() {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+    let amount = 500;
+
+    let token_account = Pubkey::new_unique();
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    // The pool has two excluded holders, but one is provided twice.
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.excluded_supply = 200;
+    pool.excluded_holders = 2;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), amount).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 1_000).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &Pubkey::new_unique(),
+        &mint,
+        100,
+    )
+    .await;
+    setup_excluded_holder_rewards_account(&mut context, &holder_rewards, &mint, 100).await;
+
+    let instruction = distribute_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[
+            (holder_rewards, token_account),
+            (holder_rewards, token_account),
+        ],
+        amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::ExcludedHoldersOutOfOrder as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_excluded_holder_not_excluded() {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+    let amount = 500;
+
+    let token_account = Pubkey::new_unique();
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    // The pool has an excluded holder, but an included holder is provided in
+    // its place.
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.excluded_supply = 100;
+    pool.excluded_holders = 1;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), amount).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 1_000).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &Pubkey::new_unique(),
+        &mint,
+        100,
+    )
+    .await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 100).await;
+
+    let instruction = distribute_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[(holder_rewards, token_account)],
+        amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::HolderRewardsNotExcluded as u32)
+        )
+    );
+}
+
+#[test_case(750, Some(250), 250; "Excluded holder burned")]
+#[test_case(500, None, 0; "Excluded holder burned all and closed its token account")]
+#[tokio::test]
+async fn success_excluded_holder_burned(
+    token_supply: u64,
+    token_account_balance: Option<u64>,
+    expected_excluded_supply: u64,
+) {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+    let reward_amount = 500;
+
+    let token_account = Pubkey::new_unique();
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    // The excluded holder was last settled at a balance of 500, out of a
+    // supply of 1_000, but has since burned some of it, which the transfer
+    // hook doesn't see. The other 500 tokens are held elsewhere.
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.excluded_supply = 500;
+    pool.excluded_holders = 1;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), reward_amount).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+    if let Some(token_account_balance) = token_account_balance {
+        setup_token_account(
+            &mut context,
+            &token_account,
+            &Pubkey::new_unique(),
+            &mint,
+            token_account_balance,
+        )
+        .await;
+    }
+    setup_excluded_holder_rewards_account(&mut context, &holder_rewards, &mint, 500).await;
+
+    let instruction = distribute_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[(holder_rewards, token_account)],
+        reward_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the rewards were distributed over the 500 tokens held
+    // elsewhere, at one lamport per token, rather than over the supply less
+    // the stale excluded supply.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state =
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap();
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        1_000_000_000_000_000_000
+    );
+    assert_eq!(pool_state.excluded_supply, expected_excluded_supply);
+
+    // Assert the excluded holder now records its current balance.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data)
+            .unwrap()
+            .last_seen_balance,
+        expected_excluded_supply,
+    );
+}
+
+#[test_case(false, 0, 500; "No distribution authority, no minimum")]
+#[test_case(true, 0, 500; "Distribution authority")]
+#[test_case(false, 500, 500; "Amount equals minimum")]
+#[test_case(true, 100, 500; "Distribution authority, amount above minimum")]
+#[tokio::test]
+async fn success_distribution_config(
+    set_distribution_authority: bool,
+    min_distribution_amount: u64,
+    reward_amount: u64,
+) {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    if set_distribution_authority {
+        pool.distribution_authority = payer.pubkey();
+    }
+    pool.min_distribution_amount = min_distribution_amount;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), reward_amount).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 1_000).await;
+
+    let instruction = distribute_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        reward_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the rewards were distributed, leaving the distribution config
+    // unchanged.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state =
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap();
+    assert_eq!(pool_state.total_distributed, reward_amount);
+    assert_eq!(
+        pool_state.distribution_authority,
+        pool.distribution_authority
+    );
+    assert_eq!(pool_state.min_distribution_amount, min_distribution_amount);
+}
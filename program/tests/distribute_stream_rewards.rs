@@ -0,0 +1,509 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::distribute_stream_rewards,
+        state::{
+            get_holder_rewards_pool_address, unpack_versioned, HolderRewardsPool, RewardStream,
+            VersionedAccount,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_pool_account_with_state, setup_mint, setup_system_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+const REWARD_ID: u64 = 7;
+
+fn pool_with_reward_stream(
+    accumulated_rewards_per_token: u128,
+    stream_accumulated_rewards_per_token: u128,
+    stream_total_distributed: u64,
+) -> HolderRewardsPool {
+    let mut pool = HolderRewardsPool::new(accumulated_rewards_per_token, Pubkey::new_unique());
+    pool.reward_streams[1] = RewardStream {
+        accumulated_rewards_per_token: stream_accumulated_rewards_per_token,
+        reward_id: REWARD_ID,
+        total_distributed: stream_total_distributed,
+        ..RewardStream::default()
+    };
+    pool
+}
+
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &pool_with_reward_stream(0, 0, 0),
+    )
+    .await;
+
+    let mut instruction = distribute_stream_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        REWARD_ID,
+        1_000,
+    );
+    instruction.accounts[0].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing payer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[test_case(0; "Reward ID zero")]
+#[test_case(8; "Unknown reward ID")]
+#[tokio::test]
+async fn fail_reward_stream_not_found(reward_id: u64) {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &pool_with_reward_stream(0, 0, 0),
+    )
+    .await;
+
+    let instruction = distribute_stream_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        reward_id,
+        1_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::RewardStreamNotFound as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_distribution_authority() {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new(); // Not the distribution authority.
+
+    let mut pool = pool_with_reward_stream(0, 0, 0);
+    pool.distribution_authority = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+
+    let instruction = distribute_stream_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        REWARD_ID,
+        1_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectDistributionAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_amount_below_min_distribution_amount() {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+
+    let mut pool = pool_with_reward_stream(0, 0, 0);
+    pool.min_distribution_amount = 1_000;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+
+    let instruction = distribute_stream_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        REWARD_ID,
+        999,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::DistributionAmountTooSmall as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_zero_token_supply() {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &pool_with_reward_stream(0, 0, 0),
+    )
+    .await;
+
+    let instruction = distribute_stream_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        REWARD_ID,
+        1_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::ZeroTokenSupply as u32)
+        )
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test_case(
+    100_000,
+    0,
+    0,
+    250_000,
+    2_500_000_000_000_000_000;
+    "Zero initial rate, resulting rate 250%"
+)]
+#[test_case(
+    1_000_000,
+    0,
+    0,
+    1_000,
+    1_000_000_000_000_000;
+    "Zero initial rate, resulting rate 0.1%"
+)]
+#[test_case(
+    100_000,
+    500_000_000_000_000_000,
+    50_000,
+    2_500,
+    525_000_000_000_000_000;
+    "50% initial rate, resulting rate 52.5%"
+)]
+#[tokio::test]
+async fn success(
+    token_supply: u64,
+    stream_accumulated_rewards_per_token: u128,
+    stream_total_distributed: u64,
+    reward_amount: u64,
+    expected_stream_accumulated_rewards_per_token: u128,
+) {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+
+    let initial_pool = pool_with_reward_stream(
+        100_000_000, // The base rate should not change.
+        stream_accumulated_rewards_per_token,
+        stream_total_distributed,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), reward_amount).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &initial_pool,
+    )
+    .await;
+
+    // For checks later.
+    let payer_beginning_lamports = context
+        .banks_client
+        .get_account(payer.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = distribute_stream_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        REWARD_ID,
+        reward_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the stream's rate and total, and the pool's distribution
+    // totals, were updated.
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let mut expected_pool = initial_pool;
+    expected_pool.reward_streams[1].accumulated_rewards_per_token =
+        expected_stream_accumulated_rewards_per_token;
+    expected_pool.reward_streams[1].total_distributed = stream_total_distributed + reward_amount;
+    expected_pool.total_distributed = reward_amount;
+    expected_pool.distribution_count = 1;
+    expected_pool.last_distribution_slot = clock.slot;
+
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &expected_pool,
+    );
+
+    // Assert the pool was credited lamports.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let expected_lamports = rent.minimum_balance(HolderRewardsPool::LEN) + reward_amount;
+    assert_eq!(holder_rewards_pool_account.lamports, expected_lamports);
+
+    // Assert the payer's account balance was debited.
+    let payer_resulting_lamports = context
+        .banks_client
+        .get_account(payer.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        payer_resulting_lamports,
+        payer_beginning_lamports - reward_amount
+    );
+}
+
+#[test_case(
+    3,
+    0,
+    0,
+    1,
+    333_333_333_333_333_333, // 1e18 / 3
+    1;
+    "Remainder stored"
+)]
+#[test_case(
+    3,
+    333_333_333_333_333_333,
+    1,
+    2,
+    1_000_000_000_000_000_000, // 333_333_333_333_333_333 + (2 * 1e18 + 1) / 3
+    0;
+    "Remainder carried into rate"
+)]
+#[test_case(
+    7,
+    0,
+    5,
+    1,
+    142_857_142_857_142_857, // (1e18 + 5) / 7
+    6;
+    "Remainder carried into rate, new remainder stored"
+)]
+#[tokio::test]
+async fn success_rewards_per_token_remainder(
+    token_supply: u64,
+    stream_accumulated_rewards_per_token: u128,
+    stream_rewards_per_token_remainder: u64,
+    reward_amount: u64,
+    expected_stream_accumulated_rewards_per_token: u128,
+    expected_stream_rewards_per_token_remainder: u64,
+) {
+    let mint = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let payer = Keypair::new();
+
+    let mut pool = pool_with_reward_stream(0, stream_accumulated_rewards_per_token, 0);
+    pool.reward_streams[1].rewards_per_token_remainder = stream_rewards_per_token_remainder;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), reward_amount).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+
+    let instruction = distribute_stream_rewards(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &[],
+        REWARD_ID,
+        reward_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the truncated remainder of the stream's rate was kept for the
+    // next distribution to the stream, and the base rate's was untouched.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state =
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap();
+    assert_eq!(
+        pool_state.reward_streams[1].accumulated_rewards_per_token,
+        expected_stream_accumulated_rewards_per_token
+    );
+    assert_eq!(
+        pool_state.reward_streams[1].rewards_per_token_remainder,
+        expected_stream_rewards_per_token_remainder
+    );
+    assert_eq!(pool_state.rewards_per_token_remainder, 0);
+}
@@ -0,0 +1,758 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::distribute_token_rewards,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address,
+            get_token_rewards_pool_address, get_token_rewards_vault_address, unpack_versioned,
+            HolderRewardsPool, TokenRewardsPool,
+        },
+    },
+    setup::{
+        setup, setup_excluded_holder_rewards_account, setup_holder_rewards_pool_account_with_state,
+        setup_mint, setup_reward_mint, setup_reward_token_account, setup_token_account,
+        setup_token_rewards_pool_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_token_2022::state::Account as TokenAccount,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_token_rewards_pool_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let source_authority = Keypair::new();
+    let source = Pubkey::new_unique();
+
+    let token_rewards_pool = Pubkey::new_unique(); // Incorrect token rewards pool address.
+    let vault =
+        get_token_rewards_vault_address(&token_rewards_pool, &reward_mint, &spl_token::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token::id(), 6).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+
+    let instruction = distribute_token_rewards(
+        &source_authority.pubkey(),
+        &source,
+        &token_rewards_pool,
+        &vault,
+        &mint,
+        &reward_mint,
+        &spl_token::id(),
+        &holder_rewards_pool,
+        &[],
+        1_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectTokenRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_reward_token_program() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let source_authority = Keypair::new();
+    let source = Pubkey::new_unique();
+
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let vault =
+        get_token_rewards_vault_address(&token_rewards_pool, &reward_mint, &spl_token::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token::id(), 6).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+
+    let instruction = distribute_token_rewards(
+        &source_authority.pubkey(),
+        &source,
+        &token_rewards_pool,
+        &vault,
+        &mint,
+        &reward_mint,
+        &spl_token_2022::id(),
+        // Incorrect reward token program.
+        &holder_rewards_pool,
+        &[],
+        1_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+    );
+}
+
+#[tokio::test]
+async fn fail_vault_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let source_authority = Keypair::new();
+    let source = Pubkey::new_unique();
+
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let vault = Pubkey::new_unique(); // Incorrect vault address.
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token::id(), 6).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &vault,
+        &token_rewards_pool,
+        &reward_mint,
+        &spl_token::id(),
+        0,
+    )
+    .await;
+
+    let instruction = distribute_token_rewards(
+        &source_authority.pubkey(),
+        &source,
+        &token_rewards_pool,
+        &vault,
+        &mint,
+        &reward_mint,
+        &spl_token::id(),
+        &holder_rewards_pool,
+        &[],
+        1_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectTokenRewardsVaultAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_distribution_authority() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let source_authority = Keypair::new();
+    let source = Pubkey::new_unique();
+
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let vault =
+        get_token_rewards_vault_address(&token_rewards_pool, &reward_mint, &spl_token::id());
+
+    let pool = HolderRewardsPool {
+        distribution_authority: Pubkey::new_unique(), // Not the source authority.
+        ..HolderRewardsPool::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token::id(), 6).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &source,
+        &source_authority.pubkey(),
+        &reward_mint,
+        &spl_token::id(),
+        1_000_000,
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &vault,
+        &token_rewards_pool,
+        &reward_mint,
+        &spl_token::id(),
+        0,
+    )
+    .await;
+
+    let instruction = distribute_token_rewards(
+        &source_authority.pubkey(),
+        &source,
+        &token_rewards_pool,
+        &vault,
+        &mint,
+        &reward_mint,
+        &spl_token::id(),
+        &holder_rewards_pool,
+        &[],
+        1_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectDistributionAuthority as u32)
+        )
+    );
+}
+
+#[test_case(0, 0; "Zero supply")]
+#[test_case(100_000, 100_000; "All supply excluded")]
+#[tokio::test]
+async fn fail_zero_token_supply(token_supply: u64, excluded_supply: u64) {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let source_authority = Keypair::new();
+    let source = Pubkey::new_unique();
+
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let vault =
+        get_token_rewards_vault_address(&token_rewards_pool, &reward_mint, &spl_token::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool {
+            excluded_supply,
+            ..HolderRewardsPool::default()
+        },
+    )
+    .await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token::id(), 6).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &source,
+        &source_authority.pubkey(),
+        &reward_mint,
+        &spl_token::id(),
+        1_000_000,
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &vault,
+        &token_rewards_pool,
+        &reward_mint,
+        &spl_token::id(),
+        0,
+    )
+    .await;
+
+    let instruction = distribute_token_rewards(
+        &source_authority.pubkey(),
+        &source,
+        &token_rewards_pool,
+        &vault,
+        &mint,
+        &reward_mint,
+        &spl_token::id(),
+        &holder_rewards_pool,
+        &[],
+        1_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::ZeroTokenSupply as u32)
+        )
+    );
+}
+
+#[test_case(spl_token::id(), 100_000, 0, 0, 1_000, 10_000_000_000_000_000, 0; "SPL Token, rate 0")]
+#[test_case(spl_token_2022::id(), 100_000, 0, 0, 1_000, 10_000_000_000_000_000, 0; "Token-2022, rate 0")]
+#[test_case(spl_token_2022::id(), 100_000, 0, 500_000_000_000_000, 1_000, 10_500_000_000_000_000, 0; "Token-2022, existing rate")]
+#[test_case(spl_token::id(), 1_000_000, 0, 0, 50_000, 50_000_000_000_000_000, 0; "SPL Token, larger supply")]
+#[test_case(spl_token::id(), 100_000, 50_000, 0, 1_000, 20_000_000_000_000_000, 0; "SPL Token, excluded supply")]
+#[test_case(spl_token::id(), 3, 0, 0, 1, 333_333_333_333_333_333, 1; "SPL Token, remainder stored")]
+#[tokio::test]
+async fn success(
+    reward_token_program_id: Pubkey,
+    token_supply: u64,
+    excluded_supply: u64,
+    accumulated_rewards_per_token: u128,
+    amount: u64,
+    expected_accumulated_rewards_per_token: u128,
+    expected_rewards_per_token_remainder: u64,
+) {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let source_authority = Keypair::new();
+    let source = Pubkey::new_unique();
+    let source_balance = 1_000_000;
+    let vault_balance = 5_000;
+
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let vault = get_token_rewards_vault_address(
+        &token_rewards_pool,
+        &reward_mint,
+        &reward_token_program_id,
+    );
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+
+    // A single token account holds the excluded supply.
+    let mut excluded_holders = vec![];
+    if excluded_supply != 0 {
+        let token_account = Pubkey::new_unique();
+        let holder_rewards = get_holder_rewards_address(&token_account);
+        setup_token_account(
+            &mut context,
+            &token_account,
+            &Pubkey::new_unique(),
+            &mint,
+            excluded_supply,
+        )
+        .await;
+        setup_excluded_holder_rewards_account(
+            &mut context,
+            &holder_rewards,
+            &mint,
+            excluded_supply,
+        )
+        .await;
+        excluded_holders.push((holder_rewards, token_account));
+    }
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool {
+            excluded_supply,
+            excluded_holders: excluded_holders.len() as u64,
+            ..HolderRewardsPool::default()
+        },
+    )
+    .await;
+    setup_reward_mint(&mut context, &reward_mint, &reward_token_program_id, 6).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(
+            accumulated_rewards_per_token,
+            reward_mint,
+            reward_token_program_id,
+        ),
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &source,
+        &source_authority.pubkey(),
+        &reward_mint,
+        &reward_token_program_id,
+        source_balance,
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &vault,
+        &token_rewards_pool,
+        &reward_mint,
+        &reward_token_program_id,
+        vault_balance,
+    )
+    .await;
+
+    let instruction = distribute_token_rewards(
+        &source_authority.pubkey(),
+        &source,
+        &token_rewards_pool,
+        &vault,
+        &mint,
+        &reward_mint,
+        &reward_token_program_id,
+        &holder_rewards_pool,
+        &excluded_holders,
+        amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the token rewards pool's rate was updated, keeping the
+    // truncated remainder for the next distribution.
+    let token_rewards_pool_account = context
+        .banks_client
+        .get_account(token_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_rewards_pool_state =
+        bytemuck::from_bytes::<TokenRewardsPool>(&token_rewards_pool_account.data);
+    assert_eq!(
+        token_rewards_pool_state.accumulated_rewards_per_token,
+        expected_accumulated_rewards_per_token,
+    );
+    assert_eq!(
+        token_rewards_pool_state.rewards_per_token_remainder,
+        expected_rewards_per_token_remainder,
+    );
+
+    // Assert the rewards were moved from the source to the vault.
+    let source_account = context
+        .banks_client
+        .get_account(source)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        TokenAccount::unpack(&source_account.data).unwrap().amount,
+        source_balance - amount,
+    );
+    let vault_account = context
+        .banks_client
+        .get_account(vault)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        TokenAccount::unpack(&vault_account.data).unwrap().amount,
+        vault_balance + amount,
+    );
+}
+
+#[tokio::test]
+async fn success_excluded_holder_burned() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let source_authority = Keypair::new();
+    let source = Pubkey::new_unique();
+    let amount = 1_000;
+
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let vault =
+        get_token_rewards_vault_address(&token_rewards_pool, &reward_mint, &spl_token::id());
+
+    let token_account = Pubkey::new_unique();
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    // The excluded holder was last settled at a balance of 500, out of a
+    // supply of 1_000, but has since burned 250 tokens, which the transfer
+    // hook doesn't see. The other 500 tokens are held elsewhere.
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 750).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &Pubkey::new_unique(),
+        &mint,
+        250,
+    )
+    .await;
+    setup_excluded_holder_rewards_account(&mut context, &holder_rewards, &mint, 500).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool {
+            excluded_supply: 500,
+            excluded_holders: 1,
+            ..HolderRewardsPool::default()
+        },
+    )
+    .await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token::id(), 6).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &source,
+        &source_authority.pubkey(),
+        &reward_mint,
+        &spl_token::id(),
+        amount,
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &vault,
+        &token_rewards_pool,
+        &reward_mint,
+        &spl_token::id(),
+        0,
+    )
+    .await;
+
+    let instruction = distribute_token_rewards(
+        &source_authority.pubkey(),
+        &source,
+        &token_rewards_pool,
+        &vault,
+        &mint,
+        &reward_mint,
+        &spl_token::id(),
+        &holder_rewards_pool,
+        &[(holder_rewards, token_account)],
+        amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the rewards were distributed over the 500 tokens held
+    // elsewhere, at two reward tokens per token.
+    let token_rewards_pool_account = context
+        .banks_client
+        .get_account(token_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<TokenRewardsPool>(&token_rewards_pool_account.data)
+            .accumulated_rewards_per_token,
+        2_000_000_000_000_000_000,
+    );
+
+    // Assert the holder rewards pool's excluded supply was reconciled.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data)
+            .unwrap()
+            .excluded_supply,
+        250,
+    );
+}
+
+#[tokio::test]
+async fn success_distribution_authority() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let source_authority = Keypair::new();
+    let source = Pubkey::new_unique();
+
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let vault =
+        get_token_rewards_vault_address(&token_rewards_pool, &reward_mint, &spl_token::id());
+
+    // The minimum distribution amount is in lamports, so it doesn't apply to
+    // reward tokens.
+    let pool = HolderRewardsPool {
+        distribution_authority: source_authority.pubkey(),
+        min_distribution_amount: 1_000_000,
+        ..HolderRewardsPool::default()
+    };
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token::id(), 6).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &source,
+        &source_authority.pubkey(),
+        &reward_mint,
+        &spl_token::id(),
+        1_000_000,
+    )
+    .await;
+    setup_reward_token_account(
+        &mut context,
+        &vault,
+        &token_rewards_pool,
+        &reward_mint,
+        &spl_token::id(),
+        0,
+    )
+    .await;
+
+    let instruction = distribute_token_rewards(
+        &source_authority.pubkey(),
+        &source,
+        &token_rewards_pool,
+        &vault,
+        &mint,
+        &reward_mint,
+        &spl_token::id(),
+        &holder_rewards_pool,
+        &[],
+        1_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vault received the rewards.
+    let vault_account = context
+        .banks_client
+        .get_account(vault)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        TokenAccount::unpack(&vault_account.data).unwrap().amount,
+        1_000
+    );
+}
@@ -0,0 +1,546 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::harvest_and_compound,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewards, HolderRewardsPool,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_account_with_state,
+        setup_holder_rewards_pool_account_with_state, setup_system_account, setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        account_info::{next_account_info, AccountInfo},
+        entrypoint::ProgramResult,
+        instruction::InstructionError,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    test_case::test_case,
+};
+
+/// A stand-in for a swap or staking program, which checks the provided
+/// amount was moved to its vault.
+fn process_compound(_program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let _rewards_owner_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let _holder_rewards_pool_info = next_account_info(accounts_iter)?;
+
+    // The compound program must never be able to act with the rewards
+    // owner's authority.
+    if accounts.iter().any(|info| info.is_signer) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let amount = input
+        .try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    if vault_info.lamports() < amount {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    Ok(())
+}
+
+fn setup_with_compound_program(compound_program: &Pubkey) -> ProgramTest {
+    let mut program_test = setup();
+    program_test.add_program("compound", *compound_program, processor!(process_compound));
+    program_test
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+async fn setup_vault_account(
+    context: &mut ProgramTestContext,
+    vault: &Pubkey,
+    compound_program: &Pubkey,
+) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(0);
+
+    context.set_account(
+        vault,
+        &AccountSharedData::new(lamports, 0, compound_program),
+    );
+}
+
+#[tokio::test]
+async fn fail_rewards_owner_not_signer() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let compound_program = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup_with_compound_program(&compound_program)
+        .start_with_context()
+        .await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let mut instruction = harvest_and_compound(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &compound_program,
+        &vault,
+        &[],
+    );
+    instruction.accounts[4].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing owner.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_compound_program_not_allowed() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let compound_program = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup_with_compound_program(&compound_program)
+        .start_with_context()
+        .await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, Pubkey::new_unique()), // Nothing allowed.
+    )
+    .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards {
+            mint,
+            ..HolderRewards::new(0, 0, 0)
+        },
+    )
+    .await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let instruction = harvest_and_compound(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &compound_program,
+        &vault,
+        &[],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::CompoundProgramNotAllowed as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_rewards_owner_not_token_account_owner() {
+    let owner = Keypair::new();
+    let not_owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let compound_program = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.compound_programs[0] = compound_program;
+
+    let mut context = setup_with_compound_program(&compound_program)
+        .start_with_context()
+        .await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards {
+            mint,
+            ..HolderRewards::new(0, 0, 0)
+        },
+    )
+    .await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let instruction = harvest_and_compound(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &not_owner.pubkey(), // Not the token account owner.
+        &compound_program,
+        &vault,
+        &[],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &not_owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountOwnerMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_rewards_owner_not_recipient() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let compound_program = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.compound_programs[0] = compound_program;
+
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, 0)
+    };
+    holder.recipient = Pubkey::new_unique();
+
+    let mut context = setup_with_compound_program(&compound_program)
+        .start_with_context()
+        .await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let instruction = harvest_and_compound(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(), // The token account owner, but not the recipient.
+        &compound_program,
+        &vault,
+        &[],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectRewardsRecipient as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_compound_destination_owner_mismatch() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let compound_program = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(1_000_000_000_000_000_000, Pubkey::new_unique());
+    pool.compound_programs[0] = compound_program;
+
+    let holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, 10_000)
+    };
+
+    let mut context = setup_with_compound_program(&compound_program)
+        .start_with_context()
+        .await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 10_000, &pool)
+        .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 10_000).await;
+    setup_system_account(&mut context, &destination, 0).await; // Not the compound program's.
+
+    let instruction = harvest_and_compound(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &compound_program,
+        &destination,
+        &[],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::CompoundDestinationOwnerMismatch as u32)
+        )
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test_case(false, 10_000, 0, 10_000; "Owner, receive share")]
+#[test_case(true, 10_000, 0, 10_000; "Recipient, receive share")]
+#[test_case(false, 5_000, 1_000, 6_000; "Owner, some unharvested, receive share + unharvested")]
+#[test_case(false, 0, 0, 0; "Owner, nothing to harvest")]
+#[tokio::test]
+async fn success(
+    use_recipient: bool,
+    token_account_balance: u64,
+    unharvested_rewards: u64,
+    expected_harvested_rewards: u64,
+) {
+    let pool_excess_lamports = 10_000;
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_000; // 1 reward per token.
+
+    let owner = Keypair::new();
+    let recipient = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let compound_program = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(accumulated_rewards_per_token, Pubkey::new_unique());
+    pool.compound_programs[1] = compound_program;
+
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, unharvested_rewards, token_account_balance)
+    };
+    if use_recipient {
+        holder.recipient = recipient.pubkey();
+    }
+
+    let rewards_owner = if use_recipient { &recipient } else { &owner };
+
+    let mut context = setup_with_compound_program(&compound_program)
+        .start_with_context()
+        .await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        pool_excess_lamports,
+        &pool,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner.pubkey(),
+        &mint,
+        token_account_balance,
+    )
+    .await;
+    setup_system_account(&mut context, &rewards_owner.pubkey(), 0).await;
+    setup_vault_account(&mut context, &vault, &compound_program).await;
+
+    // For checks later.
+    let pool_beginning_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let rewards_owner_beginning_lamports = context
+        .banks_client
+        .get_account(rewards_owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let vault_beginning_lamports = context
+        .banks_client
+        .get_account(vault)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = harvest_and_compound(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &rewards_owner.pubkey(),
+        &compound_program,
+        &vault,
+        &[],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, rewards_owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder rewards account state was updated.
+    let mut expected_holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(accumulated_rewards_per_token, 0, token_account_balance)
+    };
+    expected_holder.recipient = holder.recipient;
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &expected_holder,
+    );
+
+    // Assert the holder rewards pool's balance was debited.
+    let pool_resulting_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        pool_resulting_lamports,
+        pool_beginning_lamports - expected_harvested_rewards,
+    );
+
+    // Assert the harvested rewards were moved straight to the compound
+    // program's vault, without passing through the rewards owner.
+    let rewards_owner_resulting_lamports = context
+        .banks_client
+        .get_account(rewards_owner.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        rewards_owner_resulting_lamports,
+        rewards_owner_beginning_lamports
+    );
+    let vault_resulting_lamports = context
+        .banks_client
+        .get_account(vault)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        vault_resulting_lamports,
+        vault_beginning_lamports + expected_harvested_rewards,
+    );
+}
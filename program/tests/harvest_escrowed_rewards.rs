@@ -0,0 +1,319 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::{harvest_escrowed_rewards, harvest_escrowed_rewards_to_recipient},
+        state::{get_holder_rewards_address, get_holder_rewards_escrow_address, HolderRewards},
+    },
+    setup::{
+        setup, setup_holder_rewards_account_with_state, setup_holder_rewards_escrow_account,
+        setup_system_account, setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_escrow_incorrect_address() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = Pubkey::new_unique(); // Incorrect escrow address.
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards::new(0, 0, 100),
+    )
+    .await;
+    setup_holder_rewards_escrow_account(&mut context, &escrow, 50).await;
+
+    let instruction = harvest_escrowed_rewards(&escrow, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinRewardsError::IncorrectHolderRewardsEscrowAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_escrow_incorrect_owner() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = get_holder_rewards_escrow_address(&token_account);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards::new(0, 0, 100),
+    )
+    .await;
+
+    // Set up an escrow account not owned by the program.
+    {
+        context.set_account(
+            &escrow,
+            &AccountSharedData::new(100_000_000, 0, &Pubkey::new_unique()),
+        );
+    }
+
+    let instruction = harvest_escrowed_rewards(&escrow, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_recipient() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = get_holder_rewards_escrow_address(&token_account);
+
+    let mut holder = HolderRewards::new(0, 0, 100);
+    holder.recipient = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_holder_rewards_escrow_account(&mut context, &escrow, 50).await;
+
+    let instruction = harvest_escrowed_rewards_to_recipient(
+        &escrow,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &Pubkey::new_unique(), // Incorrect recipient.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectRewardsRecipient as u32)
+        )
+    );
+}
+
+#[test_case(0; "No escrowed rewards")]
+#[test_case(50; "Escrowed rewards")]
+#[tokio::test]
+async fn success(escrowed_rewards: u64) {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = get_holder_rewards_escrow_address(&token_account);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards::new(0, 0, 100),
+    )
+    .await;
+    setup_holder_rewards_escrow_account(&mut context, &escrow, escrowed_rewards).await;
+
+    // For checks later.
+    let token_account_beginning_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = harvest_escrowed_rewards(&escrow, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the escrow account kept only its rent-exempt minimum.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(escrow_account.lamports, rent.minimum_balance(0));
+
+    // Assert the token account was credited the escrowed rewards.
+    let token_account_resulting_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        token_account_resulting_lamports,
+        token_account_beginning_lamports + escrowed_rewards,
+    );
+}
+
+#[tokio::test]
+async fn success_to_recipient() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let escrowed_rewards = 50;
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = get_holder_rewards_escrow_address(&token_account);
+
+    let mut holder = HolderRewards::new(0, 0, 100);
+    holder.recipient = recipient;
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_holder_rewards_escrow_account(&mut context, &escrow, escrowed_rewards).await;
+    setup_system_account(&mut context, &recipient, 0).await;
+
+    // For checks later.
+    let token_account_beginning_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let recipient_beginning_lamports = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = harvest_escrowed_rewards_to_recipient(
+        &escrow,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &recipient,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the recipient was credited the escrowed rewards, rather than
+    // the token account.
+    let recipient_resulting_lamports = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        recipient_resulting_lamports,
+        recipient_beginning_lamports + escrowed_rewards,
+    );
+    let token_account_resulting_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        token_account_resulting_lamports,
+        token_account_beginning_lamports,
+    );
+}
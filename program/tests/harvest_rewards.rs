@@ -5,15 +5,21 @@ mod setup;
 use {
     paladin_rewards_program::{
         error::PaladinRewardsError,
-        instruction::harvest_rewards,
-        state::{get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards},
+        instruction::{harvest_rewards, harvest_rewards_to_recipient},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            EmissionSchedule, HolderRewards, HolderRewardsPool, VersionedAccount,
+        },
     },
     setup::{
-        setup, setup_holder_rewards_account, setup_holder_rewards_pool_account, setup_token_account,
+        setup, setup_holder_rewards_account, setup_holder_rewards_account_with_state,
+        setup_holder_rewards_pool_account, setup_holder_rewards_pool_account_with_state,
+        setup_legacy_account, setup_mint, setup_system_account, setup_token_account,
     },
     solana_program_test::*,
     solana_sdk::{
         account::AccountSharedData,
+        clock::Clock,
         instruction::InstructionError,
         pubkey::Pubkey,
         signer::Signer,
@@ -120,7 +126,7 @@ async fn fail_holder_rewards_pool_incorrect_owner() {
     let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
 
     let mut context = setup().start_with_context().await;
-    setup_holder_rewards_account(&mut context, &holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
     setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
 
     // Setup holder rewards pool account with incorrect owner.
@@ -164,7 +170,7 @@ async fn fail_holder_rewards_pool_incorrect_address() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
     setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
 
     let instruction = harvest_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
@@ -293,7 +299,7 @@ async fn fail_holder_rewards_incorrect_address() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
     setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
 
     let instruction = harvest_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
@@ -369,6 +375,135 @@ async fn fail_holder_rewards_invalid_data() {
     );
 }
 
+#[tokio::test]
+async fn fail_holder_rewards_pool_not_migrated() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+
+    // Setup holder rewards pool account in the original layout.
+    setup_legacy_account(&mut context, &holder_rewards_pool, 0, &0u128.to_le_bytes()).await;
+
+    let instruction = harvest_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_not_migrated() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+
+    // Setup holder rewards account in the original layout, which scaled its
+    // rate by `1e9`.
+    setup_legacy_account(
+        &mut context,
+        &holder_rewards,
+        0,
+        &bytemuck::bytes_of(&HolderRewards::default())[..32],
+    )
+    .await;
+
+    let instruction = harvest_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_discriminator() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+
+    // Setup holder rewards account with the holder rewards pool's header.
+    {
+        let mut data = bytemuck::bytes_of(&HolderRewardsPool::header()).to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&HolderRewards::default()));
+        context.set_account(
+            &holder_rewards,
+            &AccountSharedData::new_data(100_000_000, &data, &paladin_rewards_program::id())
+                .unwrap(),
+        );
+    }
+
+    let instruction = harvest_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
 struct Pool {
     excess_lamports: u64,
     accumulated_rewards_per_token: u128,
@@ -376,6 +511,7 @@ struct Pool {
 
 struct Holder {
     token_account_balance: u64,
+    last_seen_balance: u64,
     last_accumulated_rewards_per_token: u128,
     unharvested_rewards: u64,
 }
@@ -387,6 +523,7 @@ struct Holder {
     },
     Holder {
         token_account_balance: 100,
+        last_seen_balance: 100,
         last_accumulated_rewards_per_token: 0,
         unharvested_rewards: 0,
     },
@@ -397,11 +534,12 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 1_000_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
         unharvested_rewards: 0,
     },
     0,
@@ -411,11 +549,12 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 1_000_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
         unharvested_rewards: 500_000,
     },
     500_000, // Unharvested.
@@ -425,10 +564,11 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 50_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 100_000,
+        last_seen_balance: 100_000,
         last_accumulated_rewards_per_token: 0,
         unharvested_rewards: 0,
     },
@@ -439,10 +579,11 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 1_000_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 10_000,
+        last_seen_balance: 10_000,
         last_accumulated_rewards_per_token: 0,
         unharvested_rewards: 0,
     },
@@ -453,10 +594,11 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 1_000_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 10_000,
+        last_seen_balance: 10_000,
         last_accumulated_rewards_per_token: 0,
         unharvested_rewards: 10_000,
     },
@@ -467,11 +609,12 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 10_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 10_000,
-        last_accumulated_rewards_per_token: 500_000_000, // 0.5 rewards per token.
+        last_seen_balance: 10_000,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
         unharvested_rewards: 0,
     },
     5_000, // (1 - 0.5) * 10_000
@@ -481,11 +624,12 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 10_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 10_000,
-        last_accumulated_rewards_per_token: 500_000_000, // 0.5 rewards per token.
+        last_seen_balance: 10_000,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
         unharvested_rewards: 1_000,
     },
     6_000, // (1 - 0.5) * 10_000 = 5_000 share + 1_000 unharvested
@@ -495,11 +639,12 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 10_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 10_000,
-        last_accumulated_rewards_per_token: 500_000_000, // 0.5 rewards per token.
+        last_seen_balance: 10_000,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
         unharvested_rewards: 8_000,
     },
     10_000, // Pool excess.
@@ -509,11 +654,12 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 10_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 10_000,
-        last_accumulated_rewards_per_token: 250_000_000, // 0.25 rewards per token.
+        last_seen_balance: 10_000,
+        last_accumulated_rewards_per_token: 250_000_000_000_000_000, // 0.25 rewards per token.
         unharvested_rewards: 0,
     },
     7_500, // (1 - 0.25) * 10_000
@@ -523,11 +669,12 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 10_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 10_000,
-        last_accumulated_rewards_per_token: 250_000_000, // 0.25 rewards per token.
+        last_seen_balance: 10_000,
+        last_accumulated_rewards_per_token: 250_000_000_000_000_000, // 0.25 rewards per token.
         unharvested_rewards: 1_000,
     },
     8_500, // (1 - 0.25) * 10_000 = 7_500 share + 1_000 unharvested
@@ -537,17 +684,48 @@ struct Holder {
 #[test_case(
     Pool {
         excess_lamports: 10_000,
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 10_000,
-        last_accumulated_rewards_per_token: 250_000_000, // 0.25 rewards per token.
+        last_seen_balance: 10_000,
+        last_accumulated_rewards_per_token: 250_000_000_000_000_000, // 0.25 rewards per token.
         unharvested_rewards: 4_000,
     },
     10_000, // Pool excess.
     1_500; // 10_000 pool excess - [(1 - 0.25) * 10_000 = 7_500 share + 4_000 unharvested]
     "Last harvested 0.25 rate, some unharvested, eligible for 0.75 rate, pool underfunded, receive pool excess"
 )]
+#[test_case(
+    Pool {
+        excess_lamports: 1_000_000,
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
+    },
+    Holder {
+        token_account_balance: 100_000, // Minted since last seen.
+        last_seen_balance: 10_000,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
+        unharvested_rewards: 0,
+    },
+    5_000, // (1 - 0.5) * 10_000
+    0;
+    "Last harvested 0.5 rate, balance increased since last seen, receive share of last seen balance"
+)]
+#[test_case(
+    Pool {
+        excess_lamports: 1_000_000,
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
+    },
+    Holder {
+        token_account_balance: 1_000, // Burned since last seen.
+        last_seen_balance: 10_000,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
+        unharvested_rewards: 0,
+    },
+    500, // (1 - 0.5) * 1_000
+    0;
+    "Last harvested 0.5 rate, balance decreased since last seen, receive share of current balance"
+)]
 #[tokio::test]
 async fn success(
     pool: Pool,
@@ -562,6 +740,7 @@ async fn success(
 
     let Holder {
         token_account_balance,
+        last_seen_balance,
         last_accumulated_rewards_per_token,
         unharvested_rewards,
     } = holder;
@@ -584,8 +763,10 @@ async fn success(
     setup_holder_rewards_account(
         &mut context,
         &holder_rewards,
+        &mint,
         unharvested_rewards,
         last_accumulated_rewards_per_token,
+        last_seen_balance,
     )
     .await;
     setup_token_account(
@@ -636,22 +817,35 @@ async fn success(
         .unwrap()
         .unwrap();
     assert_eq!(
-        bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data),
-        &HolderRewards::new(accumulated_rewards_per_token, expected_unharvested_rewards),
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &HolderRewards {
+            mint,
+            ..HolderRewards::new(
+                accumulated_rewards_per_token,
+                expected_unharvested_rewards,
+                token_account_balance,
+            )
+        },
     );
 
-    // Assert the holder rewards pool's balance was debited.
-    let pool_resulting_lamports = context
+    // Assert the holder rewards pool's balance was debited, and the harvest
+    // was added to its total harvested rewards.
+    let holder_rewards_pool_account = context
         .banks_client
         .get_account(holder_rewards_pool)
         .await
         .unwrap()
-        .unwrap()
-        .lamports;
+        .unwrap();
     assert_eq!(
-        pool_resulting_lamports,
+        holder_rewards_pool_account.lamports,
         pool_beginning_lamports.saturating_sub(expected_harvested_rewards),
     );
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data)
+            .unwrap()
+            .total_harvested,
+        expected_harvested_rewards,
+    );
 
     // Assert the token account's balance was credited.
     let token_account_resulting_lamports = context
@@ -666,3 +860,440 @@ async fn success(
         token_account_beginning_lamports.saturating_add(expected_harvested_rewards),
     );
 }
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test_case(0, 50; "Receive share of released rewards")]
+#[test_case(900, 500; "Claims exceed released rewards, escrow is not paid out")]
+#[tokio::test]
+async fn success_with_emission_schedule(unharvested_rewards: u64, expected_harvested_rewards: u64) {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let token_supply = 100;
+    let token_account_balance = 10;
+    let current_timestamp = 10_000;
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    // Half of the schedule's budget has vested, but none of it has been
+    // released to the pool's rate yet.
+    let emission_schedule = EmissionSchedule {
+        total_amount: 1_000,
+        released_amount: 0,
+        start_timestamp: current_timestamp - 100,
+        end_timestamp: current_timestamp + 100,
+    };
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.emission_schedule = emission_schedule;
+
+    let mut context = setup().start_with_context().await;
+    context.set_sysvar(&Clock {
+        unix_timestamp: current_timestamp,
+        ..Clock::default()
+    });
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), token_supply).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        emission_schedule.total_amount,
+        &pool,
+    )
+    .await;
+    setup_holder_rewards_account(
+        &mut context,
+        &holder_rewards,
+        &mint,
+        unharvested_rewards,
+        0,
+        token_account_balance,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &mint,
+        token_account_balance,
+    )
+    .await;
+
+    // For checks later.
+    let token_account_beginning_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = harvest_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vested rewards were released to the pool's rate.
+    let expected_accumulated_rewards_per_token = 5_000_000_000_000_000_000; // 500 / 100
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state =
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap();
+    assert_eq!(
+        pool_state.accumulated_rewards_per_token,
+        expected_accumulated_rewards_per_token
+    );
+    assert_eq!(pool_state.emission_schedule.released_amount, 500);
+
+    // Assert the holder was settled against the new rate.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &HolderRewards {
+            mint,
+            ..HolderRewards::new(
+                expected_accumulated_rewards_per_token,
+                unharvested_rewards + 50 - expected_harvested_rewards,
+                token_account_balance,
+            )
+        },
+    );
+
+    // Assert the token account's balance was credited.
+    let token_account_resulting_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        token_account_resulting_lamports,
+        token_account_beginning_lamports + expected_harvested_rewards,
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[tokio::test]
+async fn success_with_emission_schedule_excluded_holders() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let token_account_balance = 10;
+    let current_timestamp = 10_000;
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    // Half of the schedule's budget has vested, but the pool has an excluded
+    // holder, whose balance may be stale.
+    let emission_schedule = EmissionSchedule {
+        total_amount: 1_000,
+        released_amount: 0,
+        start_timestamp: current_timestamp - 100,
+        end_timestamp: current_timestamp + 100,
+    };
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.emission_schedule = emission_schedule;
+    pool.excluded_supply = 50;
+    pool.excluded_holders = 1;
+
+    let mut context = setup().start_with_context().await;
+    context.set_sysvar(&Clock {
+        unix_timestamp: current_timestamp,
+        ..Clock::default()
+    });
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        emission_schedule.total_amount,
+        &pool,
+    )
+    .await;
+    setup_holder_rewards_account(
+        &mut context,
+        &holder_rewards,
+        &mint,
+        0,
+        0,
+        token_account_balance,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &mint,
+        token_account_balance,
+    )
+    .await;
+
+    let instruction = harvest_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the vested rewards weren't released, since the excluded supply
+    // can only be reconciled by instructions that distribute to the pool.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &pool,
+    );
+}
+
+#[tokio::test]
+async fn fail_recipient_not_provided() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, 100)
+    };
+    holder.recipient = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        10_000,
+        1_000_000_000_000_000_000,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+
+    let instruction = harvest_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_recipient() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, 100)
+    };
+    holder.recipient = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        10_000,
+        1_000_000_000_000_000_000,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+
+    let instruction = harvest_rewards_to_recipient(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &Pubkey::new_unique(), // Incorrect recipient.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectRewardsRecipient as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_to_recipient() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let token_account_balance = 100;
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_000; // 1 reward per token.
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, token_account_balance)
+    };
+    holder.recipient = recipient;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        10_000,
+        accumulated_rewards_per_token,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &mint,
+        token_account_balance,
+    )
+    .await;
+    setup_system_account(&mut context, &recipient, 0).await;
+
+    // For checks later.
+    let token_account_beginning_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let recipient_beginning_lamports = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = harvest_rewards_to_recipient(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &recipient,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder was settled, keeping its recipient.
+    let mut expected_holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(accumulated_rewards_per_token, 0, 100)
+    };
+    expected_holder.recipient = recipient;
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &expected_holder,
+    );
+
+    // Assert the recipient was credited the rewards, rather than the token
+    // account.
+    let recipient_resulting_lamports = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        recipient_resulting_lamports,
+        recipient_beginning_lamports + token_account_balance,
+    );
+    let token_account_resulting_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        token_account_resulting_lamports,
+        token_account_beginning_lamports
+    );
+}
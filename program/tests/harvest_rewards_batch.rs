@@ -0,0 +1,426 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::harvest_rewards_batch,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewards, HolderRewardsPool,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_account, setup_holder_rewards_account_with_state,
+        setup_holder_rewards_pool_account, setup_system_account, setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_incorrect_address() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = Pubkey::new_unique(); // Incorrect holder rewards pool address.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+
+    let instruction = harvest_rewards_batch(
+        &holder_rewards_pool,
+        &mint,
+        &[(holder_rewards, token_account, None)],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_not_provided() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+
+    let mut instruction = harvest_rewards_batch(
+        &holder_rewards_pool,
+        &mint,
+        &[(holder_rewards, token_account, None)],
+    );
+    instruction.accounts.pop(); // Holder rewards without its token account.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_mint_mismatch() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        10_000,
+        1_000_000_000_000_000_000,
+    )
+    .await;
+
+    // The second holder's token account is for a different mint, which fails
+    // the whole batch.
+    let mut holders = vec![];
+    for token_account_mint in [mint, Pubkey::new_unique()] {
+        let owner = Pubkey::new_unique();
+        let token_account = get_associated_token_address(&owner, &mint);
+        let holder_rewards = get_holder_rewards_address(&token_account);
+        setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 100).await;
+        setup_token_account(
+            &mut context,
+            &token_account,
+            &owner,
+            &token_account_mint,
+            100,
+        )
+        .await;
+        holders.push((holder_rewards, token_account, None));
+    }
+
+    let instruction = harvest_rewards_batch(&holder_rewards_pool, &mint, &holders);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountMintMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let owners = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let token_accounts = owners.map(|owner| get_associated_token_address(&owner, &mint));
+    let holder_rewards =
+        token_accounts.map(|token_account| get_holder_rewards_address(&token_account));
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    for i in 0..2 {
+        setup_holder_rewards_account(&mut context, &holder_rewards[i], &mint, 0, 0, 0).await;
+        setup_token_account(&mut context, &token_accounts[i], &owners[i], &mint, 0).await;
+    }
+
+    // The holder rewards accounts are swapped between the token accounts.
+    let instruction = harvest_rewards_batch(
+        &holder_rewards_pool,
+        &mint,
+        &[
+            (holder_rewards[1], token_accounts[0], None),
+            (holder_rewards[0], token_accounts[1], None),
+        ],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_recipient_not_provided() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        10_000,
+        1_000_000_000_000_000_000,
+    )
+    .await;
+
+    // The first holder has a rewards recipient, but is followed directly by
+    // the second holder, whose holder rewards account is read as the
+    // recipient.
+    let mut holders = vec![];
+    for has_recipient in [true, false] {
+        let owner = Pubkey::new_unique();
+        let token_account = get_associated_token_address(&owner, &mint);
+        let holder_rewards = get_holder_rewards_address(&token_account);
+        let mut holder = HolderRewards {
+            mint,
+            ..HolderRewards::new(0, 0, 100)
+        };
+        if has_recipient {
+            holder.recipient = Pubkey::new_unique();
+        }
+        setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+        setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+        holders.push((holder_rewards, token_account, None));
+    }
+
+    let instruction = harvest_rewards_batch(&holder_rewards_pool, &mint, &holders);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectRewardsRecipient as u32)
+        )
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test_case(1; "One holder")]
+#[test_case(3; "Three holders")]
+#[test_case(14; "Fourteen holders")]
+#[tokio::test]
+async fn success(holder_count: u64) {
+    let mint = Pubkey::new_unique();
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_000; // 1 reward per token.
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        100_000,
+        accumulated_rewards_per_token,
+    )
+    .await;
+
+    // Each holder has a different balance, and every third holder has a
+    // rewards recipient.
+    let mut holders = vec![];
+    for i in 0..holder_count {
+        let owner = Pubkey::new_unique();
+        let token_account = get_associated_token_address(&owner, &mint);
+        let holder_rewards = get_holder_rewards_address(&token_account);
+        let token_account_balance = 100 * (i + 1);
+
+        let mut holder = HolderRewards {
+            mint,
+            ..HolderRewards::new(0, 0, token_account_balance)
+        };
+        let recipient = (i % 3 == 2).then(Pubkey::new_unique);
+        if let Some(recipient) = recipient {
+            holder.recipient = recipient;
+            setup_system_account(&mut context, &recipient, 0).await;
+        }
+        setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+        setup_token_account(
+            &mut context,
+            &token_account,
+            &owner,
+            &mint,
+            token_account_balance,
+        )
+        .await;
+        holders.push((holder_rewards, token_account, recipient));
+    }
+
+    // For checks later.
+    let pool_beginning_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let mut destinations_beginning_lamports = vec![];
+    for (_, token_account, recipient) in &holders {
+        destinations_beginning_lamports.push(
+            context
+                .banks_client
+                .get_account(recipient.unwrap_or(*token_account))
+                .await
+                .unwrap()
+                .unwrap()
+                .lamports,
+        );
+    }
+
+    let instruction = harvest_rewards_batch(&holder_rewards_pool, &mint, &holders);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert each holder was settled, and its rewards were credited to its
+    // recipient, or to its token account if it has none.
+    let mut expected_total_harvested = 0;
+    for (i, ((holder_rewards, token_account, recipient), beginning_lamports)) in holders
+        .iter()
+        .zip(destinations_beginning_lamports)
+        .enumerate()
+    {
+        let token_account_balance = 100 * (i as u64 + 1);
+        expected_total_harvested += token_account_balance;
+
+        let mut expected_holder = HolderRewards {
+            mint,
+            ..HolderRewards::new(accumulated_rewards_per_token, 0, token_account_balance)
+        };
+        if let Some(recipient) = recipient {
+            expected_holder.recipient = *recipient;
+        }
+        let holder_rewards_account = context
+            .banks_client
+            .get_account(*holder_rewards)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+            &expected_holder,
+        );
+
+        let destination_resulting_lamports = context
+            .banks_client
+            .get_account(recipient.unwrap_or(*token_account))
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports;
+        assert_eq!(
+            destination_resulting_lamports,
+            beginning_lamports + token_account_balance,
+        );
+    }
+
+    // Assert the holder rewards pool's balance was debited once per holder,
+    // and the harvests were added to its total harvested rewards.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        holder_rewards_pool_account.lamports,
+        pool_beginning_lamports - expected_total_harvested,
+    );
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data)
+            .unwrap()
+            .total_harvested,
+        expected_total_harvested,
+    );
+}
@@ -0,0 +1,480 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::harvest_rewards_to_destination,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewards,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_account, setup_holder_rewards_account_with_state,
+        setup_holder_rewards_pool_account, setup_system_account, setup_token_account,
+        setup_token_account_with_delegate,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_token_account_mint_mismatch() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner.pubkey(),
+        &Pubkey::new_unique(), // Incorrect mint.
+        0,
+    )
+    .await;
+
+    let instruction = harvest_rewards_to_destination(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountMintMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_authority_not_owner_or_delegate() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account_with_delegate(
+        &mut context,
+        &token_account,
+        &owner.pubkey(),
+        &Pubkey::new_unique(),
+        &mint,
+        0,
+    )
+    .await;
+
+    let not_authority = Keypair::new();
+
+    let instruction = harvest_rewards_to_destination(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &not_authority.pubkey(), // Neither owner nor delegate.
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &not_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountOwnerMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_authority_not_signer() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let mut instruction = harvest_rewards_to_destination(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+    );
+    instruction.accounts[4].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing owner.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_destination_is_program_account() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let instruction = harvest_rewards_to_destination(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &holder_rewards_pool, // Destination is the holder rewards pool.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+}
+
+#[tokio::test]
+async fn fail_destination_not_recipient() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, 0)
+    };
+    holder.recipient = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let instruction = harvest_rewards_to_destination(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination, // Not the holder's rewards recipient.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectRewardsRecipient as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_address() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = Pubkey::new_unique(); // Incorrect holder rewards address.
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 0).await;
+
+    let instruction = harvest_rewards_to_destination(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[test_case(true, 10_000, 0, 10_000, 0; "Owner, receive share")]
+#[test_case(false, 10_000, 0, 10_000, 0; "Delegate, receive share")]
+#[test_case(true, 5_000, 1_000, 6_000, 0; "Owner, some unharvested, receive share + unharvested")]
+#[test_case(true, 5_000, 8_000, 10_000, 3_000; "Owner, pool underfunded, receive pool excess")]
+#[tokio::test]
+async fn success(
+    sign_as_owner: bool,
+    token_account_balance: u64,
+    unharvested_rewards: u64,
+    expected_harvested_rewards: u64,
+    expected_unharvested_rewards: u64,
+) {
+    let pool_excess_lamports = 10_000;
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_000; // 1 reward per token.
+
+    let owner = Keypair::new();
+    let delegate = Keypair::new();
+    let mint = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        pool_excess_lamports,
+        accumulated_rewards_per_token,
+    )
+    .await;
+    setup_holder_rewards_account(
+        &mut context,
+        &holder_rewards,
+        &mint,
+        unharvested_rewards,
+        0,
+        token_account_balance,
+    )
+    .await;
+    setup_token_account_with_delegate(
+        &mut context,
+        &token_account,
+        &owner.pubkey(),
+        &delegate.pubkey(),
+        &mint,
+        token_account_balance,
+    )
+    .await;
+    setup_system_account(&mut context, &destination, 0).await;
+
+    // For checks later.
+    let pool_beginning_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let token_account_beginning_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let destination_beginning_lamports = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let authority = if sign_as_owner { &owner } else { &delegate };
+
+    let instruction = harvest_rewards_to_destination(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &authority.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder rewards account state was updated.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &HolderRewards {
+            mint,
+            ..HolderRewards::new(
+                accumulated_rewards_per_token,
+                expected_unharvested_rewards,
+                token_account_balance,
+            )
+        },
+    );
+
+    // Assert the holder rewards pool's balance was debited.
+    let pool_resulting_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        pool_resulting_lamports,
+        pool_beginning_lamports.saturating_sub(expected_harvested_rewards),
+    );
+
+    // Assert the destination's balance was credited.
+    let destination_resulting_lamports = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        destination_resulting_lamports,
+        destination_beginning_lamports.saturating_add(expected_harvested_rewards),
+    );
+
+    // Assert the token account's balance was not changed.
+    let token_account_resulting_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        token_account_resulting_lamports,
+        token_account_beginning_lamports
+    );
+}
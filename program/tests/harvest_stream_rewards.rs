@@ -0,0 +1,336 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::{harvest_stream_rewards, harvest_stream_rewards_to_recipient},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewards, HolderRewardsPool, RewardStream,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_account_with_state,
+        setup_holder_rewards_pool_account_with_state, setup_mint, setup_system_account,
+        setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    test_case::test_case,
+};
+
+const REWARD_ID: u64 = 7;
+const STREAM_SLOT: usize = 1;
+
+fn pool_with_reward_stream(
+    accumulated_rewards_per_token: u128,
+    stream_accumulated_rewards_per_token: u128,
+) -> HolderRewardsPool {
+    let mut pool = HolderRewardsPool::new(accumulated_rewards_per_token, Pubkey::new_unique());
+    pool.reward_streams[STREAM_SLOT] = RewardStream {
+        accumulated_rewards_per_token: stream_accumulated_rewards_per_token,
+        reward_id: REWARD_ID,
+        total_distributed: 0,
+        ..RewardStream::default()
+    };
+    pool
+}
+
+#[test_case(0; "Reward ID zero")]
+#[test_case(8; "Unknown reward ID")]
+#[tokio::test]
+async fn fail_reward_stream_not_found(reward_id: u64) {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        10_000,
+        &pool_with_reward_stream(0, 1_000_000_000),
+    )
+    .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards {
+            mint,
+            ..HolderRewards::new(0, 0, 100)
+        },
+    )
+    .await;
+
+    let instruction = harvest_stream_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        reward_id,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::RewardStreamNotFound as u32)
+        )
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test_case(10_000, 0, 0, 0; "No stream rewards")]
+#[test_case(10_000, 1_000_000_000_000_000_000, 0, 100; "New stream rewards")]
+#[test_case(10_000, 1_000_000_000_000_000_000, 50, 150; "New and unharvested stream rewards")]
+#[test_case(60, 1_000_000_000_000_000_000, 50, 60; "Pool underfunded, receive pool excess")]
+#[tokio::test]
+async fn success(
+    pool_excess_lamports: u64,
+    stream_accumulated_rewards_per_token: u128,
+    stream_unharvested_rewards: u64,
+    expected_harvested_rewards: u64,
+) {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let token_account_balance = 100;
+
+    // The base rate has also accrued rewards, which should be settled but
+    // not paid out.
+    let accumulated_rewards_per_token = 500_000_000_000_000_000;
+    let expected_base_unharvested_rewards = 50;
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let pool = pool_with_reward_stream(
+        accumulated_rewards_per_token,
+        stream_accumulated_rewards_per_token,
+    );
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, token_account_balance)
+    };
+    holder.reward_stream_checkpoints[STREAM_SLOT].unharvested_rewards = stream_unharvested_rewards;
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &mint,
+        token_account_balance,
+    )
+    .await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        pool_excess_lamports,
+        &pool,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+
+    // For checks later.
+    let pool_beginning_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let token_account_beginning_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = harvest_stream_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        REWARD_ID,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the stream rewards were moved from the pool to the token
+    // account.
+    let pool_resulting_lamports = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        pool_resulting_lamports,
+        pool_beginning_lamports - expected_harvested_rewards
+    );
+    let token_account_resulting_lamports = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        token_account_resulting_lamports,
+        token_account_beginning_lamports + expected_harvested_rewards
+    );
+
+    // Assert the holder was settled against every rate.
+    let expected_stream_unharvested_rewards = stream_unharvested_rewards
+        + (stream_accumulated_rewards_per_token * token_account_balance as u128
+            / 1_000_000_000_000_000_000) as u64
+        - expected_harvested_rewards;
+    let mut expected_holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(
+            accumulated_rewards_per_token,
+            expected_base_unharvested_rewards,
+            token_account_balance,
+        )
+    };
+    expected_holder.reward_stream_checkpoints[STREAM_SLOT].last_accumulated_rewards_per_token =
+        stream_accumulated_rewards_per_token;
+    expected_holder.reward_stream_checkpoints[STREAM_SLOT].unharvested_rewards =
+        expected_stream_unharvested_rewards;
+
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &expected_holder,
+    );
+}
+
+#[tokio::test]
+async fn success_to_recipient() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let token_account_balance = 100;
+    let stream_accumulated_rewards_per_token = 1_000_000_000_000_000_000; // 1 reward per token.
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, token_account_balance)
+    };
+    holder.recipient = recipient;
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &mint,
+        token_account_balance,
+    )
+    .await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        10_000,
+        &pool_with_reward_stream(0, stream_accumulated_rewards_per_token),
+    )
+    .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_system_account(&mut context, &recipient, 0).await;
+
+    // For checks later.
+    let recipient_beginning_lamports = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let instruction = harvest_stream_rewards_to_recipient(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        REWARD_ID,
+        &recipient,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the stream rewards were moved to the recipient.
+    let recipient_resulting_lamports = context
+        .banks_client
+        .get_account(recipient)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(
+        recipient_resulting_lamports,
+        recipient_beginning_lamports + token_account_balance
+    );
+}
@@ -0,0 +1,541 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::harvest_token_rewards,
+        state::{
+            get_holder_rewards_address, get_token_holder_rewards_address,
+            get_token_rewards_pool_address, get_token_rewards_vault_address, HolderRewards,
+            TokenHolderRewards, TokenRewardsPool,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_account_with_state, setup_mint, setup_reward_mint,
+        setup_reward_token_account, setup_token_account, setup_token_holder_rewards_account,
+        setup_token_rewards_pool_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        program_pack::Pack,
+        pubkey::Pubkey,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    spl_token_2022::state::Account as TokenAccount,
+    test_case::test_case,
+};
+
+struct Accounts {
+    mint: Pubkey,
+    reward_mint: Pubkey,
+    token_account: Pubkey,
+    token_rewards_pool: Pubkey,
+    token_holder_rewards: Pubkey,
+    holder_rewards: Pubkey,
+    vault: Pubkey,
+    destination: Pubkey,
+}
+
+async fn setup_accounts(
+    context: &mut ProgramTestContext,
+    owner: &Pubkey,
+    reward_token_program_id: &Pubkey,
+    token_account_balance: u64,
+    vault_balance: u64,
+) -> Accounts {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(owner, &mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let token_holder_rewards =
+        get_token_holder_rewards_address(&token_account, &token_rewards_pool);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let vault =
+        get_token_rewards_vault_address(&token_rewards_pool, &reward_mint, reward_token_program_id);
+    let destination = Pubkey::new_unique();
+
+    setup_mint(context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_account(context, &token_account, owner, &mint, token_account_balance).await;
+    setup_reward_mint(context, &reward_mint, reward_token_program_id, 6).await;
+    setup_reward_token_account(
+        context,
+        &vault,
+        &token_rewards_pool,
+        &reward_mint,
+        reward_token_program_id,
+        vault_balance,
+    )
+    .await;
+    setup_reward_token_account(
+        context,
+        &destination,
+        owner,
+        &reward_mint,
+        reward_token_program_id,
+        0,
+    )
+    .await;
+
+    Accounts {
+        mint,
+        reward_mint,
+        token_account,
+        token_rewards_pool,
+        token_holder_rewards,
+        holder_rewards,
+        vault,
+        destination,
+    }
+}
+
+#[tokio::test]
+async fn fail_vault_incorrect_address() {
+    let owner = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context, &owner, &spl_token::id(), 100, 10_000).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &accounts.token_rewards_pool,
+        &TokenRewardsPool::new(0, accounts.reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_token_holder_rewards_account(&mut context, &accounts.token_holder_rewards, 0, 0, 100)
+        .await;
+
+    // Set up a vault at the wrong address.
+    let vault = Pubkey::new_unique();
+    setup_reward_token_account(
+        &mut context,
+        &vault,
+        &accounts.token_rewards_pool,
+        &accounts.reward_mint,
+        &spl_token::id(),
+        10_000,
+    )
+    .await;
+
+    let instruction = harvest_token_rewards(
+        &accounts.token_rewards_pool,
+        &accounts.token_holder_rewards,
+        &accounts.token_account,
+        &accounts.mint,
+        &vault,
+        &accounts.destination,
+        &accounts.reward_mint,
+        &spl_token::id(),
+        &accounts.holder_rewards,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectTokenRewardsVaultAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_destination_mint_mismatch() {
+    let owner = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context, &owner, &spl_token::id(), 100, 10_000).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &accounts.token_rewards_pool,
+        &TokenRewardsPool::new(0, accounts.reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_token_holder_rewards_account(&mut context, &accounts.token_holder_rewards, 0, 0, 100)
+        .await;
+
+    // Set up a destination for a different mint.
+    let destination = Pubkey::new_unique();
+    setup_reward_token_account(
+        &mut context,
+        &destination,
+        &owner,
+        &Pubkey::new_unique(), // Incorrect mint.
+        &spl_token::id(),
+        0,
+    )
+    .await;
+
+    let instruction = harvest_token_rewards(
+        &accounts.token_rewards_pool,
+        &accounts.token_holder_rewards,
+        &accounts.token_account,
+        &accounts.mint,
+        &accounts.vault,
+        &destination,
+        &accounts.reward_mint,
+        &spl_token::id(),
+        &accounts.holder_rewards,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountMintMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_destination_owner_mismatch() {
+    let owner = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context, &owner, &spl_token::id(), 100, 10_000).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &accounts.token_rewards_pool,
+        &TokenRewardsPool::new(0, accounts.reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_token_holder_rewards_account(&mut context, &accounts.token_holder_rewards, 0, 0, 100)
+        .await;
+
+    // Set up a destination owned by someone other than the holder.
+    let destination = Pubkey::new_unique();
+    setup_reward_token_account(
+        &mut context,
+        &destination,
+        &Pubkey::new_unique(), // Incorrect owner.
+        &accounts.reward_mint,
+        &spl_token::id(),
+        0,
+    )
+    .await;
+
+    let instruction = harvest_token_rewards(
+        &accounts.token_rewards_pool,
+        &accounts.token_holder_rewards,
+        &accounts.token_account,
+        &accounts.mint,
+        &accounts.vault,
+        &destination,
+        &accounts.reward_mint,
+        &spl_token::id(),
+        &accounts.holder_rewards,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountOwnerMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_holder_rewards_incorrect_address() {
+    let owner = Pubkey::new_unique();
+
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(&mut context, &owner, &spl_token::id(), 100, 10_000).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &accounts.token_rewards_pool,
+        &TokenRewardsPool::new(0, accounts.reward_mint, spl_token::id()),
+    )
+    .await;
+
+    // Set up a token holder rewards account at the wrong address.
+    let token_holder_rewards = Pubkey::new_unique();
+    setup_token_holder_rewards_account(&mut context, &token_holder_rewards, 0, 0, 100).await;
+
+    let instruction = harvest_token_rewards(
+        &accounts.token_rewards_pool,
+        &token_holder_rewards,
+        &accounts.token_account,
+        &accounts.mint,
+        &accounts.vault,
+        &accounts.destination,
+        &accounts.reward_mint,
+        &spl_token::id(),
+        &accounts.holder_rewards,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[test_case(spl_token::id(), 10_000, 0, 0, 0, 0; "No rewards")]
+#[test_case(spl_token::id(), 10_000, 1_000_000_000_000_000_000, 0, 0, 100; "SPL Token, new rewards")]
+#[test_case(spl_token_2022::id(), 10_000, 1_000_000_000_000_000_000, 0, 0, 100; "Token-2022, new rewards")]
+#[test_case(spl_token_2022::id(), 10_000, 1_000_000_000_000_000_000, 500_000_000_000_000_000, 25, 75; "Token-2022, new and unharvested rewards")]
+#[test_case(spl_token::id(), 50, 1_000_000_000_000_000_000, 0, 0, 50; "Vault underfunded, receive vault balance")]
+#[tokio::test]
+async fn success(
+    reward_token_program_id: Pubkey,
+    vault_balance: u64,
+    accumulated_rewards_per_token: u128,
+    last_accumulated_rewards_per_token: u128,
+    unharvested_rewards: u64,
+    expected_harvested_rewards: u64,
+) {
+    let owner = Pubkey::new_unique();
+    let token_account_balance = 100;
+
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(
+        &mut context,
+        &owner,
+        &reward_token_program_id,
+        token_account_balance,
+        vault_balance,
+    )
+    .await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &accounts.token_rewards_pool,
+        &TokenRewardsPool::new(
+            accumulated_rewards_per_token,
+            accounts.reward_mint,
+            reward_token_program_id,
+        ),
+    )
+    .await;
+    setup_token_holder_rewards_account(
+        &mut context,
+        &accounts.token_holder_rewards,
+        unharvested_rewards,
+        last_accumulated_rewards_per_token,
+        token_account_balance,
+    )
+    .await;
+
+    let instruction = harvest_token_rewards(
+        &accounts.token_rewards_pool,
+        &accounts.token_holder_rewards,
+        &accounts.token_account,
+        &accounts.mint,
+        &accounts.vault,
+        &accounts.destination,
+        &accounts.reward_mint,
+        &reward_token_program_id,
+        &accounts.holder_rewards,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the rewards were moved from the vault to the destination.
+    let vault_account = context
+        .banks_client
+        .get_account(accounts.vault)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        TokenAccount::unpack(&vault_account.data).unwrap().amount,
+        vault_balance - expected_harvested_rewards,
+    );
+    let destination_account = context
+        .banks_client
+        .get_account(accounts.destination)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        TokenAccount::unpack(&destination_account.data)
+            .unwrap()
+            .amount,
+        expected_harvested_rewards,
+    );
+
+    // Assert the token holder rewards account was updated.
+    let token_holder_rewards_account = context
+        .banks_client
+        .get_account(accounts.token_holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let expected_unharvested_rewards = unharvested_rewards
+        + ((accumulated_rewards_per_token - last_accumulated_rewards_per_token)
+            * token_account_balance as u128
+            / 1_000_000_000_000_000_000) as u64
+        - expected_harvested_rewards;
+    assert_eq!(
+        bytemuck::from_bytes::<TokenHolderRewards>(&token_holder_rewards_account.data),
+        &TokenHolderRewards::new(
+            accumulated_rewards_per_token,
+            expected_unharvested_rewards,
+            token_account_balance,
+        ),
+    );
+}
+
+#[tokio::test]
+async fn success_excluded() {
+    let owner = Pubkey::new_unique();
+    let token_account_balance = 100;
+    let unharvested_rewards = 25;
+
+    let mut context = setup().start_with_context().await;
+    let accounts = setup_accounts(
+        &mut context,
+        &owner,
+        &spl_token::id(),
+        token_account_balance,
+        10_000,
+    )
+    .await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &accounts.token_rewards_pool,
+        &TokenRewardsPool::new(
+            1_000_000_000_000_000_000,
+            accounts.reward_mint,
+            spl_token::id(),
+        ),
+    )
+    .await;
+    setup_token_holder_rewards_account(
+        &mut context,
+        &accounts.token_holder_rewards,
+        unharvested_rewards,
+        0,
+        token_account_balance,
+    )
+    .await;
+
+    // The token account is excluded from the holder rewards pool.
+    let mut holder = HolderRewards::new(0, 0, token_account_balance);
+    holder.excluded = 1;
+    setup_holder_rewards_account_with_state(&mut context, &accounts.holder_rewards, &holder).await;
+
+    let instruction = harvest_token_rewards(
+        &accounts.token_rewards_pool,
+        &accounts.token_holder_rewards,
+        &accounts.token_account,
+        &accounts.mint,
+        &accounts.vault,
+        &accounts.destination,
+        &accounts.reward_mint,
+        &spl_token::id(),
+        &accounts.holder_rewards,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the rewards already unharvested were harvested, with
+    // nothing earned since the last checkpoint.
+    let destination_account = context
+        .banks_client
+        .get_account(accounts.destination)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        TokenAccount::unpack(&destination_account.data)
+            .unwrap()
+            .amount,
+        unharvested_rewards,
+    );
+
+    // Assert the token holder rewards account was checkpointed.
+    let token_holder_rewards_account = context
+        .banks_client
+        .get_account(accounts.token_holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<TokenHolderRewards>(&token_holder_rewards_account.data),
+        &TokenHolderRewards::new(1_000_000_000_000_000_000, 0, token_account_balance),
+    );
+}
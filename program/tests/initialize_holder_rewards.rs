@@ -5,8 +5,11 @@ mod setup;
 use {
     paladin_rewards_program::{
         error::PaladinRewardsError,
-        instruction::initialize_holder_rewards,
-        state::{get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards},
+        instruction::{initialize_holder_rewards, initialize_holder_rewards_with_payer},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewards, VersionedAccount,
+        },
     },
     setup::{setup, setup_holder_rewards_pool_account, setup_mint, setup_token_account},
     solana_program_test::*,
@@ -14,11 +17,13 @@ use {
         account::{Account, AccountSharedData},
         instruction::InstructionError,
         pubkey::Pubkey,
+        signature::Keypair,
         signer::Signer,
         system_program,
         transaction::{Transaction, TransactionError},
     },
     spl_associated_token_account::get_associated_token_address,
+    test_case::test_case,
 };
 
 #[tokio::test]
@@ -334,11 +339,57 @@ async fn fail_holder_rewards_account_initialized() {
     );
 }
 
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+
+    let mut instruction = initialize_holder_rewards_with_payer(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &payer.pubkey(),
+    );
+    instruction.accounts[5].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing payer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
 #[tokio::test]
 async fn success() {
     // Since there's no math involved here, we just need to assert that the
-    // new holder account records the current pool values.
+    // new holder account records the current pool values and the token
+    // account balance.
     let accumulated_rewards_per_token = 500_000_000;
+    let token_account_balance = 100;
 
     let owner = Pubkey::new_unique();
     let mint = Pubkey::new_unique();
@@ -360,7 +411,7 @@ async fn success() {
         &token_account,
         &owner,
         &mint,
-        100, // Token account balance (not used here).
+        token_account_balance,
     )
     .await;
     setup_mint(
@@ -374,7 +425,7 @@ async fn success() {
     // Fund the holder rewards account.
     {
         let rent = context.banks_client.get_rent().await.unwrap();
-        let lamports = rent.minimum_balance(std::mem::size_of::<HolderRewards>());
+        let lamports = rent.minimum_balance(HolderRewards::LEN);
         context.set_account(
             &holder_rewards,
             &AccountSharedData::new(lamports, 0, &system_program::id()),
@@ -404,13 +455,85 @@ async fn success() {
         .await
         .unwrap()
         .unwrap();
-    let holder_rewards_state = bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data);
+    let holder_rewards_state =
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap();
 
     assert_eq!(
         holder_rewards_state,
-        &HolderRewards::new(
-            accumulated_rewards_per_token,
-            /* unharvested_rewards */ 0
-        ),
+        &HolderRewards {
+            mint,
+            owner,
+            ..HolderRewards::new(
+                accumulated_rewards_per_token,
+                /* unharvested_rewards */ 0,
+                token_account_balance,
+            )
+        },
+    );
+}
+
+#[test_case(0; "Holder rewards account not funded")]
+#[test_case(500; "Holder rewards account partially funded")]
+#[tokio::test]
+async fn success_with_payer(starting_lamports: u64) {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+
+    if starting_lamports > 0 {
+        context.set_account(
+            &holder_rewards,
+            &AccountSharedData::new(starting_lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_holder_rewards_with_payer(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Check the holder rewards account is rent-exempt and initialized.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        holder_rewards_account.lamports,
+        rent.minimum_balance(HolderRewards::LEN),
+    );
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &HolderRewards {
+            mint,
+            owner,
+            ..HolderRewards::new(0, 0, 100)
+        },
     );
 }
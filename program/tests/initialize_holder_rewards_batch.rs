@@ -0,0 +1,381 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::initialize_holder_rewards_batch,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewards, VersionedAccount,
+        },
+    },
+    setup::{setup, setup_holder_rewards_pool_account, setup_mint, setup_token_account},
+    solana_program_test::*,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+
+    let mut instruction = initialize_holder_rewards_batch(
+        &holder_rewards_pool,
+        &mint,
+        &payer.pubkey(),
+        &[(holder_rewards, token_account)],
+    );
+    instruction.accounts[3].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing payer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_incorrect_address() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = Pubkey::new_unique(); // Incorrect holder rewards pool address.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+
+    let instruction = initialize_holder_rewards_batch(
+        &holder_rewards_pool,
+        &mint,
+        &context.payer.pubkey(),
+        &[(holder_rewards, token_account)],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_mint_mismatch() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+
+    // The second holder's token account is for a different mint, which fails
+    // the whole batch.
+    let mut holders = vec![];
+    for token_account_mint in [mint, Pubkey::new_unique()] {
+        let owner = Pubkey::new_unique();
+        let token_account = get_associated_token_address(&owner, &mint);
+        let holder_rewards = get_holder_rewards_address(&token_account);
+        setup_token_account(
+            &mut context,
+            &token_account,
+            &owner,
+            &token_account_mint,
+            100,
+        )
+        .await;
+        holders.push((holder_rewards, token_account));
+    }
+
+    let instruction = initialize_holder_rewards_batch(
+        &holder_rewards_pool,
+        &mint,
+        &context.payer.pubkey(),
+        &holders,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountMintMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_address() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = Pubkey::new_unique(); // Incorrect holder rewards address.
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+
+    let instruction = initialize_holder_rewards_batch(
+        &holder_rewards_pool,
+        &mint,
+        &context.payer.pubkey(),
+        &[(holder_rewards, token_account)],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_holder_rewards_account_initialized() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+
+    let mut holders = vec![];
+    let mut owners = vec![];
+    for _ in 0..2 {
+        let owner = Pubkey::new_unique();
+        let token_account = get_associated_token_address(&owner, &mint);
+        let holder_rewards = get_holder_rewards_address(&token_account);
+        setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+        holders.push((holder_rewards, token_account));
+        owners.push(owner);
+    }
+
+    // Set up an already (arbitrarily) initialized holder rewards account for
+    // the second holder, as if another transaction had initialized it first.
+    let initialized_account = Account {
+        lamports: 1_000_000_000,
+        data: vec![2; 16],
+        owner: paladin_rewards_program::id(),
+        ..Account::default()
+    };
+    context.set_account(
+        &holders[1].0,
+        &AccountSharedData::from(initialized_account.clone()),
+    );
+
+    let instruction = initialize_holder_rewards_batch(
+        &holder_rewards_pool,
+        &mint,
+        &context.payer.pubkey(),
+        &holders,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the first holder rewards account was created.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holders[0].0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &HolderRewards {
+            mint,
+            owner: owners[0],
+            ..HolderRewards::new(0, 0, 0)
+        },
+    );
+
+    // Assert the second holder rewards account was skipped, left untouched.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holders[1].0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(holder_rewards_account.data, initialized_account.data);
+    assert_eq!(
+        holder_rewards_account.lamports,
+        initialized_account.lamports
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test_case(1; "One holder")]
+#[test_case(3; "Three holders")]
+#[test_case(14; "Fourteen holders")]
+#[tokio::test]
+async fn success(holder_count: u64) {
+    let mint = Pubkey::new_unique();
+    let accumulated_rewards_per_token = 500_000_000;
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        0, // Excess lamports (not used here).
+        accumulated_rewards_per_token,
+    )
+    .await;
+    setup_mint(
+        &mut context,
+        &mint,
+        &Pubkey::new_unique(),
+        100_000, // Token supply (not used here).
+    )
+    .await;
+
+    // Each holder has a different balance.
+    let mut holders = vec![];
+    let mut owners = vec![];
+    for i in 0..holder_count {
+        let owner = Pubkey::new_unique();
+        let token_account = get_associated_token_address(&owner, &mint);
+        let holder_rewards = get_holder_rewards_address(&token_account);
+        setup_token_account(&mut context, &token_account, &owner, &mint, 100 * (i + 1)).await;
+        holders.push((holder_rewards, token_account));
+        owners.push(owner);
+    }
+
+    let instruction = initialize_holder_rewards_batch(
+        &holder_rewards_pool,
+        &mint,
+        &context.payer.pubkey(),
+        &holders,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Check each holder rewards account is rent-exempt and records the
+    // current pool rate and its token account balance.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    for (i, (holder_rewards, _)) in holders.iter().enumerate() {
+        let holder_rewards_account = context
+            .banks_client
+            .get_account(*holder_rewards)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            holder_rewards_account.lamports,
+            rent.minimum_balance(HolderRewards::LEN),
+        );
+        assert_eq!(
+            unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+            &HolderRewards {
+                mint,
+                owner: owners[i],
+                ..HolderRewards::new(accumulated_rewards_per_token, 0, 100 * (i as u64 + 1))
+            },
+        );
+    }
+}
@@ -5,9 +5,11 @@ mod setup;
 use {
     paladin_rewards_program::{
         error::PaladinRewardsError,
-        extra_metas::get_extra_account_metas,
-        instruction::initialize_holder_rewards_pool,
-        state::{get_holder_rewards_pool_address, HolderRewardsPool},
+        extra_metas::{get_escrow_extra_account_metas, get_extra_account_metas},
+        instruction::{initialize_holder_rewards_pool, initialize_holder_rewards_pool_with_payer},
+        state::{
+            get_holder_rewards_pool_address, unpack_versioned, HolderRewardsPool, VersionedAccount,
+        },
     },
     setup::{setup, setup_mint},
     solana_program_test::*,
@@ -311,6 +313,47 @@ async fn fail_mint_authority_not_signer() {
     );
 }
 
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Keypair::new();
+    let payer = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &mint_authority.pubkey(), 0).await;
+
+    let mut instruction = initialize_holder_rewards_pool_with_payer(
+        &holder_rewards_pool,
+        &extra_metas,
+        &mint,
+        &mint_authority.pubkey(),
+        &payer.pubkey(),
+    );
+    instruction.accounts[5].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint_authority], // Missing payer.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
 #[tokio::test]
 async fn fail_holder_rewards_pool_incorrect_address() {
     let mint = Pubkey::new_unique();
@@ -509,12 +552,12 @@ async fn success() {
     // Fund the holder rewards pool account and extra metas account.
     {
         let rent = context.banks_client.get_rent().await.unwrap();
-        let lamports = rent.minimum_balance(std::mem::size_of::<HolderRewardsPool>());
+        let lamports = rent.minimum_balance(HolderRewardsPool::LEN);
         context.set_account(
             &holder_rewards_pool,
             &AccountSharedData::new(lamports, 0, &system_program::id()),
         );
-        let lamports = rent.minimum_balance(ExtraAccountMetaList::size_of(3).unwrap());
+        let lamports = rent.minimum_balance(ExtraAccountMetaList::size_of(5).unwrap());
         context.set_account(
             &extra_metas,
             &AccountSharedData::new(lamports, 0, &system_program::id()),
@@ -549,8 +592,80 @@ async fn success() {
         .unwrap()
         .unwrap();
     assert_eq!(
-        bytemuck::from_bytes::<HolderRewardsPool>(&holder_rewards_pool_account.data),
-        &HolderRewardsPool::default(),
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &HolderRewardsPool::new(0, mint_authority.pubkey()),
+    );
+
+    // Check the extra metas account.
+    let extra_metas_account = context
+        .banks_client
+        .get_account(extra_metas)
+        .await
+        .unwrap()
+        .unwrap();
+    let state = TlvStateBorrowed::unpack(&extra_metas_account.data).unwrap();
+    let bytes = state.get_first_bytes::<ExecuteInstruction>().unwrap();
+    let extra_account_metas = PodSlice::<ExtraAccountMeta>::unpack(bytes).unwrap();
+    assert_eq!(
+        extra_account_metas.data(),
+        [
+            get_extra_account_metas().as_slice(),
+            get_escrow_extra_account_metas().as_slice(),
+        ]
+        .concat(),
+    );
+}
+
+#[tokio::test]
+async fn success_with_payer() {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &mint_authority.pubkey(), 0).await;
+
+    // Don't fund the holder rewards pool account or extra metas account.
+    // The payer will fund the rent.
+    let instruction = initialize_holder_rewards_pool_with_payer(
+        &holder_rewards_pool,
+        &extra_metas,
+        &mint,
+        &mint_authority.pubkey(),
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    // Check the holder rewards pool account.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        holder_rewards_pool_account.lamports,
+        rent.minimum_balance(HolderRewardsPool::LEN),
+    );
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &HolderRewardsPool::new(0, mint_authority.pubkey()),
     );
 
     // Check the extra metas account.
@@ -560,8 +675,19 @@ async fn success() {
         .await
         .unwrap()
         .unwrap();
+    assert_eq!(
+        extra_metas_account.lamports,
+        rent.minimum_balance(extra_metas_account.data.len()),
+    );
     let state = TlvStateBorrowed::unpack(&extra_metas_account.data).unwrap();
     let bytes = state.get_first_bytes::<ExecuteInstruction>().unwrap();
     let extra_account_metas = PodSlice::<ExtraAccountMeta>::unpack(bytes).unwrap();
-    assert_eq!(extra_account_metas.data(), &get_extra_account_metas());
+    assert_eq!(
+        extra_account_metas.data(),
+        [
+            get_extra_account_metas().as_slice(),
+            get_escrow_extra_account_metas().as_slice(),
+        ]
+        .concat(),
+    );
 }
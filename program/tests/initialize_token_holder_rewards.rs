@@ -0,0 +1,450 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::{
+            initialize_token_holder_rewards, initialize_token_holder_rewards_with_payer,
+        },
+        state::{
+            get_token_holder_rewards_address, get_token_rewards_pool_address, TokenHolderRewards,
+            TokenRewardsPool,
+        },
+    },
+    setup::{setup, setup_mint, setup_token_account, setup_token_rewards_pool_account},
+    solana_program_test::*,
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signer::Signer,
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+};
+
+#[tokio::test]
+async fn fail_token_account_mint_mismatch() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let token_holder_rewards =
+        get_token_holder_rewards_address(&token_account, &token_rewards_pool);
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &Pubkey::new_unique(), // Incorrect mint.
+        100,
+    )
+    .await;
+
+    let instruction = initialize_token_holder_rewards_with_payer(
+        &token_rewards_pool,
+        &token_holder_rewards,
+        &token_account,
+        &mint,
+        &reward_mint,
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountMintMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_rewards_pool_incorrect_owner() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let token_holder_rewards =
+        get_token_holder_rewards_address(&token_account, &token_rewards_pool);
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+
+    // Set up a token rewards pool account with the wrong owner.
+    {
+        context.set_account(
+            &token_rewards_pool,
+            &AccountSharedData::from(Account {
+                lamports: 100_000_000,
+                data: bytemuck::bytes_of(&TokenRewardsPool::new(0, reward_mint, spl_token::id()))
+                    .to_vec(),
+                owner: Pubkey::new_unique(), // Wrong owner.
+                ..Account::default()
+            }),
+        );
+    }
+
+    let instruction = initialize_token_holder_rewards_with_payer(
+        &token_rewards_pool,
+        &token_holder_rewards,
+        &token_account,
+        &mint,
+        &reward_mint,
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_token_rewards_pool_incorrect_address() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let token_rewards_pool = Pubkey::new_unique(); // Incorrect token rewards pool address.
+    let token_holder_rewards =
+        get_token_holder_rewards_address(&token_account, &token_rewards_pool);
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+
+    let instruction = initialize_token_holder_rewards_with_payer(
+        &token_rewards_pool,
+        &token_holder_rewards,
+        &token_account,
+        &mint,
+        &reward_mint,
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectTokenRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_holder_rewards_incorrect_address() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let token_holder_rewards = Pubkey::new_unique(); // Incorrect token holder rewards address.
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+
+    let instruction = initialize_token_holder_rewards_with_payer(
+        &token_rewards_pool,
+        &token_holder_rewards,
+        &token_account,
+        &mint,
+        &reward_mint,
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_holder_rewards_account_initialized() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let token_holder_rewards =
+        get_token_holder_rewards_address(&token_account, &token_rewards_pool);
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+
+    // Set up an already (arbitrarily) initialized token holder rewards
+    // account.
+    {
+        context.set_account(
+            &token_holder_rewards,
+            &AccountSharedData::new_data(100_000_000, &vec![2; 16], &paladin_rewards_program::id())
+                .unwrap(),
+        );
+    }
+
+    let instruction = initialize_token_holder_rewards_with_payer(
+        &token_rewards_pool,
+        &token_holder_rewards,
+        &token_account,
+        &mint,
+        &reward_mint,
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let accumulated_rewards_per_token = 500_000_000_000_000_000_000;
+    let token_account_balance = 100;
+
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let token_holder_rewards =
+        get_token_holder_rewards_address(&token_account, &token_rewards_pool);
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &mint,
+        token_account_balance,
+    )
+    .await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(accumulated_rewards_per_token, reward_mint, spl_token::id()),
+    )
+    .await;
+
+    // Fund the token holder rewards account.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<TokenHolderRewards>());
+        context.set_account(
+            &token_holder_rewards,
+            &AccountSharedData::new(lamports, 0, &system_program::id()),
+        );
+    }
+
+    let instruction = initialize_token_holder_rewards(
+        &token_rewards_pool,
+        &token_holder_rewards,
+        &token_account,
+        &mint,
+        &reward_mint,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Check the token holder rewards account.
+    let token_holder_rewards_account = context
+        .banks_client
+        .get_account(token_holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<TokenHolderRewards>(&token_holder_rewards_account.data),
+        &TokenHolderRewards::new(
+            accumulated_rewards_per_token,
+            /* unharvested_rewards */ 0,
+            token_account_balance,
+        ),
+    );
+}
+
+#[tokio::test]
+async fn success_with_payer() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let token_holder_rewards =
+        get_token_holder_rewards_address(&token_account, &token_rewards_pool);
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100_000).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+
+    let instruction = initialize_token_holder_rewards_with_payer(
+        &token_rewards_pool,
+        &token_holder_rewards,
+        &token_account,
+        &mint,
+        &reward_mint,
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Check the token holder rewards account.
+    let token_holder_rewards_account = context
+        .banks_client
+        .get_account(token_holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        token_holder_rewards_account.lamports,
+        rent.minimum_balance(std::mem::size_of::<TokenHolderRewards>()),
+    );
+    assert_eq!(
+        bytemuck::from_bytes::<TokenHolderRewards>(&token_holder_rewards_account.data),
+        &TokenHolderRewards::new(0, 0, 100),
+    );
+}
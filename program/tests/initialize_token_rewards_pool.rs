@@ -0,0 +1,628 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        extra_metas::{
+            get_escrow_extra_account_metas, get_extra_account_metas,
+            get_token_rewards_extra_account_metas,
+        },
+        instruction::{initialize_token_rewards_pool, initialize_token_rewards_pool_with_payer},
+        state::{
+            get_holder_rewards_pool_address, get_token_rewards_pool_address, HolderRewardsPool,
+            TokenRewardsPool, MAX_TOKEN_REWARDS_POOLS,
+        },
+    },
+    setup::{
+        setup, setup_extra_metas_account, setup_extra_metas_account_with_token_rewards_pools,
+        setup_holder_rewards_pool_account_with_state, setup_legacy_extra_metas_account, setup_mint,
+        setup_reward_mint,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_pod::slice::PodSlice,
+    spl_tlv_account_resolution::account::ExtraAccountMeta,
+    spl_transfer_hook_interface::{
+        get_extra_account_metas_address, instruction::ExecuteInstruction,
+    },
+    spl_type_length_value::state::{TlvState, TlvStateBorrowed},
+    test_case::test_case,
+};
+
+async fn setup_holder_rewards_pool(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    pool_authority: &Pubkey,
+) {
+    setup_mint(context, mint, &Pubkey::new_unique(), 100_000).await;
+    setup_holder_rewards_pool_account_with_state(
+        context,
+        &get_holder_rewards_pool_address(mint),
+        0,
+        &HolderRewardsPool::new(0, *pool_authority),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn fail_incorrect_pool_authority() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool(&mut context, &mint, &Pubkey::new_unique()).await;
+    setup_extra_metas_account(&mut context, &mint).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token_2022::id(), 6).await;
+
+    let instruction = initialize_token_rewards_pool_with_payer(
+        &token_rewards_pool,
+        &extra_metas,
+        &holder_rewards_pool,
+        &mint,
+        &reward_mint,
+        &pool_authority.pubkey(),
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_pool_authority_not_signer() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool(&mut context, &mint, &pool_authority.pubkey()).await;
+    setup_extra_metas_account(&mut context, &mint).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token_2022::id(), 6).await;
+
+    let mut instruction = initialize_token_rewards_pool_with_payer(
+        &token_rewards_pool,
+        &extra_metas,
+        &holder_rewards_pool,
+        &mint,
+        &reward_mint,
+        &pool_authority.pubkey(),
+        &context.payer.pubkey(),
+    );
+    instruction.accounts[5].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing pool authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_reward_mint_incorrect_owner() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool(&mut context, &mint, &pool_authority.pubkey()).await;
+    setup_extra_metas_account(&mut context, &mint).await;
+    setup_reward_mint(&mut context, &reward_mint, &Pubkey::new_unique(), 6).await;
+
+    let instruction = initialize_token_rewards_pool_with_payer(
+        &token_rewards_pool,
+        &extra_metas,
+        &holder_rewards_pool,
+        &mint,
+        &reward_mint,
+        &pool_authority.pubkey(),
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+    );
+}
+
+#[tokio::test]
+async fn fail_token_rewards_pool_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let token_rewards_pool = Pubkey::new_unique(); // Incorrect token rewards pool address.
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool(&mut context, &mint, &pool_authority.pubkey()).await;
+    setup_extra_metas_account(&mut context, &mint).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token_2022::id(), 6).await;
+
+    let instruction = initialize_token_rewards_pool_with_payer(
+        &token_rewards_pool,
+        &extra_metas,
+        &holder_rewards_pool,
+        &mint,
+        &reward_mint,
+        &pool_authority.pubkey(),
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectTokenRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_token_rewards_pool_account_initialized() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool(&mut context, &mint, &pool_authority.pubkey()).await;
+    setup_extra_metas_account(&mut context, &mint).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token_2022::id(), 6).await;
+
+    // Set up an already (arbitrarily) initialized token rewards pool account.
+    {
+        context.set_account(
+            &token_rewards_pool,
+            &AccountSharedData::new_data(100_000_000, &vec![2; 16], &paladin_rewards_program::id())
+                .unwrap(),
+        );
+    }
+
+    let instruction = initialize_token_rewards_pool_with_payer(
+        &token_rewards_pool,
+        &extra_metas,
+        &holder_rewards_pool,
+        &mint,
+        &reward_mint,
+        &pool_authority.pubkey(),
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn fail_extra_metas_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let extra_metas = Pubkey::new_unique(); // Incorrect extra metas address.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool(&mut context, &mint, &pool_authority.pubkey()).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token_2022::id(), 6).await;
+
+    let instruction = initialize_token_rewards_pool_with_payer(
+        &token_rewards_pool,
+        &extra_metas,
+        &holder_rewards_pool,
+        &mint,
+        &reward_mint,
+        &pool_authority.pubkey(),
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectExtraMetasAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_max_token_rewards_pools_reached() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool(&mut context, &mint, &pool_authority.pubkey()).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token_2022::id(), 6).await;
+
+    // Set up the extra metas account with the maximum number of token
+    // rewards pools.
+    let existing_reward_mints = (0..MAX_TOKEN_REWARDS_POOLS)
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+    setup_extra_metas_account_with_token_rewards_pools(&mut context, &mint, &existing_reward_mints)
+        .await;
+
+    let instruction = initialize_token_rewards_pool_with_payer(
+        &token_rewards_pool,
+        &extra_metas,
+        &holder_rewards_pool,
+        &mint,
+        &reward_mint,
+        &pool_authority.pubkey(),
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::MaxTokenRewardsPoolsReached as u32)
+        )
+    );
+}
+
+#[test_case(spl_token::id(), 0; "SPL Token reward mint, no existing token rewards pools")]
+#[test_case(spl_token_2022::id(), 0; "Token-2022 reward mint, no existing token rewards pools")]
+#[test_case(spl_token_2022::id(), 2; "Token-2022 reward mint, existing token rewards pools")]
+#[tokio::test]
+async fn success(reward_token_program_id: Pubkey, existing_token_rewards_pools: usize) {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool(&mut context, &mint, &pool_authority.pubkey()).await;
+    setup_reward_mint(&mut context, &reward_mint, &reward_token_program_id, 6).await;
+
+    let existing_reward_mints = (0..existing_token_rewards_pools)
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+    setup_extra_metas_account_with_token_rewards_pools(&mut context, &mint, &existing_reward_mints)
+        .await;
+
+    // Don't fund the token rewards pool account or the extra metas account's
+    // additional space. The payer will fund the rent.
+    let instruction = initialize_token_rewards_pool_with_payer(
+        &token_rewards_pool,
+        &extra_metas,
+        &holder_rewards_pool,
+        &mint,
+        &reward_mint,
+        &pool_authority.pubkey(),
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    // Check the token rewards pool account.
+    let token_rewards_pool_account = context
+        .banks_client
+        .get_account(token_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        token_rewards_pool_account.lamports,
+        rent.minimum_balance(std::mem::size_of::<TokenRewardsPool>()),
+    );
+    assert_eq!(
+        bytemuck::from_bytes::<TokenRewardsPool>(&token_rewards_pool_account.data),
+        &TokenRewardsPool::new(0, reward_mint, reward_token_program_id),
+    );
+
+    // Check the token rewards pool's accounts were added to the extra metas,
+    // ahead of the auto-claim escrow accounts.
+    let extra_metas_account = context
+        .banks_client
+        .get_account(extra_metas)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        extra_metas_account.lamports,
+        rent.minimum_balance(extra_metas_account.data.len()),
+    );
+    let mut expected_extra_metas = get_extra_account_metas().to_vec();
+    for (i, reward_mint) in existing_reward_mints
+        .iter()
+        .chain([&reward_mint])
+        .enumerate()
+    {
+        expected_extra_metas.extend_from_slice(&get_token_rewards_extra_account_metas(
+            &mint,
+            reward_mint,
+            i,
+        ));
+    }
+    expected_extra_metas.extend_from_slice(&get_escrow_extra_account_metas());
+    let state = TlvStateBorrowed::unpack(&extra_metas_account.data).unwrap();
+    let bytes = state.get_first_bytes::<ExecuteInstruction>().unwrap();
+    let extra_account_metas = PodSlice::<ExtraAccountMeta>::unpack(bytes).unwrap();
+    assert_eq!(extra_account_metas.data(), &expected_extra_metas);
+}
+
+#[tokio::test]
+async fn success_legacy_extra_metas() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool(&mut context, &mint, &pool_authority.pubkey()).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token::id(), 6).await;
+    setup_legacy_extra_metas_account(&mut context, &mint).await;
+
+    let instruction = initialize_token_rewards_pool_with_payer(
+        &token_rewards_pool,
+        &extra_metas,
+        &holder_rewards_pool,
+        &mint,
+        &reward_mint,
+        &pool_authority.pubkey(),
+        &context.payer.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Check the base metas were corrected, making the holder rewards pool
+    // writable, as well as the token rewards pool's accounts added, and the
+    // auto-claim escrow accounts appended.
+    let extra_metas_account = context
+        .banks_client
+        .get_account(extra_metas)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut expected_extra_metas = get_extra_account_metas().to_vec();
+    expected_extra_metas.extend_from_slice(&get_token_rewards_extra_account_metas(
+        &mint,
+        &reward_mint,
+        0,
+    ));
+    expected_extra_metas.extend_from_slice(&get_escrow_extra_account_metas());
+    let state = TlvStateBorrowed::unpack(&extra_metas_account.data).unwrap();
+    let bytes = state.get_first_bytes::<ExecuteInstruction>().unwrap();
+    let extra_account_metas = PodSlice::<ExtraAccountMeta>::unpack(bytes).unwrap();
+    assert_eq!(extra_account_metas.data(), &expected_extra_metas);
+}
+
+#[tokio::test]
+async fn success_without_payer() {
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool(&mut context, &mint, &pool_authority.pubkey()).await;
+    setup_reward_mint(&mut context, &reward_mint, &spl_token_2022::id(), 6).await;
+
+    // Fund the token rewards pool account and the extra metas account's
+    // additional space ahead of time.
+    {
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let lamports = rent.minimum_balance(std::mem::size_of::<TokenRewardsPool>());
+        context.set_account(
+            &token_rewards_pool,
+            &AccountSharedData::new(lamports, 0, &solana_sdk::system_program::id()),
+        );
+    }
+    setup_extra_metas_account(&mut context, &mint).await;
+    {
+        let mut extra_metas_account = context
+            .banks_client
+            .get_account(extra_metas)
+            .await
+            .unwrap()
+            .unwrap();
+        let rent = context.banks_client.get_rent().await.unwrap();
+        extra_metas_account.lamports = rent.minimum_balance(
+            spl_tlv_account_resolution::state::ExtraAccountMetaList::size_of(8).unwrap(),
+        );
+        context.set_account(&extra_metas, &extra_metas_account.into());
+    }
+
+    let instruction = initialize_token_rewards_pool(
+        &token_rewards_pool,
+        &extra_metas,
+        &holder_rewards_pool,
+        &mint,
+        &reward_mint,
+        &pool_authority.pubkey(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Check the token rewards pool account.
+    let token_rewards_pool_account = context
+        .banks_client
+        .get_account(token_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<TokenRewardsPool>(&token_rewards_pool_account.data),
+        &TokenRewardsPool::new(0, reward_mint, spl_token_2022::id()),
+    );
+}
@@ -0,0 +1,470 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        extra_metas::{get_escrow_extra_account_metas, get_extra_account_metas},
+        instruction::{migrate_account, migrate_account_with_extra_metas},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewards, HolderRewardsPool, VersionedAccount,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_pool_account_with_state, setup_legacy_account,
+        setup_legacy_extra_metas_account, setup_mint, setup_system_account, setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_pod::slice::PodSlice,
+    spl_tlv_account_resolution::account::ExtraAccountMeta,
+    spl_transfer_hook_interface::{
+        get_extra_account_metas_address, instruction::ExecuteInstruction,
+    },
+    spl_type_length_value::state::{TlvState, TlvStateBorrowed},
+};
+
+#[tokio::test]
+async fn fail_payer_not_signer() {
+    let mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000_000_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+    setup_legacy_account(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &0u128.to_le_bytes(), // Original layout.
+    )
+    .await;
+
+    let mut instruction = migrate_account(&payer.pubkey(), &holder_rewards_pool, &mint);
+    instruction.accounts[0].is_signer = false; // Payer not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000_000_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+    setup_legacy_account(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &0u128.to_le_bytes(), // Original layout.
+    )
+    .await;
+
+    let instruction = migrate_account(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &Pubkey::new_unique(), // Incorrect address seed.
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidSeeds)
+    );
+}
+
+#[tokio::test]
+async fn fail_already_migrated() {
+    let mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000_000_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, Pubkey::new_unique()), // Current layout.
+    )
+    .await;
+
+    let instruction = migrate_account(&payer.pubkey(), &holder_rewards_pool, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_extra_metas_missing() {
+    let mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000_000_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+    setup_legacy_account(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &0u128.to_le_bytes(), // Original layout.
+    )
+    .await;
+    setup_legacy_extra_metas_account(&mut context, &mint).await;
+
+    // Migrating a pool in the original layout without correcting the extra
+    // metas would fail every transfer of the mint.
+    let instruction = migrate_account(&payer.pubkey(), &holder_rewards_pool, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[tokio::test]
+async fn success_holder_rewards_pool() {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+    let payer = Keypair::new();
+    let rewards = 500_000;
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    // The original layout stored only the exchange rate, scaled by `1e9`.
+    let accumulated_rewards_per_token: u128 = 1_000_000_000;
+    let legacy_data = accumulated_rewards_per_token.to_le_bytes();
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000_000_000).await;
+    setup_mint(&mut context, &mint, &mint_authority, 0).await;
+    setup_legacy_account(&mut context, &holder_rewards_pool, rewards, &legacy_data).await;
+    setup_legacy_extra_metas_account(&mut context, &mint).await;
+
+    let instruction = migrate_account_with_extra_metas(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &extra_metas,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the rate was rescaled to `1e18`, the pool authority defaulted to
+    // the mint authority, and all of the pool's excess lamports were counted
+    // as distributed.
+    let mut expected_pool = HolderRewardsPool::new(
+        accumulated_rewards_per_token * 1_000_000_000,
+        mint_authority,
+    );
+    expected_pool.total_distributed = rewards;
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &expected_pool,
+    );
+
+    // Assert the payer funded the additional rent, leaving the rewards
+    // untouched.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        holder_rewards_pool_account.lamports,
+        rent.minimum_balance(HolderRewardsPool::LEN) + rewards
+    );
+}
+
+#[tokio::test]
+async fn success_holder_rewards_pool_with_extra_metas() {
+    let mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let legacy_data = 1_000_000_000u128.to_le_bytes();
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000_000_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+    setup_legacy_account(&mut context, &holder_rewards_pool, 0, &legacy_data).await;
+    setup_legacy_extra_metas_account(&mut context, &mint).await;
+
+    let instruction = migrate_account_with_extra_metas(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &extra_metas,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the base metas were corrected, making the holder rewards pool
+    // writable, and the auto-claim escrow accounts appended.
+    let extra_metas_account = context
+        .banks_client
+        .get_account(extra_metas)
+        .await
+        .unwrap()
+        .unwrap();
+    let state = TlvStateBorrowed::unpack(&extra_metas_account.data).unwrap();
+    let bytes = state.get_first_bytes::<ExecuteInstruction>().unwrap();
+    let extra_account_metas = PodSlice::<ExtraAccountMeta>::unpack(bytes).unwrap();
+    assert_eq!(
+        extra_account_metas.data(),
+        [
+            get_extra_account_metas().as_slice(),
+            get_escrow_extra_account_metas().as_slice(),
+        ]
+        .concat(),
+    );
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        extra_metas_account.lamports,
+        rent.minimum_balance(extra_metas_account.data.len()),
+    );
+}
+
+#[tokio::test]
+async fn success_current_holder_rewards_pool_with_extra_metas() {
+    let mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let pool = HolderRewardsPool::new(1_000_000_000, Pubkey::new_unique());
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000_000_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 0).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_legacy_extra_metas_account(&mut context, &mint).await;
+
+    let instruction = migrate_account_with_extra_metas(
+        &payer.pubkey(),
+        &holder_rewards_pool,
+        &mint,
+        &extra_metas,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the pool was left untouched.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &pool,
+    );
+
+    // Assert the extra metas were corrected.
+    let extra_metas_account = context
+        .banks_client
+        .get_account(extra_metas)
+        .await
+        .unwrap()
+        .unwrap();
+    let state = TlvStateBorrowed::unpack(&extra_metas_account.data).unwrap();
+    let bytes = state.get_first_bytes::<ExecuteInstruction>().unwrap();
+    let extra_account_metas = PodSlice::<ExtraAccountMeta>::unpack(bytes).unwrap();
+    assert_eq!(
+        extra_account_metas.data(),
+        [
+            get_extra_account_metas().as_slice(),
+            get_escrow_extra_account_metas().as_slice(),
+        ]
+        .concat(),
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[tokio::test]
+async fn success_holder_rewards() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let token_account = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    // The original layout stored the exchange rate checkpoint, scaled by
+    // `1e9`, and the unharvested rewards, followed by padding.
+    let last_accumulated_rewards_per_token: u128 = 1_000_000_000;
+    let unharvested_rewards: u64 = 50;
+    let mut legacy_data = last_accumulated_rewards_per_token.to_le_bytes().to_vec();
+    legacy_data.extend_from_slice(&unharvested_rewards.to_le_bytes());
+    legacy_data.extend_from_slice(&[0; 8]);
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 1_000_000_000).await;
+    setup_legacy_account(&mut context, &holder_rewards, 0, &legacy_data).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+
+    let instruction = migrate_account(&payer.pubkey(), &holder_rewards, &token_account);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the checkpoint was rescaled to `1e18`, with the unharvested
+    // rewards intact, the balance the holder was last settled at marked as
+    // unknown, and the token account's mint and owner recorded.
+    let mut expected_holder = HolderRewards {
+        mint,
+        owner,
+        ..HolderRewards::new(
+            last_accumulated_rewards_per_token * 1_000_000_000,
+            unharvested_rewards,
+            0,
+        )
+    };
+    expected_holder.last_seen_balance_unknown = 1;
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &expected_holder,
+    );
+
+    // Assert the account remains rent-exempt.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        holder_rewards_account.lamports,
+        rent.minimum_balance(HolderRewards::LEN)
+    );
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::remove_compound_program,
+        state::{get_holder_rewards_pool_address, unpack_versioned, HolderRewardsPool},
+    },
+    setup::{setup, setup_holder_rewards_pool_account_with_state},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_incorrect_pool_authority() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new(); // Not the pool authority.
+    let compound_program = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool.compound_programs[0] = compound_program;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+
+    let instruction = remove_compound_program(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &compound_program,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[test_case(Pubkey::new_unique(); "Not allowed")]
+#[test_case(Pubkey::default(); "Default address")]
+#[tokio::test]
+async fn fail_compound_program_not_allowed(compound_program: Pubkey) {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, pool_authority.pubkey()),
+    )
+    .await;
+
+    let instruction = remove_compound_program(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &compound_program,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::CompoundProgramNotAllowed as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let compound_program = Pubkey::new_unique();
+    let other_compound_program = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(500_000_000, pool_authority.pubkey());
+    pool.compound_programs[0] = other_compound_program;
+    pool.compound_programs[1] = compound_program;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+
+    let instruction = remove_compound_program(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &compound_program,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the program's slot was freed.
+    let mut expected_pool = pool;
+    expected_pool.compound_programs[1] = Pubkey::default();
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &expected_pool,
+    );
+}
@@ -0,0 +1,368 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::{set_auto_claim, set_auto_claim_with_payer},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_escrow_address, unpack_versioned,
+            HolderRewards,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_account_with_state, setup_holder_rewards_escrow_account,
+        setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_token_account_owner_mismatch() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = get_holder_rewards_escrow_address(&token_account);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &Pubkey::new_unique(), // Incorrect owner.
+        &mint,
+        100,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards::new(0, 0, 100),
+    )
+    .await;
+
+    let instruction = set_auto_claim_with_payer(
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &escrow,
+        &context.payer.pubkey(),
+        50,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountOwnerMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_owner_not_signer() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = get_holder_rewards_escrow_address(&token_account);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards::new(0, 0, 100),
+    )
+    .await;
+
+    let mut instruction = set_auto_claim_with_payer(
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner,
+        &escrow,
+        &context.payer.pubkey(),
+        50,
+    );
+    instruction.accounts[3].is_signer = false; // Owner not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_escrow_incorrect_address() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = Pubkey::new_unique(); // Incorrect escrow address.
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 100).await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards::new(0, 0, 100),
+    )
+    .await;
+
+    let instruction = set_auto_claim_with_payer(
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &escrow,
+        &context.payer.pubkey(),
+        50,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinRewardsError::IncorrectHolderRewardsEscrowAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_escrow_not_created_without_payer() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = get_holder_rewards_escrow_address(&token_account);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 100).await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards::new(0, 0, 100),
+    )
+    .await;
+
+    // No payer to create the escrow account.
+    let instruction = set_auto_claim(
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &escrow,
+        50,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[tokio::test]
+async fn success_enable() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = get_holder_rewards_escrow_address(&token_account);
+
+    let holder = HolderRewards::new(500_000_000_000_000_000, 50, 100);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 100).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+
+    let instruction = set_auto_claim_with_payer(
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &escrow,
+        &context.payer.pubkey(),
+        1_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the threshold was updated.
+    let mut expected_holder = holder;
+    expected_holder.auto_claim_threshold = 1_000;
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &expected_holder,
+    );
+
+    // Assert the escrow account was created.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(escrow_account.owner, paladin_rewards_program::id());
+    assert_eq!(escrow_account.lamports, rent.minimum_balance(0));
+    assert!(escrow_account.data.is_empty());
+}
+
+#[test_case(1_000, 2_000; "Update threshold")]
+#[test_case(1_000, 0; "Disable")]
+#[tokio::test]
+async fn success_existing_escrow(initial_threshold: u64, auto_claim_threshold: u64) {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let escrow = get_holder_rewards_escrow_address(&token_account);
+
+    let mut holder = HolderRewards::new(500_000_000_000_000_000, 50, 100);
+    holder.auto_claim_threshold = initial_threshold;
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 100).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_holder_rewards_escrow_account(&mut context, &escrow, 25).await;
+
+    // The escrow account already exists, so no payer is required.
+    let instruction = set_auto_claim(
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &escrow,
+        auto_claim_threshold,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the threshold was updated.
+    let mut expected_holder = holder;
+    expected_holder.auto_claim_threshold = auto_claim_threshold;
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &expected_holder,
+    );
+
+    // Assert the escrowed rewards were left untouched.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(escrow_account.lamports, rent.minimum_balance(0) + 25);
+}
@@ -0,0 +1,167 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::set_distribution_config,
+        state::{get_holder_rewards_pool_address, unpack_versioned, HolderRewardsPool},
+    },
+    setup::{setup, setup_holder_rewards_pool_account_with_state},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_incorrect_pool_authority() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new(); // Not the pool authority.
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, Pubkey::new_unique()),
+    )
+    .await;
+
+    let instruction = set_distribution_config(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &Pubkey::new_unique(),
+        1_000_000,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_pool_authority_not_signer() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, pool_authority.pubkey()),
+    )
+    .await;
+
+    let mut instruction = set_distribution_config(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &Pubkey::new_unique(),
+        1_000_000,
+    );
+    instruction.accounts[2].is_signer = false; // Not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer], // Missing pool authority.
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[test_case(Pubkey::new_unique(), 1_000_000; "Set config")]
+#[test_case(Pubkey::default(), 0; "Clear config")]
+#[tokio::test]
+async fn success(distribution_authority: Pubkey, min_distribution_amount: u64) {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(500_000_000, pool_authority.pubkey());
+    pool.distribution_authority = Pubkey::new_unique();
+    pool.min_distribution_amount = 5_000;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+
+    let instruction = set_distribution_config(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &distribution_authority,
+        min_distribution_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the distribution config was updated.
+    let mut expected_pool = pool;
+    expected_pool.distribution_authority = distribution_authority;
+    expected_pool.min_distribution_amount = min_distribution_amount;
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &expected_pool,
+    );
+}
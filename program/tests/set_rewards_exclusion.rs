@@ -0,0 +1,450 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::{set_rewards_exclusion, set_rewards_exclusion_with_token_holder_rewards},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address,
+            get_token_holder_rewards_address, get_token_rewards_pool_address, unpack_versioned,
+            HolderRewards, HolderRewardsPool, TokenHolderRewards, TokenRewardsPool,
+        },
+    },
+    setup::{
+        setup, setup_extra_metas_account, setup_extra_metas_account_with_token_rewards_pools,
+        setup_holder_rewards_account_with_state, setup_holder_rewards_pool_account_with_state,
+        setup_mint, setup_token_account, setup_token_holder_rewards_account,
+        setup_token_rewards_pool_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    spl_transfer_hook_interface::get_extra_account_metas_address,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_incorrect_pool_authority() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 1_000).await;
+    setup_extra_metas_account(&mut context, &mint).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, Pubkey::new_unique()), // Incorrect pool authority.
+    )
+    .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards {
+            mint,
+            ..HolderRewards::new(0, 0, 100)
+        },
+    )
+    .await;
+
+    let instruction = set_rewards_exclusion(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &pool_authority.pubkey(),
+        &extra_metas,
+        true,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_pool_authority_not_signer() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let pool_authority = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 1_000).await;
+    setup_extra_metas_account(&mut context, &mint).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        0,
+        &HolderRewardsPool::new(0, pool_authority),
+    )
+    .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards {
+            mint,
+            ..HolderRewards::new(0, 0, 100)
+        },
+    )
+    .await;
+
+    let mut instruction = set_rewards_exclusion(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &pool_authority,
+        &extra_metas,
+        true,
+    );
+    instruction.accounts[4].is_signer = false; // Pool authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn fail_token_holder_rewards_not_provided() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let token_holder_rewards =
+        get_token_holder_rewards_address(&token_account, &token_rewards_pool);
+
+    // The holder is excluded, and has a token holder rewards account for the
+    // mint's token rewards pool.
+    let mut pool = HolderRewardsPool::new(0, pool_authority.pubkey());
+    pool.excluded_supply = 100;
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, 100)
+    };
+    holder.excluded = true.into();
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 1_000).await;
+    setup_extra_metas_account_with_token_rewards_pools(&mut context, &mint, &[reward_mint]).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(1_000_000_000_000_000_000, reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_token_holder_rewards_account(&mut context, &token_holder_rewards, 0, 0, 100).await;
+
+    // Include the holder again without its token holder rewards account.
+    let instruction = set_rewards_exclusion(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &pool_authority.pubkey(),
+        &extra_metas,
+        false,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[test_case(false, true, 100, 500; "Exclude, settles earned rewards")]
+#[test_case(true, false, 0, 400; "Include, earns nothing while excluded")]
+#[test_case(true, true, 0, 500; "Already excluded")]
+#[test_case(false, false, 100, 400; "Already included")]
+#[tokio::test]
+async fn success(
+    initially_excluded: bool,
+    excluded: bool,
+    expected_unharvested_rewards: u64,
+    expected_excluded_supply: u64,
+) {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let token_account_balance = 100;
+
+    // One lamport has been distributed per token since the holder was last
+    // settled.
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_000;
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+
+    // Another token account is already excluded with a balance of `400`.
+    let mut pool = HolderRewardsPool::new(accumulated_rewards_per_token, pool_authority.pubkey());
+    pool.excluded_supply = if initially_excluded { 500 } else { 400 };
+    pool.excluded_holders = if initially_excluded { 2 } else { 1 };
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, token_account_balance)
+    };
+    holder.excluded = initially_excluded.into();
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 1_000).await;
+    setup_extra_metas_account(&mut context, &mint).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &mint,
+        token_account_balance,
+    )
+    .await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+
+    let instruction = set_rewards_exclusion(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &pool_authority.pubkey(),
+        &extra_metas,
+        excluded,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder was settled and its exclusion updated.
+    let mut expected_holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(
+            accumulated_rewards_per_token,
+            expected_unharvested_rewards,
+            token_account_balance,
+        )
+    };
+    expected_holder.excluded = excluded.into();
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &expected_holder,
+    );
+
+    // Assert the pool's excluded supply and holders track the holder.
+    let mut expected_pool = pool;
+    expected_pool.excluded_supply = expected_excluded_supply;
+    expected_pool.excluded_holders = if excluded { 2 } else { 1 };
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &expected_pool,
+    );
+}
+
+#[test_case(false, true, 100; "Exclude, settles earned rewards")]
+#[test_case(true, false, 0; "Include, earns nothing while excluded")]
+#[tokio::test]
+async fn success_with_token_holder_rewards(
+    initially_excluded: bool,
+    excluded: bool,
+    expected_unharvested_rewards: u64,
+) {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let token_account_balance = 100;
+
+    // One reward token has been distributed per token since the token holder
+    // was last settled.
+    let token_rewards_per_token = 1_000_000_000_000_000_000;
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+    let extra_metas = get_extra_account_metas_address(&mint, &paladin_rewards_program::id());
+    let token_rewards_pool = get_token_rewards_pool_address(&mint, &reward_mint);
+    let token_holder_rewards =
+        get_token_holder_rewards_address(&token_account, &token_rewards_pool);
+
+    let mut pool = HolderRewardsPool::new(0, pool_authority.pubkey());
+    pool.excluded_supply = if initially_excluded {
+        token_account_balance
+    } else {
+        0
+    };
+    pool.excluded_holders = initially_excluded.into();
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(0, 0, token_account_balance)
+    };
+    holder.excluded = initially_excluded.into();
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 1_000).await;
+    setup_extra_metas_account_with_token_rewards_pools(&mut context, &mint, &[reward_mint]).await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &mint,
+        token_account_balance,
+    )
+    .await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 0, &pool)
+        .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(token_rewards_per_token, reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_token_holder_rewards_account(
+        &mut context,
+        &token_holder_rewards,
+        0,
+        0,
+        token_account_balance,
+    )
+    .await;
+
+    let instruction = set_rewards_exclusion_with_token_holder_rewards(
+        &holder_rewards_pool,
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &pool_authority.pubkey(),
+        &extra_metas,
+        excluded,
+        &[(token_rewards_pool, token_holder_rewards)],
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the token holder was settled under its old exclusion.
+    let token_holder_rewards_account = context
+        .banks_client
+        .get_account(token_holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<TokenHolderRewards>(&token_holder_rewards_account.data),
+        &TokenHolderRewards::new(
+            token_rewards_per_token,
+            expected_unharvested_rewards,
+            token_account_balance,
+        ),
+    );
+}
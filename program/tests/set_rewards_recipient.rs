@@ -0,0 +1,177 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::set_rewards_recipient,
+        state::{get_holder_rewards_address, unpack_versioned, HolderRewards},
+    },
+    setup::{setup, setup_holder_rewards_account_with_state, setup_token_account},
+    solana_program_test::*,
+    solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_token_account_owner_mismatch() {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &Pubkey::new_unique(), // Incorrect owner.
+        &mint,
+        100,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards::new(0, 0, 100),
+    )
+    .await;
+
+    let instruction = set_rewards_recipient(
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &Pubkey::new_unique(),
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountOwnerMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_owner_not_signer() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &holder_rewards,
+        &HolderRewards::new(0, 0, 100),
+    )
+    .await;
+
+    let mut instruction = set_rewards_recipient(
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner,
+        &Pubkey::new_unique(),
+    );
+    instruction.accounts[3].is_signer = false; // Owner not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[test_case(Pubkey::default(), Pubkey::new_unique(); "Set recipient")]
+#[test_case(Pubkey::new_unique(), Pubkey::new_unique(); "Replace recipient")]
+#[test_case(Pubkey::new_unique(), Pubkey::default(); "Clear recipient")]
+#[tokio::test]
+async fn success(initial_recipient: Pubkey, recipient: Pubkey) {
+    let owner = Keypair::new();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner.pubkey(), &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+
+    let mut holder = HolderRewards::new(500_000_000_000_000_000, 50, 100);
+    holder.recipient = initial_recipient;
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner.pubkey(), &mint, 100).await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+
+    let instruction = set_rewards_recipient(
+        &holder_rewards,
+        &token_account,
+        &mint,
+        &owner.pubkey(),
+        &recipient,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the recipient was updated.
+    let mut expected_holder = holder;
+    expected_holder.recipient = recipient;
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &expected_holder,
+    );
+}
@@ -3,18 +3,25 @@
 
 use {
     paladin_rewards_program::{
-        extra_metas::get_extra_account_metas,
-        state::{HolderRewards, HolderRewardsPool},
+        extra_metas::{
+            get_escrow_extra_account_metas, get_extra_account_metas,
+            get_token_rewards_extra_account_metas,
+        },
+        state::{
+            pack_versioned, HolderRewards, HolderRewardsPool, TokenHolderRewards, TokenRewardsPool,
+            VersionedAccount,
+        },
     },
     solana_program_test::*,
     solana_sdk::{
         account::{Account, AccountSharedData},
         program_option::COption,
+        program_pack::Pack,
         pubkey::Pubkey,
         system_program,
     },
     spl_pod::primitives::PodBool,
-    spl_tlv_account_resolution::state::ExtraAccountMetaList,
+    spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList},
     spl_token_2022::{
         extension::{
             transfer_hook::{TransferHook, TransferHookAccount},
@@ -75,12 +82,83 @@ pub async fn setup_mint(
     );
 }
 
+/// Sets up a reward mint without any extensions, owned by either SPL Token or
+/// Token-2022.
+pub async fn setup_reward_mint(
+    context: &mut ProgramTestContext,
+    reward_mint: &Pubkey,
+    reward_token_program_id: &Pubkey,
+    decimals: u8,
+) {
+    let mut data = vec![0; Mint::LEN];
+    Mint::pack(
+        Mint {
+            is_initialized: true,
+            decimals,
+            ..Mint::default()
+        },
+        &mut data,
+    )
+    .unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        reward_mint,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: *reward_token_program_id,
+            ..Account::default()
+        }),
+    );
+}
+
+/// Sets up a reward token account without any extensions, owned by either
+/// SPL Token or Token-2022.
+pub async fn setup_reward_token_account(
+    context: &mut ProgramTestContext,
+    token_account: &Pubkey,
+    owner: &Pubkey,
+    reward_mint: &Pubkey,
+    reward_token_program_id: &Pubkey,
+    amount: u64,
+) {
+    let mut data = vec![0; TokenAccount::LEN];
+    TokenAccount::pack(
+        TokenAccount {
+            amount,
+            mint: *reward_mint,
+            owner: *owner,
+            state: AccountState::Initialized,
+            ..TokenAccount::default()
+        },
+        &mut data,
+    )
+    .unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        token_account,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: *reward_token_program_id,
+            ..Account::default()
+        }),
+    );
+}
+
 async fn setup_token_account_common(
     context: &mut ProgramTestContext,
     token_account: &Pubkey,
     owner: &Pubkey,
     mint: &Pubkey,
     amount: u64,
+    delegate: Option<&Pubkey>,
     is_transferring: bool,
 ) {
     let account_size = ExtensionType::try_calculate_account_len::<TokenAccount>(&[
@@ -103,6 +181,7 @@ async fn setup_token_account_common(
             amount,
             mint: *mint,
             owner: *owner,
+            delegate: delegate.copied().into(),
             state: AccountState::Initialized,
             ..TokenAccount::default()
         };
@@ -128,7 +207,27 @@ pub async fn setup_token_account(
     mint: &Pubkey,
     amount: u64,
 ) {
-    setup_token_account_common(context, token_account, owner, mint, amount, false).await;
+    setup_token_account_common(context, token_account, owner, mint, amount, None, false).await;
+}
+
+pub async fn setup_token_account_with_delegate(
+    context: &mut ProgramTestContext,
+    token_account: &Pubkey,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) {
+    setup_token_account_common(
+        context,
+        token_account,
+        owner,
+        mint,
+        amount,
+        Some(delegate),
+        false,
+    )
+    .await;
 }
 
 pub async fn setup_token_account_transferring(
@@ -138,7 +237,7 @@ pub async fn setup_token_account_transferring(
     mint: &Pubkey,
     amount: u64,
 ) {
-    setup_token_account_common(context, token_account, owner, mint, amount, true).await;
+    setup_token_account_common(context, token_account, owner, mint, amount, None, true).await;
 }
 
 #[allow(clippy::arithmetic_side_effects)]
@@ -156,7 +255,6 @@ pub async fn setup_system_account(
     );
 }
 
-#[allow(clippy::arithmetic_side_effects)]
 pub async fn setup_holder_rewards_pool_account(
     context: &mut ProgramTestContext,
     holder_rewards_pool_address: &Pubkey,
@@ -165,8 +263,26 @@ pub async fn setup_holder_rewards_pool_account(
 ) {
     let state = HolderRewardsPool {
         accumulated_rewards_per_token,
+        ..HolderRewardsPool::default()
     };
-    let data = bytemuck::bytes_of(&state).to_vec();
+    setup_holder_rewards_pool_account_with_state(
+        context,
+        holder_rewards_pool_address,
+        excess_lamports,
+        &state,
+    )
+    .await;
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+pub async fn setup_holder_rewards_pool_account_with_state(
+    context: &mut ProgramTestContext,
+    holder_rewards_pool_address: &Pubkey,
+    excess_lamports: u64,
+    state: &HolderRewardsPool,
+) {
+    let mut data = vec![0; HolderRewardsPool::LEN];
+    pack_versioned(&mut data, state).unwrap();
 
     let rent = context.banks_client.get_rent().await.unwrap();
     let lamports = rent.minimum_balance(data.len()) + excess_lamports;
@@ -182,15 +298,66 @@ pub async fn setup_holder_rewards_pool_account(
     );
 }
 
+/// Sets up a program account with the provided data, in the original layout
+/// which must be migrated to the current layout.
+#[allow(clippy::arithmetic_side_effects)]
+pub async fn setup_legacy_account(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+    excess_lamports: u64,
+    data: &[u8],
+) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len()) + excess_lamports;
+
+    context.set_account(
+        address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data: data.to_vec(),
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
 #[allow(clippy::arithmetic_side_effects)]
 pub async fn setup_holder_rewards_account(
     context: &mut ProgramTestContext,
     holder_rewards: &Pubkey,
+    mint: &Pubkey,
     unharvested_rewards: u64,
     last_accumulated_rewards_per_token: u128,
+    last_seen_balance: u64,
 ) {
-    let state = HolderRewards::new(last_accumulated_rewards_per_token, unharvested_rewards);
-    let data = bytemuck::bytes_of(&state).to_vec();
+    let mut state = HolderRewards::new(
+        last_accumulated_rewards_per_token,
+        unharvested_rewards,
+        last_seen_balance,
+    );
+    state.mint = *mint;
+    setup_holder_rewards_account_with_state(context, holder_rewards, &state).await;
+}
+
+pub async fn setup_excluded_holder_rewards_account(
+    context: &mut ProgramTestContext,
+    holder_rewards: &Pubkey,
+    mint: &Pubkey,
+    last_seen_balance: u64,
+) {
+    let mut state = HolderRewards::new(0, 0, last_seen_balance);
+    state.mint = *mint;
+    state.excluded = 1;
+    setup_holder_rewards_account_with_state(context, holder_rewards, &state).await;
+}
+
+pub async fn setup_holder_rewards_account_with_state(
+    context: &mut ProgramTestContext,
+    holder_rewards: &Pubkey,
+    state: &HolderRewards,
+) {
+    let mut data = vec![0; HolderRewards::LEN];
+    pack_versioned(&mut data, state).unwrap();
 
     let rent = context.banks_client.get_rent().await.unwrap();
     let lamports = rent.minimum_balance(data.len());
@@ -206,14 +373,107 @@ pub async fn setup_holder_rewards_account(
     );
 }
 
+#[allow(clippy::arithmetic_side_effects)]
+pub async fn setup_holder_rewards_escrow_account(
+    context: &mut ProgramTestContext,
+    holder_rewards_escrow: &Pubkey,
+    excess_lamports: u64,
+) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(0) + excess_lamports;
+
+    context.set_account(
+        holder_rewards_escrow,
+        &AccountSharedData::new(lamports, 0, &paladin_rewards_program::id()),
+    );
+}
+
+pub async fn setup_token_holder_rewards_account(
+    context: &mut ProgramTestContext,
+    token_holder_rewards: &Pubkey,
+    unharvested_rewards: u64,
+    last_accumulated_rewards_per_token: u128,
+    last_seen_balance: u64,
+) {
+    let state = TokenHolderRewards::new(
+        last_accumulated_rewards_per_token,
+        unharvested_rewards,
+        last_seen_balance,
+    );
+    let data = bytemuck::bytes_of(&state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        token_holder_rewards,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
+pub async fn setup_token_rewards_pool_account(
+    context: &mut ProgramTestContext,
+    token_rewards_pool_address: &Pubkey,
+    state: &TokenRewardsPool,
+) {
+    let data = bytemuck::bytes_of(state).to_vec();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+
+    context.set_account(
+        token_rewards_pool_address,
+        &AccountSharedData::from(Account {
+            lamports,
+            data,
+            owner: paladin_rewards_program::id(),
+            ..Account::default()
+        }),
+    );
+}
+
 pub async fn setup_extra_metas_account(context: &mut ProgramTestContext, mint: &Pubkey) {
+    setup_extra_metas_account_with_token_rewards_pools(context, mint, &[]).await;
+}
+
+pub async fn setup_extra_metas_account_with_token_rewards_pools(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    reward_mints: &[Pubkey],
+) {
+    let mut extra_metas = get_extra_account_metas().to_vec();
+    for (i, reward_mint) in reward_mints.iter().enumerate() {
+        extra_metas.extend_from_slice(&get_token_rewards_extra_account_metas(mint, reward_mint, i));
+    }
+    extra_metas.extend_from_slice(&get_escrow_extra_account_metas());
+    setup_extra_metas_account_with_metas(context, mint, &extra_metas).await;
+}
+
+/// Sets up an extra metas account as written by earlier versions of the
+/// program, with the holder rewards pool read-only and no auto-claim escrow
+/// accounts.
+pub async fn setup_legacy_extra_metas_account(context: &mut ProgramTestContext, mint: &Pubkey) {
+    let mut extra_metas = get_extra_account_metas();
+    extra_metas[0].is_writable = PodBool::from(false);
+    setup_extra_metas_account_with_metas(context, mint, &extra_metas).await;
+}
+
+async fn setup_extra_metas_account_with_metas(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    extra_metas: &[ExtraAccountMeta],
+) {
     let address = get_extra_account_metas_address(mint, &paladin_rewards_program::id());
 
-    let extra_metas = get_extra_account_metas();
     let data_len = ExtraAccountMetaList::size_of(extra_metas.len()).unwrap();
 
     let mut data = vec![0; data_len];
-    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_metas).unwrap();
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, extra_metas).unwrap();
 
     let rent = context.banks_client.get_rent().await.unwrap();
     let lamports = rent.minimum_balance(data_len);
@@ -0,0 +1,316 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::{deposit_rewards, sweep_dust},
+        state::{
+            get_holder_rewards_pool_address, unpack_versioned, EmissionSchedule, HolderRewardsPool,
+            VersionedAccount,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_pool_account_with_state, setup_mint, setup_system_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        clock::Clock,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_instruction,
+        transaction::{Transaction, TransactionError},
+    },
+    test_case::test_case,
+};
+
+const CURRENT_TIMESTAMP: i64 = 10_000;
+
+#[tokio::test]
+async fn fail_incorrect_pool_authority() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let destination = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        1_000,
+        &HolderRewardsPool::new(0, Pubkey::new_unique()), // Incorrect pool authority.
+    )
+    .await;
+
+    let instruction = sweep_dust(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectPoolAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_pool_authority_not_signer() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        1_000,
+        &HolderRewardsPool::new(0, pool_authority),
+    )
+    .await;
+
+    let mut instruction = sweep_dust(&holder_rewards_pool, &mint, &pool_authority, &destination);
+    instruction.accounts[2].is_signer = false; // Pool authority not signer.
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[test_case(1_000, 1_000, 0, 0, 0, 0, 0; "All rewards unharvested, no dust")]
+#[test_case(603, 1_000, 400, 3, 0, 0, 3; "Some rewards harvested, dust swept")]
+#[test_case(700, 1_000, 400, 3, 0, 0, 3; "Credited lamports excluded from dust")]
+#[test_case(2, 1_000, 998, 3, 0, 0, 2; "Pool underfunded, dust capped")]
+#[test_case(1_105, 1_000, 0, 5, 100, 0, 5; "Carryover excluded from dust")]
+#[test_case(102, 1_000, 998, 5, 100, 0, 2; "Carryover excluded from dust, dust capped")]
+#[test_case(1_505, 1_000, 0, 5, 0, 500, 5; "Escrowed rewards excluded from dust")]
+#[tokio::test]
+async fn success(
+    excess_lamports: u64,
+    total_distributed: u64,
+    total_harvested: u64,
+    dust: u64,
+    undistributed_carryover: u64,
+    escrowed_amount: u64,
+    expected_dust: u64,
+) {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let destination = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, pool_authority.pubkey());
+    pool.total_distributed = total_distributed;
+    pool.total_harvested = total_harvested;
+    pool.dust = dust;
+    pool.undistributed_carryover = undistributed_carryover;
+    if escrowed_amount != 0 {
+        // Not yet started, so nothing is released.
+        pool.emission_schedule = EmissionSchedule {
+            total_amount: escrowed_amount,
+            released_amount: 0,
+            start_timestamp: CURRENT_TIMESTAMP + 100,
+            end_timestamp: CURRENT_TIMESTAMP + 200,
+        };
+    }
+
+    let mut context = setup().start_with_context().await;
+    context.set_sysvar(&Clock {
+        unix_timestamp: CURRENT_TIMESTAMP,
+        ..Clock::default()
+    });
+    setup_system_account(&mut context, &destination, 1_000_000_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &holder_rewards_pool,
+        excess_lamports,
+        &pool,
+    )
+    .await;
+
+    let instruction = sweep_dust(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the dust was moved from the pool.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        holder_rewards_pool_account.lamports,
+        rent.minimum_balance(HolderRewardsPool::LEN) + excess_lamports - expected_dust
+    );
+    // Assert the swept dust was counted as harvested.
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap(),
+        &HolderRewardsPool {
+            total_harvested: total_harvested + expected_dust,
+            dust: dust - expected_dust,
+            ..pool
+        },
+    );
+
+    // Assert the destination received the dust.
+    let destination_account = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        destination_account.lamports,
+        rent.minimum_balance(0) + 1_000_000_000 + expected_dust
+    );
+}
+
+#[tokio::test]
+async fn success_credited_lamports_deposited_after_sweep() {
+    let mint = Pubkey::new_unique();
+    let pool_authority = Keypair::new();
+    let destination = Pubkey::new_unique();
+
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut pool = HolderRewardsPool::new(0, pool_authority.pubkey());
+    pool.total_distributed = 1_000;
+    pool.total_harvested = 400;
+    pool.dust = 3;
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &destination, 1_000_000_000).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100).await;
+    setup_holder_rewards_pool_account_with_state(&mut context, &holder_rewards_pool, 600, &pool)
+        .await;
+
+    // Credit the pool directly, then sweep before the credit is deposited.
+    let credit = system_instruction::transfer(&context.payer.pubkey(), &holder_rewards_pool, 500);
+    let sweep = sweep_dust(
+        &holder_rewards_pool,
+        &mint,
+        &pool_authority.pubkey(),
+        &destination,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[credit, sweep],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &pool_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert only the dust was swept.
+    let destination_account = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        destination_account.lamports,
+        rent.minimum_balance(0) + 1_000_000_000 + 3
+    );
+
+    // Deposit the credit.
+    let instruction = deposit_rewards(&holder_rewards_pool, &mint, &[]);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the whole credit was deposited.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    let pool_state =
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data).unwrap();
+    assert_eq!(pool_state.total_distributed, 1_500);
+    assert_eq!(pool_state.total_harvested, 403);
+    assert_eq!(pool_state.dust, 0);
+    assert_eq!(
+        holder_rewards_pool_account.lamports,
+        rent.minimum_balance(HolderRewardsPool::LEN) + 1_097
+    );
+}
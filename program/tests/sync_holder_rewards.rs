@@ -0,0 +1,663 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use {
+    paladin_rewards_program::{
+        error::PaladinRewardsError,
+        instruction::{distribute_rewards, sync_holder_rewards},
+        state::{
+            get_holder_rewards_address, get_holder_rewards_pool_address, unpack_versioned,
+            HolderRewards,
+        },
+    },
+    setup::{
+        setup, setup_holder_rewards_account, setup_holder_rewards_account_with_state,
+        setup_holder_rewards_pool_account, setup_mint, setup_system_account, setup_token_account,
+    },
+    solana_program_test::*,
+    solana_sdk::{
+        account::AccountSharedData,
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signer::{keypair::Keypair, Signer},
+        system_program,
+        transaction::{Transaction, TransactionError},
+    },
+    spl_associated_token_account::get_associated_token_address,
+    test_case::test_case,
+};
+
+#[tokio::test]
+async fn fail_token_account_invalid_data() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+
+    // Setup token account with invalid data.
+    {
+        context.set_account(
+            &token_account,
+            &AccountSharedData::new_data(100_000_000, &vec![5; 165], &spl_token_2022::id())
+                .unwrap(),
+        );
+    }
+
+    let instruction =
+        sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn fail_token_account_mint_mismatch() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &Pubkey::new_unique(), // Incorrect mint.
+        0,
+    )
+    .await;
+
+    let instruction =
+        sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::TokenAccountMintMismatch as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_incorrect_owner() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+
+    // Setup holder rewards pool account with incorrect owner.
+    {
+        context.set_account(
+            &holder_rewards_pool,
+            &AccountSharedData::new_data(100_000_000, &vec![5; 8], &system_program::id()).unwrap(),
+        );
+    }
+
+    let instruction =
+        sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_incorrect_address() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = Pubkey::new_unique(); // Incorrect holder rewards pool address.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+
+    let instruction =
+        sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsPoolAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_pool_invalid_data() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+
+    // Setup holder rewards pool account with invalid data.
+    {
+        context.set_account(
+            &holder_rewards_pool,
+            &AccountSharedData::new_data(
+                100_000_000,
+                &vec![5; 165],
+                &paladin_rewards_program::id(),
+            )
+            .unwrap(),
+        );
+    }
+
+    let instruction =
+        sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_owner() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+
+    // Setup holder rewards account with incorrect owner.
+    {
+        context.set_account(
+            &holder_rewards,
+            &AccountSharedData::new_data(100_000_000, &vec![5; 16], &system_program::id()).unwrap(),
+        );
+    }
+
+    let instruction =
+        sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_incorrect_address() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = Pubkey::new_unique(); // Incorrect holder rewards address.
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+
+    let instruction =
+        sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_holder_rewards_invalid_data() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+
+    // Setup holder rewards account with invalid data.
+    {
+        context.set_account(
+            &holder_rewards,
+            &AccountSharedData::new_data(
+                100_000_000,
+                &vec![5; 165],
+                &paladin_rewards_program::id(),
+            )
+            .unwrap(),
+        );
+    }
+
+    let instruction =
+        sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+struct Holder {
+    last_seen_balance: u64,
+    last_accumulated_rewards_per_token: u128,
+    unharvested_rewards: u64,
+}
+
+#[test_case(
+    0,
+    Holder {
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 0,
+        unharvested_rewards: 0,
+    },
+    100,
+    0;
+    "All zeroes, balance unchanged, no rewards"
+)]
+#[test_case(
+    1_000_000_000_000_000_000, // 1 reward per token.
+    Holder {
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
+        unharvested_rewards: 500,
+    },
+    100,
+    500;
+    "Rate unchanged, balance unchanged, unharvested unchanged"
+)]
+#[test_case(
+    1_000_000_000_000_000_000, // 1 reward per token.
+    Holder {
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
+        unharvested_rewards: 0,
+    },
+    100,
+    50; // (1 - 0.5) * 100
+    "Last seen 0.5 rate, balance unchanged, settle share"
+)]
+#[test_case(
+    1_000_000_000_000_000_000, // 1 reward per token.
+    Holder {
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
+        unharvested_rewards: 0,
+    },
+    10_000, // Minted.
+    50; // (1 - 0.5) * 100
+    "Last seen 0.5 rate, tokens minted, settle share of last seen balance"
+)]
+#[test_case(
+    1_000_000_000_000_000_000, // 1 reward per token.
+    Holder {
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 250_000_000_000_000_000, // 0.25 rewards per token.
+        unharvested_rewards: 1_000,
+    },
+    0, // Burned.
+    1_000;
+    "Last seen 0.25 rate, some unharvested, tokens burned, settle share of current balance"
+)]
+#[test_case(
+    1_000_000_000_000_000_000, // 1 reward per token.
+    Holder {
+        last_seen_balance: 0,
+        last_accumulated_rewards_per_token: 0,
+        unharvested_rewards: 0,
+    },
+    10_000, // Minted.
+    0;
+    "No last seen balance, tokens minted, no retroactive rewards"
+)]
+#[tokio::test]
+async fn success(
+    accumulated_rewards_per_token: u128,
+    holder: Holder,
+    token_account_balance: u64,
+    expected_unharvested_rewards: u64,
+) {
+    let Holder {
+        last_seen_balance,
+        last_accumulated_rewards_per_token,
+        unharvested_rewards,
+    } = holder;
+
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        0, // Excess lamports (not used here).
+        accumulated_rewards_per_token,
+    )
+    .await;
+    setup_holder_rewards_account(
+        &mut context,
+        &holder_rewards,
+        &mint,
+        unharvested_rewards,
+        last_accumulated_rewards_per_token,
+        last_seen_balance,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &token_account,
+        &owner,
+        &mint,
+        token_account_balance,
+    )
+    .await;
+
+    let instruction =
+        sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder rewards account was settled at the lesser of the
+    // last seen and current balances, and now records the current balance.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &HolderRewards {
+            mint,
+            owner,
+            ..HolderRewards::new(
+                accumulated_rewards_per_token,
+                expected_unharvested_rewards,
+                token_account_balance,
+            )
+        },
+    );
+}
+
+#[tokio::test]
+async fn success_last_seen_balance_unknown() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_000; // 1 reward per token.
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    // The holder was migrated from the original layout, which didn't record
+    // the balance it was last settled at.
+    let mut holder = HolderRewards {
+        mint,
+        ..HolderRewards::new(500_000_000_000_000_000, 0, 0)
+    };
+    holder.last_seen_balance_unknown = 1;
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        0, // Excess lamports (not used here).
+        accumulated_rewards_per_token,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(&mut context, &holder_rewards, &holder).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 100).await;
+
+    let instruction =
+        sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder rewards account was settled at the current balance,
+    // which is now known.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &HolderRewards {
+            mint,
+            owner,
+            ..HolderRewards::new(accumulated_rewards_per_token, 50, 100)
+        },
+    );
+}
+
+#[tokio::test]
+async fn success_burn_distribute_then_sync() {
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let payer = Keypair::new();
+
+    let token_account = get_associated_token_address(&owner, &mint);
+    let holder_rewards = get_holder_rewards_address(&token_account);
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_system_account(&mut context, &payer.pubkey(), 100).await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &holder_rewards_pool,
+        0, // Excess lamports (not used here).
+        0, // Accumulated rewards per token.
+    )
+    .await;
+
+    // The holder was last settled at a balance of 100, but has since burned
+    // all of its tokens, leaving the rest of the supply held elsewhere.
+    setup_holder_rewards_account(&mut context, &holder_rewards, &mint, 0, 0, 100).await;
+    setup_token_account(&mut context, &token_account, &owner, &mint, 0).await;
+    setup_mint(&mut context, &mint, &Pubkey::new_unique(), 100).await;
+
+    // Rewards are distributed over the supply remaining after the burn, then
+    // the holder is synced.
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            distribute_rewards(&payer.pubkey(), &holder_rewards_pool, &mint, &[], 100),
+            sync_holder_rewards(&holder_rewards_pool, &holder_rewards, &token_account, &mint),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder earned nothing on the burned tokens, which the
+    // rewards weren't distributed over.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &HolderRewards {
+            mint,
+            owner,
+            ..HolderRewards::new(1_000_000_000_000_000_000, 0, 0)
+        },
+    );
+}
@@ -8,12 +8,22 @@ mod setup;
 use {
     paladin_rewards_program::{
         error::PaladinRewardsError,
-        state::{get_holder_rewards_address, get_holder_rewards_pool_address, HolderRewards},
+        instruction::migrate_account_with_extra_metas,
+        state::{
+            get_holder_rewards_address, get_holder_rewards_escrow_address,
+            get_holder_rewards_pool_address, get_token_holder_rewards_address,
+            get_token_rewards_pool_address, unpack_versioned, HolderRewards, HolderRewardsPool,
+            TokenHolderRewards, TokenRewardsPool, VersionedAccount,
+        },
     },
     setup::{
-        setup, setup_extra_metas_account, setup_holder_rewards_account,
-        setup_holder_rewards_pool_account, setup_mint, setup_token_account,
-        setup_token_account_transferring,
+        setup, setup_extra_metas_account, setup_extra_metas_account_with_token_rewards_pools,
+        setup_holder_rewards_account, setup_holder_rewards_account_with_state,
+        setup_holder_rewards_escrow_account, setup_holder_rewards_pool_account,
+        setup_holder_rewards_pool_account_with_state, setup_legacy_account,
+        setup_legacy_extra_metas_account, setup_mint, setup_token_account,
+        setup_token_account_transferring, setup_token_holder_rewards_account,
+        setup_token_rewards_pool_account,
     },
     solana_program_test::*,
     solana_sdk::{
@@ -51,7 +61,7 @@ fn execute_with_extra_metas_instruction(
         owner,
         &Pubkey::new_unique(), // (Extra metas) Doesn't matter if we're invoking directly.
         &[
-            AccountMeta::new_readonly(*holder_rewards_pool, false),
+            AccountMeta::new(*holder_rewards_pool, false),
             AccountMeta::new(*source_holder_rewards, false),
             AccountMeta::new(*destination_holder_rewards, false),
         ],
@@ -289,7 +299,7 @@ async fn fail_source_holder_rewards_incorrect_address() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &source_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 0).await;
 
     let instruction = execute_with_extra_metas_instruction(
         &source_token_account,
@@ -340,13 +350,13 @@ async fn fail_source_holder_rewards_invalid_data() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &source_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 0).await;
 
     // Setup source holder rewards account with invalid data.
     {
         context.set_account(
             &source_holder_rewards,
-            &AccountSharedData::new_data(100_000_000, &vec![5; 32], &paladin_rewards_program::id())
+            &AccountSharedData::new_data(100_000_000, &vec![5; 33], &paladin_rewards_program::id())
                 .unwrap(),
         );
     }
@@ -397,7 +407,7 @@ async fn fail_source_token_account_invalid_data() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &source_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 0).await;
 
     // Set up source token account with invalid data.
     {
@@ -454,7 +464,7 @@ async fn fail_source_token_account_mint_mismatch() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &source_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 0).await;
     setup_token_account_transferring(
         &mut context,
         &source_token_account,
@@ -513,7 +523,7 @@ async fn fail_source_token_account_not_transferring() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &source_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 0).await;
     // Not transferring.
     setup_token_account(
         &mut context,
@@ -573,8 +583,8 @@ async fn fail_destination_holder_rewards_incorrect_address() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &source_holder_rewards, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &destination_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &destination_holder_rewards, &mint, 0, 0, 0).await;
     setup_token_account_transferring(
         &mut context,
         &source_token_account,
@@ -633,7 +643,7 @@ async fn fail_destination_holder_rewards_invalid_data() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &source_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 0).await;
     setup_token_account_transferring(
         &mut context,
         &source_token_account,
@@ -647,7 +657,7 @@ async fn fail_destination_holder_rewards_invalid_data() {
     {
         context.set_account(
             &destination_holder_rewards,
-            &AccountSharedData::new_data(100_000_000, &vec![5; 32], &paladin_rewards_program::id())
+            &AccountSharedData::new_data(100_000_000, &vec![5; 33], &paladin_rewards_program::id())
                 .unwrap(),
         );
     }
@@ -698,8 +708,8 @@ async fn fail_destination_token_account_invalid_data() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &source_holder_rewards, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &destination_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &destination_holder_rewards, &mint, 0, 0, 0).await;
     setup_token_account_transferring(
         &mut context,
         &source_token_account,
@@ -764,8 +774,8 @@ async fn fail_destination_token_account_mint_mismatch() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &source_holder_rewards, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &destination_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &destination_holder_rewards, &mint, 0, 0, 0).await;
     setup_token_account_transferring(
         &mut context,
         &source_token_account,
@@ -832,8 +842,8 @@ async fn fail_destination_token_account_not_transferring() {
 
     let mut context = setup().start_with_context().await;
     setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &source_holder_rewards, 0, 0).await;
-    setup_holder_rewards_account(&mut context, &destination_holder_rewards, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &destination_holder_rewards, &mint, 0, 0, 0).await;
     setup_token_account_transferring(
         &mut context,
         &source_token_account,
@@ -908,6 +918,7 @@ impl PoolAddresses {
 
 struct Holder {
     token_account_balance: u64,
+    last_seen_balance: u64,
     last_accumulated_rewards_per_token: u128,
     unharvested_rewards: u64,
     expected_unharvested_rewards: u64,
@@ -951,6 +962,7 @@ async fn setup_direct_invoke(
     } = pool_addresses;
     let Holder {
         token_account_balance: source_token_account_balance,
+        last_seen_balance: source_last_seen_balance,
         last_accumulated_rewards_per_token: source_last_accumulated_rewards_per_token,
         unharvested_rewards: source_unharvested_rewards,
         expected_unharvested_rewards: _,
@@ -962,6 +974,7 @@ async fn setup_direct_invoke(
     } = source_addresses;
     let Holder {
         token_account_balance: destination_token_account_balance,
+        last_seen_balance: destination_last_seen_balance,
         last_accumulated_rewards_per_token: destination_last_accumulated_rewards_per_token,
         unharvested_rewards: destination_unharvested_rewards,
         expected_unharvested_rewards: _,
@@ -982,15 +995,19 @@ async fn setup_direct_invoke(
     setup_holder_rewards_account(
         context,
         source_holder_rewards,
+        mint,
         *source_unharvested_rewards,
         *source_last_accumulated_rewards_per_token,
+        *source_last_seen_balance,
     )
     .await;
     setup_holder_rewards_account(
         context,
         destination_holder_rewards,
+        mint,
         *destination_unharvested_rewards,
         *destination_last_accumulated_rewards_per_token,
+        *destination_last_seen_balance,
     )
     .await;
     setup_token_account_transferring(
@@ -1036,6 +1053,7 @@ async fn setup_transfer_hook(
     } = pool_addresses;
     let Holder {
         token_account_balance: source_token_account_balance,
+        last_seen_balance: source_last_seen_balance,
         last_accumulated_rewards_per_token: source_last_accumulated_rewards_per_token,
         unharvested_rewards: source_unharvested_rewards,
         expected_unharvested_rewards: _,
@@ -1047,6 +1065,7 @@ async fn setup_transfer_hook(
     } = source_addresses;
     let Holder {
         token_account_balance: destination_token_account_balance,
+        last_seen_balance: destination_last_seen_balance,
         last_accumulated_rewards_per_token: destination_last_accumulated_rewards_per_token,
         unharvested_rewards: destination_unharvested_rewards,
         expected_unharvested_rewards: _,
@@ -1068,15 +1087,19 @@ async fn setup_transfer_hook(
     setup_holder_rewards_account(
         context,
         source_holder_rewards,
+        mint,
         *source_unharvested_rewards,
         *source_last_accumulated_rewards_per_token,
+        *source_last_seen_balance,
     )
     .await;
     setup_holder_rewards_account(
         context,
         destination_holder_rewards,
+        mint,
         *destination_unharvested_rewards,
         *destination_last_accumulated_rewards_per_token,
+        *destination_last_seen_balance,
     )
     .await;
     setup_token_account(
@@ -1109,6 +1132,8 @@ async fn check_holder_rewards(
     pool: &Pool,
     holder: &Holder,
     holder_addresses: &HolderAddresses,
+    mint: &Pubkey,
+    expected_last_seen_balance: u64,
 ) {
     let holder_rewards_account = context
         .banks_client
@@ -1117,11 +1142,15 @@ async fn check_holder_rewards(
         .unwrap()
         .unwrap();
     assert_eq!(
-        bytemuck::from_bytes::<HolderRewards>(&holder_rewards_account.data),
-        &HolderRewards::new(
-            pool.accumulated_rewards_per_token,
-            holder.expected_unharvested_rewards,
-        ),
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &HolderRewards {
+            mint: *mint,
+            ..HolderRewards::new(
+                pool.accumulated_rewards_per_token,
+                holder.expected_unharvested_rewards,
+                expected_last_seen_balance,
+            )
+        },
     );
 }
 
@@ -1131,12 +1160,14 @@ async fn check_holder_rewards(
     },
     Holder {
         token_account_balance: 100,
+        last_seen_balance: 100,
         last_accumulated_rewards_per_token: 0,
         unharvested_rewards: 0,
         expected_unharvested_rewards: 0,
     },
     Holder {
         token_account_balance: 100,
+        last_seen_balance: 100,
         last_accumulated_rewards_per_token: 0,
         unharvested_rewards: 0,
         expected_unharvested_rewards: 0,
@@ -1145,17 +1176,19 @@ async fn check_holder_rewards(
 )]
 #[test_case(
     Pool {
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
         unharvested_rewards: 0,
         expected_unharvested_rewards: 0,
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
         unharvested_rewards: 0,
         expected_unharvested_rewards: 0,
     };
@@ -1163,17 +1196,19 @@ async fn check_holder_rewards(
 )]
 #[test_case(
     Pool {
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 500_000_000, // 0.5 rewards per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
         unharvested_rewards: 0,
         expected_unharvested_rewards: 50, // (1 - 0.5) * 100 = 50
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 500_000_000, // 0.5 rewards per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
         unharvested_rewards: 0,
         expected_unharvested_rewards: 50, // (1 - 0.5) * 100 = 50
     };
@@ -1181,17 +1216,19 @@ async fn check_holder_rewards(
 )]
 #[test_case(
     Pool {
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 250_000_000, // 0.25 rewards per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 250_000_000_000_000_000, // 0.25 rewards per token.
         unharvested_rewards: 0,
         expected_unharvested_rewards: 75, // (1 - 0.25) * 100 = 75
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 750_000_000, // 0.75 rewards per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 750_000_000_000_000_000, // 0.75 rewards per token.
         unharvested_rewards: 0,
         expected_unharvested_rewards: 25, // (1 - 0.75) * 100 = 25
     };
@@ -1199,17 +1236,19 @@ async fn check_holder_rewards(
 )]
 #[test_case(
     Pool {
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 250_000_000, // 0.25 rewards per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 250_000_000_000_000_000, // 0.25 rewards per token.
         unharvested_rewards: 100,
         expected_unharvested_rewards: 175, // (1 - 0.25) * 100 + 100 = 175
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 750_000_000, // 0.75 rewards per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 750_000_000_000_000_000, // 0.75 rewards per token.
         unharvested_rewards: 0,
         expected_unharvested_rewards: 25, // (1 - 0.75) * 100 = 25
     };
@@ -1217,17 +1256,19 @@ async fn check_holder_rewards(
 )]
 #[test_case(
     Pool {
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 250_000_000, // 0.25 rewards per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 250_000_000_000_000_000, // 0.25 rewards per token.
         unharvested_rewards: 0,
         expected_unharvested_rewards: 75, // (1 - 0.25) * 100 = 75
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 750_000_000, // 0.75 rewards per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 750_000_000_000_000_000, // 0.75 rewards per token.
         unharvested_rewards: 200,
         expected_unharvested_rewards: 225, // (1 - 0.75) * 100 + 200 = 225
     };
@@ -1235,22 +1276,64 @@ async fn check_holder_rewards(
 )]
 #[test_case(
     Pool {
-        accumulated_rewards_per_token: 1_000_000_000, // 1 reward per token.
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 250_000_000, // 0.25 rewards per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 250_000_000_000_000_000, // 0.25 rewards per token.
         unharvested_rewards: 100,
         expected_unharvested_rewards: 175, // (1 - 0.25) * 100 + 100 = 175
     },
     Holder {
         token_account_balance: 100,
-        last_accumulated_rewards_per_token: 750_000_000, // 0.75 rewards per token.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 750_000_000_000_000_000, // 0.75 rewards per token.
         unharvested_rewards: 200,
         expected_unharvested_rewards: 225, // (1 - 0.75) * 100 + 200 = 225
     };
     "source last seen 0.25 with unharvested, dest last seen 0.75 with unharvested, current rate 1, both diffs to unharvested rewards"
 )]
+#[test_case(
+    Pool {
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
+    },
+    Holder {
+        token_account_balance: 100, // Minted since last seen.
+        last_seen_balance: 20,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
+        unharvested_rewards: 0,
+        expected_unharvested_rewards: 10, // (1 - 0.5) * 20 = 10
+    },
+    Holder {
+        token_account_balance: 100,
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
+        unharvested_rewards: 0,
+        expected_unharvested_rewards: 50, // (1 - 0.5) * 100 = 50
+    };
+    "source balance increased since last seen, source settled on last seen balance"
+)]
+#[test_case(
+    Pool {
+        accumulated_rewards_per_token: 1_000_000_000_000_000_000, // 1 reward per token.
+    },
+    Holder {
+        token_account_balance: 100,
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
+        unharvested_rewards: 0,
+        expected_unharvested_rewards: 50, // (1 - 0.5) * 100 = 50
+    },
+    Holder {
+        token_account_balance: 40, // Burned since last seen.
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 500_000_000_000_000_000, // 0.5 rewards per token.
+        unharvested_rewards: 0,
+        expected_unharvested_rewards: 20, // (1 - 0.5) * 40 = 20
+    };
+    "destination balance decreased since last seen, destination settled on current balance"
+)]
 #[tokio::test]
 async fn success(pool: Pool, source: Holder, destination: Holder) {
     let source_owner = Keypair::new();
@@ -1301,8 +1384,24 @@ async fn success(pool: Pool, source: Holder, destination: Holder) {
             .await
             .unwrap();
 
-        check_holder_rewards(&mut context, &pool, &source, &source_addresses).await;
-        check_holder_rewards(&mut context, &pool, &destination, &destination_addresses).await;
+        check_holder_rewards(
+            &mut context,
+            &pool,
+            &source,
+            &source_addresses,
+            &pool_addresses.mint,
+            source.token_account_balance - transfer_amount,
+        )
+        .await;
+        check_holder_rewards(
+            &mut context,
+            &pool,
+            &destination,
+            &destination_addresses,
+            &pool_addresses.mint,
+            destination.token_account_balance + transfer_amount,
+        )
+        .await;
     }
 
     // Then test transfer hook with Token-2022.
@@ -1343,7 +1442,1381 @@ async fn success(pool: Pool, source: Holder, destination: Holder) {
             .await
             .unwrap();
 
-        check_holder_rewards(&mut context, &pool, &source, &source_addresses).await;
-        check_holder_rewards(&mut context, &pool, &destination, &destination_addresses).await;
+        check_holder_rewards(
+            &mut context,
+            &pool,
+            &source,
+            &source_addresses,
+            &pool_addresses.mint,
+            source.token_account_balance - transfer_amount,
+        )
+        .await;
+        check_holder_rewards(
+            &mut context,
+            &pool,
+            &destination,
+            &destination_addresses,
+            &pool_addresses.mint,
+            destination.token_account_balance + transfer_amount,
+        )
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn success_after_migrating_holder_rewards_pool() {
+    let source_owner = Keypair::new();
+    let destination_owner = Pubkey::new_unique();
+
+    let transfer_amount = 10;
+
+    let pool_addresses = PoolAddresses::new();
+    let source_addresses = HolderAddresses::new(&source_owner.pubkey(), &pool_addresses.mint);
+    let destination_addresses = HolderAddresses::new(&destination_owner, &pool_addresses.mint);
+    let extra_metas =
+        get_extra_account_metas_address(&pool_addresses.mint, &paladin_rewards_program::id());
+
+    // Set up a pool and extra metas as written by earlier versions of the
+    // program, with the pool read-only in the extra metas.
+    let mut context = setup().start_with_context().await;
+    setup_legacy_account(
+        &mut context,
+        &pool_addresses.holder_rewards_pool,
+        0,
+        &0u128.to_le_bytes(), // Original layout.
+    )
+    .await;
+    setup_legacy_extra_metas_account(&mut context, &pool_addresses.mint).await;
+    for addresses in [&source_addresses, &destination_addresses] {
+        setup_holder_rewards_account(
+            &mut context,
+            &addresses.holder_rewards,
+            &pool_addresses.mint,
+            0,
+            0,
+            100,
+        )
+        .await;
+        setup_token_account(
+            &mut context,
+            &addresses.token_account,
+            &addresses.owner,
+            &pool_addresses.mint,
+            100,
+        )
+        .await;
+    }
+    setup_mint(
+        &mut context,
+        &pool_addresses.mint,
+        &Pubkey::new_unique(),
+        200,
+    )
+    .await;
+
+    let instruction = migrate_account_with_extra_metas(
+        &context.payer.pubkey(),
+        &pool_addresses.holder_rewards_pool,
+        &pool_addresses.mint,
+        &extra_metas,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Transfer through Token-2022, resolving the corrected extra metas.
+    let instruction = transfer_with_extra_metas_instruction(
+        &mut context,
+        &source_addresses.token_account,
+        &pool_addresses.mint,
+        &destination_addresses.token_account,
+        &source_addresses.owner,
+        transfer_amount,
+        0, // Decimals.
+    )
+    .await;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert both holders were settled at their new balances.
+    for (addresses, expected_balance) in [
+        (&source_addresses, 100 - transfer_amount),
+        (&destination_addresses, 100 + transfer_amount),
+    ] {
+        let holder_rewards_account = context
+            .banks_client
+            .get_account(addresses.holder_rewards)
+            .await
+            .unwrap()
+            .unwrap();
+        let holder_rewards_state =
+            unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap();
+        assert_eq!(holder_rewards_state.last_seen_balance, expected_balance);
     }
 }
+
+#[tokio::test]
+async fn success_excluded_holder() {
+    let source_owner = Pubkey::new_unique();
+    let destination_owner = Pubkey::new_unique();
+
+    let transfer_amount = 10;
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_000; // 1 reward per token.
+
+    let pool_addresses = PoolAddresses::new();
+    let source_addresses = HolderAddresses::new(&source_owner, &pool_addresses.mint);
+    let destination_addresses = HolderAddresses::new(&destination_owner, &pool_addresses.mint);
+
+    // The source is excluded, so its balance is counted in the pool's
+    // excluded supply.
+    let mut pool = HolderRewardsPool::new(accumulated_rewards_per_token, Pubkey::new_unique());
+    pool.excluded_supply = 100;
+    pool.excluded_holders = 1;
+    let mut source = HolderRewards::new(500_000_000_000_000_000, 0, 100);
+    source.excluded = 1;
+    let destination = HolderRewards::new(500_000_000_000_000_000, 0, 100);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &pool_addresses.holder_rewards_pool,
+        0,
+        &pool,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &source_addresses.holder_rewards,
+        &source,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &destination_addresses.holder_rewards,
+        &destination,
+    )
+    .await;
+    setup_token_account_transferring(
+        &mut context,
+        &source_addresses.token_account,
+        &source_owner,
+        &pool_addresses.mint,
+        100 - transfer_amount, // Post-transfer balance.
+    )
+    .await;
+    setup_token_account_transferring(
+        &mut context,
+        &destination_addresses.token_account,
+        &destination_owner,
+        &pool_addresses.mint,
+        100 + transfer_amount, // Post-transfer balance.
+    )
+    .await;
+    setup_mint(
+        &mut context,
+        &pool_addresses.mint,
+        &Pubkey::new_unique(),
+        1_000,
+    )
+    .await;
+
+    let instruction = execute_with_extra_metas_instruction(
+        &source_addresses.token_account,
+        &pool_addresses.mint,
+        &destination_addresses.token_account,
+        &source_addresses.owner,
+        &pool_addresses.holder_rewards_pool,
+        &source_addresses.holder_rewards,
+        &destination_addresses.holder_rewards,
+        transfer_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the excluded source earned nothing, while the destination was
+    // settled as usual.
+    let mut expected_source = HolderRewards::new(accumulated_rewards_per_token, 0, 90);
+    expected_source.excluded = 1;
+    let source_holder_rewards_account = context
+        .banks_client
+        .get_account(source_addresses.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&source_holder_rewards_account.data).unwrap(),
+        &expected_source,
+    );
+    let destination_holder_rewards_account = context
+        .banks_client
+        .get_account(destination_addresses.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&destination_holder_rewards_account.data).unwrap(),
+        &HolderRewards::new(accumulated_rewards_per_token, 50, 110),
+    );
+
+    // Assert the pool's excluded supply tracks the source's new balance.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(pool_addresses.holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data)
+            .unwrap()
+            .excluded_supply,
+        90,
+    );
+}
+
+#[tokio::test]
+async fn success_holder_rewards_not_migrated() {
+    let source_owner = Pubkey::new_unique();
+    let destination_owner = Pubkey::new_unique();
+
+    let transfer_amount = 10;
+    // Slightly more than 1 reward per token, which can't be represented with
+    // `1e9` precision.
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_007;
+
+    let pool_addresses = PoolAddresses::new();
+    let source_addresses = HolderAddresses::new(&source_owner, &pool_addresses.mint);
+    let destination_addresses = HolderAddresses::new(&destination_owner, &pool_addresses.mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &pool_addresses.holder_rewards_pool,
+        0,
+        accumulated_rewards_per_token,
+    )
+    .await;
+
+    // Setup the source holder rewards account in the original layout, which
+    // has no header, scales its rate by `1e9` and doesn't record the balance
+    // the holder was last settled at.
+    setup_legacy_account(
+        &mut context,
+        &source_addresses.holder_rewards,
+        0,
+        &bytemuck::bytes_of(&HolderRewards::new(500_000_000, 0, 0))[..32],
+    )
+    .await;
+
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &destination_addresses.holder_rewards,
+        &HolderRewards::new(500_000_000_000_000_000, 0, 100),
+    )
+    .await;
+    setup_token_account_transferring(
+        &mut context,
+        &source_addresses.token_account,
+        &source_owner,
+        &pool_addresses.mint,
+        100 - transfer_amount, // Post-transfer balance.
+    )
+    .await;
+    setup_token_account_transferring(
+        &mut context,
+        &destination_addresses.token_account,
+        &destination_owner,
+        &pool_addresses.mint,
+        100 + transfer_amount, // Post-transfer balance.
+    )
+    .await;
+    setup_mint(
+        &mut context,
+        &pool_addresses.mint,
+        &Pubkey::new_unique(),
+        1_000,
+    )
+    .await;
+
+    let instruction = execute_with_extra_metas_instruction(
+        &source_addresses.token_account,
+        &pool_addresses.mint,
+        &destination_addresses.token_account,
+        &source_addresses.owner,
+        &pool_addresses.holder_rewards_pool,
+        &source_addresses.holder_rewards,
+        &destination_addresses.holder_rewards,
+        transfer_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the source was settled in the original layout, checkpointed at
+    // the pool's rate truncated to `1e9` precision.
+    let source_holder_rewards_account = context
+        .banks_client
+        .get_account(source_addresses.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        source_holder_rewards_account.data,
+        &bytemuck::bytes_of(&HolderRewards::new(
+            accumulated_rewards_per_token / 1_000_000_000,
+            50,
+            90,
+        ))[..32],
+    );
+
+    // Assert the destination was settled as usual.
+    let destination_holder_rewards_account = context
+        .banks_client
+        .get_account(destination_addresses.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&destination_holder_rewards_account.data).unwrap(),
+        &HolderRewards::new(accumulated_rewards_per_token, 50, 110),
+    );
+}
+
+#[test_case(10; "Transfer amount within balance")]
+#[test_case(150; "Transfer amount exceeds balance")]
+#[tokio::test]
+async fn success_self_transfer(transfer_amount: u64) {
+    let owner = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_000; // 1 reward per token.
+
+    let pool_addresses = PoolAddresses::new();
+    let holder_addresses = HolderAddresses::new(&owner, &pool_addresses.mint);
+
+    let token_rewards_pool = get_token_rewards_pool_address(&pool_addresses.mint, &reward_mint);
+    let token_holder_rewards =
+        get_token_holder_rewards_address(&holder_addresses.token_account, &token_rewards_pool);
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &pool_addresses.holder_rewards_pool,
+        0, // Excess lamports (unused here).
+        accumulated_rewards_per_token,
+    )
+    .await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(1_000_000_000_000_000_000, reward_mint, spl_token::id()), // 1 reward token per token.
+    )
+    .await;
+    setup_token_holder_rewards_account(&mut context, &token_holder_rewards, 0, 0, 100).await;
+    setup_holder_rewards_account(
+        &mut context,
+        &holder_addresses.holder_rewards,
+        &pool_addresses.mint,
+        0,
+        500_000_000_000_000_000,
+        100,
+    )
+    .await;
+    // A self-transfer leaves the balance unchanged.
+    setup_token_account_transferring(
+        &mut context,
+        &holder_addresses.token_account,
+        &owner,
+        &pool_addresses.mint,
+        100,
+    )
+    .await;
+    setup_mint(
+        &mut context,
+        &pool_addresses.mint,
+        &Pubkey::new_unique(),
+        100_000, // Token supply (unused here).
+    )
+    .await;
+
+    let mut instruction = execute_with_extra_metas_instruction(
+        &holder_addresses.token_account,
+        &pool_addresses.mint,
+        &holder_addresses.token_account,
+        &owner,
+        &pool_addresses.holder_rewards_pool,
+        &holder_addresses.holder_rewards,
+        &holder_addresses.holder_rewards,
+        transfer_amount,
+    );
+    instruction.accounts.extend_from_slice(&[
+        AccountMeta::new_readonly(token_rewards_pool, false),
+        AccountMeta::new(token_holder_rewards, false),
+        AccountMeta::new(token_holder_rewards, false),
+    ]);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the holder was settled once, against its unchanged balance.
+    let holder_rewards_account = context
+        .banks_client
+        .get_account(holder_addresses.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&holder_rewards_account.data).unwrap(),
+        &HolderRewards {
+            mint: pool_addresses.mint,
+            ..HolderRewards::new(accumulated_rewards_per_token, 50, 100)
+        },
+    );
+    let token_holder_rewards_account = context
+        .banks_client
+        .get_account(token_holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        bytemuck::from_bytes::<TokenHolderRewards>(&token_holder_rewards_account.data),
+        &TokenHolderRewards::new(1_000_000_000_000_000_000, 100, 100),
+    );
+}
+
+#[tokio::test]
+async fn fail_uninitialized_destination_holder_rewards_incorrect_address() {
+    let mint = Pubkey::new_unique();
+    let holder_rewards_pool = get_holder_rewards_pool_address(&mint);
+
+    let source_owner = Keypair::new();
+    let source_token_account = get_associated_token_address(&source_owner.pubkey(), &mint);
+    let source_holder_rewards = get_holder_rewards_address(&source_token_account);
+
+    let destination_owner = Pubkey::new_unique();
+    let destination_token_account = get_associated_token_address(&destination_owner, &mint);
+    let destination_holder_rewards = Pubkey::new_unique(); // Incorrect, and uninitialized.
+
+    let mut context = setup().start_with_context().await;
+    setup_holder_rewards_pool_account(&mut context, &holder_rewards_pool, 0, 0).await;
+    setup_holder_rewards_account(&mut context, &source_holder_rewards, &mint, 0, 0, 10).await;
+    setup_token_account_transferring(
+        &mut context,
+        &source_token_account,
+        &source_owner.pubkey(),
+        &mint,
+        10,
+    )
+    .await;
+    setup_token_account_transferring(
+        &mut context,
+        &destination_token_account,
+        &destination_owner,
+        &mint,
+        0,
+    )
+    .await;
+
+    let instruction = execute_with_extra_metas_instruction(
+        &source_token_account,
+        &mint,
+        &destination_token_account,
+        &source_owner.pubkey(),
+        &holder_rewards_pool,
+        &source_holder_rewards,
+        &destination_holder_rewards,
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[test_case(true, false; "Destination holder rewards uninitialized")]
+#[test_case(false, true; "Source holder rewards uninitialized")]
+#[test_case(false, false; "Both holder rewards uninitialized")]
+#[tokio::test]
+async fn success_uninitialized_holder_rewards(
+    source_initialized: bool,
+    destination_initialized: bool,
+) {
+    let source_owner = Keypair::new();
+    let destination_owner = Pubkey::new_unique();
+
+    let transfer_amount = 10;
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_000; // 1 reward per token.
+
+    let pool_addresses = PoolAddresses::new();
+    let source_addresses = HolderAddresses::new(&source_owner.pubkey(), &pool_addresses.mint);
+    let destination_addresses = HolderAddresses::new(&destination_owner, &pool_addresses.mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_extra_metas_account(&mut context, &pool_addresses.mint).await;
+    setup_holder_rewards_pool_account(
+        &mut context,
+        &pool_addresses.holder_rewards_pool,
+        0,
+        accumulated_rewards_per_token,
+    )
+    .await;
+    if source_initialized {
+        setup_holder_rewards_account(
+            &mut context,
+            &source_addresses.holder_rewards,
+            &pool_addresses.mint,
+            0,
+            500_000_000_000_000_000, // 0.5 rewards per token.
+            100,
+        )
+        .await;
+    }
+    if destination_initialized {
+        setup_holder_rewards_account(
+            &mut context,
+            &destination_addresses.holder_rewards,
+            &pool_addresses.mint,
+            0,
+            500_000_000_000_000_000, // 0.5 rewards per token.
+            100,
+        )
+        .await;
+    }
+    setup_token_account(
+        &mut context,
+        &source_addresses.token_account,
+        &source_owner.pubkey(),
+        &pool_addresses.mint,
+        100,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &destination_addresses.token_account,
+        &destination_owner,
+        &pool_addresses.mint,
+        100,
+    )
+    .await;
+    setup_mint(
+        &mut context,
+        &pool_addresses.mint,
+        &Pubkey::new_unique(),
+        100_000,
+    )
+    .await;
+
+    let instruction = transfer_with_extra_metas_instruction(
+        &mut context,
+        &source_addresses.token_account,
+        &pool_addresses.mint,
+        &destination_addresses.token_account,
+        &source_addresses.owner,
+        transfer_amount,
+        0, // Decimals.
+    )
+    .await;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert any initialized holder was settled as usual, while any
+    // uninitialized holder rewards account was left for the holder to
+    // initialize later.
+    for (addresses, initialized, expected_last_seen_balance) in [
+        (&source_addresses, source_initialized, 90),
+        (&destination_addresses, destination_initialized, 110),
+    ] {
+        let holder_rewards_account = context
+            .banks_client
+            .get_account(addresses.holder_rewards)
+            .await
+            .unwrap();
+        if initialized {
+            assert_eq!(
+                unpack_versioned::<HolderRewards>(&holder_rewards_account.unwrap().data).unwrap(),
+                &HolderRewards {
+                    mint: pool_addresses.mint,
+                    ..HolderRewards::new(
+                        accumulated_rewards_per_token,
+                        50, // (1 - 0.5) * 100 = 50
+                        expected_last_seen_balance,
+                    )
+                },
+            );
+        } else {
+            assert!(holder_rewards_account.is_none());
+        }
+    }
+}
+
+#[tokio::test]
+async fn fail_source_token_holder_rewards_incorrect_address() {
+    let source_owner = Keypair::new();
+    let destination_owner = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let transfer_amount = 10;
+
+    let pool = Pool {
+        accumulated_rewards_per_token: 0,
+    };
+    let holder = Holder {
+        token_account_balance: 100,
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 0,
+        unharvested_rewards: 0,
+        expected_unharvested_rewards: 0,
+    };
+
+    let pool_addresses = PoolAddresses::new();
+    let source_addresses = HolderAddresses::new(&source_owner.pubkey(), &pool_addresses.mint);
+    let destination_addresses = HolderAddresses::new(&destination_owner, &pool_addresses.mint);
+
+    let token_rewards_pool = get_token_rewards_pool_address(&pool_addresses.mint, &reward_mint);
+    let source_token_holder_rewards = Pubkey::new_unique(); // Incorrect address.
+    let destination_token_holder_rewards =
+        get_token_holder_rewards_address(&destination_addresses.token_account, &token_rewards_pool);
+
+    let mut context = setup().start_with_context().await;
+    setup_direct_invoke(
+        &mut context,
+        &pool,
+        &pool_addresses,
+        &holder,
+        &source_addresses,
+        &holder,
+        &destination_addresses,
+        transfer_amount,
+    )
+    .await;
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(0, reward_mint, spl_token::id()),
+    )
+    .await;
+    setup_token_holder_rewards_account(&mut context, &source_token_holder_rewards, 0, 0, 100).await;
+    setup_token_holder_rewards_account(&mut context, &destination_token_holder_rewards, 0, 0, 100)
+        .await;
+
+    let mut instruction = execute_with_extra_metas_instruction(
+        &source_addresses.token_account,
+        &pool_addresses.mint,
+        &destination_addresses.token_account,
+        &source_addresses.owner,
+        &pool_addresses.holder_rewards_pool,
+        &source_addresses.holder_rewards,
+        &destination_addresses.holder_rewards,
+        transfer_amount,
+    );
+    instruction.accounts.extend_from_slice(&[
+        AccountMeta::new_readonly(token_rewards_pool, false),
+        AccountMeta::new(source_token_holder_rewards, false),
+        AccountMeta::new(destination_token_holder_rewards, false),
+    ]);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(PaladinRewardsError::IncorrectHolderRewardsAddress as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_with_token_rewards_pools() {
+    let source_owner = Keypair::new();
+    let destination_owner = Pubkey::new_unique();
+    let reward_mints = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let transfer_amount = 10;
+
+    let pool = Pool {
+        accumulated_rewards_per_token: 0,
+    };
+    let source = Holder {
+        token_account_balance: 100,
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 0,
+        unharvested_rewards: 0,
+        expected_unharvested_rewards: 0,
+    };
+    let destination = Holder {
+        token_account_balance: 50,
+        last_seen_balance: 50,
+        last_accumulated_rewards_per_token: 0,
+        unharvested_rewards: 0,
+        expected_unharvested_rewards: 0,
+    };
+
+    let pool_addresses = PoolAddresses::new();
+    let source_addresses = HolderAddresses::new(&source_owner.pubkey(), &pool_addresses.mint);
+    let destination_addresses = HolderAddresses::new(&destination_owner, &pool_addresses.mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_transfer_hook(
+        &mut context,
+        &pool,
+        &pool_addresses,
+        &source,
+        &source_addresses,
+        &destination,
+        &destination_addresses,
+    )
+    .await;
+    setup_extra_metas_account_with_token_rewards_pools(
+        &mut context,
+        &pool_addresses.mint,
+        &reward_mints,
+    )
+    .await;
+
+    // Each token rewards pool has a different rate, and neither holder has
+    // settled against it yet.
+    let rates = [1_000_000_000_000_000_000, 2_000_000_000_000_000_000]; // 1 and 2 reward tokens per token.
+    for (reward_mint, rate) in reward_mints.iter().zip(rates) {
+        let token_rewards_pool = get_token_rewards_pool_address(&pool_addresses.mint, reward_mint);
+        setup_token_rewards_pool_account(
+            &mut context,
+            &token_rewards_pool,
+            &TokenRewardsPool::new(rate, *reward_mint, spl_token::id()),
+        )
+        .await;
+        for (holder, holder_addresses) in [
+            (&source, &source_addresses),
+            (&destination, &destination_addresses),
+        ] {
+            setup_token_holder_rewards_account(
+                &mut context,
+                &get_token_holder_rewards_address(
+                    &holder_addresses.token_account,
+                    &token_rewards_pool,
+                ),
+                0,
+                0,
+                holder.last_seen_balance,
+            )
+            .await;
+        }
+    }
+
+    let instruction = transfer_with_extra_metas_instruction(
+        &mut context,
+        &source_addresses.token_account,
+        &pool_addresses.mint,
+        &destination_addresses.token_account,
+        &source_addresses.owner,
+        transfer_amount,
+        0, // Decimals.
+    )
+    .await;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Each token holder rewards account was settled against its token
+    // rewards pool, using the pre-transfer balance.
+    for (reward_mint, rate) in reward_mints.iter().zip(rates) {
+        let token_rewards_pool = get_token_rewards_pool_address(&pool_addresses.mint, reward_mint);
+        for (holder, holder_addresses, expected_last_seen_balance) in [
+            (
+                &source,
+                &source_addresses,
+                source.token_account_balance - transfer_amount,
+            ),
+            (
+                &destination,
+                &destination_addresses,
+                destination.token_account_balance + transfer_amount,
+            ),
+        ] {
+            let token_holder_rewards_account = context
+                .banks_client
+                .get_account(get_token_holder_rewards_address(
+                    &holder_addresses.token_account,
+                    &token_rewards_pool,
+                ))
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                bytemuck::from_bytes::<TokenHolderRewards>(&token_holder_rewards_account.data),
+                &TokenHolderRewards::new(
+                    rate,
+                    holder.token_account_balance * (rate / 1_000_000_000_000_000_000) as u64,
+                    expected_last_seen_balance,
+                ),
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn success_with_token_rewards_pool_excluded_holder() {
+    let source_owner = Keypair::new();
+    let destination_owner = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    let transfer_amount = 10;
+    let rate = 1_000_000_000_000_000_000; // 1 reward token per token.
+
+    let pool = Pool {
+        accumulated_rewards_per_token: 0,
+    };
+    let holder = Holder {
+        token_account_balance: 100,
+        last_seen_balance: 100,
+        last_accumulated_rewards_per_token: 0,
+        unharvested_rewards: 0,
+        expected_unharvested_rewards: 0,
+    };
+
+    let pool_addresses = PoolAddresses::new();
+    let source_addresses = HolderAddresses::new(&source_owner.pubkey(), &pool_addresses.mint);
+    let destination_addresses = HolderAddresses::new(&destination_owner, &pool_addresses.mint);
+    let token_rewards_pool = get_token_rewards_pool_address(&pool_addresses.mint, &reward_mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_transfer_hook(
+        &mut context,
+        &pool,
+        &pool_addresses,
+        &holder,
+        &source_addresses,
+        &holder,
+        &destination_addresses,
+    )
+    .await;
+    setup_extra_metas_account_with_token_rewards_pools(
+        &mut context,
+        &pool_addresses.mint,
+        &[reward_mint],
+    )
+    .await;
+
+    // The source is excluded, so its balance is counted in the pool's
+    // excluded supply.
+    let mut pool_state = HolderRewardsPool::new(0, Pubkey::new_unique());
+    pool_state.excluded_supply = holder.last_seen_balance;
+    pool_state.excluded_holders = 1;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &pool_addresses.holder_rewards_pool,
+        0,
+        &pool_state,
+    )
+    .await;
+    let mut source_state = HolderRewards::new(0, 0, holder.last_seen_balance);
+    source_state.excluded = 1;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &source_addresses.holder_rewards,
+        &source_state,
+    )
+    .await;
+
+    setup_token_rewards_pool_account(
+        &mut context,
+        &token_rewards_pool,
+        &TokenRewardsPool::new(rate, reward_mint, spl_token::id()),
+    )
+    .await;
+    for holder_addresses in [&source_addresses, &destination_addresses] {
+        setup_token_holder_rewards_account(
+            &mut context,
+            &get_token_holder_rewards_address(&holder_addresses.token_account, &token_rewards_pool),
+            0,
+            0,
+            holder.last_seen_balance,
+        )
+        .await;
+    }
+
+    let instruction = transfer_with_extra_metas_instruction(
+        &mut context,
+        &source_addresses.token_account,
+        &pool_addresses.mint,
+        &destination_addresses.token_account,
+        &source_addresses.owner,
+        transfer_amount,
+        0, // Decimals.
+    )
+    .await;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // The excluded source earned nothing from the token rewards pool, while
+    // the destination earned on its pre-transfer balance.
+    for (holder_addresses, expected_unharvested_rewards, expected_last_seen_balance) in [
+        (
+            &source_addresses,
+            0,
+            holder.token_account_balance - transfer_amount,
+        ),
+        (
+            &destination_addresses,
+            holder.token_account_balance,
+            holder.token_account_balance + transfer_amount,
+        ),
+    ] {
+        let token_holder_rewards_account = context
+            .banks_client
+            .get_account(get_token_holder_rewards_address(
+                &holder_addresses.token_account,
+                &token_rewards_pool,
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            bytemuck::from_bytes::<TokenHolderRewards>(&token_holder_rewards_account.data),
+            &TokenHolderRewards::new(
+                rate,
+                expected_unharvested_rewards,
+                expected_last_seen_balance,
+            ),
+        );
+    }
+}
+
+#[tokio::test]
+async fn success_auto_claim() {
+    let source_owner = Keypair::new();
+    let destination_owner = Pubkey::new_unique();
+
+    let transfer_amount = 10;
+    let accumulated_rewards_per_token = 1_000_000_000_000_000_000; // 1 reward per token.
+
+    let pool_addresses = PoolAddresses::new();
+    let source_addresses = HolderAddresses::new(&source_owner.pubkey(), &pool_addresses.mint);
+    let destination_addresses = HolderAddresses::new(&destination_owner, &pool_addresses.mint);
+    let source_escrow = get_holder_rewards_escrow_address(&source_addresses.token_account);
+    let destination_escrow =
+        get_holder_rewards_escrow_address(&destination_addresses.token_account);
+
+    // Both holders accrue 50 rewards, which reaches only the source's
+    // threshold.
+    let pool = HolderRewardsPool::new(accumulated_rewards_per_token, Pubkey::new_unique());
+    let mut source = HolderRewards::new(500_000_000_000_000_000, 0, 100);
+    source.auto_claim_threshold = 50;
+    let mut destination = HolderRewards::new(500_000_000_000_000_000, 0, 100);
+    destination.auto_claim_threshold = 100;
+
+    let mut context = setup().start_with_context().await;
+    setup_extra_metas_account(&mut context, &pool_addresses.mint).await;
+    setup_holder_rewards_pool_account_with_state(
+        &mut context,
+        &pool_addresses.holder_rewards_pool,
+        100,
+        &pool,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &source_addresses.holder_rewards,
+        &source,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(
+        &mut context,
+        &destination_addresses.holder_rewards,
+        &destination,
+    )
+    .await;
+    setup_holder_rewards_escrow_account(&mut context, &source_escrow, 0).await;
+    setup_holder_rewards_escrow_account(&mut context, &destination_escrow, 0).await;
+    setup_token_account(
+        &mut context,
+        &source_addresses.token_account,
+        &source_owner.pubkey(),
+        &pool_addresses.mint,
+        100,
+    )
+    .await;
+    setup_token_account(
+        &mut context,
+        &destination_addresses.token_account,
+        &destination_owner,
+        &pool_addresses.mint,
+        100,
+    )
+    .await;
+    setup_mint(
+        &mut context,
+        &pool_addresses.mint,
+        &Pubkey::new_unique(),
+        1_000,
+    )
+    .await;
+
+    let instruction = transfer_with_extra_metas_instruction(
+        &mut context,
+        &source_addresses.token_account,
+        &pool_addresses.mint,
+        &destination_addresses.token_account,
+        &source_addresses.owner,
+        transfer_amount,
+        0, // Decimals.
+    )
+    .await;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source_owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the source's rewards were paid out, while the destination's
+    // were left unharvested.
+    let mut expected_source = HolderRewards::new(accumulated_rewards_per_token, 0, 90);
+    expected_source.auto_claim_threshold = 50;
+    let source_holder_rewards_account = context
+        .banks_client
+        .get_account(source_addresses.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&source_holder_rewards_account.data).unwrap(),
+        &expected_source,
+    );
+    let mut expected_destination = HolderRewards::new(accumulated_rewards_per_token, 50, 110);
+    expected_destination.auto_claim_threshold = 100;
+    let destination_holder_rewards_account = context
+        .banks_client
+        .get_account(destination_addresses.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&destination_holder_rewards_account.data).unwrap(),
+        &expected_destination,
+    );
+
+    // Assert the source's rewards were moved to its escrow account.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let source_escrow_account = context
+        .banks_client
+        .get_account(source_escrow)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(source_escrow_account.lamports, rent.minimum_balance(0) + 50);
+    let destination_escrow_account = context
+        .banks_client
+        .get_account(destination_escrow)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(destination_escrow_account.lamports, rent.minimum_balance(0));
+
+    // Assert the pool recorded the harvested rewards.
+    let holder_rewards_pool_account = context
+        .banks_client
+        .get_account(pool_addresses.holder_rewards_pool)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        holder_rewards_pool_account.lamports,
+        rent.minimum_balance(HolderRewardsPool::LEN) + 50,
+    );
+    assert_eq!(
+        unpack_versioned::<HolderRewardsPool>(&holder_rewards_pool_account.data)
+            .unwrap()
+            .total_harvested,
+        50,
+    );
+}
+
+/// Sets up a transfer, directly invoking the program, where the source has
+/// accrued rewards reaching its auto-claim threshold.
+async fn setup_auto_claim_direct_invoke(
+    context: &mut ProgramTestContext,
+    pool_addresses: &PoolAddresses,
+    source_addresses: &HolderAddresses,
+    destination_addresses: &HolderAddresses,
+    transfer_amount: u64,
+) {
+    let pool = HolderRewardsPool::new(1_000_000_000_000_000_000, Pubkey::new_unique());
+    let mut source = HolderRewards::new(500_000_000_000_000_000, 0, 100);
+    source.auto_claim_threshold = 50;
+    let destination = HolderRewards::new(500_000_000_000_000_000, 0, 100);
+
+    setup_holder_rewards_pool_account_with_state(
+        context,
+        &pool_addresses.holder_rewards_pool,
+        100,
+        &pool,
+    )
+    .await;
+    setup_holder_rewards_account_with_state(context, &source_addresses.holder_rewards, &source)
+        .await;
+    setup_holder_rewards_account_with_state(
+        context,
+        &destination_addresses.holder_rewards,
+        &destination,
+    )
+    .await;
+    setup_token_account_transferring(
+        context,
+        &source_addresses.token_account,
+        &source_addresses.owner,
+        &pool_addresses.mint,
+        100 - transfer_amount, // Post-transfer balance.
+    )
+    .await;
+    setup_token_account_transferring(
+        context,
+        &destination_addresses.token_account,
+        &destination_addresses.owner,
+        &pool_addresses.mint,
+        100 + transfer_amount, // Post-transfer balance.
+    )
+    .await;
+    setup_mint(context, &pool_addresses.mint, &Pubkey::new_unique(), 1_000).await;
+}
+
+#[tokio::test]
+async fn fail_auto_claim_escrow_incorrect_address() {
+    let transfer_amount = 10;
+
+    let pool_addresses = PoolAddresses::new();
+    let source_addresses = HolderAddresses::new(&Pubkey::new_unique(), &pool_addresses.mint);
+    let destination_addresses = HolderAddresses::new(&Pubkey::new_unique(), &pool_addresses.mint);
+    let source_escrow = Pubkey::new_unique(); // Incorrect escrow address.
+    let destination_escrow =
+        get_holder_rewards_escrow_address(&destination_addresses.token_account);
+
+    let mut context = setup().start_with_context().await;
+    setup_auto_claim_direct_invoke(
+        &mut context,
+        &pool_addresses,
+        &source_addresses,
+        &destination_addresses,
+        transfer_amount,
+    )
+    .await;
+    setup_holder_rewards_escrow_account(&mut context, &source_escrow, 0).await;
+
+    let mut instruction = execute_with_extra_metas_instruction(
+        &source_addresses.token_account,
+        &pool_addresses.mint,
+        &destination_addresses.token_account,
+        &source_addresses.owner,
+        &pool_addresses.holder_rewards_pool,
+        &source_addresses.holder_rewards,
+        &destination_addresses.holder_rewards,
+        transfer_amount,
+    );
+    instruction.accounts.extend([
+        AccountMeta::new(source_escrow, false),
+        AccountMeta::new(destination_escrow, false),
+    ]);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(
+                PaladinRewardsError::IncorrectHolderRewardsEscrowAddress as u32
+            )
+        )
+    );
+}
+
+#[tokio::test]
+async fn success_auto_claim_without_escrow_accounts() {
+    let transfer_amount = 10;
+
+    let pool_addresses = PoolAddresses::new();
+    let source_addresses = HolderAddresses::new(&Pubkey::new_unique(), &pool_addresses.mint);
+    let destination_addresses = HolderAddresses::new(&Pubkey::new_unique(), &pool_addresses.mint);
+
+    let mut context = setup().start_with_context().await;
+    setup_auto_claim_direct_invoke(
+        &mut context,
+        &pool_addresses,
+        &source_addresses,
+        &destination_addresses,
+        transfer_amount,
+    )
+    .await;
+
+    // Extra metas written before auto-claim was introduced don't include the
+    // escrow accounts.
+    let instruction = execute_with_extra_metas_instruction(
+        &source_addresses.token_account,
+        &pool_addresses.mint,
+        &destination_addresses.token_account,
+        &source_addresses.owner,
+        &pool_addresses.holder_rewards_pool,
+        &source_addresses.holder_rewards,
+        &destination_addresses.holder_rewards,
+        transfer_amount,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Assert the source's rewards were left unharvested.
+    let mut expected_source = HolderRewards::new(1_000_000_000_000_000_000, 50, 90);
+    expected_source.auto_claim_threshold = 50;
+    let source_holder_rewards_account = context
+        .banks_client
+        .get_account(source_addresses.holder_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        unpack_versioned::<HolderRewards>(&source_holder_rewards_account.data).unwrap(),
+        &expected_source,
+    );
+}
@@ -19,6 +19,45 @@ kinobi.update(
   })
 );
 
+// Versioned accounts are prefixed with an account header.
+kinobi.update(
+  k.bottomUpTransformerVisitor(
+    ["holderRewards", "holderRewardsPool"].map((name) => ({
+      select: `[accountNode]${name}`,
+      transform: (node) => {
+        k.assertIsNode(node, "accountNode");
+        return k.accountNode({
+          ...node,
+          data: k.structTypeNode([
+            k.structFieldTypeNode({
+              name: "header",
+              type: k.definedTypeLinkNode("accountHeader"),
+            }),
+            ...node.data.fields,
+          ]),
+        });
+      },
+    }))
+  )
+);
+
+// Trailing optional accounts are omitted rather than replaced by the
+// program ID.
+kinobi.update(
+  k.bottomUpTransformerVisitor([
+    {
+      select: "[instructionNode]",
+      transform: (node) => {
+        k.assertIsNode(node, "instructionNode");
+        return k.instructionNode({
+          ...node,
+          optionalAccountStrategy: "omitted",
+        });
+      },
+    },
+  ])
+);
+
 // Render JavaScript.
 const jsClient = path.join(__dirname, "..", "clients", "js");
 kinobi.accept(